@@ -5,7 +5,7 @@ mod h256;
 mod heap_address;
 mod heap_h256;
 
-pub use address::Address;
+pub use address::{Address, SC_ADDRESS_NUM_LEADING_ZEROS, VM_TYPE_LEN};
 pub use boxed_bytes::BoxedBytes;
 pub use flags::*;
 pub use h256::H256;
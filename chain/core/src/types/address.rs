@@ -2,7 +2,8 @@ use super::H256;
 use alloc::{boxed::Box, vec::Vec};
 use core::fmt::Debug;
 
-const SC_ADDRESS_NUM_LEADING_ZEROS: u8 = 8;
+/// All smart contract addresses start with this many zero bytes.
+pub const SC_ADDRESS_NUM_LEADING_ZEROS: u8 = 8;
 pub const NUM_INT_CHARACTERS_FOR_ADDRESS: usize = 10;
 pub const VM_TYPE_LEN: usize = 2;
 pub const DEFAULT_VM_TYPE: &[u8] = &[5, 0];
@@ -1,10 +1,8 @@
-use super::{heap_h256::HeapH256, BoxedBytes};
+use super::{heap_h256::HeapH256, BoxedBytes, SC_ADDRESS_NUM_LEADING_ZEROS};
 
 use alloc::{boxed::Box, vec::Vec};
 use core::fmt::Debug;
 
-const SC_ADDRESS_NUM_LEADING_ZEROS: u8 = 8;
-
 /// Old smart contracts were using this Address implementation,
 /// which was explicitly relying on the heap, to avoid large data copies on the stack.
 ///
@@ -1,8 +1,20 @@
+mod crypto_bls;
+mod crypto_secp256k1;
+mod crypto_secp256r1;
+mod elliptic_curve;
+
+pub use crypto_bls::*;
+pub use crypto_secp256k1::*;
+pub use crypto_secp256r1::*;
+pub use elliptic_curve::*;
+
+use ripemd::Ripemd160;
 use sha2::Sha256;
 use sha3::{Digest, Keccak256};
 
 pub const SHA256_RESULT_LEN: usize = 32;
 pub const KECCAK256_RESULT_LEN: usize = 32;
+pub const RIPEMD160_RESULT_LEN: usize = 20;
 
 pub fn sha256(data: &[u8]) -> [u8; SHA256_RESULT_LEN] {
     let mut hasher = Sha256::new();
@@ -16,6 +28,12 @@ pub fn keccak256(data: &[u8]) -> [u8; KECCAK256_RESULT_LEN] {
     hasher.finalize().into()
 }
 
+pub fn ripemd160(data: &[u8]) -> [u8; RIPEMD160_RESULT_LEN] {
+    let mut hasher = Ripemd160::new();
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
 pub fn verify_ed25519(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
     use ed25519_dalek::{Signature, Verifier, VerifyingKey};
 
@@ -0,0 +1,286 @@
+use bls12_381::{pairing, G1Affine, G1Projective, G2Affine, G2Projective, Scalar};
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use sha2::{Digest, Sha512};
+
+/// Compressed G2 point.
+pub const BLS_PUBLIC_KEY_LEN: usize = 96;
+
+/// Compressed G1 point.
+pub const BLS_SIGNATURE_LEN: usize = 48;
+
+/// Size of a field element, in bytes.
+const BLS_FIELD_ELEMENT_LEN: usize = 48;
+
+/// Size of the field order, in bits.
+const BLS_FIELD_BIT_SIZE: u64 = 381;
+
+/// Order of the field of BLS12-381, in hex.
+const BLS_FIELD_ORDER: &[u8] = b"1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab";
+
+/// Constant of the G1 curve equation: y² = x³ + 4.
+const BLS_G1_CURVE_B: u32 = 4;
+
+/// Cofactor of G1, (z - 1)² / 3, multiplied into mapped points to get them into the subgroup.
+const BLS_G1_COFACTOR: u128 = 0x396c8c005555e1568c00aaab0000aaab;
+
+/// Generator of the public keys in the Herumi library, the image of 1 under its map to G2.
+///
+/// It is not the standard G2 generator. Given here in the standard (ZCash) compressed form.
+const BLS_PUBLIC_KEY_GENERATOR: [u8; BLS_PUBLIC_KEY_LEN] = hex_literal::hex!(
+    "b71df7a5080f908a16c2658ea90164e28c924c3f0e6655f6d82adca6bfbdfb5f9efca82c1609676fa15cd30396f1a4b30f3d011af81acf00140aab3c122c61bbdf0628db81c37664bdfc828163ce074ee33a1a5ce5488556603bc5d8d9f21ecc"
+);
+
+/// In the Herumi serialization, the top bit of the last byte flags an odd y coordinate.
+const HERUMI_Y_ODD_FLAG: u8 = 0x80;
+
+/// In the standard serialization, the top bit of the first byte flags a compressed point.
+const ZCASH_COMPRESSION_FLAG: u8 = 0x80;
+
+fn field_order() -> BigUint {
+    BigUint::parse_bytes(BLS_FIELD_ORDER, 16).unwrap()
+}
+
+/// Arithmetic in the base field, only what the map to G1 needs.
+struct BlsField {
+    p: BigUint,
+}
+
+impl BlsField {
+    fn new() -> Self {
+        BlsField { p: field_order() }
+    }
+
+    fn add(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + b) % &self.p
+    }
+
+    fn sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + &self.p - b) % &self.p
+    }
+
+    fn mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a * b) % &self.p
+    }
+
+    fn neg(&self, a: &BigUint) -> BigUint {
+        self.sub(&BigUint::zero(), a)
+    }
+
+    fn inv(&self, a: &BigUint) -> BigUint {
+        a.modpow(&(&self.p - 2u32), &self.p)
+    }
+
+    fn is_square(&self, a: &BigUint) -> bool {
+        a.modpow(&((&self.p - 1u32) >> 1), &self.p) == BigUint::one()
+    }
+
+    /// The field order is 3 mod 4, so a^((p + 1) / 4) is a root, if there is one.
+    fn sqrt(&self, a: &BigUint) -> Option<BigUint> {
+        let root = a.modpow(&((&self.p + 1u32) >> 2), &self.p);
+        if self.mul(&root, &root) == *a {
+            Some(root)
+        } else {
+            None
+        }
+    }
+
+    fn g1_curve_rhs(&self, x: &BigUint) -> BigUint {
+        self.add(
+            &self.mul(&self.mul(x, x), x),
+            &BigUint::from(BLS_G1_CURVE_B),
+        )
+    }
+
+    /// The hash of the message, as a field element, the way Herumi's `setHashOf` computes it.
+    ///
+    /// The SHA-512 digest is read as a little endian number and masked to the bit size of the field.
+    /// If that is still too large, one more bit is masked off.
+    fn hash_to_field(&self, message: &[u8]) -> BigUint {
+        let digest = Sha512::digest(message);
+        let mut t = BigUint::from_bytes_le(&digest[..BLS_FIELD_ELEMENT_LEN]);
+        t &= (BigUint::one() << BLS_FIELD_BIT_SIZE) - 1u32;
+        if t >= self.p {
+            t &= (BigUint::one() << (BLS_FIELD_BIT_SIZE - 1)) - 1u32;
+        }
+        t
+    }
+
+    /// The Shallue-van de Woestijne map for BN curves, as implemented in Herumi's `MapTo::calcBN`.
+    ///
+    /// Yields a point on the curve, which is not yet in the G1 subgroup.
+    fn map_to_curve(&self, t: &BigUint) -> Option<(BigUint, BigUint)> {
+        if t.is_zero() {
+            return None;
+        }
+        let negative = !self.is_square(t);
+        let one = BigUint::one();
+
+        let c1 = self.sqrt(&self.neg(&BigUint::from(3u32)))?;
+        let c2 = self.mul(&self.sub(&c1, &one), &self.inv(&BigUint::from(2u32)));
+
+        let w = self.add(
+            &self.add(&self.mul(t, t), &BigUint::from(BLS_G1_CURVE_B)),
+            &one,
+        );
+        if w.is_zero() {
+            return None;
+        }
+        let w = self.mul(&self.mul(&self.inv(&w), &c1), t);
+
+        let x1 = self.sub(&c2, &self.mul(t, &w));
+        let x2 = self.sub(&self.neg(&x1), &one);
+        let x3 = self.add(&self.inv(&self.mul(&w, &w)), &one);
+        for x in [x1, x2, x3] {
+            if let Some(y) = self.sqrt(&self.g1_curve_rhs(&x)) {
+                let y = if negative { self.neg(&y) } else { y };
+                return Some((x, y));
+            }
+        }
+        None
+    }
+}
+
+fn field_element_to_be(value: &BigUint) -> [u8; BLS_FIELD_ELEMENT_LEN] {
+    let bytes = value.to_bytes_be();
+    let mut result = [0u8; BLS_FIELD_ELEMENT_LEN];
+    result[BLS_FIELD_ELEMENT_LEN - bytes.len()..].copy_from_slice(&bytes);
+    result
+}
+
+/// Maps the message to the G1 point that gets signed, the way Herumi's `hashAndMapToG1` does.
+pub fn bls_hash_message(message: &[u8]) -> Option<G1Affine> {
+    let field = BlsField::new();
+    let (x, y) = field.map_to_curve(&field.hash_to_field(message))?;
+
+    let mut uncompressed = [0u8; 2 * BLS_FIELD_ELEMENT_LEN];
+    uncompressed[..BLS_FIELD_ELEMENT_LEN].copy_from_slice(&field_element_to_be(&x));
+    uncompressed[BLS_FIELD_ELEMENT_LEN..].copy_from_slice(&field_element_to_be(&y));
+    let point = Option::<G1Affine>::from(G1Affine::from_uncompressed_unchecked(&uncompressed))?;
+
+    let cofactor = Scalar::from_raw([BLS_G1_COFACTOR as u64, (BLS_G1_COFACTOR >> 64) as u64, 0, 0]);
+    Some(G1Affine::from(G1Projective::from(point) * cofactor))
+}
+
+/// The generator that public keys are multiples of.
+pub fn bls_public_key_generator() -> G2Affine {
+    G2Affine::from_compressed(&BLS_PUBLIC_KEY_GENERATOR).unwrap()
+}
+
+/// Converts a Herumi field element (little endian) to the standard big endian form.
+///
+/// Returns `None` if the value is not a field element.
+fn herumi_to_zcash_field_element(le_bytes: &[u8]) -> Option<[u8; BLS_FIELD_ELEMENT_LEN]> {
+    let value = BigUint::from_bytes_le(le_bytes);
+    if value >= field_order() {
+        return None;
+    }
+    Some(field_element_to_be(&value))
+}
+
+/// Splits off the y parity flag, the rest is the x coordinate.
+fn split_herumi_y_odd_flag<const N: usize>(bytes: &[u8]) -> Option<([u8; N], bool)> {
+    let mut x_bytes: [u8; N] = bytes.try_into().ok()?;
+    let y_odd = x_bytes[N - 1] & HERUMI_Y_ODD_FLAG != 0;
+    x_bytes[N - 1] &= !HERUMI_Y_ODD_FLAG;
+    Some((x_bytes, y_odd))
+}
+
+/// Reads a compressed G1 point in the Herumi serialization, checking that it is in the subgroup.
+fn parse_herumi_g1(bytes: &[u8]) -> Option<G1Affine> {
+    let (x_bytes, y_odd) = split_herumi_y_odd_flag::<BLS_SIGNATURE_LEN>(bytes)?;
+    if x_bytes.iter().all(|b| *b == 0) && !y_odd {
+        return Some(G1Affine::identity());
+    }
+
+    let mut compressed = herumi_to_zcash_field_element(&x_bytes)?;
+    compressed[0] |= ZCASH_COMPRESSION_FLAG;
+    let point = Option::<G1Affine>::from(G1Affine::from_compressed(&compressed))?;
+
+    // the standard serialization picks y by a different rule, so the parity gets checked separately
+    let point_y_odd = point.to_uncompressed()[2 * BLS_FIELD_ELEMENT_LEN - 1] & 1 == 1;
+    if point_y_odd == y_odd {
+        Some(point)
+    } else {
+        Some(-point)
+    }
+}
+
+/// Reads a compressed G2 point in the Herumi serialization, checking that it is in the subgroup.
+///
+/// The x coordinate comes as its real part, followed by its imaginary part.
+/// The parity flag refers to the real part of y.
+fn parse_herumi_g2(bytes: &[u8]) -> Option<G2Affine> {
+    let (x_bytes, y_odd) = split_herumi_y_odd_flag::<BLS_PUBLIC_KEY_LEN>(bytes)?;
+    if x_bytes.iter().all(|b| *b == 0) && !y_odd {
+        return Some(G2Affine::identity());
+    }
+
+    let (x_real, x_imaginary) = x_bytes.split_at(BLS_FIELD_ELEMENT_LEN);
+    let mut compressed = [0u8; BLS_PUBLIC_KEY_LEN];
+    compressed[..BLS_FIELD_ELEMENT_LEN]
+        .copy_from_slice(&herumi_to_zcash_field_element(x_imaginary)?);
+    compressed[BLS_FIELD_ELEMENT_LEN..].copy_from_slice(&herumi_to_zcash_field_element(x_real)?);
+    compressed[0] |= ZCASH_COMPRESSION_FLAG;
+    let point = Option::<G2Affine>::from(G2Affine::from_compressed(&compressed))?;
+
+    let point_y_real_odd = point.to_uncompressed()[4 * BLS_FIELD_ELEMENT_LEN - 1] & 1 == 1;
+    if point_y_real_odd == y_odd {
+        Some(point)
+    } else {
+        Some(-point)
+    }
+}
+
+fn parse_bls_public_key(key: &[u8]) -> Option<G2Affine> {
+    let public_key = parse_herumi_g2(key)?;
+    if bool::from(public_key.is_identity()) {
+        return None;
+    }
+    Some(public_key)
+}
+
+fn verify_bls_pairing(public_key: &G2Affine, message: &[u8], signature: &G1Affine) -> bool {
+    let Some(hashed_message) = bls_hash_message(message) else {
+        return false;
+    };
+    pairing(signature, &bls_public_key_generator()) == pairing(&hashed_message, public_key)
+}
+
+/// Verifies a BLS12-381 signature, with public keys in G2 and signatures in G1.
+///
+/// Follows the Herumi BLS library used by the Go VM: its point serialization,
+/// its map from messages to G1 and its generator of public keys.
+pub fn verify_bls(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let (Some(public_key), Some(signature)) =
+        (parse_bls_public_key(key), parse_herumi_g1(signature))
+    else {
+        return false;
+    };
+    verify_bls_pairing(&public_key, message, &signature)
+}
+
+/// A signature share is verified against the public key share, just like a regular signature.
+pub fn verify_bls_signature_share(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    verify_bls(key, message, signature)
+}
+
+/// Verifies a signature aggregated from the signatures of all the keys over the same message.
+pub fn verify_bls_aggregated_signature(keys: &[Vec<u8>], message: &[u8], signature: &[u8]) -> bool {
+    if keys.is_empty() {
+        return false;
+    }
+    let Some(signature) = parse_herumi_g1(signature) else {
+        return false;
+    };
+
+    let mut aggregated_key = G2Projective::identity();
+    for key in keys {
+        let Some(public_key) = parse_bls_public_key(key) else {
+            return false;
+        };
+        aggregated_key += public_key;
+    }
+
+    verify_bls_pairing(&G2Affine::from(aggregated_key), message, &signature)
+}
@@ -0,0 +1,91 @@
+use k256::ecdsa::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey};
+use num_bigint::BigUint;
+
+use super::{keccak256, ripemd160, sha256};
+
+/// Same encoding as the `MessageHashType` in the framework.
+pub const SECP256K1_HASH_TYPE_PLAIN_MSG: u8 = 0;
+pub const SECP256K1_HASH_TYPE_SHA256: u8 = 1;
+pub const SECP256K1_HASH_TYPE_DOUBLE_SHA256: u8 = 2;
+pub const SECP256K1_HASH_TYPE_KECCAK256: u8 = 3;
+pub const SECP256K1_HASH_TYPE_RIPEMD160: u8 = 4;
+
+/// The order of the secp256k1 base point, needed to canonicalize signatures.
+const SECP256K1_ORDER_HEX: &str =
+    "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+
+/// The signature is DER-encoded.
+///
+/// The first byte is the DER sequence tag, the second one the length of the rest,
+/// so the signature length can always be deduced from its first 2 bytes.
+pub fn secp256k1_der_signature_len(signature_prefix: &[u8]) -> usize {
+    if signature_prefix.len() < 2 {
+        return signature_prefix.len();
+    }
+    signature_prefix[1] as usize + 2
+}
+
+fn hash_secp256k1_message(message: &[u8], hash_type: u8) -> Vec<u8> {
+    match hash_type {
+        SECP256K1_HASH_TYPE_SHA256 => sha256(message).to_vec(),
+        SECP256K1_HASH_TYPE_DOUBLE_SHA256 => sha256(&sha256(message)).to_vec(),
+        SECP256K1_HASH_TYPE_KECCAK256 => keccak256(message).to_vec(),
+        SECP256K1_HASH_TYPE_RIPEMD160 => ripemd160(message).to_vec(),
+        _ => message.to_vec(),
+    }
+}
+
+/// Verifies a DER-encoded secp256k1 signature.
+///
+/// The message is first hashed according to the hash type, unless it is the plain message type,
+/// in which case the message is expected to already be a hash.
+///
+/// The key can be either compressed or uncompressed. High-S signatures are accepted too.
+pub fn verify_secp256k1(key: &[u8], message: &[u8], signature: &[u8], hash_type: u8) -> bool {
+    let Ok(verifying_key) = VerifyingKey::from_sec1_bytes(key) else {
+        return false;
+    };
+    let Ok(sig) = Signature::from_der(signature) else {
+        return false;
+    };
+    let sig = sig.normalize_s().unwrap_or(sig);
+
+    let message_hash = hash_secp256k1_message(message, hash_type);
+    verifying_key.verify_prehash(&message_hash, &sig).is_ok()
+}
+
+/// Encodes the r and s components of a secp256k1 signature as DER.
+///
+/// Same as the chain, the signature is canonicalized to its low-S form.
+pub fn encode_secp256k1_der_signature(r: &[u8], s: &[u8]) -> Vec<u8> {
+    let order = BigUint::parse_bytes(SECP256K1_ORDER_HEX.as_bytes(), 16).unwrap();
+    let mut s_value = BigUint::from_bytes_be(s);
+    if s_value > &order >> 1 && s_value < order {
+        s_value = &order - s_value;
+    }
+
+    let r_der = der_integer(r);
+    let s_der = der_integer(&s_value.to_bytes_be());
+
+    let mut result = Vec::with_capacity(2 + r_der.len() + s_der.len());
+    result.push(0x30);
+    result.push((r_der.len() + s_der.len()) as u8);
+    result.extend_from_slice(&r_der);
+    result.extend_from_slice(&s_der);
+    result
+}
+
+/// Minimal DER encoding of an unsigned integer.
+fn der_integer(bytes: &[u8]) -> Vec<u8> {
+    let first_non_zero = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    let mut value = bytes[first_non_zero..].to_vec();
+    if value.first().is_none_or(|b| b & 0x80 != 0) {
+        value.insert(0, 0);
+    }
+
+    let mut result = Vec::with_capacity(2 + value.len());
+    result.push(0x02);
+    result.push(value.len() as u8);
+    result.extend_from_slice(&value);
+    result
+}
@@ -0,0 +1,21 @@
+use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+
+/// Verifies a secp256r1 (P-256) ECDSA signature over the SHA-256 hash of the message.
+///
+/// The key can be either compressed or uncompressed.
+/// The signature can be either DER-encoded, or the 64 bytes of r and s concatenated.
+pub fn verify_secp256r1(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let Ok(verifying_key) = VerifyingKey::from_sec1_bytes(key) else {
+        return false;
+    };
+    let sig_result = if signature.len() == 64 {
+        Signature::from_slice(signature)
+    } else {
+        Signature::from_der(signature)
+    };
+    let Ok(sig) = sig_result else {
+        return false;
+    };
+
+    verifying_key.verify(message, &sig).is_ok()
+}
@@ -0,0 +1,329 @@
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+/// Affine coordinates. The point at infinity is (0, 0), none of the supported curves contains it.
+pub type EllipticCurvePoint = (BigUint, BigUint);
+
+/// One of the NIST curves supported by the VM: y² = x³ - 3x + b, over the prime field of order p.
+///
+/// The arithmetic follows the generic implementation in Go's `crypto/elliptic`,
+/// so that the results are the same as on the real VM.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EllipticCurve {
+    pub name: &'static str,
+
+    /// Order of the underlying field.
+    pub p: BigUint,
+
+    /// Order of the base point.
+    pub n: BigUint,
+
+    /// Constant of the curve equation.
+    pub b: BigUint,
+
+    /// Base point.
+    pub gx: BigUint,
+    pub gy: BigUint,
+
+    pub bit_size: u32,
+}
+
+struct EllipticCurveHexParams {
+    name: &'static str,
+    p: &'static str,
+    n: &'static str,
+    b: &'static str,
+    gx: &'static str,
+    gy: &'static str,
+    bit_size: u32,
+}
+
+const P224: EllipticCurveHexParams = EllipticCurveHexParams {
+    name: "p224",
+    p: "ffffffffffffffffffffffffffffffff000000000000000000000001",
+    n: "ffffffffffffffffffffffffffff16a2e0b8f03e13dd29455c5c2a3d",
+    b: "b4050a850c04b3abf54132565044b0b7d7bfd8ba270b39432355ffb4",
+    gx: "b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21",
+    gy: "bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34",
+    bit_size: 224,
+};
+
+const P256: EllipticCurveHexParams = EllipticCurveHexParams {
+    name: "p256",
+    p: "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+    n: "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+    b: "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+    gx: "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+    gy: "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+    bit_size: 256,
+};
+
+const P384: EllipticCurveHexParams = EllipticCurveHexParams {
+    name: "p384",
+    p: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff",
+    n: "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+    b: "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef",
+    gx: "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+    gy: "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+    bit_size: 384,
+};
+
+const P521: EllipticCurveHexParams = EllipticCurveHexParams {
+    name: "p521",
+    p: "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    n: "01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409",
+    b: "0051953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00",
+    gx: "00c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+    gy: "011839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650",
+    bit_size: 521,
+};
+
+fn parse_hex(hex: &str) -> BigUint {
+    BigUint::parse_bytes(hex.as_bytes(), 16).expect("invalid elliptic curve parameter")
+}
+
+fn is_infinity(point: &EllipticCurvePoint) -> bool {
+    point.0.is_zero() && point.1.is_zero()
+}
+
+fn infinity() -> EllipticCurvePoint {
+    (BigUint::zero(), BigUint::zero())
+}
+
+impl EllipticCurve {
+    /// Only "p224", "p256", "p384" and "p521" are supported.
+    pub fn from_name(name: &[u8]) -> Option<Self> {
+        let params = [P224, P256, P384, P521]
+            .into_iter()
+            .find(|params| params.name.as_bytes() == name)?;
+        Some(EllipticCurve {
+            name: params.name,
+            p: parse_hex(params.p),
+            n: parse_hex(params.n),
+            b: parse_hex(params.b),
+            gx: parse_hex(params.gx),
+            gy: parse_hex(params.gy),
+            bit_size: params.bit_size,
+        })
+    }
+
+    pub fn byte_len(&self) -> usize {
+        (self.bit_size as usize).div_ceil(8)
+    }
+
+    pub fn private_key_byte_len(&self) -> usize {
+        self.byte_len()
+    }
+
+    fn sub_mod(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + &self.p - (b % &self.p)) % &self.p
+    }
+
+    fn inv_mod(&self, a: &BigUint) -> BigUint {
+        a.modpow(&(&self.p - 2u32), &self.p)
+    }
+
+    /// x³ - 3x + b
+    fn polynomial(&self, x: &BigUint) -> BigUint {
+        let x3 = x * x * x;
+        let three_x = x * 3u32;
+        self.sub_mod(&(x3 + &self.b), &three_x)
+    }
+
+    pub fn is_on_curve(&self, x: &BigUint, y: &BigUint) -> bool {
+        if x >= &self.p || y >= &self.p {
+            return false;
+        }
+        (y * y) % &self.p == self.polynomial(x)
+    }
+
+    pub fn add(
+        &self,
+        first: &EllipticCurvePoint,
+        second: &EllipticCurvePoint,
+    ) -> EllipticCurvePoint {
+        if is_infinity(first) {
+            return second.clone();
+        }
+        if is_infinity(second) {
+            return first.clone();
+        }
+        let (x1, y1) = first;
+        let (x2, y2) = second;
+        if x1 == x2 {
+            if y1 == y2 {
+                return self.double(first);
+            }
+            return infinity();
+        }
+
+        let lambda = self.sub_mod(y2, y1) * self.inv_mod(&self.sub_mod(x2, x1)) % &self.p;
+        self.point_from_lambda(&lambda, x1, y1, x2)
+    }
+
+    pub fn double(&self, point: &EllipticCurvePoint) -> EllipticCurvePoint {
+        let (x, y) = point;
+        if is_infinity(point) || y.is_zero() {
+            return infinity();
+        }
+
+        let numerator = self.sub_mod(&(x * x * 3u32), &BigUint::from(3u32));
+        let lambda = numerator * self.inv_mod(&(y * 2u32)) % &self.p;
+        self.point_from_lambda(&lambda, x, y, x)
+    }
+
+    fn point_from_lambda(
+        &self,
+        lambda: &BigUint,
+        x1: &BigUint,
+        y1: &BigUint,
+        x2: &BigUint,
+    ) -> EllipticCurvePoint {
+        let x3 = self.sub_mod(&(lambda * lambda), &(x1 + x2));
+        let y3 = self.sub_mod(&(lambda * self.sub_mod(x1, &x3)), y1);
+        (x3, y3)
+    }
+
+    /// The scalar is big endian.
+    pub fn scalar_mult(&self, point: &EllipticCurvePoint, scalar: &[u8]) -> EllipticCurvePoint {
+        let mut result = infinity();
+        for byte in scalar {
+            for bit_index in (0..8).rev() {
+                result = self.double(&result);
+                if (byte >> bit_index) & 1 == 1 {
+                    result = self.add(&result, point);
+                }
+            }
+        }
+        result
+    }
+
+    pub fn scalar_base_mult(&self, scalar: &[u8]) -> EllipticCurvePoint {
+        self.scalar_mult(&(self.gx.clone(), self.gy.clone()), scalar)
+    }
+
+    /// Uncompressed form: 0x04, followed by x and y.
+    pub fn marshal(&self, x: &BigUint, y: &BigUint) -> Vec<u8> {
+        let mut result = vec![4u8];
+        result.extend_from_slice(&self.fixed_len_bytes(x));
+        result.extend_from_slice(&self.fixed_len_bytes(y));
+        result
+    }
+
+    /// Compressed form: 0x02 or 0x03, depending on the parity of y, followed by x.
+    pub fn marshal_compressed(&self, x: &BigUint, y: &BigUint) -> Vec<u8> {
+        let mut result = vec![2u8 | y.bit(0) as u8];
+        result.extend_from_slice(&self.fixed_len_bytes(x));
+        result
+    }
+
+    /// Returns `None` if the data is not the uncompressed form of a point on the curve.
+    pub fn unmarshal(&self, data: &[u8]) -> Option<EllipticCurvePoint> {
+        let byte_len = self.byte_len();
+        if data.len() != 1 + 2 * byte_len || data[0] != 4 {
+            return None;
+        }
+        let x = BigUint::from_bytes_be(&data[1..1 + byte_len]);
+        let y = BigUint::from_bytes_be(&data[1 + byte_len..]);
+        if !self.is_on_curve(&x, &y) {
+            return None;
+        }
+        Some((x, y))
+    }
+
+    /// Returns `None` if the data is not the compressed form of a point on the curve.
+    pub fn unmarshal_compressed(&self, data: &[u8]) -> Option<EllipticCurvePoint> {
+        let byte_len = self.byte_len();
+        if data.len() != 1 + byte_len || (data[0] != 2 && data[0] != 3) {
+            return None;
+        }
+        let x = BigUint::from_bytes_be(&data[1..]);
+        if x >= self.p {
+            return None;
+        }
+        let mut y = self.sqrt_mod(&self.polynomial(&x))?;
+        if y.bit(0) != (data[0] & 1 == 1) {
+            y = self.sub_mod(&BigUint::zero(), &y);
+        }
+        if !self.is_on_curve(&x, &y) {
+            return None;
+        }
+        Some((x, y))
+    }
+
+    /// Same algorithm as Go's `elliptic.GenerateKey`, but with the given source of randomness.
+    ///
+    /// Returns the private key and the public key point.
+    pub fn generate_key<F>(&self, mut random_bytes: F) -> (Vec<u8>, EllipticCurvePoint)
+    where
+        F: FnMut(usize) -> Vec<u8>,
+    {
+        const MASK: [u8; 8] = [0xff, 0x1, 0x3, 0x7, 0xf, 0x1f, 0x3f, 0x7f];
+
+        loop {
+            let mut private_key = random_bytes(self.byte_len());
+            private_key[0] &= MASK[(self.bit_size % 8) as usize];
+            // avoids the point at infinity in case of an all-zero source
+            private_key[1] ^= 0x42;
+            if BigUint::from_bytes_be(&private_key) >= self.n {
+                continue;
+            }
+            let public_key = self.scalar_base_mult(&private_key);
+            return (private_key, public_key);
+        }
+    }
+
+    fn fixed_len_bytes(&self, value: &BigUint) -> Vec<u8> {
+        let bytes = value.to_bytes_be();
+        let mut result = vec![0u8; self.byte_len().saturating_sub(bytes.len())];
+        result.extend_from_slice(&bytes);
+        result
+    }
+
+    /// Tonelli-Shanks, with a shortcut for fields where p = 3 mod 4.
+    fn sqrt_mod(&self, a: &BigUint) -> Option<BigUint> {
+        let p = &self.p;
+        let a = a % p;
+        if a.is_zero() {
+            return Some(a);
+        }
+        let p_minus_one = p - 1u32;
+        let legendre_exp = &p_minus_one >> 1;
+        if !a.modpow(&legendre_exp, p).is_one() {
+            return None;
+        }
+        if p.bit(0) && p.bit(1) {
+            return Some(a.modpow(&((p + 1u32) >> 2), p));
+        }
+
+        let mut q = p_minus_one.clone();
+        let mut s = 0u32;
+        while !q.bit(0) {
+            q >>= 1;
+            s += 1;
+        }
+        let mut z = BigUint::from(2u32);
+        while z.modpow(&legendre_exp, p) != p_minus_one {
+            z += 1u32;
+        }
+
+        let mut m = s;
+        let mut c = z.modpow(&q, p);
+        let mut t = a.modpow(&q, p);
+        let mut r = a.modpow(&((&q + 1u32) >> 1), p);
+        while !t.is_one() {
+            let mut i = 0u32;
+            let mut t_pow = t.clone();
+            while !t_pow.is_one() {
+                t_pow = &t_pow * &t_pow % p;
+                i += 1;
+            }
+            let b = c.modpow(&(BigUint::one() << (m - i - 1)), p);
+            m = i;
+            c = &b * &b % p;
+            t = t * &c % p;
+            r = r * &b % p;
+        }
+        Some(r)
+    }
+}
@@ -1,9 +1,16 @@
+use bech32::{Bech32, Hrp};
+
 use crate::types::VMAddress;
 
 pub fn address_hex(address: &VMAddress) -> String {
     alloc::format!("0x{}", hex::encode(address.as_bytes()))
 }
 
+pub fn address_bech32(address: &VMAddress) -> String {
+    let hrp = Hrp::parse("drt").expect("invalid hrp");
+    bech32::encode::<Bech32>(hrp, address.as_bytes()).expect("bech32 encode error")
+}
+
 pub fn key_hex(key: &[u8]) -> String {
     alloc::format!("0x{}", hex::encode(key))
 }
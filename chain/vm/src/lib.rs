@@ -1,6 +1,7 @@
 pub mod crypto_functions;
 pub mod display_util;
 pub mod mem_conv;
+mod serde_util;
 pub mod tx_execution;
 pub mod tx_mock;
 pub mod types;
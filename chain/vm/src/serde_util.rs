@@ -0,0 +1,320 @@
+//! Field serializers for the blockchain state, so that it can be saved to a readable JSON file.
+//!
+//! Bytes and addresses are written as hex, big integers as decimal strings.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+};
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::types::{VMAddress, H256};
+
+pub(crate) trait HexBytes: Sized {
+    fn to_hex(&self) -> String;
+
+    fn from_hex(hex_str: &str) -> Result<Self, String>;
+}
+
+fn decode_hex(hex_str: &str) -> Result<Vec<u8>, String> {
+    hex::decode(hex_str).map_err(|err| format!("invalid hex {hex_str:?}: {err}"))
+}
+
+fn decode_hex_array<const N: usize>(hex_str: &str) -> Result<[u8; N], String> {
+    decode_hex(hex_str)?
+        .try_into()
+        .map_err(|_| format!("expected {N} bytes: {hex_str:?}"))
+}
+
+impl HexBytes for Vec<u8> {
+    fn to_hex(&self) -> String {
+        hex::encode(self)
+    }
+
+    fn from_hex(hex_str: &str) -> Result<Self, String> {
+        decode_hex(hex_str)
+    }
+}
+
+impl HexBytes for VMAddress {
+    fn to_hex(&self) -> String {
+        hex::encode(self.as_bytes())
+    }
+
+    fn from_hex(hex_str: &str) -> Result<Self, String> {
+        decode_hex_array(hex_str).map(VMAddress::new)
+    }
+}
+
+impl HexBytes for H256 {
+    fn to_hex(&self) -> String {
+        hex::encode(self.as_bytes())
+    }
+
+    fn from_hex(hex_str: &str) -> Result<Self, String> {
+        decode_hex_array(hex_str).map(H256::new)
+    }
+}
+
+impl HexBytes for Box<[u8; 48]> {
+    fn to_hex(&self) -> String {
+        hex::encode(self.as_slice())
+    }
+
+    fn from_hex(hex_str: &str) -> Result<Self, String> {
+        decode_hex_array(hex_str).map(Box::new)
+    }
+}
+
+fn deserialize_hex_str<'de, T: HexBytes, D: Deserializer<'de>>(
+    hex_str: &str,
+) -> Result<T, D::Error> {
+    T::from_hex(hex_str).map_err(D::Error::custom)
+}
+
+pub(crate) mod hex_bytes {
+    use super::*;
+
+    pub fn serialize<T: HexBytes, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_hex())
+    }
+
+    pub fn deserialize<'de, T: HexBytes, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        deserialize_hex_str::<T, D>(&String::deserialize(deserializer)?)
+    }
+}
+
+pub(crate) mod hex_option {
+    use super::*;
+
+    pub fn serialize<T: HexBytes, S: Serializer>(
+        value: &Option<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.as_ref().map(HexBytes::to_hex).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: HexBytes, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<T>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|hex_str| deserialize_hex_str::<T, D>(&hex_str))
+            .transpose()
+    }
+}
+
+pub(crate) mod hex_vec {
+    use super::*;
+
+    pub fn serialize<T: HexBytes, S: Serializer>(
+        values: &[T],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(values.iter().map(HexBytes::to_hex))
+    }
+
+    pub fn deserialize<'de, T: HexBytes, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<T>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|hex_str| deserialize_hex_str::<T, D>(hex_str))
+            .collect()
+    }
+}
+
+/// Maps with byte keys, written with the keys sorted, so that the same state always gives the same file.
+pub(crate) mod hex_key_map {
+    use super::*;
+
+    pub fn serialize<K: HexBytes, V: Serialize, S: Serializer>(
+        map: &HashMap<K, V>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let sorted: BTreeMap<String, &V> = map.iter().map(|(k, v)| (k.to_hex(), v)).collect();
+        sorted.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+    where
+        K: HexBytes + Eq + Hash,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        BTreeMap::<String, V>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(k, v)| Ok((deserialize_hex_str::<K, D>(&k)?, v)))
+            .collect()
+    }
+}
+
+/// Account storage, both keys and values in hex.
+pub(crate) mod hex_storage {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        storage: &HashMap<Vec<u8>, Vec<u8>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let sorted: BTreeMap<String, String> = storage
+            .iter()
+            .map(|(key, value)| (key.to_hex(), value.to_hex()))
+            .collect();
+        sorted.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<Vec<u8>, Vec<u8>>, D::Error> {
+        BTreeMap::<String, String>::deserialize(deserializer)?
+            .iter()
+            .map(|(key, value)| {
+                Ok((
+                    deserialize_hex_str::<_, D>(key)?,
+                    deserialize_hex_str::<_, D>(value)?,
+                ))
+            })
+            .collect()
+    }
+}
+
+pub(crate) mod big_uint {
+    use num_bigint::BigUint;
+
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &BigUint, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BigUint, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse()
+            .map_err(|_| D::Error::custom(format!("invalid big uint: {s:?}")))
+    }
+}
+
+pub(crate) mod code_metadata {
+    use super::*;
+    use crate::types::VMCodeMetadata;
+
+    pub fn serialize<S: Serializer>(
+        code_metadata: &VMCodeMetadata,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(code_metadata.bits())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<VMCodeMetadata, D::Error> {
+        Ok(VMCodeMetadata::from_bits_retain(u16::deserialize(
+            deserializer,
+        )?))
+    }
+}
+
+/// Same names as in the system SC arguments.
+pub(crate) mod token_type {
+    use super::*;
+    use crate::types::VMTokenType;
+
+    pub fn serialize<S: Serializer>(
+        token_type: &VMTokenType,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let name = match token_type {
+            VMTokenType::Fungible => "FNG",
+            VMTokenType::SemiFungible => "SFT",
+            VMTokenType::Meta => "META",
+            VMTokenType::NonFungible => "NFT",
+        };
+        serializer.serialize_str(name)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<VMTokenType, D::Error> {
+        match String::deserialize(deserializer)?.as_str() {
+            "FNG" => Ok(VMTokenType::Fungible),
+            "SFT" => Ok(VMTokenType::SemiFungible),
+            "META" => Ok(VMTokenType::Meta),
+            "NFT" => Ok(VMTokenType::NonFungible),
+            other => Err(D::Error::custom(format!("invalid token type: {other:?}"))),
+        }
+    }
+}
+
+pub(crate) mod return_code {
+    use super::*;
+    use crate::chain_core::types::ReturnCode;
+
+    pub fn serialize<S: Serializer>(
+        return_code: &ReturnCode,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(return_code.as_u64())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ReturnCode, D::Error> {
+        let value = u64::deserialize(deserializer)?;
+        ReturnCode::from_u64(value)
+            .ok_or_else(|| D::Error::custom(format!("invalid return code: {value}")))
+    }
+}
+
+/// The new address map, as a list sorted by creator, since its keys are not strings.
+pub(crate) mod new_addresses {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct NewAddressEntry {
+        #[serde(with = "hex_bytes")]
+        creator_address: VMAddress,
+        creator_nonce: u64,
+        #[serde(with = "hex_bytes")]
+        new_address: VMAddress,
+    }
+
+    pub fn serialize<S: Serializer>(
+        new_addresses: &HashMap<(VMAddress, u64), VMAddress>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut entries: Vec<NewAddressEntry> = new_addresses
+            .iter()
+            .map(
+                |((creator_address, creator_nonce), new_address)| NewAddressEntry {
+                    creator_address: creator_address.clone(),
+                    creator_nonce: *creator_nonce,
+                    new_address: new_address.clone(),
+                },
+            )
+            .collect();
+        entries.sort_by(|a, b| {
+            (a.creator_address.as_bytes(), a.creator_nonce)
+                .cmp(&(b.creator_address.as_bytes(), b.creator_nonce))
+        });
+        entries.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<(VMAddress, u64), VMAddress>, D::Error> {
+        Ok(Vec::<NewAddressEntry>::deserialize(deserializer)?
+            .into_iter()
+            .map(|entry| {
+                (
+                    (entry.creator_address, entry.creator_nonce),
+                    entry.new_address,
+                )
+            })
+            .collect())
+    }
+}
@@ -2,11 +2,19 @@ use std::{ops::Deref, sync::Arc};
 
 use dharitri_vm_executor::Executor;
 
-use super::BuiltinFunctionContainer;
+use crate::tx_mock::{ExecutionTracer, TxInput, TxResult};
+
+use super::{BuiltinFunctionContainer, GasSchedule};
 
 pub struct BlockchainVM {
     pub builtin_functions: BuiltinFunctionContainer,
     pub executor: Box<dyn Executor + Send + Sync>,
+
+    /// Gas is only metered if a gas schedule is set.
+    pub gas_schedule: Option<GasSchedule>,
+
+    /// Execution is only traced if a tracer is set.
+    pub tracer: Option<Arc<ExecutionTracer>>,
 }
 
 #[derive(Clone)]
@@ -17,6 +25,24 @@ impl BlockchainVM {
         BlockchainVM {
             builtin_functions: BuiltinFunctionContainer,
             executor,
+            gas_schedule: None,
+            tracer: None,
+        }
+    }
+
+    pub fn is_gas_metered(&self) -> bool {
+        self.gas_schedule.is_some()
+    }
+
+    pub(crate) fn trace_begin_call(&self, tx_input: &TxInput) {
+        if let Some(tracer) = &self.tracer {
+            tracer.begin_call(tx_input);
+        }
+    }
+
+    pub(crate) fn trace_end_call(&self, tx_result: &TxResult) {
+        if let Some(tracer) = &self.tracer {
+            tracer.end_call(tx_result);
         }
     }
 }
@@ -25,6 +51,24 @@ impl BlockchainVMRef {
     pub fn new(executor: Box<dyn Executor + Send + Sync>) -> Self {
         BlockchainVMRef(Arc::new(BlockchainVM::new(executor)))
     }
+
+    /// Enables (or disables, if `None`) gas metering.
+    ///
+    /// Can only be called while no transaction is being executed.
+    pub fn set_gas_schedule(&mut self, gas_schedule: Option<GasSchedule>) {
+        Arc::get_mut(&mut self.0)
+            .expect("cannot change the gas schedule while the VM is in use")
+            .gas_schedule = gas_schedule;
+    }
+
+    /// Enables (or disables, if `None`) execution tracing.
+    ///
+    /// Can only be called while no transaction is being executed.
+    pub fn set_tracer(&mut self, tracer: Option<Arc<ExecutionTracer>>) {
+        Arc::get_mut(&mut self.0)
+            .expect("cannot change the tracer while the VM is in use")
+            .tracer = tracer;
+    }
 }
 
 impl Deref for BlockchainVMRef {
@@ -11,7 +11,7 @@ use super::{
 
 use crate::{
     tx_execution::BlockchainVMRef,
-    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
     types::DcdtLocalRole,
 };
 
@@ -121,12 +121,27 @@ impl<'a> BuiltinFunctionCall<'a> {
         }
     }
 
-    fn execute_bf<B, F>(self, builtin_func: B, f: F) -> (TxResult, BlockchainUpdate)
+    fn execute_bf<B, F>(mut self, builtin_func: B, f: F) -> (TxResult, BlockchainUpdate)
     where
         B: BuiltinFunction,
         F: FnOnce(),
     {
-        builtin_func.execute(self.tx_input, self.tx_cache, self.vm, f)
+        let builtin_gas = self
+            .vm
+            .gas_schedule
+            .as_ref()
+            .map_or(0, |gas_schedule| gas_schedule.builtin_function);
+        if builtin_gas > self.tx_input.gas_limit {
+            let mut tx_result = TxResult::from_panic_obj(&TxPanic::out_of_gas());
+            tx_result.gas_used = self.tx_input.gas_limit;
+            return (tx_result, BlockchainUpdate::empty());
+        }
+        self.tx_input.gas_limit -= builtin_gas;
+
+        let (mut tx_result, blockchain_updates) =
+            builtin_func.execute(self.tx_input, self.tx_cache, self.vm, f);
+        tx_result.gas_used += builtin_gas;
+        (tx_result, blockchain_updates)
     }
 
     fn check_role_and_execute<B, F>(
@@ -3,6 +3,7 @@ use crate::{
     tx_execution::{create_transfer_value_log, BlockchainVMRef},
     tx_mock::{BlockchainUpdate, CallType, TxCache, TxFunctionName, TxInput, TxResult},
     types::VMCodeMetadata,
+    vm_err_msg,
 };
 
 use super::super::builtin_func_trait::BuiltinFunction;
@@ -31,6 +32,13 @@ impl BuiltinFunction for UpgradeContract {
             );
         }
 
+        if !is_upgrade_allowed(&tx_input, &tx_cache) {
+            return (
+                TxResult::from_vm_error(vm_err_msg::UPGRADE_NOT_ALLOWED),
+                BlockchainUpdate::empty(),
+            );
+        }
+
         let new_code = tx_input.args[0].clone();
         let code_metadata = VMCodeMetadata::from(&tx_input.args[1]);
 
@@ -68,6 +76,15 @@ impl BuiltinFunction for UpgradeContract {
     }
 }
 
+/// Only contracts deployed as upgradeable can be upgraded.
+fn is_upgrade_allowed(tx_input: &TxInput, tx_cache: &TxCache) -> bool {
+    tx_cache.with_account_or_else(
+        &tx_input.to,
+        |account| account.contract_path.is_some() && account.code_metadata.is_upgradeable(),
+        || false,
+    )
+}
+
 /// A small anomaly in the VM: logs from the "upgrade" function appear under "upgradeContract".
 fn adjust_upgrade_log_endpoint(tx_result: &mut TxResult) {
     for log in &mut tx_result.result_logs {
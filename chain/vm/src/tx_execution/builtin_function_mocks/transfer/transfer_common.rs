@@ -1,13 +1,15 @@
 use crate::{
+    chain_core::REWA_000000_TOKEN_IDENTIFIER,
     tx_execution::{
         builtin_function_mocks::builtin_func_trait::BuiltinFunctionDcdtTransferInfo,
-        BlockchainVMRef,
+        is_system_sc_address, BlockchainVMRef,
     },
     tx_mock::{
-        BlockchainUpdate, CallType, TxCache, TxFunctionName, TxInput, TxLog, TxResult,
+        BlockchainUpdate, CallType, TxCache, TxFunctionName, TxInput, TxLog, TxPanic, TxResult,
         TxTokenTransfer,
     },
     types::{top_decode_u64, VMAddress},
+    vm_err_msg,
 };
 use num_bigint::BigUint;
 use num_traits::Zero;
@@ -82,6 +84,10 @@ where
         ..Default::default()
     };
 
+    if let Err(err) = check_transfers_allowed(&tx_cache, &exec_input) {
+        return (TxResult::from_panic_obj(&err), BlockchainUpdate::empty());
+    }
+
     let (mut tx_result, blockchain_updates) = vm.default_execution(exec_input, tx_cache, f);
 
     // prepends dcdt log
@@ -91,6 +97,52 @@ where
     (tx_result, blockchain_updates)
 }
 
+/// Paused tokens cannot be transferred at all,
+/// frozen tokens cannot leave or enter the account that is frozen.
+fn check_transfers_allowed(tx_cache: &TxCache, exec_input: &TxInput) -> Result<(), TxPanic> {
+    for transfer in &exec_input.dcdt_values {
+        if transfer.token_identifier == REWA_000000_TOKEN_IDENTIFIER.as_bytes() {
+            continue;
+        }
+
+        if let Some(properties) = tx_cache.get_dcdt_token(&transfer.token_identifier) {
+            if properties.is_paused {
+                return Err(TxPanic::vm_error(vm_err_msg::DCDT_TOKEN_IS_PAUSED));
+            }
+        }
+
+        for address in [&exec_input.from, &exec_input.to] {
+            if is_frozen(tx_cache, address, transfer) {
+                return Err(TxPanic::vm_error(vm_err_msg::DCDT_IS_FROZEN_FOR_ACCOUNT));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn is_frozen(tx_cache: &TxCache, address: &VMAddress, transfer: &TxTokenTransfer) -> bool {
+    if is_system_sc_address(address) {
+        return false;
+    }
+
+    tx_cache.with_account_or_else(
+        address,
+        |account| {
+            let Some(dcdt_data) = account.dcdt.get_by_identifier(&transfer.token_identifier) else {
+                return false;
+            };
+            dcdt_data.frozen
+                || dcdt_data
+                    .instances
+                    .get_by_nonce(transfer.nonce)
+                    .map(|instance| instance.frozen)
+                    .unwrap_or(false)
+        },
+        || false,
+    )
+}
+
 pub(super) fn adjust_call_type(
     call_type: CallType,
     call: &ParsedTransferBuiltinFunCall,
@@ -4,7 +4,7 @@ use crate::{
         merge_results, AsyncCallTxData, BlockchainUpdate, CallType, Promise, TxCache, TxContext,
         TxContextStack, TxInput, TxPanic, TxResult, TxResultCalls,
     },
-    types::VMCodeMetadata,
+    types::{VMAddress, VMCodeMetadata},
     with_shared::Shareable,
     world_mock::{AccountData, AccountDcdt, BlockchainState},
 };
@@ -49,13 +49,16 @@ impl BlockchainVMRef {
     where
         F: FnOnce(),
     {
-        state.with_shared(|state_arc| {
+        self.trace_begin_call(&tx_input);
+        let (tx_result, blockchain_updates) = state.with_shared(|state_arc| {
             let tx_cache = TxCache::new(state_arc);
             let mut tx_context_sh =
                 Shareable::new(TxContext::new(self.clone(), tx_input, tx_cache));
             TxContextStack::execute_on_vm_stack(&mut tx_context_sh, f);
             tx_context_sh.into_inner().into_results()
-        })
+        });
+        self.trace_end_call(&tx_result);
+        (tx_result, blockchain_updates)
     }
 
     pub fn execute_builtin_function_or_default<F>(
@@ -85,44 +88,90 @@ impl BlockchainVMRef {
     where
         F: FnOnce(),
     {
-        state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
+        let gas_payment = GasPayment::new(&tx_input);
+        let tx_result = self.execute_sc_call_lambda_no_refund(tx_input, state, f);
+        self.refund_unused_gas(&gas_payment, &tx_result, state);
+        tx_result
+    }
 
-        let (tx_result, blockchain_updates) = state.with_shared(|state_arc| {
+    /// Pays for gas upfront and executes the call, but does not refund the unused gas yet,
+    /// since it might still be needed by the async calls that follow.
+    fn execute_sc_call_lambda_no_refund<F>(
+        &self,
+        tx_input: TxInput,
+        state: &mut Shareable<BlockchainState>,
+        f: F,
+    ) -> TxResult
+    where
+        F: FnOnce(),
+    {
+        state.subtract_tx_gas(tx_input.gas_payer(), tx_input.gas_limit, tx_input.gas_price);
+
+        let call_type = tx_input.call_type;
+        let gas_limit = tx_input.gas_limit;
+        let (mut tx_result, blockchain_updates) = state.with_shared(|state_arc| {
             let tx_cache = TxCache::new(state_arc);
             self.execute_builtin_function_or_default(tx_input, tx_cache, f)
         });
 
         if tx_result.result_status.is_success() {
             blockchain_updates.apply(state);
+            state.cross_shard_calls.extend(std::mem::take(
+                &mut tx_result.pending_calls.cross_shard_calls,
+            ));
+        } else if self.is_gas_metered() && call_type == CallType::DirectCall {
+            // failed transactions consume all their gas
+            tx_result.gas_used = gas_limit;
         }
 
         tx_result
     }
 
+    fn refund_unused_gas(
+        &self,
+        gas_payment: &GasPayment,
+        tx_result: &TxResult,
+        state: &mut Shareable<BlockchainState>,
+    ) {
+        if self.is_gas_metered() {
+            let gas_refunded = gas_payment.gas_limit.saturating_sub(tx_result.gas_used);
+            state.refund_tx_gas(&gas_payment.payer, gas_refunded, gas_payment.gas_price);
+        }
+    }
+
+    /// Async calls and callbacks receive all the gas left, when gas is metered.
+    pub(super) fn set_async_gas_limit(&self, tx_input: &mut TxInput, gas_left: u64) {
+        if self.is_gas_metered() {
+            tx_input.gas_limit = gas_left;
+        }
+    }
+
     pub fn execute_async_call_and_callback(
         &self,
         async_data: AsyncCallTxData,
+        gas_left: u64,
         state: &mut Shareable<BlockchainState>,
     ) -> (TxResult, TxResult) {
-        if state.accounts.contains_key(&async_data.to) {
-            let async_input = async_call_tx_input(&async_data, CallType::AsyncCall);
-
-            let async_result = self.sc_call_with_async_and_callback(
-                async_input,
-                state,
-                execute_current_tx_context_input,
-            );
+        if self.is_cross_shard_call(&async_data, state) {
+            return match self.send_cross_shard_async_call(async_data.clone(), None, gas_left, state)
+            {
+                Ok(()) => (TxResult::empty(), TxResult::empty()),
+                Err(err) => {
+                    let async_result = TxResult::from_panic_obj(&err);
+                    let callback_result =
+                        self.execute_async_callback(&async_data, &async_result, gas_left, state);
+                    (async_result, callback_result)
+                },
+            };
+        }
 
-            let callback_input =
-                async_callback_tx_input(&async_data, &async_result, &self.builtin_functions);
-            let callback_result = self.execute_sc_call_lambda(
-                callback_input,
+        if state.accounts.contains_key(&async_data.to) {
+            let async_result = self.execute_async_call(&async_data, gas_left, state);
+            let callback_result = self.execute_async_callback(
+                &async_data,
+                &async_result,
+                gas_left.saturating_sub(async_result.gas_used),
                 state,
-                execute_current_tx_context_input,
-            );
-            assert!(
-                callback_result.pending_calls.async_call.is_none(),
-                "successive asyncs currently not supported"
             );
             (async_result, callback_result)
         } else {
@@ -137,7 +186,41 @@ impl BlockchainVMRef {
         }
     }
 
-    // TODO: refactor
+    pub(super) fn execute_async_call(
+        &self,
+        async_data: &AsyncCallTxData,
+        gas_left: u64,
+        state: &mut Shareable<BlockchainState>,
+    ) -> TxResult {
+        let mut async_input = async_call_tx_input(async_data, CallType::AsyncCall);
+        self.set_async_gas_limit(&mut async_input, gas_left);
+        self.sc_call_with_async_and_callback(async_input, state, execute_current_tx_context_input)
+    }
+
+    pub(super) fn execute_async_callback(
+        &self,
+        async_data: &AsyncCallTxData,
+        async_result: &TxResult,
+        gas_left: u64,
+        state: &mut Shareable<BlockchainState>,
+    ) -> TxResult {
+        let mut callback_input =
+            async_callback_tx_input(async_data, async_result, &self.builtin_functions);
+        self.set_async_gas_limit(&mut callback_input, gas_left);
+        self.sc_call_with_async_and_callback(
+            callback_input,
+            state,
+            execute_current_tx_context_input,
+        )
+    }
+
+    /// Executes a call, followed by all the async calls and promises it launched,
+    /// each of them followed by its callback.
+    ///
+    /// Async calls and callbacks go through this same function, so calls chained
+    /// from them get executed too, to any depth. Results and logs are merged
+    /// in execution order: the call, then for each async call its own result
+    /// (including whatever it launched) followed by its callback result.
     pub fn sc_call_with_async_and_callback<F>(
         &self,
         tx_input: TxInput,
@@ -147,52 +230,73 @@ impl BlockchainVMRef {
     where
         F: FnOnce(),
     {
+        let gas_payment = GasPayment::new(&tx_input);
+
         // main call
-        let mut tx_result = self.execute_sc_call_lambda(tx_input, state, f);
+        let mut tx_result = self.execute_sc_call_lambda_no_refund(tx_input, state, f);
 
         // take & clear pending calls
         let pending_calls = std::mem::replace(&mut tx_result.pending_calls, TxResultCalls::empty());
 
-        // legacy async call
-        // the async call also gets reset
+        // legacy async calls
+        // only executed if the call was successful
         if tx_result.result_status.is_success() {
-            if let Some(async_data) = pending_calls.async_call {
+            for async_data in pending_calls.async_calls {
+                let gas_left = gas_payment.gas_left(&tx_result);
                 let (async_result, callback_result) =
-                    self.execute_async_call_and_callback(async_data, state);
+                    self.execute_async_call_and_callback(async_data, gas_left, state);
 
                 tx_result = merge_results(tx_result, async_result);
                 tx_result = merge_results(tx_result, callback_result);
-
-                return tx_result;
             }
         }
 
         // calling all promises
         // the promises are also reset
         for promise in pending_calls.promises {
+            let gas_left = gas_payment.gas_left(&tx_result);
             let (async_result, callback_result) =
-                self.execute_promise_call_and_callback(&promise, state);
+                self.execute_promise_call_and_callback(&promise, gas_left, state);
 
             tx_result = merge_results(tx_result, async_result.clone());
             tx_result = merge_results(tx_result, callback_result.clone());
         }
 
+        self.refund_unused_gas(&gas_payment, &tx_result, state);
         tx_result
     }
 
     pub fn execute_promise_call_and_callback(
         &self,
         promise: &Promise,
+        gas_left: u64,
         state: &mut Shareable<BlockchainState>,
     ) -> (TxResult, TxResult) {
+        if self.is_cross_shard_call(&promise.call, state) {
+            return match self.send_cross_shard_async_call(
+                promise.call.clone(),
+                Some(promise.clone()),
+                gas_left,
+                state,
+            ) {
+                Ok(()) => (TxResult::empty(), TxResult::empty()),
+                Err(err) => {
+                    let async_result = TxResult::from_panic_obj(&err);
+                    let callback_result =
+                        self.execute_promises_callback(&async_result, promise, gas_left, state);
+                    (async_result, callback_result)
+                },
+            };
+        }
+
         if state.accounts.contains_key(&promise.call.to) {
-            let async_input = async_call_tx_input(&promise.call, CallType::AsyncCall);
-            let async_result = self.sc_call_with_async_and_callback(
-                async_input,
+            let async_result = self.execute_async_call(&promise.call, gas_left, state);
+            let callback_result = self.execute_promises_callback(
+                &async_result,
+                promise,
+                gas_left.saturating_sub(async_result.gas_used),
                 state,
-                execute_current_tx_context_input,
             );
-            let callback_result = self.execute_promises_callback(&async_result, promise, state);
             (async_result, callback_result)
         } else {
             let result = self.insert_ghost_account(&promise.call, state);
@@ -206,28 +310,28 @@ impl BlockchainVMRef {
         }
     }
 
-    fn execute_promises_callback(
+    pub(super) fn execute_promises_callback(
         &self,
         async_result: &TxResult,
         promise: &Promise,
+        gas_left: u64,
         state: &mut Shareable<BlockchainState>,
     ) -> TxResult {
         if !promise.has_callback() {
             return TxResult::empty();
         }
-        let callback_input =
+        let mut callback_input =
             async_promise_callback_tx_input(promise, async_result, &self.builtin_functions);
-        let callback_result =
-            self.execute_sc_call_lambda(callback_input, state, execute_current_tx_context_input);
-        assert!(
-            callback_result.pending_calls.promises.is_empty(),
-            "successive promises currently not supported"
-        );
-        callback_result
+        self.set_async_gas_limit(&mut callback_input, gas_left);
+        self.sc_call_with_async_and_callback(
+            callback_input,
+            state,
+            execute_current_tx_context_input,
+        )
     }
 
     /// When calling a contract that is unknown to the state, we insert a ghost account.
-    fn insert_ghost_account(
+    pub(super) fn insert_ghost_account(
         &self,
         async_data: &AsyncCallTxData,
         state: &mut Shareable<BlockchainState>,
@@ -251,3 +355,24 @@ impl BlockchainVMRef {
         })
     }
 }
+
+/// Keeps track of who pays for the gas of a transaction, and how much.
+struct GasPayment {
+    payer: VMAddress,
+    gas_limit: u64,
+    gas_price: u64,
+}
+
+impl GasPayment {
+    fn new(tx_input: &TxInput) -> Self {
+        GasPayment {
+            payer: tx_input.gas_payer().clone(),
+            gas_limit: tx_input.gas_limit,
+            gas_price: tx_input.gas_price,
+        }
+    }
+
+    fn gas_left(&self, tx_result: &TxResult) -> u64 {
+        self.gas_limit.saturating_sub(tx_result.gas_used)
+    }
+}
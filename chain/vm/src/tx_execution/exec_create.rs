@@ -22,15 +22,35 @@ impl BlockchainVMRef {
         // nonce gets increased irrespective of whether the tx fails or not
         // must be done after computing the new address
         state.increase_account_nonce(&tx_input.from);
-        state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
+        state.subtract_tx_gas(tx_input.gas_payer(), tx_input.gas_limit, tx_input.gas_price);
 
-        let (tx_result, new_address, blockchain_updates) = state.with_shared(|state_arc| {
+        let gas_payer = tx_input.gas_payer().clone();
+        let gas_limit = tx_input.gas_limit;
+        let gas_price = tx_input.gas_price;
+        let (mut tx_result, new_address, blockchain_updates) = state.with_shared(|state_arc| {
             let tx_cache = TxCache::new(state_arc);
 
             self.deploy_contract(tx_input, contract_path.to_vec(), code_metadata, tx_cache, f)
         });
 
         blockchain_updates.apply(state);
+        if tx_result.result_status.is_success() {
+            state.cross_shard_calls.extend(std::mem::take(
+                &mut tx_result.pending_calls.cross_shard_calls,
+            ));
+        }
+
+        if self.is_gas_metered() {
+            if !tx_result.result_status.is_success() {
+                // failed transactions consume all their gas
+                tx_result.gas_used = gas_limit;
+            }
+            state.refund_tx_gas(
+                &gas_payer,
+                gas_limit.saturating_sub(tx_result.gas_used),
+                gas_price,
+            );
+        }
 
         (new_address, tx_result)
     }
@@ -0,0 +1,200 @@
+use crate::{
+    tx_mock::{
+        async_call_tx_input, AsyncCallTxData, CallType, CrossShardCall, CrossShardCallKind,
+        InFlightPayments, Promise, TxCache, TxPanic, TxResult,
+    },
+    types::VMAddress,
+    with_shared::Shareable,
+    world_mock::BlockchainState,
+};
+
+use super::{execute_current_tx_context_input, BlockchainVMRef};
+
+impl BlockchainVMRef {
+    /// Moves on to the next round, and delivers all cross-shard calls due in it.
+    ///
+    /// Returns the results of the delivered calls, in delivery order.
+    pub fn advance_round(&self, state: &mut Shareable<BlockchainState>) -> Vec<TxResult> {
+        state.advance_round();
+        self.deliver_cross_shard_calls(state)
+    }
+
+    /// Delivers the cross-shard calls due in the current round, in the order in which they were sent.
+    ///
+    /// Whatever these calls send to other shards in turn only gets delivered in a later round.
+    pub fn deliver_cross_shard_calls(
+        &self,
+        state: &mut Shareable<BlockchainState>,
+    ) -> Vec<TxResult> {
+        let current_round = state.current_block_info.block_round;
+        let mut results = Vec::new();
+        while state
+            .cross_shard_calls
+            .front()
+            .is_some_and(|call| call.delivery_round <= current_round)
+        {
+            let call = state.cross_shard_calls.pop_front().unwrap();
+            results.push(self.deliver_cross_shard_call(call, state));
+        }
+        results
+    }
+
+    /// Only ever true in sharded mode.
+    pub(super) fn is_cross_shard_call(
+        &self,
+        async_data: &AsyncCallTxData,
+        state: &BlockchainState,
+    ) -> bool {
+        let tx_input = async_call_tx_input(async_data, CallType::AsyncCall);
+        let token_transfers = self.builtin_functions.extract_token_transfers(&tx_input);
+        state.is_cross_shard(&async_data.from, &token_transfers.real_recipient)
+    }
+
+    /// Takes the payments out of the caller and queues the call for the destination shard.
+    pub(super) fn send_cross_shard_async_call(
+        &self,
+        call: AsyncCallTxData,
+        promise: Option<Promise>,
+        gas_limit: u64,
+        state: &mut Shareable<BlockchainState>,
+    ) -> Result<(), TxPanic> {
+        let payments = self.take_in_flight_payments(&call, state)?;
+        let delivery_round = state.next_round();
+        state.cross_shard_calls.push_back(CrossShardCall {
+            delivery_round,
+            payments,
+            kind: CrossShardCallKind::AsyncCall {
+                call,
+                promise,
+                gas_limit,
+            },
+        });
+        Ok(())
+    }
+
+    fn deliver_cross_shard_call(
+        &self,
+        cross_shard_call: CrossShardCall,
+        state: &mut Shareable<BlockchainState>,
+    ) -> TxResult {
+        let payments_owner = cross_shard_call.kind.payments_owner().clone();
+        self.return_in_flight_payments(&payments_owner, cross_shard_call.payments, state);
+
+        match cross_shard_call.kind {
+            CrossShardCallKind::TransferExecute { call, gas_limit } => {
+                let mut tx_input = async_call_tx_input(&call, CallType::TransferExecute);
+                tx_input.gas_limit = gas_limit;
+                self.sc_call_with_async_and_callback(
+                    tx_input,
+                    state,
+                    execute_current_tx_context_input,
+                )
+            },
+            CrossShardCallKind::AsyncCall {
+                call,
+                promise,
+                gas_limit,
+            } => self.deliver_async_call(call, promise, gas_limit, state),
+            CrossShardCallKind::Callback {
+                call,
+                promise,
+                async_result,
+                gas_limit,
+            } => {
+                let gas_left = gas_limit.saturating_sub(async_result.gas_used);
+                match &promise {
+                    Some(promise) => {
+                        self.execute_promises_callback(&async_result, promise, gas_left, state)
+                    },
+                    None => self.execute_async_callback(&call, &async_result, gas_left, state),
+                }
+            },
+        }
+    }
+
+    /// Executes the call in the destination shard, then sends the result back to the caller.
+    ///
+    /// If the call failed, the payments go back to the caller together with the result.
+    fn deliver_async_call(
+        &self,
+        call: AsyncCallTxData,
+        promise: Option<Promise>,
+        gas_limit: u64,
+        state: &mut Shareable<BlockchainState>,
+    ) -> TxResult {
+        if !state.accounts.contains_key(&call.to) {
+            return match self.insert_ghost_account(&call, state) {
+                Ok(blockchain_updates) => {
+                    state.commit_updates(blockchain_updates);
+                    TxResult::empty()
+                },
+                Err(err) => TxResult::from_panic_obj(&err),
+            };
+        }
+
+        let async_result = self.execute_async_call(&call, gas_limit, state);
+
+        let success = async_result.result_status.is_success();
+        let has_callback = promise.as_ref().is_none_or(Promise::has_callback);
+        if success && !has_callback {
+            return async_result;
+        }
+
+        let refund = if success {
+            InFlightPayments::default()
+        } else {
+            self.take_in_flight_payments(&call, state)
+                .expect("the payments of a failed call should still be with the caller")
+        };
+        let delivery_round = state.next_round();
+        state.cross_shard_calls.push_back(CrossShardCall {
+            delivery_round,
+            payments: refund,
+            kind: CrossShardCallKind::Callback {
+                call,
+                promise,
+                async_result: Box::new(async_result.clone()),
+                gas_limit,
+            },
+        });
+
+        async_result
+    }
+
+    fn take_in_flight_payments(
+        &self,
+        call: &AsyncCallTxData,
+        state: &mut Shareable<BlockchainState>,
+    ) -> Result<InFlightPayments, TxPanic> {
+        let tx_input = async_call_tx_input(call, CallType::AsyncCall);
+        let token_transfers = self.builtin_functions.extract_token_transfers(&tx_input);
+        let (payments, blockchain_updates) = state.with_shared(|state_arc| {
+            let tx_cache = TxCache::new(state_arc);
+            let payments = tx_cache.take_in_flight_payments(
+                &call.from,
+                &call.call_value,
+                &token_transfers.transfers,
+            )?;
+            Ok((payments, tx_cache.into_blockchain_updates()))
+        })?;
+        state.commit_updates(blockchain_updates);
+        Ok(payments)
+    }
+
+    fn return_in_flight_payments(
+        &self,
+        to: &VMAddress,
+        payments: InFlightPayments,
+        state: &mut Shareable<BlockchainState>,
+    ) {
+        if payments.is_empty() {
+            return;
+        }
+        let blockchain_updates = state.with_shared(|state_arc| {
+            let tx_cache = TxCache::new(state_arc);
+            tx_cache.return_in_flight_payments(to, payments);
+            tx_cache.into_blockchain_updates()
+        });
+        state.commit_updates(blockchain_updates);
+    }
+}
@@ -86,6 +86,22 @@ impl BlockchainVMRef {
         tx_cache: TxCache,
         f: F,
     ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        self.trace_begin_call(&tx_input);
+        let (tx_result, blockchain_updates) =
+            self.default_execution_untraced(tx_input, tx_cache, f);
+        self.trace_end_call(&tx_result);
+        (tx_result, blockchain_updates)
+    }
+
+    fn default_execution_untraced<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        f: F,
+    ) -> (TxResult, BlockchainUpdate)
     where
         F: FnOnce(),
     {
@@ -150,6 +166,7 @@ impl BlockchainVMRef {
         let new_address = tx_cache.get_new_address(&tx_input.from);
         tx_input.to = new_address.clone();
         tx_input.func_name = TxFunctionName::INIT;
+        self.trace_begin_call(&tx_input);
         let tx_context = TxContext::new(self.clone(), tx_input, tx_cache);
         let mut tx_context_sh = Shareable::new(tx_context);
         let tx_input_ref = tx_context_sh.input_ref();
@@ -158,6 +175,7 @@ impl BlockchainVMRef {
             .tx_cache
             .subtract_rewa_balance(&tx_input_ref.from, &tx_input_ref.rewa_value)
         {
+            self.trace_end_call(&TxResult::from_panic_obj(&err));
             return (
                 TxResult::from_panic_obj(&err),
                 VMAddress::zero(),
@@ -177,6 +195,7 @@ impl BlockchainVMRef {
         TxContextStack::execute_on_vm_stack(&mut tx_context_sh, f);
 
         let (tx_result, blockchain_updates) = tx_context_sh.into_inner().into_results();
+        self.trace_end_call(&tx_result);
         (tx_result, new_address, blockchain_updates)
     }
 }
@@ -0,0 +1,52 @@
+/// Gas costs applied by the Rust VM, when gas metering is enabled.
+///
+/// The model is deliberately simple: a flat cost for each VM hook call,
+/// a cost per byte of storage read or written, and a flat cost for each builtin function.
+///
+/// The default values are in the same order of magnitude as the ones in the Go VM gas schedule,
+/// but no attempt is made to reproduce its gas consumption exactly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasSchedule {
+    /// Charged for every VM hook called by a contract.
+    pub vm_hook: u64,
+
+    /// Charged for every byte of key and value loaded from storage.
+    pub storage_load_per_byte: u64,
+
+    /// Charged for every byte of key and value written to storage.
+    pub storage_store_per_byte: u64,
+
+    /// Charged for every builtin function call, before the function itself is executed.
+    pub builtin_function: u64,
+}
+
+impl GasSchedule {
+    /// A schedule where everything is free. Useful for checking gas flows in isolation.
+    pub fn zero() -> Self {
+        GasSchedule {
+            vm_hook: 0,
+            storage_load_per_byte: 0,
+            storage_store_per_byte: 0,
+            builtin_function: 0,
+        }
+    }
+
+    pub fn storage_load_cost(&self, key: &[u8], value: &[u8]) -> u64 {
+        self.storage_load_per_byte * (key.len() + value.len()) as u64
+    }
+
+    pub fn storage_store_cost(&self, key: &[u8], value: &[u8]) -> u64 {
+        self.storage_store_per_byte * (key.len() + value.len()) as u64
+    }
+}
+
+impl Default for GasSchedule {
+    fn default() -> Self {
+        GasSchedule {
+            vm_hook: 1_000,
+            storage_load_per_byte: 50,
+            storage_store_per_byte: 10_000,
+            builtin_function: 200_000,
+        }
+    }
+}
@@ -1,15 +1,22 @@
+mod system_sc_common;
+mod system_sc_freeze;
 mod system_sc_issue;
+mod system_sc_query;
 mod system_sc_special_roles;
-mod system_sc_unimplemented;
+mod system_sc_supply;
+mod system_sc_token_management;
 
 use crate::{
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
     types::VMAddress,
 };
 use hex_literal::hex;
+use system_sc_freeze::*;
 use system_sc_issue::*;
+use system_sc_query::*;
 use system_sc_special_roles::*;
-use system_sc_unimplemented::*;
+use system_sc_supply::*;
+use system_sc_token_management::*;
 
 /// Address of the system smart contract that manages DCDT.
 /// Bech32: drt1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls6prdez
@@ -29,6 +36,8 @@ pub fn execute_system_sc(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Blo
         "registerMetaDCDT" => register_meta_dcdt(tx_input, tx_cache),
         "changeSFTToMetaDCDT" => change_sft_to_meta_dcdt(tx_input, tx_cache),
         "registerAndSetAllRoles" => register_and_set_all_roles(tx_input, tx_cache),
+        "registerDynamic" => register_dynamic(tx_input, tx_cache),
+        "registerAndSetAllRolesDynamic" => register_and_set_all_roles_dynamic(tx_input, tx_cache),
         "DCDTBurn" => dcdt_burn(tx_input, tx_cache),
         "mint" => mint(tx_input, tx_cache),
         "freeze" => freeze(tx_input, tx_cache),
@@ -55,6 +64,8 @@ pub fn execute_system_sc(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Blo
         "setBurnRoleGlobally" => set_burn_role_globally(tx_input, tx_cache),
         "unsetBurnRoleGlobally" => unset_burn_role_globally(tx_input, tx_cache),
         "sendAllTransferRoleAddresses" => send_all_transfer_role_addresses(tx_input, tx_cache),
+        "changeToDynamic" => change_to_dynamic(tx_input, tx_cache),
+        "updateTokenID" => update_token_id(tx_input, tx_cache),
         invalid_func_name => panic!("invalid system SC function: {invalid_func_name}"),
     }
 }
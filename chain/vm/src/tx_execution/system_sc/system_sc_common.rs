@@ -0,0 +1,94 @@
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    types::{top_decode_u64, VMAddress},
+    world_mock::{AccountData, DcdtTokenProperties},
+};
+
+/// Converts the outcome of a system SC function into the (result, updates) pair.
+///
+/// Errors discard all changes made to the cache.
+pub(super) fn system_sc_result(
+    result: Result<TxResult, TxPanic>,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    match result {
+        Ok(tx_result) => (tx_result, tx_cache.into_blockchain_updates()),
+        Err(err) => (TxResult::from_panic_obj(&err), BlockchainUpdate::empty()),
+    }
+}
+
+pub(super) fn check_num_args(tx_input: &TxInput, min_num_args: usize) -> Result<(), TxPanic> {
+    if tx_input.args.len() < min_num_args {
+        return Err(TxPanic::user_error("invalid number of arguments"));
+    }
+    Ok(())
+}
+
+pub(super) fn address_arg(arg: &[u8]) -> Result<VMAddress, TxPanic> {
+    if arg.len() != 32 {
+        return Err(TxPanic::user_error("invalid address"));
+    }
+    Ok(VMAddress::from_slice(arg))
+}
+
+pub(super) fn u64_arg(arg: &[u8]) -> Result<u64, TxPanic> {
+    if arg.len() > 8 {
+        return Err(TxPanic::user_error("invalid argument"));
+    }
+    Ok(top_decode_u64(arg))
+}
+
+fn bool_arg(arg: &[u8]) -> Result<bool, TxPanic> {
+    match arg {
+        b"true" => Ok(true),
+        b"false" => Ok(false),
+        _ => Err(TxPanic::user_error("invalid argument")),
+    }
+}
+
+/// Applies property arguments, given as (name, "true"/"false") pairs.
+pub(super) fn apply_token_property_args(
+    properties: &mut DcdtTokenProperties,
+    args: &[Vec<u8>],
+) -> Result<(), TxPanic> {
+    let pairs = args.chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return Err(TxPanic::user_error("invalid number of arguments"));
+    }
+
+    for pair in pairs {
+        let value = bool_arg(&pair[1])?;
+        if !properties.set_property(&pair[0], value) {
+            return Err(TxPanic::user_error("invalid argument"));
+        }
+    }
+
+    Ok(())
+}
+
+/// Loads the properties of a token, only allowing the call if it comes from the token owner.
+pub(super) fn load_owned_token(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+) -> Result<DcdtTokenProperties, TxPanic> {
+    let properties = tx_cache
+        .get_dcdt_token(token_identifier)
+        .ok_or_else(|| TxPanic::user_error("no ticker with given name"))?;
+    if properties.owner != tx_input.from {
+        return Err(TxPanic::user_error("can be called by owner only"));
+    }
+    Ok(properties)
+}
+
+/// The system SC can target accounts that do not exist yet, these get created.
+pub(super) fn with_account_or_new<R, F>(tx_cache: &TxCache, address: &VMAddress, f: F) -> R
+where
+    F: FnOnce(&mut AccountData) -> R,
+{
+    let exists = tx_cache.with_account_or_else(address, |_| true, || false);
+    if !exists {
+        tx_cache.insert_account(AccountData::new_empty(address.clone()));
+    }
+    tx_cache.with_account_mut(address, f)
+}
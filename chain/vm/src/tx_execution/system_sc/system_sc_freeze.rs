@@ -0,0 +1,202 @@
+use num_traits::Zero;
+
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    types::VMTokenType,
+};
+
+use super::system_sc_common::{
+    address_arg, check_num_args, load_owned_token, system_sc_result, u64_arg, with_account_or_new,
+};
+
+/// Freezes a token in a given account. Frozen tokens cannot be transferred to or from that account.
+pub fn freeze(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_set_frozen(&tx_input, &tx_cache, true);
+    system_sc_result(result, tx_cache)
+}
+
+pub fn unfreeze(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_set_frozen(&tx_input, &tx_cache, false);
+    system_sc_result(result, tx_cache)
+}
+
+fn try_set_frozen(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    frozen: bool,
+) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 2)?;
+    let token_identifier = &tx_input.args[0];
+    let properties = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    if !properties.can_freeze {
+        return Err(TxPanic::user_error("cannot freeze"));
+    }
+    let address = address_arg(&tx_input.args[1])?;
+
+    with_account_or_new(tx_cache, &address, |account| {
+        account
+            .dcdt
+            .get_mut_by_identifier_or_default(token_identifier)
+            .frozen = frozen;
+    });
+
+    Ok(TxResult::empty())
+}
+
+/// Freezes a single NFT/SFT/meta-DCDT instance in a given account.
+pub fn freeze_single_nft(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_set_frozen_single_nft(&tx_input, &tx_cache, true);
+    system_sc_result(result, tx_cache)
+}
+
+pub fn unfreeze_single_nft(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_set_frozen_single_nft(&tx_input, &tx_cache, false);
+    system_sc_result(result, tx_cache)
+}
+
+fn try_set_frozen_single_nft(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    frozen: bool,
+) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 3)?;
+    let token_identifier = &tx_input.args[0];
+    let properties = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    if !properties.can_freeze {
+        return Err(TxPanic::user_error("cannot freeze"));
+    }
+    if properties.token_type == VMTokenType::Fungible {
+        return Err(TxPanic::user_error(
+            "only non fungible tokens can be frozen per nonce",
+        ));
+    }
+    let nonce = u64_arg(&tx_input.args[1])?;
+    let address = address_arg(&tx_input.args[2])?;
+
+    with_account_or_new(tx_cache, &address, |account| {
+        let instance = account
+            .dcdt
+            .get_mut_by_identifier(token_identifier)
+            .and_then(|dcdt_data| dcdt_data.instances.get_mut_by_nonce(nonce))
+            .ok_or_else(|| TxPanic::user_error("no nft with given nonce"))?;
+        instance.frozen = frozen;
+        Ok(())
+    })?;
+
+    Ok(TxResult::empty())
+}
+
+/// Removes the entire balance of a fungible token from a frozen account.
+pub fn wipe(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_wipe(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+fn try_wipe(tx_input: &TxInput, tx_cache: &TxCache) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 2)?;
+    let token_identifier = &tx_input.args[0];
+    let mut properties = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    if !properties.can_wipe {
+        return Err(TxPanic::user_error("cannot wipe"));
+    }
+    let address = address_arg(&tx_input.args[1])?;
+
+    let wiped = with_account_or_new(tx_cache, &address, |account| {
+        let dcdt_data = account
+            .dcdt
+            .get_mut_by_identifier(token_identifier)
+            .filter(|dcdt_data| dcdt_data.frozen)
+            .ok_or_else(|| {
+                TxPanic::user_error("cannot wipe because the account is not frozen for this dcdt")
+            })?;
+        let instance = dcdt_data
+            .instances
+            .get_mut_by_nonce(0)
+            .ok_or_else(|| TxPanic::user_error("no balance to wipe"))?;
+        Ok(std::mem::take(&mut instance.balance))
+    })?;
+
+    properties.burnt_value += wiped;
+    properties.num_wiped += 1;
+    tx_cache.set_dcdt_token(token_identifier, properties);
+
+    Ok(TxResult::empty())
+}
+
+/// Removes a single frozen NFT/SFT/meta-DCDT instance from an account.
+pub fn wipe_single_nft(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_wipe_single_nft(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+fn try_wipe_single_nft(tx_input: &TxInput, tx_cache: &TxCache) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 3)?;
+    let token_identifier = &tx_input.args[0];
+    let mut properties = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    if !properties.can_wipe {
+        return Err(TxPanic::user_error("cannot wipe"));
+    }
+    let nonce = u64_arg(&tx_input.args[1])?;
+    let address = address_arg(&tx_input.args[2])?;
+
+    let wiped = with_account_or_new(tx_cache, &address, |account| {
+        let instance = account
+            .dcdt
+            .get_mut_by_identifier(token_identifier)
+            .and_then(|dcdt_data| dcdt_data.instances.get_mut_by_nonce(nonce))
+            .ok_or_else(|| TxPanic::user_error("no nft with given nonce"))?;
+        if !instance.frozen {
+            return Err(TxPanic::user_error(
+                "cannot wipe because the account is not frozen for this dcdt",
+            ));
+        }
+        instance.frozen = false;
+        Ok(std::mem::take(&mut instance.balance))
+    })?;
+
+    if wiped.is_zero() {
+        return Err(TxPanic::user_error("no balance to wipe"));
+    }
+
+    properties.burnt_value += wiped;
+    properties.num_wiped += 1;
+    tx_cache.set_dcdt_token(token_identifier, properties);
+
+    Ok(TxResult::empty())
+}
+
+/// Pauses all transfers of a token.
+pub fn pause(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_set_paused(&tx_input, &tx_cache, true);
+    system_sc_result(result, tx_cache)
+}
+
+pub fn unpause(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_set_paused(&tx_input, &tx_cache, false);
+    system_sc_result(result, tx_cache)
+}
+
+fn try_set_paused(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    paused: bool,
+) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 1)?;
+    let token_identifier = &tx_input.args[0];
+    let mut properties = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    if !properties.can_pause {
+        return Err(TxPanic::user_error("cannot pause"));
+    }
+    if properties.is_paused == paused {
+        return Err(TxPanic::user_error(if paused {
+            "cannot pause an already paused contract"
+        } else {
+            "cannot unPause an already un-paused contract"
+        }));
+    }
+
+    properties.is_paused = paused;
+    tx_cache.set_dcdt_token(token_identifier, properties);
+
+    Ok(TxResult::empty())
+}
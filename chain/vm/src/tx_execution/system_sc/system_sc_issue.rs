@@ -1,13 +1,18 @@
 use num_bigint::BigUint;
+use num_traits::Zero;
 
 use crate::{
     crypto_functions::keccak256,
-    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
     types::{top_decode_u64, VMTokenType},
+    world_mock::{DcdtInstanceMetadata, DcdtTokenProperties},
 };
 
-/// Issues a new fungible token.
-#[allow(unused_variables)]
+use super::system_sc_common::{
+    apply_token_property_args, check_num_args, load_owned_token, system_sc_result, u64_arg,
+};
+
+/// Issues a new fungible token, crediting the initial supply to the issuer.
 pub fn issue(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 4 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
@@ -17,43 +22,119 @@ pub fn issue(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdat
     let ticker = tx_input.args[1].clone();
     let total_supply = BigUint::from_bytes_be(tx_input.args[2].clone().as_ref());
     let decimals = top_decode_u64(tx_input.args[3].clone().as_ref()) as u32;
+    let property_args = tx_input.args[4..].to_vec();
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::Fungible)
+    register_and_set_roles(
+        tx_input,
+        tx_cache,
+        ticker,
+        NewTokenArgs {
+            name,
+            token_type: VMTokenType::Fungible,
+            num_decimals: decimals,
+            property_args,
+            initial_supply: total_supply,
+            is_dynamic: false,
+            set_roles: false,
+        },
+    )
 }
 
 /// Issues a new semi-fungible token.
-#[allow(unused_variables)]
 pub fn issue_semi_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    issue_non_fungible_of_type(tx_input, tx_cache, VMTokenType::SemiFungible)
+}
+
+/// Issues a new non-fungible token.
+pub fn issue_non_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    issue_non_fungible_of_type(tx_input, tx_cache, VMTokenType::NonFungible)
+}
+
+fn issue_non_fungible_of_type(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    token_type: VMTokenType,
+) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 2 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
         return (tx_result, BlockchainUpdate::empty());
     }
     let name = tx_input.args[0].clone();
     let ticker = tx_input.args[1].clone();
+    let property_args = tx_input.args[2..].to_vec();
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::SemiFungible)
+    register_and_set_roles(
+        tx_input,
+        tx_cache,
+        ticker,
+        NewTokenArgs {
+            name,
+            token_type,
+            num_decimals: 0,
+            property_args,
+            initial_supply: BigUint::zero(),
+            is_dynamic: false,
+            set_roles: false,
+        },
+    )
 }
 
-/// Issues a new non-fungible token.
-#[allow(unused_variables)]
-pub fn issue_non_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    if tx_input.args.len() < 2 {
+/// Registers a new meta-DCDT token.
+pub fn register_meta_dcdt(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() < 3 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
         return (tx_result, BlockchainUpdate::empty());
     }
     let name = tx_input.args[0].clone();
     let ticker = tx_input.args[1].clone();
+    let decimals = top_decode_u64(tx_input.args[2].as_slice()) as u32;
+    let property_args = tx_input.args[3..].to_vec();
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::NonFungible)
+    register_and_set_roles(
+        tx_input,
+        tx_cache,
+        ticker,
+        NewTokenArgs {
+            name,
+            token_type: VMTokenType::Meta,
+            num_decimals: decimals,
+            property_args,
+            initial_supply: BigUint::zero(),
+            is_dynamic: false,
+            set_roles: false,
+        },
+    )
 }
 
 // Issues a new token and sets all roles for its type.
-#[allow(unused_variables)]
 pub fn register_and_set_all_roles(
     tx_input: TxInput,
     tx_cache: TxCache,
 ) -> (TxResult, BlockchainUpdate) {
-    if tx_input.args.len() < 4 {
+    register_with_type_arg(tx_input, tx_cache, false, true)
+}
+
+/// Registers a new dynamic token.
+pub fn register_dynamic(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    register_with_type_arg(tx_input, tx_cache, true, false)
+}
+
+/// Registers a new dynamic token and sets all roles for its type.
+pub fn register_and_set_all_roles_dynamic(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    register_with_type_arg(tx_input, tx_cache, true, true)
+}
+
+/// Arguments: name, ticker, type, and optionally the number of decimals.
+fn register_with_type_arg(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    is_dynamic: bool,
+    set_roles: bool,
+) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() < 3 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
         return (tx_result, BlockchainUpdate::empty());
     }
@@ -61,17 +142,87 @@ pub fn register_and_set_all_roles(
     let name = tx_input.args[0].clone();
     let ticker = tx_input.args[1].clone();
     let token_type = VMTokenType::from_system_sc_arg(&tx_input.args[2]);
-    let decimals = top_decode_u64(tx_input.args[3].clone().as_ref()) as u32;
+    let decimals = tx_input
+        .args
+        .get(3)
+        .map(|arg| top_decode_u64(arg.as_slice()) as u32)
+        .unwrap_or_default();
 
-    register_and_set_roles(tx_input, tx_cache, ticker, token_type)
+    register_and_set_roles(
+        tx_input,
+        tx_cache,
+        ticker,
+        NewTokenArgs {
+            name,
+            token_type,
+            num_decimals: decimals,
+            property_args: Vec::new(),
+            initial_supply: BigUint::zero(),
+            is_dynamic,
+            set_roles,
+        },
+    )
+}
+
+/// Converts a semi-fungible token into a meta-DCDT, by giving it decimals.
+pub fn change_sft_to_meta_dcdt(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = try_change_sft_to_meta_dcdt(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+fn try_change_sft_to_meta_dcdt(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 2)?;
+    let token_identifier = &tx_input.args[0];
+    let mut properties = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    if properties.token_type != VMTokenType::SemiFungible {
+        return Err(TxPanic::user_error(
+            "change can happen to semi fungible tokens only",
+        ));
+    }
+
+    properties.token_type = VMTokenType::Meta;
+    properties.num_decimals = u64_arg(&tx_input.args[1])? as u32;
+    tx_cache.set_dcdt_token(token_identifier, properties);
+
+    Ok(TxResult::empty())
+}
+
+struct NewTokenArgs {
+    name: Vec<u8>,
+    token_type: VMTokenType,
+    num_decimals: u32,
+    property_args: Vec<Vec<u8>>,
+    initial_supply: BigUint,
+    is_dynamic: bool,
+    /// Only the `registerAndSetAllRoles` variants grant the issuer the roles for the token type.
+    set_roles: bool,
 }
 
 fn register_and_set_roles(
     tx_input: TxInput,
     tx_cache: TxCache,
     ticker: Vec<u8>,
-    token_type: VMTokenType,
+    new_token_args: NewTokenArgs,
 ) -> (TxResult, BlockchainUpdate) {
+    let token_type = new_token_args.token_type;
+    let mut properties = DcdtTokenProperties::new(
+        new_token_args.name,
+        token_type,
+        tx_input.from.clone(),
+        new_token_args.num_decimals,
+    );
+    properties.is_dynamic = new_token_args.is_dynamic;
+    properties.minted_value = new_token_args.initial_supply.clone();
+    if let Err(err) = apply_token_property_args(&mut properties, &new_token_args.property_args) {
+        return (TxResult::from_panic_obj(&err), BlockchainUpdate::empty());
+    }
+
     let mut new_token_identifiers = tx_cache.get_new_token_identifiers();
 
     let token_identifier = if let Some((i, ti)) =
@@ -84,11 +235,22 @@ fn register_and_set_roles(
     };
 
     tx_cache.with_account_mut(&tx_input.from, |account| {
-        account
-            .dcdt
-            .register_and_set_roles(&token_identifier, token_type);
+        if new_token_args.set_roles {
+            account
+                .dcdt
+                .register_and_set_roles(&token_identifier, token_type);
+        }
+        if !new_token_args.initial_supply.is_zero() {
+            account.dcdt.increase_balance(
+                token_identifier.clone(),
+                0,
+                &new_token_args.initial_supply,
+                DcdtInstanceMetadata::default(),
+            );
+        }
     });
     tx_cache.set_new_token_identifiers(new_token_identifiers);
+    tx_cache.set_dcdt_token(&token_identifier, properties);
 
     let tx_result = TxResult {
         result_values: vec![token_identifier],
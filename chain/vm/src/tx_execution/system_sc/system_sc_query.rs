@@ -0,0 +1,174 @@
+use num_bigint::BigUint;
+
+use crate::{
+    display_util::address_bech32,
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    types::VMAddress,
+};
+
+use super::{
+    system_sc_common::{check_num_args, system_sc_result},
+    DCDT_SYSTEM_SC_ADDRESS_ARRAY,
+};
+
+/// The cost of issuing a token, as configured on the real chain: 0.05 REWA.
+const BASE_ISSUING_COST: u64 = 50_000_000_000_000_000;
+const MIN_TOKEN_NAME_LENGTH: u64 = 3;
+const MAX_TOKEN_NAME_LENGTH: u64 = 20;
+
+/// Returns the token properties, encoded the same way as on the real chain.
+pub fn get_token_properties(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_get_token_properties(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+fn try_get_token_properties(tx_input: &TxInput, tx_cache: &TxCache) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 1)?;
+    let properties = tx_cache
+        .get_dcdt_token(&tx_input.args[0])
+        .ok_or_else(|| TxPanic::user_error("no ticker with given name"))?;
+
+    let result_values = vec![
+        properties.name.clone(),
+        properties.token_type_name().as_bytes().to_vec(),
+        properties.owner.to_vec(),
+        properties.minted_value.to_string().into_bytes(),
+        properties.burnt_value.to_string().into_bytes(),
+        format!("NumDecimals-{}", properties.num_decimals).into_bytes(),
+        bool_property("IsPaused", properties.is_paused),
+        bool_property("CanUpgrade", properties.can_upgrade),
+        bool_property("CanMint", properties.can_mint),
+        bool_property("CanBurn", properties.can_burn),
+        bool_property("CanChangeOwner", properties.can_change_owner),
+        bool_property("CanPause", properties.can_pause),
+        bool_property("CanFreeze", properties.can_freeze),
+        bool_property("CanWipe", properties.can_wipe),
+        bool_property("CanAddSpecialRoles", properties.can_add_special_roles),
+        bool_property(
+            "CanTransferNFTCreateRole",
+            properties.can_transfer_nft_create_role,
+        ),
+        bool_property("NFTCreateStopped", properties.nft_create_stopped),
+        format!("NumWiped-{}", properties.num_wiped).into_bytes(),
+    ];
+
+    Ok(TxResult {
+        result_values,
+        ..Default::default()
+    })
+}
+
+fn bool_property(name: &str, value: bool) -> Vec<u8> {
+    format!("{name}-{value}").into_bytes()
+}
+
+/// Returns one entry per address that has roles, as `<bech32 address>:<role>,<role>,...`.
+pub fn get_special_roles(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_get_special_roles(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+fn try_get_special_roles(tx_input: &TxInput, tx_cache: &TxCache) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 1)?;
+    let token_identifier = &tx_input.args[0];
+
+    let result_values = addresses_and_roles(tx_cache, token_identifier)
+        .into_iter()
+        .map(|(address, roles)| {
+            let roles: Vec<String> = roles
+                .iter()
+                .map(|role| String::from_utf8_lossy(role).into_owned())
+                .collect();
+            format!("{}:{}", address_bech32(&address), roles.join(",")).into_bytes()
+        })
+        .collect();
+
+    Ok(TxResult {
+        result_values,
+        ..Default::default()
+    })
+}
+
+/// Returns, for each address that has roles, the address followed by each of its roles.
+pub fn get_all_addresses_and_roles(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = try_get_all_addresses_and_roles(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+fn try_get_all_addresses_and_roles(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 1)?;
+    let token_identifier = &tx_input.args[0];
+
+    let mut result_values = Vec::new();
+    for (address, roles) in addresses_and_roles(tx_cache, token_identifier) {
+        result_values.push(address.to_vec());
+        result_values.extend(roles);
+    }
+
+    Ok(TxResult {
+        result_values,
+        ..Default::default()
+    })
+}
+
+fn addresses_and_roles(
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+) -> Vec<(VMAddress, Vec<Vec<u8>>)> {
+    tx_cache
+        .all_account_addresses()
+        .into_iter()
+        .filter_map(|address| {
+            let roles =
+                tx_cache.with_account(&address, |account| account.dcdt.get_roles(token_identifier));
+            if roles.is_empty() {
+                None
+            } else {
+                Some((address, roles))
+            }
+        })
+        .collect()
+}
+
+/// Returns the owner, the base issuing cost, and the min and max token name lengths.
+///
+/// The mock has no governance, so the system SC is its own owner.
+pub fn get_contract_config(_tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let tx_result = TxResult {
+        result_values: vec![
+            DCDT_SYSTEM_SC_ADDRESS_ARRAY.to_vec(),
+            BigUint::from(BASE_ISSUING_COST).to_bytes_be(),
+            BigUint::from(MIN_TOKEN_NAME_LENGTH).to_bytes_be(),
+            BigUint::from(MAX_TOKEN_NAME_LENGTH).to_bytes_be(),
+        ],
+        ..Default::default()
+    };
+    (tx_result, tx_cache.into_blockchain_updates())
+}
+
+/// Only the system SC owner can claim, and the mock has no such owner.
+pub fn claim(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = check_system_sc_owner(&tx_input, "claim");
+    system_sc_result(result, tx_cache)
+}
+
+/// Only the system SC owner can change the config, and the mock has no such owner.
+pub fn config_change(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = check_system_sc_owner(&tx_input, "configChange");
+    system_sc_result(result, tx_cache)
+}
+
+fn check_system_sc_owner(tx_input: &TxInput, func_name: &str) -> Result<TxResult, TxPanic> {
+    if tx_input.from.as_array() != &DCDT_SYSTEM_SC_ADDRESS_ARRAY {
+        return Err(TxPanic::user_error(&format!(
+            "{func_name} can be called by whitelisted address only"
+        )));
+    }
+    Ok(TxResult::empty())
+}
@@ -1,6 +1,11 @@
 use crate::{
-    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
-    types::VMAddress,
+    chain_core::types::DcdtLocalRole,
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    types::{VMAddress, VMTokenType},
+};
+
+use super::system_sc_common::{
+    address_arg, check_num_args, load_owned_token, system_sc_result, with_account_or_new,
 };
 
 pub fn set_special_role(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
@@ -11,13 +16,213 @@ pub fn set_special_role(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Bloc
         );
     }
 
-    let token_identifier = tx_input.args[0].clone();
+    let result = try_change_special_roles(&tx_input, &tx_cache, true);
+    system_sc_result(result, tx_cache)
+}
+
+pub fn unset_special_role(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() < 3 {
+        return (
+            TxResult::from_vm_error("unSetSpecialRole too few arguments"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let result = try_change_special_roles(&tx_input, &tx_cache, false);
+    system_sc_result(result, tx_cache)
+}
+
+/// Tokens that were not issued via the system SC (e.g. set up directly in the state)
+/// have no known owner, so anyone is allowed to change their roles.
+fn try_change_special_roles(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    set: bool,
+) -> Result<TxResult, TxPanic> {
+    let token_identifier = &tx_input.args[0];
+    if tx_cache.get_dcdt_token(token_identifier).is_some() {
+        let properties = load_owned_token(tx_input, tx_cache, token_identifier)?;
+        if set && !properties.can_add_special_roles {
+            return Err(TxPanic::user_error("cannot add special roles"));
+        }
+    }
+
     let address = VMAddress::from_slice(tx_input.args[1].as_slice());
-    let role = tx_input.args[2].clone();
+    let roles = &tx_input.args[2..];
 
-    tx_cache.with_account_mut(&address, |account| {
-        account.dcdt.set_special_role(&token_identifier, &role);
+    with_account_or_new(tx_cache, &address, |account| {
+        for role in roles {
+            if set {
+                account.dcdt.set_special_role(token_identifier, role);
+            } else {
+                account.dcdt.unset_special_role(token_identifier, role);
+            }
+        }
     });
 
-    (TxResult::empty(), tx_cache.into_blockchain_updates())
+    Ok(TxResult::empty())
+}
+
+/// Moves the NFT create role from one address to another.
+pub fn transfer_nft_create_role(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = try_transfer_nft_create_role(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+fn try_transfer_nft_create_role(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 3)?;
+    let token_identifier = &tx_input.args[0];
+    let properties = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    if !properties.can_transfer_nft_create_role {
+        return Err(TxPanic::user_error(
+            "NFT create role transfer is not allowed",
+        ));
+    }
+    let old_creator = address_arg(&tx_input.args[1])?;
+    let new_creator = address_arg(&tx_input.args[2])?;
+    let nft_create_role = DcdtLocalRole::NftCreate.as_role_name();
+
+    with_account_or_new(tx_cache, &old_creator, |account| {
+        if !account
+            .dcdt
+            .has_special_role(token_identifier, nft_create_role)
+        {
+            return Err(TxPanic::user_error(
+                "address does not have the NFT create role",
+            ));
+        }
+        account
+            .dcdt
+            .unset_special_role(token_identifier, nft_create_role);
+        Ok(())
+    })?;
+    with_account_or_new(tx_cache, &new_creator, |account| {
+        account
+            .dcdt
+            .set_special_role(token_identifier, nft_create_role);
+    });
+
+    Ok(TxResult::empty())
+}
+
+/// Permanently stops NFT creation for a token, by removing the create role from everyone.
+pub fn stop_nft_create(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_stop_nft_create(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+fn try_stop_nft_create(tx_input: &TxInput, tx_cache: &TxCache) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 1)?;
+    let token_identifier = &tx_input.args[0];
+    let mut properties = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    if properties.token_type == VMTokenType::Fungible {
+        return Err(TxPanic::user_error("invalid token type"));
+    }
+    if properties.nft_create_stopped {
+        return Err(TxPanic::user_error("cannot stop NFT create again"));
+    }
+
+    let nft_create_role = DcdtLocalRole::NftCreate.as_role_name();
+    for address in role_holders(tx_cache, token_identifier, nft_create_role) {
+        tx_cache.with_account_mut(&address, |account| {
+            account
+                .dcdt
+                .unset_special_role(token_identifier, nft_create_role);
+        });
+    }
+
+    properties.nft_create_stopped = true;
+    tx_cache.set_dcdt_token(token_identifier, properties);
+
+    Ok(TxResult::empty())
+}
+
+/// Allows everyone to burn the token, regardless of their roles.
+pub fn set_burn_role_globally(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = try_set_burn_role_globally(&tx_input, &tx_cache, true);
+    system_sc_result(result, tx_cache)
+}
+
+pub fn unset_burn_role_globally(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = try_set_burn_role_globally(&tx_input, &tx_cache, false);
+    system_sc_result(result, tx_cache)
+}
+
+fn try_set_burn_role_globally(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    value: bool,
+) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 1)?;
+    let token_identifier = &tx_input.args[0];
+    let mut properties = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    if properties.burn_role_global == value {
+        return Err(TxPanic::user_error(if value {
+            "cannot set burn role globally as it was already set"
+        } else {
+            "cannot unset burn role globally as it was not set"
+        }));
+    }
+
+    properties.burn_role_global = value;
+    tx_cache.set_dcdt_token(token_identifier, properties);
+
+    Ok(TxResult::empty())
+}
+
+/// On the real chain this propagates the transfer role holders to all shards.
+/// There are no shards here, so it only validates the call.
+pub fn send_all_transfer_role_addresses(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = try_send_all_transfer_role_addresses(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+fn try_send_all_transfer_role_addresses(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 1)?;
+    let token_identifier = &tx_input.args[0];
+    if tx_cache.get_dcdt_token(token_identifier).is_none() {
+        return Err(TxPanic::user_error("no ticker with given name"));
+    }
+
+    let transfer_role = DcdtLocalRole::Transfer.as_role_name();
+    if role_holders(tx_cache, token_identifier, transfer_role).is_empty() {
+        return Err(TxPanic::user_error("no address with transfer role"));
+    }
+
+    Ok(TxResult::empty())
+}
+
+/// All addresses that have the given role for the given token.
+pub(super) fn role_holders(
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+    role: &[u8],
+) -> Vec<VMAddress> {
+    tx_cache
+        .all_account_addresses()
+        .into_iter()
+        .filter(|address| {
+            tx_cache.with_account(address, |account| {
+                account.dcdt.has_special_role(token_identifier, role)
+            })
+        })
+        .collect()
 }
@@ -0,0 +1,86 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    types::VMTokenType,
+    world_mock::DcdtInstanceMetadata,
+};
+
+use super::system_sc_common::{
+    address_arg, check_num_args, load_owned_token, system_sc_result, with_account_or_new,
+};
+
+/// Mints more of a fungible token, to the owner or to a given address.
+pub fn mint(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_mint(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+fn try_mint(tx_input: &TxInput, tx_cache: &TxCache) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 2)?;
+    let token_identifier = &tx_input.args[0];
+    let mut properties = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    if properties.token_type != VMTokenType::Fungible {
+        return Err(TxPanic::user_error("invalid token type"));
+    }
+    if !properties.can_mint {
+        return Err(TxPanic::user_error("token is not mintable"));
+    }
+
+    let amount = BigUint::from_bytes_be(&tx_input.args[1]);
+    if amount.is_zero() {
+        return Err(TxPanic::user_error("invalid amount to mint"));
+    }
+
+    let destination = match tx_input.args.get(2) {
+        Some(arg) => address_arg(arg)?,
+        None => tx_input.from.clone(),
+    };
+
+    with_account_or_new(tx_cache, &destination, |account| {
+        account.dcdt.increase_balance(
+            token_identifier.clone(),
+            0,
+            &amount,
+            DcdtInstanceMetadata::default(),
+        );
+    });
+
+    properties.minted_value += amount;
+    tx_cache.set_dcdt_token(token_identifier, properties);
+
+    Ok(TxResult::empty())
+}
+
+/// Burns fungible tokens held by the caller.
+pub fn dcdt_burn(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_dcdt_burn(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+fn try_dcdt_burn(tx_input: &TxInput, tx_cache: &TxCache) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 2)?;
+    let token_identifier = &tx_input.args[0];
+    let mut properties = tx_cache
+        .get_dcdt_token(token_identifier)
+        .ok_or_else(|| TxPanic::user_error("no ticker with given name"))?;
+    if properties.token_type != VMTokenType::Fungible {
+        return Err(TxPanic::user_error("invalid token type"));
+    }
+    if !properties.can_burn && !properties.burn_role_global {
+        return Err(TxPanic::user_error("token is not burnable"));
+    }
+
+    let amount = BigUint::from_bytes_be(&tx_input.args[1]);
+    if amount.is_zero() {
+        return Err(TxPanic::user_error("negative or 0 value to burn"));
+    }
+
+    tx_cache.subtract_dcdt_balance(&tx_input.from, token_identifier, 0, &amount)?;
+
+    properties.burnt_value += amount;
+    tx_cache.set_dcdt_token(token_identifier, properties);
+
+    Ok(TxResult::empty())
+}
@@ -0,0 +1,113 @@
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    types::VMTokenType,
+};
+
+use super::system_sc_common::{
+    address_arg, apply_token_property_args, check_num_args, load_owned_token, system_sc_result,
+};
+
+/// Gives the token management rights to another address.
+pub fn transfer_ownership(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_transfer_ownership(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+fn try_transfer_ownership(tx_input: &TxInput, tx_cache: &TxCache) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 2)?;
+    let token_identifier = &tx_input.args[0];
+    let mut properties = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    if !properties.can_change_owner {
+        return Err(TxPanic::user_error("cannot change owner of the token"));
+    }
+
+    properties.owner = address_arg(&tx_input.args[1])?;
+    tx_cache.set_dcdt_token(token_identifier, properties);
+
+    Ok(TxResult::empty())
+}
+
+/// Changes token properties, given as (name, "true"/"false") pairs after the token identifier.
+pub fn control_changes(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_control_changes(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+fn try_control_changes(tx_input: &TxInput, tx_cache: &TxCache) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 3)?;
+    let token_identifier = &tx_input.args[0];
+    let mut properties = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    if !properties.can_upgrade {
+        return Err(TxPanic::user_error("token is not upgradable"));
+    }
+
+    apply_token_property_args(&mut properties, &tx_input.args[1..])?;
+    tx_cache.set_dcdt_token(token_identifier, properties);
+
+    Ok(TxResult::empty())
+}
+
+pub fn change_to_multi_shard_create(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = try_change_to_multi_shard_create(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+fn try_change_to_multi_shard_create(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 1)?;
+    let token_identifier = &tx_input.args[0];
+    let mut properties = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    if properties.token_type == VMTokenType::Fungible {
+        return Err(TxPanic::user_error("invalid token type"));
+    }
+    if properties.can_create_multi_shard {
+        return Err(TxPanic::user_error("it is already multi shard create"));
+    }
+    if !properties.can_add_special_roles {
+        return Err(TxPanic::user_error("cannot add special roles"));
+    }
+
+    properties.can_create_multi_shard = true;
+    tx_cache.set_dcdt_token(token_identifier, properties);
+
+    Ok(TxResult::empty())
+}
+
+/// Makes an existing non-fungible token dynamic.
+pub fn change_to_dynamic(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_change_to_dynamic(&tx_input, &tx_cache);
+    system_sc_result(result, tx_cache)
+}
+
+fn try_change_to_dynamic(tx_input: &TxInput, tx_cache: &TxCache) -> Result<TxResult, TxPanic> {
+    check_num_args(tx_input, 1)?;
+    let token_identifier = &tx_input.args[0];
+    let mut properties = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    if properties.token_type == VMTokenType::Fungible {
+        return Err(TxPanic::user_error(
+            "cannot change fungible token to dynamic",
+        ));
+    }
+    if properties.is_dynamic {
+        return Err(TxPanic::user_error("token is already dynamic"));
+    }
+
+    properties.is_dynamic = true;
+    tx_cache.set_dcdt_token(token_identifier, properties);
+
+    Ok(TxResult::empty())
+}
+
+/// On the real chain this migrates the token type to the new storage format.
+/// The mock always stores the type, so it only validates the call.
+pub fn update_token_id(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = check_num_args(&tx_input, 1).and_then(|_| {
+        load_owned_token(&tx_input, &tx_cache, &tx_input.args[0]).map(|_| TxResult::empty())
+    });
+    system_sc_result(result, tx_cache)
+}
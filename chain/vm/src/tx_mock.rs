@@ -10,6 +10,7 @@ mod tx_cache_source;
 mod tx_context;
 mod tx_context_ref;
 mod tx_context_stack;
+mod tx_cross_shard;
 mod tx_input;
 mod tx_input_call_type;
 mod tx_input_function;
@@ -18,6 +19,8 @@ mod tx_managed_types;
 mod tx_panic;
 mod tx_result;
 mod tx_result_calls;
+mod tx_trace;
+mod tx_trace_tree;
 
 pub use blockchain_update::BlockchainUpdate;
 pub use tx_async_call_data::*;
@@ -28,6 +31,7 @@ pub use tx_cache_source::*;
 pub use tx_context::*;
 pub use tx_context_ref::*;
 pub use tx_context_stack::*;
+pub use tx_cross_shard::*;
 pub use tx_input::*;
 pub use tx_input_call_type::CallType;
 pub use tx_input_function::*;
@@ -36,6 +40,7 @@ pub use tx_managed_types::*;
 pub use tx_panic::*;
 pub use tx_result::*;
 pub use tx_result_calls::*;
+pub use tx_trace::*;
 
 #[cfg(feature = "wasm-incompatible")]
 mod blockchain_rng;
@@ -5,16 +5,23 @@ use crate::{
 };
 
 use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
 
 use super::{CallType, CallbackPayments, Promise, TxFunctionName};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct AsyncCallTxData {
+    #[serde(with = "crate::serde_util::hex_bytes")]
     pub from: VMAddress,
+    #[serde(with = "crate::serde_util::hex_bytes")]
     pub to: VMAddress,
+    #[serde(with = "crate::serde_util::big_uint")]
     pub call_value: BigUint,
     pub endpoint_name: TxFunctionName,
+    #[serde(with = "crate::serde_util::hex_vec")]
     pub arguments: Vec<Vec<u8>>,
+    #[serde(with = "crate::serde_util::hex_bytes")]
     pub tx_hash: H256,
 }
 
@@ -126,8 +133,10 @@ pub fn merge_results(mut original: TxResult, mut new: TxResult) -> TxResult {
         original.result_values.append(&mut new.result_values);
         original.result_logs.append(&mut new.result_logs);
         original.result_message = new.result_message;
+        original.gas_used += new.gas_used;
         original
     } else {
+        new.gas_used += original.gas_used;
         new
     }
 }
@@ -1,10 +1,14 @@
+use serde::{Deserialize, Serialize};
+
 use super::{AsyncCallTxData, TxFunctionName};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Promise {
     pub call: AsyncCallTxData,
     pub success_callback: TxFunctionName,
     pub error_callback: TxFunctionName,
+    #[serde(with = "crate::serde_util::hex_bytes")]
     pub callback_closure_data: Vec<u8>,
 }
 
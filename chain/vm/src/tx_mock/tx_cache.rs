@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fmt,
     sync::{Arc, Mutex},
 };
@@ -9,7 +9,7 @@ use colored::Colorize;
 use crate::{
     display_util::address_hex,
     types::VMAddress,
-    world_mock::{AccountData, BlockchainState},
+    world_mock::{AccountData, BlockchainState, DcdtTokenProperties},
 };
 
 use super::{BlockchainUpdate, TxCacheSource};
@@ -18,6 +18,8 @@ pub struct TxCache {
     source_ref: Arc<dyn TxCacheSource>,
     pub(super) accounts: Mutex<HashMap<VMAddress, AccountData>>,
     pub(super) new_token_identifiers: Mutex<Option<Vec<String>>>,
+    pub(super) dcdt_tokens: Mutex<HashMap<Vec<u8>, DcdtTokenProperties>>,
+    pub(super) deleted_accounts: Mutex<HashSet<VMAddress>>,
 }
 
 impl fmt::Debug for TxCache {
@@ -34,6 +36,8 @@ impl TxCache {
             source_ref,
             accounts: Mutex::new(HashMap::new()),
             new_token_identifiers: Mutex::new(None),
+            dcdt_tokens: Mutex::new(HashMap::new()),
+            deleted_accounts: Mutex::new(HashSet::new()),
         }
     }
 
@@ -42,6 +46,10 @@ impl TxCache {
     }
 
     fn load_account_if_necessary(&self, address: &VMAddress) {
+        if self.deleted_accounts.lock().unwrap().contains(address) {
+            return;
+        }
+
         let mut accounts_mut = self.accounts.lock().unwrap();
         if !accounts_mut.contains_key(address) {
             if let Some(blockchain_account) = self.source_ref.load_account(address) {
@@ -86,12 +94,27 @@ impl TxCache {
     }
 
     pub fn insert_account(&self, account_data: AccountData) {
+        self.deleted_accounts
+            .lock()
+            .unwrap()
+            .remove(&account_data.address);
         self.accounts
             .lock()
             .unwrap()
             .insert(account_data.address.clone(), account_data);
     }
 
+    /// Removes the account, together with its storage, balances and code.
+    ///
+    /// The deletion is only applied to the blockchain state once the transaction succeeds.
+    pub fn delete_account(&self, address: &VMAddress) {
+        self.accounts.lock().unwrap().remove(address);
+        self.deleted_accounts
+            .lock()
+            .unwrap()
+            .insert(address.clone());
+    }
+
     pub fn increase_acount_nonce(&self, address: &VMAddress) {
         self.with_account_mut(address, |account| {
             account.nonce += 1;
@@ -127,14 +150,54 @@ impl TxCache {
         *self.new_token_identifiers.lock().unwrap() = Some(token_identifiers);
     }
 
+    /// Will provide a clone of the token properties, if the token was issued via the system SC.
+    pub fn get_dcdt_token(&self, token_identifier: &[u8]) -> Option<DcdtTokenProperties> {
+        let mut dcdt_tokens = self.dcdt_tokens.lock().unwrap();
+        if let Some(properties) = dcdt_tokens.get(token_identifier) {
+            return Some(properties.clone());
+        }
+
+        let properties = self.source_ref.load_dcdt_token(token_identifier)?;
+        dcdt_tokens.insert(token_identifier.to_vec(), properties.clone());
+        Some(properties)
+    }
+
+    pub fn set_dcdt_token(&self, token_identifier: &[u8], properties: DcdtTokenProperties) {
+        self.dcdt_tokens
+            .lock()
+            .unwrap()
+            .insert(token_identifier.to_vec(), properties);
+    }
+
+    /// All addresses known to the cache, either loaded in it or present in the underlying blockchain state.
+    pub fn all_account_addresses(&self) -> Vec<VMAddress> {
+        let mut addresses: Vec<VMAddress> =
+            self.blockchain_ref().accounts.keys().cloned().collect();
+        for address in self.accounts.lock().unwrap().keys() {
+            if !addresses.contains(address) {
+                addresses.push(address.clone());
+            }
+        }
+        let deleted_accounts = self.deleted_accounts.lock().unwrap();
+        addresses.retain(|address| !deleted_accounts.contains(address));
+        addresses.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
+        addresses
+    }
+
     pub fn into_blockchain_updates(self) -> BlockchainUpdate {
         BlockchainUpdate {
             accounts: self.accounts.into_inner().unwrap(),
             new_token_identifiers: self.new_token_identifiers.into_inner().unwrap(),
+            dcdt_tokens: self.dcdt_tokens.into_inner().unwrap(),
+            deleted_accounts: self.deleted_accounts.into_inner().unwrap(),
         }
     }
 
     pub fn commit_updates(&self, updates: BlockchainUpdate) {
         self.accounts.lock().unwrap().extend(updates.accounts);
+        for address in updates.deleted_accounts {
+            self.delete_account(&address);
+        }
+        self.dcdt_tokens.lock().unwrap().extend(updates.dcdt_tokens);
     }
 }
@@ -1,6 +1,6 @@
 use crate::{
     types::VMAddress,
-    world_mock::{AccountData, BlockchainState},
+    world_mock::{AccountData, BlockchainState, DcdtTokenProperties},
 };
 
 use super::TxCache;
@@ -8,12 +8,18 @@ use super::TxCache;
 pub trait TxCacheSource: Send + Sync {
     fn load_account(&self, address: &VMAddress) -> Option<AccountData>;
 
+    fn load_dcdt_token(&self, token_identifier: &[u8]) -> Option<DcdtTokenProperties>;
+
     fn blockchain_ref(&self) -> &BlockchainState;
 }
 
 impl TxCacheSource for TxCache {
     fn load_account(&self, address: &VMAddress) -> Option<AccountData> {
-        Some(self.with_account(address, AccountData::clone))
+        self.with_account_or_else(address, |account| Some(account.clone()), || None)
+    }
+
+    fn load_dcdt_token(&self, token_identifier: &[u8]) -> Option<DcdtTokenProperties> {
+        self.get_dcdt_token(token_identifier)
     }
 
     fn blockchain_ref(&self) -> &BlockchainState {
@@ -26,6 +32,10 @@ impl TxCacheSource for BlockchainState {
         self.accounts.get(address).cloned()
     }
 
+    fn load_dcdt_token(&self, token_identifier: &[u8]) -> Option<DcdtTokenProperties> {
+        self.dcdt_tokens.get(token_identifier).cloned()
+    }
+
     fn blockchain_ref(&self) -> &BlockchainState {
         self
     }
@@ -1,5 +1,5 @@
 use crate::{
-    tx_execution::BlockchainVMRef,
+    tx_execution::{BlockchainVMRef, GasSchedule},
     types::{VMAddress, VMCodeMetadata},
     world_mock::{AccountData, AccountDcdt, BlockchainState, FailingExecutor},
 };
@@ -11,7 +11,8 @@ use std::{
 };
 
 use super::{
-    BackTransfers, BlockchainRng, BlockchainUpdate, TxCache, TxInput, TxManagedTypes, TxResult,
+    BackTransfers, BlockchainRng, BlockchainUpdate, ExecutionTracer, TxCache, TxInput,
+    TxManagedTypes, TxPanic, TxResult,
 };
 
 pub struct TxContext {
@@ -22,6 +23,7 @@ pub struct TxContext {
     pub back_transfers: Mutex<BackTransfers>,
     pub tx_result_cell: Mutex<TxResult>,
     pub b_rng: Mutex<BlockchainRng>,
+    pub gas_used: Mutex<u64>,
 }
 
 impl TxContext {
@@ -35,6 +37,7 @@ impl TxContext {
             back_transfers: Mutex::default(),
             tx_result_cell: Mutex::new(TxResult::empty()),
             b_rng,
+            gas_used: Mutex::new(0),
         }
     }
 
@@ -70,6 +73,7 @@ impl TxContext {
             back_transfers: Mutex::default(),
             tx_result_cell: Mutex::new(TxResult::empty()),
             b_rng,
+            gas_used: Mutex::new(0),
         }
     }
 
@@ -145,6 +149,39 @@ impl TxContext {
         self.b_rng.lock().unwrap()
     }
 
+    pub fn gas_schedule(&self) -> Option<&GasSchedule> {
+        self.vm_ref.gas_schedule.as_ref()
+    }
+
+    pub fn tracer(&self) -> Option<&ExecutionTracer> {
+        self.vm_ref.tracer.as_deref()
+    }
+
+    /// Gas still available to the current call. Equal to the gas limit when gas is not metered.
+    pub fn gas_left(&self) -> u64 {
+        let gas_used = *self.gas_used.lock().unwrap();
+        self.input_ref().gas_limit.saturating_sub(gas_used)
+    }
+
+    /// Consumes gas from the current call, if gas metering is enabled.
+    ///
+    /// When running out of gas, all the gas is considered consumed.
+    pub fn use_gas(&self, gas: u64) -> Result<(), TxPanic> {
+        if !self.vm_ref.is_gas_metered() {
+            return Ok(());
+        }
+
+        let gas_limit = self.input_ref().gas_limit;
+        let mut gas_used = self.gas_used.lock().unwrap();
+        if gas > gas_limit.saturating_sub(*gas_used) {
+            *gas_used = gas_limit;
+            return Err(TxPanic::out_of_gas());
+        }
+
+        *gas_used += gas;
+        Ok(())
+    }
+
     pub fn create_new_contract(
         &self,
         new_address: &VMAddress,
@@ -178,7 +215,8 @@ impl TxContext {
 
     pub fn into_results(self) -> (TxResult, BlockchainUpdate) {
         let tx_cache = Arc::try_unwrap(self.tx_cache).unwrap();
-        let tx_result = Mutex::into_inner(self.tx_result_cell).unwrap();
+        let mut tx_result = Mutex::into_inner(self.tx_result_cell).unwrap();
+        tx_result.gas_used = Mutex::into_inner(self.gas_used).unwrap();
         let blockchain_updates = tx_cache.into_blockchain_updates();
         (tx_result, blockchain_updates)
     }
@@ -192,6 +230,7 @@ impl std::fmt::Debug for TxContext {
             .field("managed_types", &self.managed_types)
             .field("tx_result_cell", &self.tx_result_cell)
             .field("b_rng", &self.b_rng)
+            .field("gas_used", &self.gas_used)
             .finish()
     }
 }
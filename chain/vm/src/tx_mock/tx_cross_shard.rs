@@ -0,0 +1,133 @@
+use dharitri_chain_core::REWA_000000_TOKEN_IDENTIFIER;
+use num_bigint::BigUint;
+use num_traits::Zero;
+use serde::{Deserialize, Serialize};
+
+use crate::{tx_mock::TxPanic, types::VMAddress, world_mock::DcdtInstanceMetadata};
+
+use super::{AsyncCallTxData, Promise, TxCache, TxResult, TxTokenTransfer};
+
+/// A call or transfer that crosses shards, as a smart contract result waiting to be delivered.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossShardCall {
+    /// The round in which the destination shard receives it.
+    pub delivery_round: u64,
+
+    /// Funds that already left the sender, but have not yet arrived.
+    pub payments: InFlightPayments,
+
+    pub kind: CrossShardCallKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum CrossShardCallKind {
+    /// Transfer & execute, no callback.
+    TransferExecute {
+        call: AsyncCallTxData,
+        gas_limit: u64,
+    },
+
+    /// Legacy async call (no promise), or promise.
+    AsyncCall {
+        call: AsyncCallTxData,
+        promise: Option<Promise>,
+        gas_limit: u64,
+    },
+
+    /// The result of an async call, going back to the caller.
+    ///
+    /// The payments are the refund, in case the async call failed.
+    Callback {
+        call: AsyncCallTxData,
+        promise: Option<Promise>,
+        async_result: Box<TxResult>,
+        gas_limit: u64,
+    },
+}
+
+impl CrossShardCallKind {
+    /// The account that gets back the payments, when delivered.
+    pub fn payments_owner(&self) -> &VMAddress {
+        match self {
+            CrossShardCallKind::TransferExecute { call, .. } => &call.from,
+            CrossShardCallKind::AsyncCall { call, .. } => &call.from,
+            CrossShardCallKind::Callback { call, .. } => &call.from,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InFlightPayments {
+    #[serde(with = "crate::serde_util::big_uint")]
+    pub rewa_value: BigUint,
+    pub dcdt_values: Vec<InFlightDcdtPayment>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InFlightDcdtPayment {
+    #[serde(with = "crate::serde_util::hex_bytes")]
+    pub token_identifier: Vec<u8>,
+    pub nonce: u64,
+    #[serde(with = "crate::serde_util::big_uint")]
+    pub value: BigUint,
+    pub metadata: DcdtInstanceMetadata,
+}
+
+impl InFlightPayments {
+    pub fn is_empty(&self) -> bool {
+        self.rewa_value.is_zero() && self.dcdt_values.is_empty()
+    }
+}
+
+impl TxCache {
+    /// Takes the payments out of the sender account, when they leave its shard.
+    pub fn take_in_flight_payments(
+        &self,
+        from: &VMAddress,
+        rewa_value: &BigUint,
+        dcdt_values: &[TxTokenTransfer],
+    ) -> Result<InFlightPayments, TxPanic> {
+        let mut payments = InFlightPayments {
+            rewa_value: rewa_value.clone(),
+            dcdt_values: Vec::new(),
+        };
+        for transfer in dcdt_values {
+            if transfer.token_identifier == REWA_000000_TOKEN_IDENTIFIER.as_bytes() {
+                payments.rewa_value += &transfer.value;
+                continue;
+            }
+            let metadata = self.subtract_dcdt_balance(
+                from,
+                &transfer.token_identifier,
+                transfer.nonce,
+                &transfer.value,
+            )?;
+            payments.dcdt_values.push(InFlightDcdtPayment {
+                token_identifier: transfer.token_identifier.clone(),
+                nonce: transfer.nonce,
+                value: transfer.value.clone(),
+                metadata,
+            });
+        }
+        self.subtract_rewa_balance(from, &payments.rewa_value)?;
+        Ok(payments)
+    }
+
+    /// Gives the payments back to the sender, so that the delivered call can move them as usual.
+    pub fn return_in_flight_payments(&self, to: &VMAddress, payments: InFlightPayments) {
+        self.increase_rewa_balance(to, &payments.rewa_value);
+        for dcdt in payments.dcdt_values {
+            self.increase_dcdt_balance(
+                to,
+                &dcdt.token_identifier,
+                dcdt.nonce,
+                &dcdt.value,
+                dcdt.metadata,
+            );
+        }
+    }
+}
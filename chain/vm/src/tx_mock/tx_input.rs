@@ -23,6 +23,8 @@ pub struct TxInput {
     pub tx_hash: H256,
     pub promise_callback_closure_data: Option<Vec<u8>>,
     pub callback_payments: CallbackPayments,
+    /// Relayed transactions: the relayer pays for the gas, instead of the sender.
+    pub relayer_address: Option<VMAddress>,
 }
 
 impl Default for TxInput {
@@ -40,6 +42,7 @@ impl Default for TxInput {
             tx_hash: H256::zero(),
             promise_callback_closure_data: None,
             callback_payments: Default::default(),
+            relayer_address: None,
         }
     }
 }
@@ -57,6 +60,11 @@ impl fmt::Display for TxInput {
 }
 
 impl TxInput {
+    /// The account that pays for the gas: the relayer, if there is one, the sender otherwise.
+    pub fn gas_payer(&self) -> &VMAddress {
+        self.relayer_address.as_ref().unwrap_or(&self.from)
+    }
+
     pub fn add_arg(&mut self, arg: Vec<u8>) {
         self.args.push(arg);
     }
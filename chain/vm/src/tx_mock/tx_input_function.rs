@@ -1,11 +1,14 @@
 use std::borrow::Cow;
 
+use serde::{Deserialize, Serialize};
+
 const TX_FUNC_NAME_UTF8_ERROR: &str = "error converting function name to utf-8";
 
 /// Contains a SC function name (endpoint, "init", etc.)
 ///
 /// Can be initialized statically and we can make constants out of it.
-#[derive(Default, Clone, PartialEq, Eq, Debug)]
+#[derive(Default, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct TxFunctionName(Cow<'static, str>);
 
 impl From<String> for TxFunctionName {
@@ -1,11 +1,16 @@
+use serde::{Deserialize, Serialize};
+
 use crate::types::VMAddress;
 
 use super::TxFunctionName;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TxLog {
+    #[serde(with = "crate::serde_util::hex_bytes")]
     pub address: VMAddress,
     pub endpoint: TxFunctionName,
+    #[serde(with = "crate::serde_util::hex_vec")]
     pub topics: Vec<Vec<u8>>,
+    #[serde(with = "crate::serde_util::hex_vec")]
     pub data: Vec<Vec<u8>>,
 }
@@ -1,5 +1,7 @@
 use dharitri_chain_core::types::ReturnCode;
 
+use crate::vm_err_msg;
+
 #[derive(Debug, Clone)]
 pub struct TxPanic {
     pub status: ReturnCode,
@@ -21,4 +23,8 @@ impl TxPanic {
     pub fn vm_error(message: &str) -> TxPanic {
         TxPanic::new(ReturnCode::ExecutionFailed, message)
     }
+
+    pub fn out_of_gas() -> TxPanic {
+        TxPanic::new(ReturnCode::OutOfGas, vm_err_msg::OUT_OF_GAS)
+    }
 }
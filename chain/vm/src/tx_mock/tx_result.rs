@@ -1,14 +1,18 @@
 use std::fmt;
 
 use dharitri_chain_core::types::ReturnCode;
+use serde::{Deserialize, Serialize};
 
 use super::{AsyncCallTxData, TxLog, TxPanic, TxResultCalls};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 #[must_use]
 pub struct TxResult {
+    #[serde(with = "crate::serde_util::return_code")]
     pub result_status: ReturnCode,
     pub result_message: String,
+    #[serde(with = "crate::serde_util::hex_vec")]
     pub result_values: Vec<Vec<u8>>,
     pub result_logs: Vec<TxLog>,
 
@@ -21,6 +25,11 @@ pub struct TxResult {
     ///
     /// Is never cleared of its contents.
     pub all_calls: Vec<AsyncCallTxData>,
+
+    /// Gas consumed by the call, including nested calls.
+    ///
+    /// Always 0 when gas metering is disabled.
+    pub gas_used: u64,
 }
 
 impl Default for TxResult {
@@ -32,6 +41,7 @@ impl Default for TxResult {
             result_logs: Vec::new(),
             pending_calls: TxResultCalls::empty(),
             all_calls: Vec::new(),
+            gas_used: 0,
         }
     }
 }
@@ -85,13 +95,15 @@ impl TxResult {
             .extend_from_slice(sync_call_result.result_values.as_slice());
         self.result_logs
             .extend_from_slice(sync_call_result.result_logs.as_slice());
-        if let Some(sync_result_async) = &sync_call_result.pending_calls.async_call {
-            assert!(
-                self.pending_calls.async_call.is_none(),
-                "Multiple async calls not supported"
-            );
-            self.pending_calls.async_call = Some(sync_result_async.clone());
-        }
+        self.pending_calls
+            .async_calls
+            .extend_from_slice(sync_call_result.pending_calls.async_calls.as_slice());
+        self.pending_calls
+            .promises
+            .extend_from_slice(sync_call_result.pending_calls.promises.as_slice());
+        self.pending_calls
+            .cross_shard_calls
+            .extend_from_slice(sync_call_result.pending_calls.cross_shard_calls.as_slice());
     }
 
     pub fn assert_ok(&self) {
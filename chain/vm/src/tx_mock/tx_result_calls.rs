@@ -1,20 +1,31 @@
-use super::{AsyncCallTxData, Promise};
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Default, Debug)]
+use super::{AsyncCallTxData, CrossShardCall, Promise};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct TxResultCalls {
-    pub async_call: Option<AsyncCallTxData>,
+    /// Legacy async calls, in the order in which they were launched.
+    ///
+    /// A contract can only launch one, since it ends its execution,
+    /// but the ones launched from sync calls also end up here.
+    pub async_calls: Vec<AsyncCallTxData>,
     pub promises: Vec<Promise>,
+
+    /// Transfers to other shards, in sharded mode. They only get sent if the call succeeds.
+    pub cross_shard_calls: Vec<CrossShardCall>,
 }
 
 impl TxResultCalls {
     pub fn empty() -> Self {
         TxResultCalls {
-            async_call: None,
+            async_calls: Vec::new(),
             promises: Vec::new(),
+            cross_shard_calls: Vec::new(),
         }
     }
 
     pub fn no_calls(&self) -> bool {
-        self.async_call.is_none() && self.promises.is_empty()
+        self.async_calls.is_empty() && self.promises.is_empty() && self.cross_shard_calls.is_empty()
     }
 }
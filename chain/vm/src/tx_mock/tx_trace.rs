@@ -0,0 +1,262 @@
+use std::{path::Path, sync::Mutex};
+
+use serde::Serialize;
+
+use crate::{
+    chain_core::types::{SC_ADDRESS_NUM_LEADING_ZEROS, VM_TYPE_LEN},
+    display_util::{address_bech32, verbose_hex},
+    types::VMAddress,
+};
+
+use super::{TxInput, TxLog, TxResult};
+
+/// Records everything that happens during execution, when tracing is enabled.
+///
+/// Calls get nested according to the order in which they start and finish,
+/// so sync calls end up inside their caller, while async calls and callbacks,
+/// which only run after their caller has finished, end up next to it.
+#[derive(Debug, Default)]
+pub struct ExecutionTracer {
+    state: Mutex<ExecutionTracerState>,
+}
+
+#[derive(Debug, Default)]
+struct ExecutionTracerState {
+    finished: Vec<TraceCall>,
+    open_calls: Vec<TraceCall>,
+}
+
+/// The full record of the traced executions, in the order in which they were executed.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ExecutionTrace {
+    pub calls: Vec<TraceCall>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceCall {
+    pub call_type: String,
+    pub from: String,
+    pub to: String,
+    pub function: String,
+    pub arguments: Vec<String>,
+    pub rewa_value: String,
+    pub dcdt_transfers: Vec<TraceDcdtTransfer>,
+    pub gas_limit: u64,
+    pub events: Vec<TraceEvent>,
+
+    /// Missing if the call never finished, e.g. if the debugger itself crashed.
+    pub result: Option<TraceCallResult>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceDcdtTransfer {
+    pub token_identifier: String,
+    pub nonce: u64,
+    pub value: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceCallResult {
+    pub status: u64,
+    pub message: String,
+    pub out: Vec<String>,
+    pub gas_used: u64,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum TraceEvent {
+    #[serde(rename_all = "camelCase")]
+    VMHook {
+        name: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    StorageRead {
+        address: String,
+        key: String,
+        value: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    StorageWrite {
+        key: String,
+        value: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    Log {
+        address: String,
+        identifier: String,
+        topics: Vec<String>,
+        data: Vec<String>,
+    },
+    Call(TraceCall),
+}
+
+impl ExecutionTracer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn begin_call(&self, tx_input: &TxInput) {
+        let call = TraceCall::new(tx_input);
+        self.state.lock().unwrap().open_calls.push(call);
+    }
+
+    pub fn end_call(&self, tx_result: &TxResult) {
+        let mut state = self.state.lock().unwrap();
+        let Some(mut call) = state.open_calls.pop() else {
+            return;
+        };
+        call.result = Some(TraceCallResult::new(tx_result));
+        if let Some(parent) = state.open_calls.last_mut() {
+            parent.events.push(TraceEvent::Call(call));
+        } else {
+            state.finished.push(call);
+        }
+    }
+
+    /// Events outside of any call are ignored.
+    pub fn record(&self, event: TraceEvent) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(call) = state.open_calls.last_mut() {
+            call.events.push(event);
+        }
+    }
+
+    /// The VM hook is named after the framework API method that called it.
+    pub fn record_vm_hook(&self, hook_name: &str) {
+        self.record(TraceEvent::VMHook {
+            name: hook_name.to_string(),
+        });
+    }
+
+    pub fn record_storage_read(&self, address: &VMAddress, key: &[u8], value: &[u8]) {
+        self.record(TraceEvent::StorageRead {
+            address: trace_address(address),
+            key: verbose_hex(key),
+            value: verbose_hex(value),
+        });
+    }
+
+    pub fn record_storage_write(&self, key: &[u8], value: &[u8]) {
+        self.record(TraceEvent::StorageWrite {
+            key: verbose_hex(key),
+            value: verbose_hex(value),
+        });
+    }
+
+    pub fn record_log(&self, tx_log: &TxLog) {
+        self.record(TraceEvent::Log {
+            address: trace_address(&tx_log.address),
+            identifier: tx_log.endpoint.to_string(),
+            topics: tx_log
+                .topics
+                .iter()
+                .map(|topic| verbose_hex(topic))
+                .collect(),
+            data: tx_log.data.iter().map(|data| verbose_hex(data)).collect(),
+        });
+    }
+
+    /// A copy of everything traced so far. Calls still in progress are not included.
+    pub fn trace(&self) -> ExecutionTrace {
+        ExecutionTrace {
+            calls: self.state.lock().unwrap().finished.clone(),
+        }
+    }
+
+    /// Discards everything traced so far.
+    pub fn clear(&self) {
+        self.state.lock().unwrap().finished.clear();
+    }
+}
+
+/// Scenario addresses are shown the way they are written in scenarios, all others in bech32.
+fn trace_address(address: &VMAddress) -> String {
+    let bytes = address.as_bytes();
+    if bytes.iter().all(u8::is_ascii_graphic) {
+        return format!(
+            "address:{}",
+            String::from_utf8_lossy(bytes).trim_end_matches('_')
+        );
+    }
+
+    // smart contract addresses start with the zero bytes, followed by the VM type
+    let name = &bytes[SC_ADDRESS_NUM_LEADING_ZEROS as usize + VM_TYPE_LEN..];
+    if address.is_smart_contract_address() && name.iter().all(u8::is_ascii_graphic) {
+        return format!("sc:{}", String::from_utf8_lossy(name).trim_end_matches('_'));
+    }
+
+    address_bech32(address)
+}
+
+impl TraceCall {
+    fn new(tx_input: &TxInput) -> Self {
+        TraceCall {
+            call_type: format!("{:?}", tx_input.call_type),
+            from: trace_address(&tx_input.from),
+            to: trace_address(&tx_input.to),
+            function: tx_input.func_name.to_string(),
+            arguments: tx_input.args.iter().map(|arg| verbose_hex(arg)).collect(),
+            rewa_value: tx_input.rewa_value.to_string(),
+            dcdt_transfers: tx_input
+                .dcdt_values
+                .iter()
+                .map(|transfer| TraceDcdtTransfer {
+                    token_identifier: String::from_utf8_lossy(&transfer.token_identifier)
+                        .into_owned(),
+                    nonce: transfer.nonce,
+                    value: transfer.value.to_string(),
+                })
+                .collect(),
+            gas_limit: tx_input.gas_limit,
+            events: Vec::new(),
+            result: None,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.result
+            .as_ref()
+            .is_some_and(|result| result.status == 0)
+    }
+
+    /// Follows the failed calls down to the innermost one, which is the one that caused the failure.
+    pub fn failed_call(&self) -> Option<&TraceCall> {
+        if self.is_success() {
+            return None;
+        }
+        let failed_sub_call = self.events.iter().find_map(|event| match event {
+            TraceEvent::Call(sub_call) => sub_call.failed_call(),
+            _ => None,
+        });
+        Some(failed_sub_call.unwrap_or(self))
+    }
+}
+
+impl TraceCallResult {
+    fn new(tx_result: &TxResult) -> Self {
+        TraceCallResult {
+            status: tx_result.result_status.as_u64(),
+            message: tx_result.result_message.clone(),
+            out: tx_result
+                .result_values
+                .iter()
+                .map(|value| verbose_hex(value))
+                .collect(),
+            gas_used: tx_result.gas_used,
+        }
+    }
+}
+
+impl ExecutionTrace {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("failed to serialize execution trace")
+    }
+
+    pub fn write_json_file<P: AsRef<Path>>(&self, path: P) {
+        std::fs::write(path, self.to_json()).expect("failed to write execution trace file");
+    }
+}
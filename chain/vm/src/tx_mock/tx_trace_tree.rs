@@ -0,0 +1,126 @@
+use std::fmt::{self, Write};
+
+use super::{ExecutionTrace, TraceCall, TraceEvent};
+
+/// Consecutive VM hooks are shown on the same line, to keep the tree readable.
+enum TreeNode<'a> {
+    Line(String),
+    Call(&'a TraceCall),
+}
+
+impl ExecutionTrace {
+    /// Human-readable call tree of all the traced executions.
+    pub fn call_tree(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for ExecutionTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for call in &self.calls {
+            write_call(f, call, "")?;
+        }
+        Ok(())
+    }
+}
+
+fn call_header(call: &TraceCall) -> String {
+    let mut header = format!(
+        "{} {} -> {} {}({})",
+        call.call_type,
+        call.from,
+        call.to,
+        call.function,
+        call.arguments.join(", ")
+    );
+    if call.rewa_value != "0" {
+        write!(header, " rewa: {}", call.rewa_value).unwrap();
+    }
+    for transfer in &call.dcdt_transfers {
+        write!(
+            header,
+            " dcdt: {}-{} {}",
+            transfer.token_identifier, transfer.nonce, transfer.value
+        )
+        .unwrap();
+    }
+    write!(header, " gas limit: {}", call.gas_limit).unwrap();
+    header
+}
+
+fn call_nodes(call: &TraceCall) -> Vec<TreeNode<'_>> {
+    let mut nodes = Vec::new();
+    let mut vm_hooks: Vec<&str> = Vec::new();
+    for event in &call.events {
+        if let TraceEvent::VMHook { name } = event {
+            vm_hooks.push(name);
+            continue;
+        }
+        flush_vm_hooks(&mut nodes, &mut vm_hooks);
+        match event {
+            TraceEvent::VMHook { .. } => unreachable!(),
+            TraceEvent::StorageRead {
+                address,
+                key,
+                value,
+            } => nodes.push(TreeNode::Line(format!(
+                "storage read {address} {key} = {value}"
+            ))),
+            TraceEvent::StorageWrite { key, value } => {
+                nodes.push(TreeNode::Line(format!("storage write {key} = {value}")))
+            },
+            TraceEvent::Log {
+                address,
+                identifier,
+                topics,
+                data,
+            } => nodes.push(TreeNode::Line(format!(
+                "log {address} {identifier} topics: [{}] data: [{}]",
+                topics.join(", "),
+                data.join(", ")
+            ))),
+            TraceEvent::Call(sub_call) => nodes.push(TreeNode::Call(sub_call)),
+        }
+    }
+    flush_vm_hooks(&mut nodes, &mut vm_hooks);
+
+    let result_line = match &call.result {
+        Some(result) if result.status == 0 => format!(
+            "ok, gas used: {}, out: [{}]",
+            result.gas_used,
+            result.out.join(", ")
+        ),
+        Some(result) => format!("error {}: {}", result.status, result.message),
+        None => "not finished".to_string(),
+    };
+    nodes.push(TreeNode::Line(result_line));
+    nodes
+}
+
+fn flush_vm_hooks(nodes: &mut Vec<TreeNode<'_>>, vm_hooks: &mut Vec<&str>) {
+    if !vm_hooks.is_empty() {
+        nodes.push(TreeNode::Line(format!("vm hooks: {}", vm_hooks.join(", "))));
+        vm_hooks.clear();
+    }
+}
+
+fn write_call(f: &mut fmt::Formatter<'_>, call: &TraceCall, indent: &str) -> fmt::Result {
+    writeln!(f, "{}", call_header(call))?;
+    let nodes = call_nodes(call);
+    let last_index = nodes.len() - 1;
+    for (i, node) in nodes.into_iter().enumerate() {
+        let (branch, child_indent) = if i == last_index {
+            ("└─ ", "   ")
+        } else {
+            ("├─ ", "│  ")
+        };
+        write!(f, "{indent}{branch}")?;
+        match node {
+            TreeNode::Line(line) => writeln!(f, "{line}")?,
+            TreeNode::Call(sub_call) => {
+                write_call(f, sub_call, &format!("{indent}{child_indent}"))?
+            },
+        }
+    }
+    Ok(())
+}
@@ -10,6 +10,26 @@ pub const NUMBER_IS_NOT_NORMAL: &str =
     "number is not normal. It is either infinite, NaN or subnormal";
 pub const CANNOT_COMPARE_VALUES: &str = "values are not comparable";
 
+pub const INVALID_SIGNATURE: &str = "invalid signature";
+/// The BLS and secp256r1 hooks word their error differently from the ed25519 one.
+pub const SIGNATURE_IS_INVALID: &str = "signature is invalid";
+pub const NO_ELLIPTIC_CURVE_WITH_THIS_NAME: &str = "no elliptic curve with this name";
+pub const POINT_NOT_ON_CURVE: &str = "point is not on curve";
+pub const LENGTH_OF_BUFFER_IS_NOT_CORRECT: &str = "length of buffer is not correct";
+
+pub const OUT_OF_GAS: &str = "out of gas";
+
+pub const NEGATIVE_LOCK_TIMESTAMP: &str = "negative lock timestamp";
+
+pub const DCDT_TOKEN_IS_PAUSED: &str = "dcdt token is paused";
+pub const DCDT_IS_FROZEN_FOR_ACCOUNT: &str = "account is frozen for this dcdt";
+
+pub const SYNC_EXECUTION_NOT_IN_SAME_SHARD: &str =
+    "sync execution request is not in the same shard";
+
+pub const UPGRADE_NOT_ALLOWED: &str = "upgrade not allowed";
+pub const DELETE_NOT_ALLOWED: &str = "delete not allowed";
+
 pub const ERROR_SIGNALLED_BY_SMARTCONTRACT: &str = "error signalled by smartcontract";
 
 pub const ERROR_NO_CALLBACK_CLOSURE: &str =
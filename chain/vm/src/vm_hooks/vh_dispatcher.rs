@@ -2,7 +2,10 @@ use std::ffi::c_void;
 
 use dharitri_vm_executor::{MemLength, MemPtr, VMHooks};
 
-use crate::mem_conv;
+use crate::{
+    crypto_functions, mem_conv,
+    types::{VMAddress, VMCodeMetadata},
+};
 
 use super::VMHooksHandler;
 
@@ -26,6 +29,85 @@ fn bool_to_i32(b: bool) -> i32 {
     }
 }
 
+/// Signature verification hooks return 0 for a valid signature.
+fn sig_valid_to_i32(sig_valid: bool) -> i32 {
+    if sig_valid {
+        0
+    } else {
+        -1
+    }
+}
+
+const ED25519_KEY_LEN: MemLength = 32;
+const ED25519_SIGNATURE_LEN: MemLength = 64;
+
+/// Copies the result of a hook into contract memory.
+///
+/// # Safety
+///
+/// The destination needs to be large enough to hold the result.
+unsafe fn write_result_bytes(result_offset: MemPtr, result: &[u8]) {
+    mem_conv::with_bytes_mut(result_offset, result.len() as MemLength, |dest| {
+        dest.copy_from_slice(result)
+    })
+}
+
+const VALUE_LEN: MemLength = 32;
+const ADDRESS_LEN: MemLength = 32;
+const CODE_METADATA_LEN: MemLength = 2;
+
+/// Reads an REWA value, an address and a code metadata, for the contract creation hooks.
+///
+/// # Safety
+///
+/// The offsets need to point to the full encoded values in contract memory.
+unsafe fn read_value(value_offset: MemPtr) -> num_bigint::BigUint {
+    mem_conv::with_bytes(value_offset, VALUE_LEN, num_bigint::BigUint::from_bytes_be)
+}
+
+unsafe fn read_address(address_offset: MemPtr) -> VMAddress {
+    mem_conv::with_bytes(address_offset, ADDRESS_LEN, VMAddress::from_slice)
+}
+
+unsafe fn read_code_metadata(code_metadata_offset: MemPtr) -> VMCodeMetadata {
+    mem_conv::with_bytes(code_metadata_offset, CODE_METADATA_LEN, |bytes| {
+        VMCodeMetadata::from(bytes)
+    })
+}
+
+/// Reads contract call arguments, given as a list of little endian i32 lengths,
+/// followed by the concatenated argument data.
+///
+/// # Safety
+///
+/// Both lists need to be fully present in contract memory.
+unsafe fn read_arguments(
+    num_arguments: i32,
+    arguments_length_offset: MemPtr,
+    data_offset: MemPtr,
+) -> Vec<Vec<u8>> {
+    let lengths: Vec<MemLength> = mem_conv::with_bytes(
+        arguments_length_offset,
+        num_arguments as MemLength * 4,
+        |bytes| {
+            bytes
+                .chunks_exact(4)
+                .map(|chunk| i32::from_le_bytes(chunk.try_into().unwrap()) as MemLength)
+                .collect()
+        },
+    );
+
+    let mut arg_offset = data_offset;
+    lengths
+        .into_iter()
+        .map(|length| {
+            let arg = mem_conv::with_bytes(arg_offset, length, <[u8]>::to_vec);
+            arg_offset += length;
+            arg
+        })
+        .collect()
+}
+
 #[allow(unused)]
 impl VMHooks for VMHooksDispatcher {
     fn set_vm_hooks_ptr(&mut self, _vm_hooks_ptr: *mut c_void) {}
@@ -253,7 +335,15 @@ impl VMHooks for VMHooksDispatcher {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) {
-        panic!("Unavailable: upgrade_contract");
+        unsafe {
+            let to = read_address(dest_offset);
+            let rewa_value = read_value(value_offset);
+            let code = mem_conv::with_bytes(code_offset, length, <[u8]>::to_vec);
+            let code_metadata = read_code_metadata(code_metadata_offset);
+            let args = read_arguments(num_arguments, arguments_length_offset, data_offset);
+            self.handler
+                .perform_upgrade_contract(to, rewa_value, code, code_metadata, args);
+        }
     }
 
     fn upgrade_from_source_contract(
@@ -267,7 +357,17 @@ impl VMHooks for VMHooksDispatcher {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) {
-        panic!("Unavailable: upgrade_from_source_contract");
+        unsafe {
+            let to = read_address(dest_offset);
+            let rewa_value = read_value(value_offset);
+            let code = self
+                .handler
+                .account_code(&read_address(source_contract_address_offset));
+            let code_metadata = read_code_metadata(code_metadata_offset);
+            let args = read_arguments(num_arguments, arguments_length_offset, data_offset);
+            self.handler
+                .perform_upgrade_contract(to, rewa_value, code, code_metadata, args);
+        }
     }
 
     fn delete_contract(
@@ -278,7 +378,10 @@ impl VMHooks for VMHooksDispatcher {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) {
-        panic!("Unavailable: delete_contract");
+        unsafe {
+            self.handler
+                .perform_delete_contract(read_address(dest_offset));
+        }
     }
 
     fn async_call(
@@ -341,19 +444,35 @@ impl VMHooks for VMHooksDispatcher {
         key_length: MemLength,
         lock_timestamp: i64,
     ) -> i32 {
-        panic!("Unavailable: set_storage_lock")
+        unsafe {
+            mem_conv::with_bytes(key_offset, key_length, |key| {
+                self.handler.set_storage_lock(key, lock_timestamp)
+            })
+        }
     }
 
     fn get_storage_lock(&self, key_offset: MemPtr, key_length: MemLength) -> i64 {
-        panic!("Unavailable: get_storage_lock")
+        unsafe {
+            mem_conv::with_bytes(key_offset, key_length, |key| {
+                self.handler.get_storage_lock(key)
+            })
+        }
     }
 
     fn is_storage_locked(&self, key_offset: MemPtr, key_length: MemLength) -> i32 {
-        panic!("Unavailable: is_storage_locked")
+        unsafe {
+            mem_conv::with_bytes(key_offset, key_length, |key| {
+                self.handler.is_storage_locked(key)
+            })
+        }
     }
 
     fn clear_storage_lock(&self, key_offset: MemPtr, key_length: MemLength) -> i32 {
-        panic!("Unavailable: clear_storage_lock")
+        unsafe {
+            mem_conv::with_bytes(key_offset, key_length, |key| {
+                self.handler.clear_storage_lock(key)
+            })
+        }
     }
 
     fn get_caller(&self, result_offset: MemPtr) {
@@ -564,7 +683,17 @@ impl VMHooks for VMHooksDispatcher {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: create_contract")
+        unsafe {
+            let rewa_value = read_value(value_offset);
+            let code = mem_conv::with_bytes(code_offset, length, <[u8]>::to_vec);
+            let code_metadata = read_code_metadata(code_metadata_offset);
+            let args = read_arguments(num_arguments, arguments_length_offset, data_offset);
+            let (new_address, _) =
+                self.handler
+                    .perform_deploy(rewa_value, code, code_metadata, args);
+            write_result_bytes(result_offset, new_address.as_bytes());
+        }
+        0
     }
 
     fn deploy_from_source_contract(
@@ -578,7 +707,19 @@ impl VMHooks for VMHooksDispatcher {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: deploy_from_source_contract")
+        unsafe {
+            let rewa_value = read_value(value_offset);
+            let code = self
+                .handler
+                .account_code(&read_address(source_contract_address_offset));
+            let code_metadata = read_code_metadata(code_metadata_offset);
+            let args = read_arguments(num_arguments, arguments_length_offset, data_offset);
+            let (new_address, _) =
+                self.handler
+                    .perform_deploy(rewa_value, code, code_metadata, args);
+            write_result_bytes(result_address_offset, new_address.as_bytes());
+        }
+        0
     }
 
     fn get_num_return_data(&self) -> i32 {
@@ -793,7 +934,8 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_delete_contract(&self, dest_handle: i32, gas_limit: i64, arguments_handle: i32) {
-        panic!("Unavailable: managed_delete_contract");
+        self.handler
+            .delete_contract(dest_handle, gas_limit as u64, arguments_handle);
     }
 
     fn managed_deploy_from_source_contract(
@@ -1539,7 +1681,12 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn sha256(&self, data_offset: MemPtr, length: MemLength, result_offset: MemPtr) -> i32 {
-        panic!("Unavailable: sha256")
+        unsafe {
+            let result =
+                mem_conv::with_bytes(data_offset, length, |data| self.handler.sha256(data));
+            write_result_bytes(result_offset, &result[..]);
+        }
+        0
     }
 
     fn managed_sha256(&self, input_handle: i32, output_handle: i32) -> i32 {
@@ -1548,7 +1695,12 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn keccak256(&self, data_offset: MemPtr, length: MemLength, result_offset: MemPtr) -> i32 {
-        panic!("Unavailable: keccak256")
+        unsafe {
+            let result =
+                mem_conv::with_bytes(data_offset, length, |data| self.handler.keccak256(data));
+            write_result_bytes(result_offset, &result[..]);
+        }
+        0
     }
 
     fn managed_keccak256(&self, input_handle: i32, output_handle: i32) -> i32 {
@@ -1557,11 +1709,17 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn ripemd160(&self, data_offset: MemPtr, length: MemLength, result_offset: MemPtr) -> i32 {
-        panic!("Unavailable: ripemd160")
+        unsafe {
+            let result =
+                mem_conv::with_bytes(data_offset, length, |data| self.handler.ripemd160(data));
+            write_result_bytes(result_offset, &result[..]);
+        }
+        0
     }
 
     fn managed_ripemd160(&self, input_handle: i32, output_handle: i32) -> i32 {
-        panic!("Unavailable: managed_ripemd160")
+        self.handler.ripemd160_managed(output_handle, input_handle);
+        0
     }
 
     fn verify_bls(
@@ -1571,11 +1729,28 @@ impl VMHooks for VMHooksDispatcher {
         message_length: MemLength,
         sig_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: verify_bls")
+        unsafe {
+            mem_conv::with_bytes(
+                key_offset,
+                crypto_functions::BLS_PUBLIC_KEY_LEN as MemLength,
+                |key| {
+                    mem_conv::with_bytes(message_offset, message_length, |message| {
+                        mem_conv::with_bytes(
+                            sig_offset,
+                            crypto_functions::BLS_SIGNATURE_LEN as MemLength,
+                            |signature| self.handler.verify_bls(key, message, signature),
+                        )
+                    })
+                },
+            );
+        }
+        0
     }
 
     fn managed_verify_bls(&self, key_handle: i32, message_handle: i32, sig_handle: i32) -> i32 {
-        panic!("Unavailable: managed_verify_bls")
+        self.handler
+            .verify_bls_managed(key_handle, message_handle, sig_handle);
+        0
     }
 
     fn verify_ed25519(
@@ -1585,7 +1760,16 @@ impl VMHooks for VMHooksDispatcher {
         message_length: MemLength,
         sig_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: verify_ed25519")
+        unsafe {
+            mem_conv::with_bytes(key_offset, ED25519_KEY_LEN, |key| {
+                mem_conv::with_bytes(message_offset, message_length, |message| {
+                    mem_conv::with_bytes(sig_offset, ED25519_SIGNATURE_LEN, |signature| {
+                        self.handler.verify_ed25519(key, message, signature)
+                    })
+                })
+            });
+        }
+        0
     }
 
     fn managed_verify_ed25519(&self, key_handle: i32, message_handle: i32, sig_handle: i32) -> i32 {
@@ -1603,7 +1787,19 @@ impl VMHooks for VMHooksDispatcher {
         sig_offset: MemPtr,
         hash_type: i32,
     ) -> i32 {
-        panic!("Unavailable: verify_custom_secp256k1")
+        unsafe {
+            let sig_length =
+                mem_conv::with_bytes(sig_offset, 2, crypto_functions::secp256k1_der_signature_len);
+            let sig_valid = mem_conv::with_bytes(key_offset, key_length, |key| {
+                mem_conv::with_bytes(message_offset, message_length, |message| {
+                    mem_conv::with_bytes(sig_offset, sig_length as MemLength, |signature| {
+                        self.handler
+                            .verify_secp256k1(key, message, signature, hash_type as u8)
+                    })
+                })
+            });
+            sig_valid_to_i32(sig_valid)
+        }
     }
 
     fn managed_verify_custom_secp256k1(
@@ -1613,7 +1809,12 @@ impl VMHooks for VMHooksDispatcher {
         sig_handle: i32,
         hash_type: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_verify_custom_secp256k1")
+        sig_valid_to_i32(self.handler.verify_custom_secp256k1_managed(
+            key_handle,
+            message_handle,
+            sig_handle,
+            hash_type as u8,
+        ))
     }
 
     fn verify_secp256k1(
@@ -1624,7 +1825,14 @@ impl VMHooks for VMHooksDispatcher {
         message_length: MemLength,
         sig_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: verify_secp256k1")
+        self.verify_custom_secp256k1(
+            key_offset,
+            key_length,
+            message_offset,
+            message_length,
+            sig_offset,
+            crypto_functions::SECP256K1_HASH_TYPE_DOUBLE_SHA256 as i32,
+        )
     }
 
     fn managed_verify_secp256k1(
@@ -1633,7 +1841,11 @@ impl VMHooks for VMHooksDispatcher {
         message_handle: i32,
         sig_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_verify_secp256k1")
+        sig_valid_to_i32(self.handler.verify_secp256k1_managed(
+            key_handle,
+            message_handle,
+            sig_handle,
+        ))
     }
 
     fn encode_secp256k1_der_signature(
@@ -1644,7 +1856,15 @@ impl VMHooks for VMHooksDispatcher {
         s_length: MemLength,
         sig_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: encode_secp256k1_der_signature")
+        unsafe {
+            let signature = mem_conv::with_bytes(r_offset, r_length, |r| {
+                mem_conv::with_bytes(s_offset, s_length, |s| {
+                    self.handler.encode_secp256k1_der_signature(r, s)
+                })
+            });
+            write_result_bytes(sig_offset, &signature);
+        }
+        0
     }
 
     fn managed_encode_secp256k1_der_signature(
@@ -1653,7 +1873,9 @@ impl VMHooks for VMHooksDispatcher {
         s_handle: i32,
         sig_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_encode_secp256k1_der_signature")
+        self.handler
+            .encode_secp256k1_der_signature_managed(r_handle, s_handle, sig_handle);
+        0
     }
 
     fn add_ec(
@@ -1666,7 +1888,15 @@ impl VMHooks for VMHooksDispatcher {
         snd_point_xhandle: i32,
         snd_point_yhandle: i32,
     ) {
-        panic!("Unavailable: add_ec");
+        self.handler.ec_add(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            fst_point_xhandle,
+            fst_point_yhandle,
+            snd_point_xhandle,
+            snd_point_yhandle,
+        );
     }
 
     fn double_ec(
@@ -1677,11 +1907,20 @@ impl VMHooks for VMHooksDispatcher {
         point_xhandle: i32,
         point_yhandle: i32,
     ) {
-        panic!("Unavailable: double_ec");
+        self.handler.ec_double(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            point_xhandle,
+            point_yhandle,
+        );
     }
 
     fn is_on_curve_ec(&self, ec_handle: i32, point_xhandle: i32, point_yhandle: i32) -> i32 {
-        panic!("Unavailable: is_on_curve_ec")
+        bool_to_i32(
+            self.handler
+                .ec_is_on_curve(ec_handle, point_xhandle, point_yhandle),
+        )
     }
 
     fn scalar_base_mult_ec(
@@ -1692,7 +1931,13 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         length: MemLength,
     ) -> i32 {
-        panic!("Unavailable: scalar_base_mult_ec")
+        unsafe {
+            mem_conv::with_bytes(data_offset, length, |data| {
+                self.handler
+                    .ec_scalar_base_mult(x_result_handle, y_result_handle, ec_handle, data)
+            });
+        }
+        0
     }
 
     fn managed_scalar_base_mult_ec(
@@ -1702,7 +1947,13 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         data_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_scalar_base_mult_ec")
+        self.handler.ec_scalar_base_mult_managed(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            data_handle,
+        );
+        0
     }
 
     fn scalar_mult_ec(
@@ -1715,7 +1966,19 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         length: MemLength,
     ) -> i32 {
-        panic!("Unavailable: scalar_mult_ec")
+        unsafe {
+            mem_conv::with_bytes(data_offset, length, |data| {
+                self.handler.ec_scalar_mult(
+                    x_result_handle,
+                    y_result_handle,
+                    ec_handle,
+                    point_xhandle,
+                    point_yhandle,
+                    data,
+                )
+            });
+        }
+        0
     }
 
     fn managed_scalar_mult_ec(
@@ -1727,7 +1990,15 @@ impl VMHooks for VMHooksDispatcher {
         point_yhandle: i32,
         data_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_scalar_mult_ec")
+        self.handler.ec_scalar_mult_managed(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            point_xhandle,
+            point_yhandle,
+            data_handle,
+        );
+        0
     }
 
     fn marshal_ec(
@@ -1737,7 +2008,13 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: marshal_ec")
+        let result = self
+            .handler
+            .ec_marshal(ec_handle, x_pair_handle, y_pair_handle);
+        unsafe {
+            write_result_bytes(result_offset, &result);
+        }
+        result.len() as i32
     }
 
     fn managed_marshal_ec(
@@ -1747,7 +2024,9 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_marshal_ec")
+        self.handler
+            .ec_marshal_managed(ec_handle, x_pair_handle, y_pair_handle, result_handle);
+        0
     }
 
     fn marshal_compressed_ec(
@@ -1757,7 +2036,13 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: marshal_compressed_ec")
+        let result = self
+            .handler
+            .ec_marshal_compressed(ec_handle, x_pair_handle, y_pair_handle);
+        unsafe {
+            write_result_bytes(result_offset, &result);
+        }
+        result.len() as i32
     }
 
     fn managed_marshal_compressed_ec(
@@ -1767,7 +2052,13 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_marshal_compressed_ec")
+        self.handler.ec_marshal_compressed_managed(
+            ec_handle,
+            x_pair_handle,
+            y_pair_handle,
+            result_handle,
+        );
+        0
     }
 
     fn unmarshal_ec(
@@ -1778,7 +2069,13 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         length: MemLength,
     ) -> i32 {
-        panic!("Unavailable: unmarshal_ec")
+        unsafe {
+            mem_conv::with_bytes(data_offset, length, |data| {
+                self.handler
+                    .ec_unmarshal(x_result_handle, y_result_handle, ec_handle, data)
+            });
+        }
+        0
     }
 
     fn managed_unmarshal_ec(
@@ -1788,7 +2085,9 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         data_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_unmarshal_ec")
+        self.handler
+            .ec_unmarshal_managed(x_result_handle, y_result_handle, ec_handle, data_handle);
+        0
     }
 
     fn unmarshal_compressed_ec(
@@ -1799,7 +2098,17 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         length: MemLength,
     ) -> i32 {
-        panic!("Unavailable: unmarshal_compressed_ec")
+        unsafe {
+            mem_conv::with_bytes(data_offset, length, |data| {
+                self.handler.ec_unmarshal_compressed(
+                    x_result_handle,
+                    y_result_handle,
+                    ec_handle,
+                    data,
+                )
+            });
+        }
+        0
     }
 
     fn managed_unmarshal_compressed_ec(
@@ -1809,7 +2118,13 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         data_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_unmarshal_compressed_ec")
+        self.handler.ec_unmarshal_compressed_managed(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            data_handle,
+        );
+        0
     }
 
     fn generate_key_ec(
@@ -1819,7 +2134,13 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: generate_key_ec")
+        let private_key =
+            self.handler
+                .ec_generate_key(x_pub_key_handle, y_pub_key_handle, ec_handle);
+        unsafe {
+            write_result_bytes(result_offset, &private_key);
+        }
+        private_key.len() as i32
     }
 
     fn managed_generate_key_ec(
@@ -1829,23 +2150,33 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_generate_key_ec")
+        self.handler.ec_generate_key_managed(
+            x_pub_key_handle,
+            y_pub_key_handle,
+            ec_handle,
+            result_handle,
+        );
+        0
     }
 
     fn create_ec(&self, data_offset: MemPtr, data_length: MemLength) -> i32 {
-        panic!("Unavailable: create_ec")
+        unsafe {
+            mem_conv::with_bytes(data_offset, data_length, |name| {
+                self.handler.ec_create_from_name(name)
+            })
+        }
     }
 
     fn managed_create_ec(&self, data_handle: i32) -> i32 {
-        panic!("Unavailable: managed_create_ec")
+        self.handler.ec_create_from_name_managed(data_handle)
     }
 
     fn get_curve_length_ec(&self, ec_handle: i32) -> i32 {
-        panic!("Unavailable: get_curve_length_ec")
+        self.handler.ec_curve_length(ec_handle) as i32
     }
 
     fn get_priv_key_byte_length_ec(&self, ec_handle: i32) -> i32 {
-        panic!("Unavailable: get_priv_key_byte_length_ec")
+        self.handler.ec_private_key_byte_length(ec_handle) as i32
     }
 
     fn elliptic_curve_get_values(
@@ -1857,9 +2188,17 @@ impl VMHooks for VMHooksDispatcher {
         x_base_point_handle: i32,
         y_base_point_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: elliptic_curve_get_values")
+        self.handler.ec_get_values(
+            ec_handle,
+            field_order_handle,
+            base_point_order_handle,
+            eq_constant_handle,
+            x_base_point_handle,
+            y_base_point_handle,
+        );
+        0
     }
-    
+
     fn is_reserved_function_name(&self, name_handle: i32) -> i32 {
         panic!("Unavailable: is_reserved_function_name")
     }
@@ -1890,7 +2229,9 @@ impl VMHooks for VMHooksDispatcher {
         message_handle: i32,
         sig_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_verify_secp256r1")
+        self.handler
+            .verify_secp256r1_managed(key_handle, message_handle, sig_handle);
+        0
     }
     fn managed_verify_blssignature_share(
         &self,
@@ -1898,7 +2239,9 @@ impl VMHooks for VMHooksDispatcher {
         message_handle: i32,
         sig_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_verify_blssignature_share")
+        self.handler
+            .verify_bls_signature_share_managed(key_handle, message_handle, sig_handle);
+        0
     }
     fn managed_verify_blsaggregated_signature(
         &self,
@@ -1906,6 +2249,11 @@ impl VMHooks for VMHooksDispatcher {
         message_handle: i32,
         sig_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_verify_blsaggregated_signature")
+        self.handler.verify_bls_aggregated_signature_managed(
+            key_handle,
+            message_handle,
+            sig_handle,
+        );
+        0
     }
 }
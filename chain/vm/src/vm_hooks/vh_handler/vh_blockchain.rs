@@ -77,7 +77,7 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
     }
 
     fn get_gas_left(&self) -> u64 {
-        self.input_ref().gas_limit
+        self.gas_left()
     }
 
     fn get_block_timestamp(&self) -> u64 {
@@ -1,6 +1,10 @@
-use crate::{crypto_functions, types::RawHandle, vm_hooks::VMHooksHandlerSource};
+use crate::{crypto_functions, types::RawHandle, vm_err_msg, vm_hooks::VMHooksHandlerSource};
 
 pub trait VMHooksCrypto: VMHooksHandlerSource {
+    fn sha256(&self, data: &[u8]) -> [u8; crypto_functions::SHA256_RESULT_LEN] {
+        crypto_functions::sha256(data)
+    }
+
     fn sha256_managed(&self, dest: RawHandle, data_handle: RawHandle) {
         // default implementation used in debugger
         // the VM has a dedicated hook
@@ -19,6 +23,28 @@ pub trait VMHooksCrypto: VMHooksHandlerSource {
         types.mb_set(dest, result_bytes[..].to_vec());
     }
 
+    fn keccak256(&self, data: &[u8]) -> [u8; crypto_functions::KECCAK256_RESULT_LEN] {
+        crypto_functions::keccak256(data)
+    }
+
+    fn ripemd160(&self, data: &[u8]) -> [u8; crypto_functions::RIPEMD160_RESULT_LEN] {
+        crypto_functions::ripemd160(data)
+    }
+
+    fn ripemd160_managed(&self, dest: RawHandle, data_handle: RawHandle) {
+        let mut types = self.m_types_lock();
+        let data = types.mb_get(data_handle);
+        let result_bytes = crypto_functions::ripemd160(data);
+        types.mb_set(dest, result_bytes[..].to_vec());
+    }
+
+    /// Should crash if the signature is invalid.
+    fn verify_ed25519(&self, key: &[u8], message: &[u8], signature: &[u8]) {
+        if !crypto_functions::verify_ed25519(key, message, signature) {
+            self.vm_error(vm_err_msg::INVALID_SIGNATURE);
+        }
+    }
+
     /// Should crash if the signature is invalid.
     fn verify_ed25519_managed(&self, key: RawHandle, message: RawHandle, signature: RawHandle) {
         let types = self.m_types_lock();
@@ -27,7 +53,133 @@ pub trait VMHooksCrypto: VMHooksHandlerSource {
         let signature = types.mb_get(signature);
         let sig_valid = crypto_functions::verify_ed25519(key, message, signature);
         if !sig_valid {
-            self.vm_error("invalid signature");
+            self.vm_error(vm_err_msg::INVALID_SIGNATURE);
+        }
+    }
+
+    /// Should crash if the signature is invalid.
+    fn verify_bls(&self, key: &[u8], message: &[u8], signature: &[u8]) {
+        if !crypto_functions::verify_bls(key, message, signature) {
+            self.vm_error(vm_err_msg::SIGNATURE_IS_INVALID);
+        }
+    }
+
+    /// Should crash if the signature is invalid.
+    fn verify_bls_managed(&self, key: RawHandle, message: RawHandle, signature: RawHandle) {
+        let types = self.m_types_lock();
+        let sig_valid = crypto_functions::verify_bls(
+            types.mb_get(key),
+            types.mb_get(message),
+            types.mb_get(signature),
+        );
+        if !sig_valid {
+            self.vm_error(vm_err_msg::SIGNATURE_IS_INVALID);
+        }
+    }
+
+    /// Should crash if the signature is invalid.
+    fn verify_bls_signature_share_managed(
+        &self,
+        key: RawHandle,
+        message: RawHandle,
+        signature: RawHandle,
+    ) {
+        let types = self.m_types_lock();
+        let sig_valid = crypto_functions::verify_bls_signature_share(
+            types.mb_get(key),
+            types.mb_get(message),
+            types.mb_get(signature),
+        );
+        if !sig_valid {
+            self.vm_error(vm_err_msg::SIGNATURE_IS_INVALID);
+        }
+    }
+
+    /// The keys come as a list of buffers.
+    ///
+    /// Should crash if the signature is invalid.
+    fn verify_bls_aggregated_signature_managed(
+        &self,
+        keys: RawHandle,
+        message: RawHandle,
+        signature: RawHandle,
+    ) {
+        let types = self.m_types_lock();
+        let keys = types.mb_get_vec_of_bytes(keys);
+        let sig_valid = crypto_functions::verify_bls_aggregated_signature(
+            &keys,
+            types.mb_get(message),
+            types.mb_get(signature),
+        );
+        if !sig_valid {
+            self.vm_error(vm_err_msg::SIGNATURE_IS_INVALID);
+        }
+    }
+
+    /// Does not crash, the result says whether the signature is valid.
+    fn verify_secp256k1(
+        &self,
+        key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+        hash_type: u8,
+    ) -> bool {
+        crypto_functions::verify_secp256k1(key, message, signature, hash_type)
+    }
+
+    /// Same as the VM, the message is hashed twice with SHA-256.
+    fn verify_secp256k1_managed(
+        &self,
+        key: RawHandle,
+        message: RawHandle,
+        signature: RawHandle,
+    ) -> bool {
+        self.verify_custom_secp256k1_managed(
+            key,
+            message,
+            signature,
+            crypto_functions::SECP256K1_HASH_TYPE_DOUBLE_SHA256,
+        )
+    }
+
+    /// Does not crash, the result says whether the signature is valid.
+    fn verify_custom_secp256k1_managed(
+        &self,
+        key: RawHandle,
+        message: RawHandle,
+        signature: RawHandle,
+        hash_type: u8,
+    ) -> bool {
+        let types = self.m_types_lock();
+        crypto_functions::verify_secp256k1(
+            types.mb_get(key),
+            types.mb_get(message),
+            types.mb_get(signature),
+            hash_type,
+        )
+    }
+
+    fn encode_secp256k1_der_signature(&self, r: &[u8], s: &[u8]) -> Vec<u8> {
+        crypto_functions::encode_secp256k1_der_signature(r, s)
+    }
+
+    fn encode_secp256k1_der_signature_managed(&self, r: RawHandle, s: RawHandle, dest: RawHandle) {
+        let mut types = self.m_types_lock();
+        let signature =
+            crypto_functions::encode_secp256k1_der_signature(types.mb_get(r), types.mb_get(s));
+        types.mb_set(dest, signature);
+    }
+
+    /// Should crash if the signature is invalid.
+    fn verify_secp256r1_managed(&self, key: RawHandle, message: RawHandle, signature: RawHandle) {
+        let types = self.m_types_lock();
+        let sig_valid = crypto_functions::verify_secp256r1(
+            types.mb_get(key),
+            types.mb_get(message),
+            types.mb_get(signature),
+        );
+        if !sig_valid {
+            self.vm_error(vm_err_msg::SIGNATURE_IS_INVALID);
         }
     }
 }
@@ -0,0 +1,321 @@
+use num_bigint::BigInt;
+
+use crate::{
+    crypto_functions::{EllipticCurve, EllipticCurvePoint},
+    types::RawHandle,
+    vm_err_msg,
+    vm_hooks::{VMHooksError, VMHooksHandlerSource},
+};
+
+/// Elliptic curve operations, on the NIST curves supported by the VM.
+///
+/// Points are passed around as pairs of big int handles, one for each coordinate.
+pub trait VMHooksEllipticCurve: VMHooksHandlerSource + VMHooksError {
+    fn ec_create_from_name(&self, name: &[u8]) -> RawHandle {
+        let Some(curve) = EllipticCurve::from_name(name) else {
+            self.vm_error(vm_err_msg::NO_ELLIPTIC_CURVE_WITH_THIS_NAME);
+        };
+        self.m_types_lock().ec_new(curve)
+    }
+
+    fn ec_create_from_name_managed(&self, name_handle: RawHandle) -> RawHandle {
+        let name = self.m_types_lock().mb_to_bytes(name_handle);
+        self.ec_create_from_name(&name)
+    }
+
+    fn ec_get_curve(&self, ec_handle: RawHandle) -> EllipticCurve {
+        self.m_types_lock().ec_get(ec_handle).clone()
+    }
+
+    fn ec_get_values(
+        &self,
+        ec_handle: RawHandle,
+        field_order_handle: RawHandle,
+        base_point_order_handle: RawHandle,
+        eq_constant_handle: RawHandle,
+        x_base_point_handle: RawHandle,
+        y_base_point_handle: RawHandle,
+    ) {
+        let curve = self.ec_get_curve(ec_handle);
+        let mut types = self.m_types_lock();
+        types.bi_overwrite(field_order_handle, BigInt::from(curve.p));
+        types.bi_overwrite(base_point_order_handle, BigInt::from(curve.n));
+        types.bi_overwrite(eq_constant_handle, BigInt::from(curve.b));
+        types.bi_overwrite(x_base_point_handle, BigInt::from(curve.gx));
+        types.bi_overwrite(y_base_point_handle, BigInt::from(curve.gy));
+    }
+
+    fn ec_curve_length(&self, ec_handle: RawHandle) -> u32 {
+        self.ec_get_curve(ec_handle).bit_size
+    }
+
+    fn ec_private_key_byte_length(&self, ec_handle: RawHandle) -> u32 {
+        self.ec_get_curve(ec_handle).private_key_byte_len() as u32
+    }
+
+    /// Negative coordinates are never on the curve.
+    fn ec_point_get(&self, x_handle: RawHandle, y_handle: RawHandle) -> Option<EllipticCurvePoint> {
+        let types = self.m_types_lock();
+        let x = types.bi_get(x_handle).to_biguint()?;
+        let y = types.bi_get(y_handle).to_biguint()?;
+        Some((x, y))
+    }
+
+    /// Crashes if the point is not on the curve.
+    fn ec_point_get_on_curve(
+        &self,
+        curve: &EllipticCurve,
+        x_handle: RawHandle,
+        y_handle: RawHandle,
+    ) -> EllipticCurvePoint {
+        match self.ec_point_get(x_handle, y_handle) {
+            Some((x, y)) if curve.is_on_curve(&x, &y) => (x, y),
+            _ => self.vm_error(vm_err_msg::POINT_NOT_ON_CURVE),
+        }
+    }
+
+    fn ec_point_set(&self, x_handle: RawHandle, y_handle: RawHandle, point: EllipticCurvePoint) {
+        let mut types = self.m_types_lock();
+        types.bi_overwrite(x_handle, BigInt::from(point.0));
+        types.bi_overwrite(y_handle, BigInt::from(point.1));
+    }
+
+    /// The result cannot be the point at infinity.
+    fn ec_point_set_on_curve(
+        &self,
+        curve: &EllipticCurve,
+        x_handle: RawHandle,
+        y_handle: RawHandle,
+        point: EllipticCurvePoint,
+    ) {
+        if !curve.is_on_curve(&point.0, &point.1) {
+            self.vm_error(vm_err_msg::POINT_NOT_ON_CURVE);
+        }
+        self.ec_point_set(x_handle, y_handle, point);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn ec_add(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        x_first_point: RawHandle,
+        y_first_point: RawHandle,
+        x_second_point: RawHandle,
+        y_second_point: RawHandle,
+    ) {
+        let curve = self.ec_get_curve(ec_handle);
+        let first = self.ec_point_get_on_curve(&curve, x_first_point, y_first_point);
+        let second = self.ec_point_get_on_curve(&curve, x_second_point, y_second_point);
+        let result = curve.add(&first, &second);
+        self.ec_point_set(x_result_handle, y_result_handle, result);
+    }
+
+    fn ec_double(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        x_point_handle: RawHandle,
+        y_point_handle: RawHandle,
+    ) {
+        let curve = self.ec_get_curve(ec_handle);
+        let point = self.ec_point_get_on_curve(&curve, x_point_handle, y_point_handle);
+        let result = curve.double(&point);
+        self.ec_point_set(x_result_handle, y_result_handle, result);
+    }
+
+    fn ec_is_on_curve(
+        &self,
+        ec_handle: RawHandle,
+        x_point_handle: RawHandle,
+        y_point_handle: RawHandle,
+    ) -> bool {
+        let curve = self.ec_get_curve(ec_handle);
+        self.ec_point_get(x_point_handle, y_point_handle)
+            .is_some_and(|(x, y)| curve.is_on_curve(&x, &y))
+    }
+
+    fn ec_scalar_mult(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        x_point_handle: RawHandle,
+        y_point_handle: RawHandle,
+        data: &[u8],
+    ) {
+        let curve = self.ec_get_curve(ec_handle);
+        let point = self.ec_point_get_on_curve(&curve, x_point_handle, y_point_handle);
+        let result = curve.scalar_mult(&point, data);
+        self.ec_point_set_on_curve(&curve, x_result_handle, y_result_handle, result);
+    }
+
+    fn ec_scalar_mult_managed(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        x_point_handle: RawHandle,
+        y_point_handle: RawHandle,
+        data_handle: RawHandle,
+    ) {
+        let data = self.m_types_lock().mb_to_bytes(data_handle);
+        self.ec_scalar_mult(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            x_point_handle,
+            y_point_handle,
+            &data,
+        );
+    }
+
+    fn ec_scalar_base_mult(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data: &[u8],
+    ) {
+        let curve = self.ec_get_curve(ec_handle);
+        let result = curve.scalar_base_mult(data);
+        self.ec_point_set_on_curve(&curve, x_result_handle, y_result_handle, result);
+    }
+
+    fn ec_scalar_base_mult_managed(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data_handle: RawHandle,
+    ) {
+        let data = self.m_types_lock().mb_to_bytes(data_handle);
+        self.ec_scalar_base_mult(x_result_handle, y_result_handle, ec_handle, &data);
+    }
+
+    fn ec_marshal(
+        &self,
+        ec_handle: RawHandle,
+        x_pair_handle: RawHandle,
+        y_pair_handle: RawHandle,
+    ) -> Vec<u8> {
+        let curve = self.ec_get_curve(ec_handle);
+        let (x, y) = self.ec_point_get_on_curve(&curve, x_pair_handle, y_pair_handle);
+        curve.marshal(&x, &y)
+    }
+
+    fn ec_marshal_managed(
+        &self,
+        ec_handle: RawHandle,
+        x_pair_handle: RawHandle,
+        y_pair_handle: RawHandle,
+        result_handle: RawHandle,
+    ) {
+        let result = self.ec_marshal(ec_handle, x_pair_handle, y_pair_handle);
+        self.m_types_lock().mb_set(result_handle, result);
+    }
+
+    fn ec_marshal_compressed(
+        &self,
+        ec_handle: RawHandle,
+        x_pair_handle: RawHandle,
+        y_pair_handle: RawHandle,
+    ) -> Vec<u8> {
+        let curve = self.ec_get_curve(ec_handle);
+        let (x, y) = self.ec_point_get_on_curve(&curve, x_pair_handle, y_pair_handle);
+        curve.marshal_compressed(&x, &y)
+    }
+
+    fn ec_marshal_compressed_managed(
+        &self,
+        ec_handle: RawHandle,
+        x_pair_handle: RawHandle,
+        y_pair_handle: RawHandle,
+        result_handle: RawHandle,
+    ) {
+        let result = self.ec_marshal_compressed(ec_handle, x_pair_handle, y_pair_handle);
+        self.m_types_lock().mb_set(result_handle, result);
+    }
+
+    fn ec_unmarshal(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data: &[u8],
+    ) {
+        let curve = self.ec_get_curve(ec_handle);
+        if data.len() != 1 + 2 * curve.byte_len() {
+            self.vm_error(vm_err_msg::LENGTH_OF_BUFFER_IS_NOT_CORRECT);
+        }
+        let Some(point) = curve.unmarshal(data) else {
+            self.vm_error(vm_err_msg::POINT_NOT_ON_CURVE);
+        };
+        self.ec_point_set(x_result_handle, y_result_handle, point);
+    }
+
+    fn ec_unmarshal_managed(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data_handle: RawHandle,
+    ) {
+        let data = self.m_types_lock().mb_to_bytes(data_handle);
+        self.ec_unmarshal(x_result_handle, y_result_handle, ec_handle, &data);
+    }
+
+    fn ec_unmarshal_compressed(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data: &[u8],
+    ) {
+        let curve = self.ec_get_curve(ec_handle);
+        if data.len() != 1 + curve.byte_len() {
+            self.vm_error(vm_err_msg::LENGTH_OF_BUFFER_IS_NOT_CORRECT);
+        }
+        let Some(point) = curve.unmarshal_compressed(data) else {
+            self.vm_error(vm_err_msg::POINT_NOT_ON_CURVE);
+        };
+        self.ec_point_set(x_result_handle, y_result_handle, point);
+    }
+
+    fn ec_unmarshal_compressed_managed(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data_handle: RawHandle,
+    ) {
+        let data = self.m_types_lock().mb_to_bytes(data_handle);
+        self.ec_unmarshal_compressed(x_result_handle, y_result_handle, ec_handle, &data);
+    }
+
+    /// Uses the blockchain randomness source. Returns the private key.
+    fn ec_generate_key(
+        &self,
+        x_pub_key_handle: RawHandle,
+        y_pub_key_handle: RawHandle,
+        ec_handle: RawHandle,
+    ) -> Vec<u8> {
+        let curve = self.ec_get_curve(ec_handle);
+        let (private_key, public_key) = curve.generate_key(|length| self.random_next_bytes(length));
+        self.ec_point_set(x_pub_key_handle, y_pub_key_handle, public_key);
+        private_key
+    }
+
+    fn ec_generate_key_managed(
+        &self,
+        x_pub_key_handle: RawHandle,
+        y_pub_key_handle: RawHandle,
+        ec_handle: RawHandle,
+        result_handle: RawHandle,
+    ) {
+        let private_key = self.ec_generate_key(x_pub_key_handle, y_pub_key_handle, ec_handle);
+        self.m_types_lock().mb_set(result_handle, private_key);
+    }
+}
@@ -352,6 +352,16 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         self.perform_upgrade_contract(to, rewa_value, code, code_metadata, arg_buffer)
     }
 
+    fn delete_contract(
+        &self,
+        sc_address_handle: RawHandle,
+        _gas: u64,
+        _arg_buffer_handle: RawHandle,
+    ) {
+        let to = self.m_types_lock().mb_to_address(sc_address_handle);
+        self.perform_delete_contract(to);
+    }
+
     fn execute_on_dest_context_raw(
         &self,
         _gas: u64,
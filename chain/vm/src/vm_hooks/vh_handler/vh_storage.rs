@@ -1,10 +1,38 @@
+use num_traits::ToPrimitive;
+
 use crate::{
-    types::{RawHandle, VMAddress},
+    types::{top_encode_u64, RawHandle, VMAddress},
+    vm_err_msg,
     vm_hooks::VMHooksHandlerSource,
 };
 
 use super::VMHooksManagedTypes;
 
+/// Storage locks are kept in the contract storage, under this prefix, followed by the locked key.
+const TIMELOCK_KEY_PREFIX: &[u8] = b"timelock";
+
+/// Storage status codes, as returned by the VM after a write.
+const STORAGE_UNCHANGED: i32 = 0;
+const STORAGE_MODIFIED: i32 = 1;
+const STORAGE_ADDED: i32 = 2;
+const STORAGE_DELETED: i32 = 3;
+
+fn timelock_key(key: &[u8]) -> Vec<u8> {
+    [TIMELOCK_KEY_PREFIX, key].concat()
+}
+
+fn storage_status(old_value: &[u8], new_value: &[u8]) -> i32 {
+    if old_value == new_value {
+        STORAGE_UNCHANGED
+    } else if old_value.is_empty() {
+        STORAGE_ADDED
+    } else if new_value.is_empty() {
+        STORAGE_DELETED
+    } else {
+        STORAGE_MODIFIED
+    }
+}
+
 pub trait VMHooksStorageRead: VMHooksHandlerSource {
     fn storage_load_managed_buffer_raw(&self, key_handle: RawHandle, dest: RawHandle) {
         let value = self.storage_read(self.m_types_lock().mb_get(key_handle));
@@ -21,6 +49,24 @@ pub trait VMHooksStorageRead: VMHooksHandlerSource {
         let value = self.storage_read_any_address(&address, self.m_types_lock().mb_get(key_handle));
         self.m_types_lock().mb_set(dest, value);
     }
+
+    fn get_storage_lock(&self, key: &[u8]) -> i64 {
+        let lock_value = self.storage_read(&timelock_key(key));
+        num_bigint::BigUint::from_bytes_be(&lock_value)
+            .to_i64()
+            .unwrap_or(i64::MAX)
+    }
+
+    /// Returns 1 while the lock is in the future, 0 once the block timestamp has reached it.
+    fn is_storage_locked(&self, key: &[u8]) -> i32 {
+        let lock_timestamp = self.get_storage_lock(key);
+        let block_timestamp = self.get_current_block_info().block_timestamp;
+        if lock_timestamp as u64 <= block_timestamp {
+            0
+        } else {
+            1
+        }
+    }
 }
 
 pub trait VMHooksStorageWrite: VMHooksHandlerSource + VMHooksManagedTypes {
@@ -30,4 +76,22 @@ pub trait VMHooksStorageWrite: VMHooksHandlerSource + VMHooksManagedTypes {
         let value_bytes = types.mb_get(value_handle);
         self.storage_write(key_bytes, value_bytes);
     }
+
+    /// Locks the key until the given block timestamp. Returns the storage status of the lock entry.
+    fn set_storage_lock(&self, key: &[u8], lock_timestamp: i64) -> i32 {
+        let Ok(lock_timestamp) = u64::try_from(lock_timestamp) else {
+            self.vm_error(vm_err_msg::NEGATIVE_LOCK_TIMESTAMP);
+        };
+
+        let lock_key = timelock_key(key);
+        let old_value = self.storage_read(&lock_key);
+        let new_value = top_encode_u64(lock_timestamp);
+        self.storage_write(&lock_key, &new_value);
+        storage_status(&old_value, &new_value)
+    }
+
+    /// Removes the lock entry. Returns its storage status: deleted, or unchanged if there was no lock.
+    fn clear_storage_lock(&self, key: &[u8]) -> i32 {
+        self.set_storage_lock(key, 0)
+    }
 }
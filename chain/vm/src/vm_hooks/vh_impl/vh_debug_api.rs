@@ -6,16 +6,18 @@ use dharitri_vm_executor::BreakpointValue;
 use crate::{
     tx_execution::execute_current_tx_context_input,
     tx_mock::{
-        async_call_tx_input, AsyncCallTxData, BackTransfers, BlockchainUpdate, CallType, TxCache,
-        TxContext, TxFunctionName, TxInput, TxManagedTypes, TxPanic, TxResult,
+        async_call_tx_input, AsyncCallTxData, BackTransfers, BlockchainUpdate, CallType,
+        CrossShardCall, CrossShardCallKind, TxCache, TxContext, TxFunctionName, TxInput, TxLog,
+        TxManagedTypes, TxPanic, TxResult,
     },
     types::{VMAddress, VMCodeMetadata},
     vm_err_msg,
     vm_hooks::{
         VMHooksBigFloat, VMHooksBigInt, VMHooksBlockchain, VMHooksCallValue, VMHooksCrypto,
-        VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError, VMHooksErrorManaged,
-        VMHooksHandler, VMHooksHandlerSource, VMHooksLog, VMHooksManagedBuffer, VMHooksManagedMap,
-        VMHooksManagedTypes, VMHooksSend, VMHooksStorageRead, VMHooksStorageWrite,
+        VMHooksEllipticCurve, VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError,
+        VMHooksErrorManaged, VMHooksHandler, VMHooksHandlerSource, VMHooksLog,
+        VMHooksManagedBuffer, VMHooksManagedMap, VMHooksManagedTypes, VMHooksSend,
+        VMHooksStorageRead, VMHooksStorageWrite,
     },
     world_mock::{reserved::STORAGE_RESERVED_PREFIX, AccountData, BlockInfo},
 };
@@ -30,6 +32,29 @@ impl DebugApiVMHooksHandler {
     pub fn new(tx_context_arc: Arc<TxContext>) -> Self {
         DebugApiVMHooksHandler(tx_context_arc)
     }
+
+    /// Consumes gas from the current call, halts execution if there is not enough left.
+    ///
+    /// Does nothing if gas is not metered.
+    pub fn use_gas(&self, gas: u64) {
+        if let Err(err) = self.0.use_gas(gas) {
+            self.halt_with_error(err.status, &err.message);
+        }
+    }
+
+    /// Charges the flat VM hook cost from the gas schedule.
+    pub fn use_gas_for_vm_hook(&self) {
+        if let Some(gas_schedule) = self.0.gas_schedule() {
+            self.use_gas(gas_schedule.vm_hook);
+        }
+    }
+
+    /// Records the VM hook in the execution trace, if tracing is enabled.
+    pub fn trace_vm_hook(&self, hook_name: &str) {
+        if let Some(tracer) = self.0.tracer() {
+            tracer.record_vm_hook(hook_name);
+        }
+    }
 }
 
 impl VMHooksHandlerSource for DebugApiVMHooksHandler {
@@ -50,6 +75,10 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         self.0.input_ref()
     }
 
+    fn gas_left(&self) -> u64 {
+        self.0.gas_left()
+    }
+
     fn random_next_bytes(&self, length: usize) -> Vec<u8> {
         self.0.rng_lock().next_bytes(length)
     }
@@ -58,14 +87,34 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         self.0.result_lock()
     }
 
+    fn push_tx_log(&self, tx_log: TxLog) {
+        if let Some(tracer) = self.0.tracer() {
+            tracer.record_log(&tx_log);
+        }
+        self.result_lock().result_logs.push(tx_log);
+    }
+
     fn storage_read_any_address(&self, address: &VMAddress, key: &[u8]) -> Vec<u8> {
-        self.0.with_account_mut(address, |account| {
+        let value = self.0.with_account_mut(address, |account| {
             account.storage.get(key).cloned().unwrap_or_default()
-        })
+        });
+        if let Some(gas_schedule) = self.0.gas_schedule() {
+            self.use_gas(gas_schedule.storage_load_cost(key, &value));
+        }
+        if let Some(tracer) = self.0.tracer() {
+            tracer.record_storage_read(address, key, &value);
+        }
+        value
     }
 
     fn storage_write(&self, key: &[u8], value: &[u8]) {
         self.check_reserved_key(key);
+        if let Some(gas_schedule) = self.0.gas_schedule() {
+            self.use_gas(gas_schedule.storage_store_cost(key, value));
+        }
+        if let Some(tracer) = self.0.tracer() {
+            tracer.record_storage_write(key, value);
+        }
 
         self.0.with_contract_account_mut(|account| {
             account.storage.insert(key.to_vec(), value.to_vec());
@@ -107,7 +156,7 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         // the cell is no longer needed, since we end in a panic
         let mut tx_result = self.result_lock();
         tx_result.all_calls.push(async_call_data.clone());
-        tx_result.pending_calls.async_call = Some(async_call_data);
+        tx_result.pending_calls.async_calls.push(async_call_data);
         drop(tx_result); // this avoid to poison the mutex
         std::panic::panic_any(BreakpointValue::AsyncCall);
     }
@@ -120,13 +169,18 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         arguments: Vec<Vec<u8>>,
     ) -> Vec<Vec<u8>> {
         let async_call_data = self.create_async_call_data(to, rewa_value, func_name, arguments);
-        let tx_input = async_call_tx_input(&async_call_data, CallType::ExecuteOnDestContext);
+        let mut tx_input = async_call_tx_input(&async_call_data, CallType::ExecuteOnDestContext);
+        if self.is_cross_shard(&tx_input) {
+            self.vm_error(vm_err_msg::SYNC_EXECUTION_NOT_IN_SAME_SHARD);
+        }
+        self.set_nested_call_gas_limit(&mut tx_input);
         let tx_cache = TxCache::new(self.0.blockchain_cache_arc());
         let (tx_result, blockchain_updates) = self.0.vm_ref.execute_builtin_function_or_default(
             tx_input,
             tx_cache,
             execute_current_tx_context_input,
         );
+        self.use_gas(tx_result.gas_used);
 
         if tx_result.result_status.is_success() {
             self.sync_call_post_processing(tx_result, blockchain_updates)
@@ -145,7 +199,7 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
     ) -> (VMAddress, Vec<Vec<u8>>) {
         let contract_address = self.current_address();
         let tx_hash = self.tx_hash();
-        let tx_input = TxInput {
+        let mut tx_input = TxInput {
             from: contract_address.clone(),
             to: VMAddress::zero(),
             rewa_value,
@@ -157,6 +211,7 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
             tx_hash,
             ..Default::default()
         };
+        self.set_nested_call_gas_limit(&mut tx_input);
 
         let tx_cache = TxCache::new(self.0.blockchain_cache_arc());
         tx_cache.increase_acount_nonce(contract_address);
@@ -167,6 +222,7 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
             tx_cache,
             execute_current_tx_context_input,
         );
+        self.use_gas(tx_result.gas_used);
 
         match tx_result.result_status {
             ReturnCode::Success => (
@@ -190,6 +246,11 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         if self.is_back_transfer(&tx_input) {
             tx_input.call_type = CallType::BackTransfer;
         }
+        self.set_nested_call_gas_limit(&mut tx_input);
+        if self.is_cross_shard(&tx_input) {
+            self.send_cross_shard_transfer(async_call_data, &tx_input);
+            return;
+        }
 
         let tx_cache = TxCache::new(self.0.blockchain_cache_arc());
         let (tx_result, blockchain_updates) = self.0.vm_ref.execute_builtin_function_or_default(
@@ -197,6 +258,7 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
             tx_cache,
             execute_current_tx_context_input,
         );
+        self.use_gas(tx_result.gas_used);
 
         match tx_result.result_status {
             ReturnCode::Success => {
@@ -208,6 +270,24 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
             _ => self.vm_error(vm_err_msg::ERROR_SIGNALLED_BY_SMARTCONTRACT),
         }
     }
+
+    fn perform_delete_contract(&self, to: VMAddress) {
+        let current_address = self.current_address();
+        let is_delete_allowed = self.0.with_account_or_else(
+            &to,
+            |account| {
+                account.contract_path.is_some()
+                    && account.code_metadata.is_upgradeable()
+                    && account.contract_owner.as_ref() == Some(current_address)
+            },
+            || false,
+        );
+        if !is_delete_allowed {
+            self.vm_error(vm_err_msg::DELETE_NOT_ALLOWED);
+        }
+
+        self.0.blockchain_cache().delete_account(&to);
+    }
 }
 
 impl DebugApiVMHooksHandler {
@@ -230,6 +310,13 @@ impl DebugApiVMHooksHandler {
         }
     }
 
+    /// Nested synchronous calls receive all the remaining gas, when gas is metered.
+    fn set_nested_call_gas_limit(&self, tx_input: &mut TxInput) {
+        if self.0.vm_ref.is_gas_metered() {
+            tx_input.gas_limit = self.0.gas_left();
+        }
+    }
+
     fn sync_call_post_processing(
         &self,
         tx_result: TxResult,
@@ -247,6 +334,44 @@ impl DebugApiVMHooksHandler {
         tx_result.result_values
     }
 
+    /// Only ever true in sharded mode.
+    fn is_cross_shard(&self, tx_input: &TxInput) -> bool {
+        let builtin_functions = &self.0.vm_ref.builtin_functions;
+        let token_transfers = builtin_functions.extract_token_transfers(tx_input);
+        self.0
+            .blockchain_cache()
+            .blockchain_ref()
+            .is_cross_shard(&tx_input.from, &token_transfers.real_recipient)
+    }
+
+    /// The funds leave the contract right away, but only arrive in a later round.
+    fn send_cross_shard_transfer(&self, async_call_data: AsyncCallTxData, tx_input: &TxInput) {
+        let builtin_functions = &self.0.vm_ref.builtin_functions;
+        let token_transfers = builtin_functions.extract_token_transfers(tx_input);
+        let blockchain_cache = self.0.blockchain_cache();
+        let payments = blockchain_cache
+            .take_in_flight_payments(
+                &tx_input.from,
+                &tx_input.rewa_value,
+                &token_transfers.transfers,
+            )
+            .unwrap_or_else(|err| self.vm_error(&err.message));
+
+        let mut tx_result = self.0.result_lock();
+        tx_result.all_calls.push(async_call_data.clone());
+        tx_result
+            .pending_calls
+            .cross_shard_calls
+            .push(CrossShardCall {
+                delivery_round: blockchain_cache.blockchain_ref().next_round(),
+                payments,
+                kind: CrossShardCallKind::TransferExecute {
+                    call: async_call_data,
+                    gas_limit: tx_input.gas_limit,
+                },
+            });
+    }
+
     fn check_reserved_key(&self, key: &[u8]) {
         if key.starts_with(STORAGE_RESERVED_PREFIX) {
             self.vm_error("cannot write to storage under reserved key");
@@ -269,6 +394,7 @@ impl VMHooksBigInt for DebugApiVMHooksHandler {}
 impl VMHooksManagedBuffer for DebugApiVMHooksHandler {}
 impl VMHooksManagedMap for DebugApiVMHooksHandler {}
 impl VMHooksBigFloat for DebugApiVMHooksHandler {}
+impl VMHooksEllipticCurve for DebugApiVMHooksHandler {}
 impl VMHooksManagedTypes for DebugApiVMHooksHandler {}
 
 impl VMHooksCallValue for DebugApiVMHooksHandler {}
@@ -10,9 +10,10 @@ use crate::{
     types::{VMAddress, VMCodeMetadata},
     vm_hooks::{
         VMHooksBigFloat, VMHooksBigInt, VMHooksBlockchain, VMHooksCallValue, VMHooksCrypto,
-        VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError, VMHooksErrorManaged,
-        VMHooksHandler, VMHooksHandlerSource, VMHooksLog, VMHooksManagedBuffer, VMHooksManagedMap,
-        VMHooksManagedTypes, VMHooksSend, VMHooksStorageRead, VMHooksStorageWrite,
+        VMHooksEllipticCurve, VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError,
+        VMHooksErrorManaged, VMHooksHandler, VMHooksHandlerSource, VMHooksLog,
+        VMHooksManagedBuffer, VMHooksManagedMap, VMHooksManagedTypes, VMHooksSend,
+        VMHooksStorageRead, VMHooksStorageWrite,
     },
     world_mock::{AccountData, BlockInfo},
 };
@@ -146,12 +147,17 @@ impl VMHooksHandlerSource for SingleTxApiVMHooksHandler {
     ) {
         panic!("cannot launch contract calls in the SingleTxApi")
     }
+
+    fn perform_delete_contract(&self, _to: VMAddress) {
+        panic!("cannot launch contract calls in the SingleTxApi")
+    }
 }
 
 impl VMHooksBigInt for SingleTxApiVMHooksHandler {}
 impl VMHooksManagedBuffer for SingleTxApiVMHooksHandler {}
 impl VMHooksManagedMap for SingleTxApiVMHooksHandler {}
 impl VMHooksBigFloat for SingleTxApiVMHooksHandler {}
+impl VMHooksEllipticCurve for SingleTxApiVMHooksHandler {}
 impl VMHooksManagedTypes for SingleTxApiVMHooksHandler {}
 
 impl VMHooksCallValue for SingleTxApiVMHooksHandler {}
@@ -7,9 +7,10 @@ use crate::{
     types::{VMAddress, VMCodeMetadata},
     vm_hooks::{
         VMHooksBigFloat, VMHooksBigInt, VMHooksBlockchain, VMHooksCallValue, VMHooksCrypto,
-        VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError, VMHooksErrorManaged,
-        VMHooksHandler, VMHooksHandlerSource, VMHooksLog, VMHooksManagedBuffer, VMHooksManagedMap,
-        VMHooksManagedTypes, VMHooksSend, VMHooksStorageRead, VMHooksStorageWrite,
+        VMHooksEllipticCurve, VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError,
+        VMHooksErrorManaged, VMHooksHandler, VMHooksHandlerSource, VMHooksLog,
+        VMHooksManagedBuffer, VMHooksManagedMap, VMHooksManagedTypes, VMHooksSend,
+        VMHooksStorageRead, VMHooksStorageWrite,
     },
     world_mock::{AccountData, BlockInfo},
 };
@@ -121,12 +122,17 @@ impl VMHooksHandlerSource for StaticApiVMHooksHandler {
     ) {
         panic!("cannot launch contract calls in the StaticApi")
     }
+
+    fn perform_delete_contract(&self, _to: VMAddress) {
+        panic!("cannot launch contract calls in the StaticApi")
+    }
 }
 
 impl VMHooksBigInt for StaticApiVMHooksHandler {}
 impl VMHooksManagedBuffer for StaticApiVMHooksHandler {}
 impl VMHooksManagedMap for StaticApiVMHooksHandler {}
 impl VMHooksBigFloat for StaticApiVMHooksHandler {}
+impl VMHooksEllipticCurve for StaticApiVMHooksHandler {}
 impl VMHooksManagedTypes for StaticApiVMHooksHandler {}
 
 impl VMHooksCallValue for StaticApiVMHooksHandler {}
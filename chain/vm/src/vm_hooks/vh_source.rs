@@ -28,6 +28,11 @@ pub trait VMHooksHandlerSource: Debug {
         self.input_ref().tx_hash.clone()
     }
 
+    /// Equal to the gas limit, unless gas is metered.
+    fn gas_left(&self) -> u64 {
+        self.input_ref().gas_limit
+    }
+
     /// Random number generator, based on the blockchain randomness source.
     fn random_next_bytes(&self, length: usize) -> Vec<u8>;
 
@@ -97,4 +102,7 @@ pub trait VMHooksHandlerSource: Debug {
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     );
+
+    /// Removes a contract owned by the current contract, together with all its storage.
+    fn perform_delete_contract(&self, to: VMAddress);
 }
@@ -8,8 +8,10 @@ mod dcdt_instance;
 mod dcdt_instance_metadata;
 mod dcdt_instances;
 mod dcdt_roles;
+mod dcdt_token_properties;
 mod failing_executor;
 pub mod reserved;
+mod shard_config;
 
 pub use account_data::*;
 pub use block_info::*;
@@ -20,4 +22,6 @@ pub use dcdt_instance::*;
 pub use dcdt_instance_metadata::*;
 pub use dcdt_instances::*;
 pub use dcdt_roles::*;
+pub use dcdt_token_properties::*;
 pub use failing_executor::FailingExecutor;
+pub use shard_config::*;
@@ -1,5 +1,6 @@
 use num_bigint::BigUint;
 use num_traits::Zero;
+use serde::{Deserialize, Serialize};
 
 use super::AccountDcdt;
 use crate::{
@@ -10,17 +11,26 @@ use std::{collections::HashMap, fmt, fmt::Write};
 
 pub type AccountStorage = HashMap<Vec<u8>, Vec<u8>>;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct AccountData {
+    #[serde(with = "crate::serde_util::hex_bytes")]
     pub address: VMAddress,
     pub nonce: u64,
+    #[serde(with = "crate::serde_util::big_uint")]
     pub rewa_balance: BigUint,
     pub dcdt: AccountDcdt,
+    #[serde(with = "crate::serde_util::hex_storage")]
     pub storage: AccountStorage,
+    #[serde(with = "crate::serde_util::hex_bytes")]
     pub username: Vec<u8>,
+    #[serde(with = "crate::serde_util::hex_option")]
     pub contract_path: Option<Vec<u8>>,
+    #[serde(with = "crate::serde_util::code_metadata")]
     pub code_metadata: VMCodeMetadata,
+    #[serde(with = "crate::serde_util::hex_option")]
     pub contract_owner: Option<VMAddress>,
+    #[serde(with = "crate::serde_util::big_uint")]
     pub developer_rewards: BigUint,
 }
 
@@ -1,9 +1,13 @@
-#[derive(Clone, Debug)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct BlockInfo {
     pub block_timestamp: u64,
     pub block_nonce: u64,
     pub block_round: u64,
     pub block_epoch: u64,
+    #[serde(with = "crate::serde_util::hex_bytes")]
     pub block_random_seed: Box<[u8; 48]>,
 }
 
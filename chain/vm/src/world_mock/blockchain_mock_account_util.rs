@@ -1,4 +1,7 @@
-use std::{collections::HashMap, fmt::Write};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write,
+};
 
 use crate::{display_util::address_hex, types::VMAddress};
 
@@ -19,6 +22,11 @@ impl BlockchainState {
         self.accounts.extend(accounts);
     }
 
+    pub fn remove_accounts(&mut self, addresses: &HashSet<VMAddress>) {
+        self.accounts
+            .retain(|address, _| !addresses.contains(address));
+    }
+
     pub fn print_accounts(&self) {
         let mut accounts_buf = String::new();
         for (address, account) in &self.accounts {
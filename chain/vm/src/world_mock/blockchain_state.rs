@@ -1,18 +1,40 @@
 use num_bigint::BigUint;
 use num_traits::Zero;
-use std::{collections::HashMap, fmt::Debug};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Debug,
+};
 
-use crate::{tx_mock::BlockchainUpdate, types::VMAddress};
+use crate::{
+    tx_mock::{BlockchainUpdate, CrossShardCall},
+    types::VMAddress,
+};
 
-use super::{reserved::STORAGE_REWARD_KEY, AccountData, BlockInfo};
+use super::{
+    reserved::STORAGE_REWARD_KEY, AccountData, BlockInfo, DcdtTokenProperties, ShardConfig,
+};
 
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct BlockchainState {
+    #[serde(with = "crate::serde_util::hex_key_map")]
     pub accounts: HashMap<VMAddress, AccountData>,
+    #[serde(with = "crate::serde_util::new_addresses")]
     pub new_addresses: HashMap<(VMAddress, u64), VMAddress>,
     pub previous_block_info: BlockInfo,
     pub current_block_info: BlockInfo,
     pub new_token_identifiers: Vec<String>,
+
+    /// Token properties, as kept by the DCDT system SC, indexed by token identifier.
+    #[serde(with = "crate::serde_util::hex_key_map")]
+    pub dcdt_tokens: HashMap<Vec<u8>, DcdtTokenProperties>,
+
+    /// Sharded mode is opt-in. Without it, all calls execute right away, as if intra-shard.
+    pub shard_config: Option<ShardConfig>,
+
+    /// Calls and transfers sent between shards, in the order in which they were sent.
+    pub cross_shard_calls: VecDeque<CrossShardCall>,
 }
 
 impl BlockchainState {
@@ -20,6 +42,24 @@ impl BlockchainState {
         updates.apply(self);
     }
 
+    /// Always false, unless sharded mode is enabled.
+    pub fn is_cross_shard(&self, from: &VMAddress, to: &VMAddress) -> bool {
+        self.shard_config
+            .is_some_and(|shard_config| shard_config.is_cross_shard(from, to))
+    }
+
+    /// Calls sent to another shard in the current round get delivered in this one.
+    pub fn next_round(&self) -> u64 {
+        self.current_block_info.block_round + 1
+    }
+
+    /// Moves on to the next block.
+    pub fn advance_round(&mut self) {
+        self.previous_block_info = self.current_block_info.clone();
+        self.current_block_info.block_round += 1;
+        self.current_block_info.block_nonce += 1;
+    }
+
     pub fn account_exists(&self, address: &VMAddress) -> bool {
         self.accounts.contains_key(address)
     }
@@ -49,6 +89,17 @@ impl BlockchainState {
         account.rewa_balance -= &gas_cost;
     }
 
+    /// Gives back the value of the gas that was paid upfront, but not consumed.
+    pub fn refund_tx_gas(&mut self, address: &VMAddress, gas_refunded: u64, gas_price: u64) {
+        let account = self.accounts.get_mut(address).unwrap_or_else(|| {
+            panic!(
+                "Account not found: {}",
+                &std::str::from_utf8(address.as_ref()).unwrap()
+            )
+        });
+        account.rewa_balance += BigUint::from(gas_refunded) * BigUint::from(gas_price);
+    }
+
     pub fn increase_validator_reward(&mut self, address: &VMAddress, amount: &BigUint) {
         let account = self.accounts.get_mut(address).unwrap_or_else(|| {
             panic!(
@@ -80,6 +131,10 @@ impl BlockchainState {
     pub fn update_new_token_identifiers(&mut self, token_identifiers: Vec<String>) {
         self.new_token_identifiers = token_identifiers;
     }
+
+    pub fn update_dcdt_tokens(&mut self, dcdt_tokens: HashMap<Vec<u8>, DcdtTokenProperties>) {
+        self.dcdt_tokens.extend(dcdt_tokens);
+    }
 }
 
 impl Debug for BlockchainState {
@@ -88,6 +143,9 @@ impl Debug for BlockchainState {
             .field("accounts", &self.accounts)
             .field("new_addresses", &self.new_addresses)
             .field("current_block_info", &self.current_block_info)
+            .field("dcdt_tokens", &self.dcdt_tokens)
+            .field("shard_config", &self.shard_config)
+            .field("cross_shard_calls", &self.cross_shard_calls)
             .finish()
     }
 }
@@ -1,5 +1,6 @@
 use num_bigint::BigUint;
 use num_traits::Zero;
+use serde::{Deserialize, Serialize};
 
 use crate::{display_util::key_hex, types::VMTokenType};
 use std::{
@@ -9,7 +10,8 @@ use std::{
 
 use super::{DcdtInstanceMetadata, DcdtInstances, DcdtRoles};
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct DcdtData {
     pub instances: DcdtInstances,
     pub last_nonce: u64,
@@ -30,8 +32,10 @@ impl DcdtData {
     }
 }
 
-#[derive(Clone, Default, Debug)]
-pub struct AccountDcdt(HashMap<Vec<u8>, DcdtData>);
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AccountDcdt(
+    #[serde(with = "crate::serde_util::hex_key_map")] HashMap<Vec<u8>, DcdtData>,
+);
 
 impl AccountDcdt {
     pub fn is_empty(&self) -> bool {
@@ -70,6 +74,10 @@ impl AccountDcdt {
         self.0.get_mut(identifier)
     }
 
+    pub fn get_mut_by_identifier_or_default(&mut self, identifier: &[u8]) -> &mut DcdtData {
+        self.0.entry(identifier.to_vec()).or_default()
+    }
+
     pub fn new_from_raw_map(hash: HashMap<Vec<u8>, DcdtData>) -> Self {
         AccountDcdt(hash)
     }
@@ -151,16 +159,33 @@ impl AccountDcdt {
     }
 
     pub fn set_special_role(&mut self, token_identifier: &[u8], role: &[u8]) {
+        let dcdt_data = self.get_mut_by_identifier_or_default(token_identifier);
+        let roles = dcdt_data.roles.get();
+        if !roles.contains(role.to_vec().as_ref()) {
+            let mut new_roles = roles;
+            new_roles.push(role.to_vec());
+            dcdt_data.roles = DcdtRoles::new(new_roles);
+        }
+    }
+
+    pub fn unset_special_role(&mut self, token_identifier: &[u8], role: &[u8]) {
         if let Some(dcdt_data) = self.get_mut_by_identifier(token_identifier) {
-            let roles = dcdt_data.roles.get();
-            if !roles.contains(role.to_vec().as_ref()) {
-                let mut new_roles = roles;
-                new_roles.push(role.to_vec());
-                dcdt_data.roles = DcdtRoles::new(new_roles);
-            }
+            let new_roles = dcdt_data
+                .roles
+                .get()
+                .into_iter()
+                .filter(|r| r.as_slice() != role)
+                .collect();
+            dcdt_data.roles = DcdtRoles::new(new_roles);
         }
     }
 
+    pub fn has_special_role(&self, token_identifier: &[u8], role: &[u8]) -> bool {
+        self.get_by_identifier(token_identifier)
+            .map(|dcdt_data| dcdt_data.roles.contains(role))
+            .unwrap_or(false)
+    }
+
     pub fn register_and_set_roles(&mut self, token_identifier: &[u8], token_type: VMTokenType) {
         self.issue_token(token_identifier);
         self.set_roles(
@@ -1,14 +1,19 @@
 use num_bigint::BigUint;
 use num_traits::Zero;
+use serde::{Deserialize, Serialize};
 
 use super::DcdtInstanceMetadata;
 
 /// Holds the data for a Dharitri standard digital token transaction
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DcdtInstance {
     pub nonce: u64,
+    #[serde(with = "crate::serde_util::big_uint")]
     pub balance: BigUint,
     pub metadata: DcdtInstanceMetadata,
+
+    /// Set by the system SC via `freezeSingleNFT`. Frozen instances cannot be transferred.
+    pub frozen: bool,
 }
 
 impl DcdtInstance {
@@ -17,6 +22,7 @@ impl DcdtInstance {
             nonce,
             balance: BigUint::zero(),
             metadata: DcdtInstanceMetadata::default(),
+            frozen: false,
         }
     }
 
@@ -25,6 +31,7 @@ impl DcdtInstance {
             nonce: 0,
             balance,
             metadata: DcdtInstanceMetadata::default(),
+            frozen: false,
         }
     }
 
@@ -1,12 +1,19 @@
+use serde::{Deserialize, Serialize};
+
 use crate::types::VMAddress;
 
 /// Holds the data for a Dharitri standard digital token transaction
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DcdtInstanceMetadata {
+    #[serde(with = "crate::serde_util::hex_bytes")]
     pub name: Vec<u8>,
+    #[serde(with = "crate::serde_util::hex_option")]
     pub creator: Option<VMAddress>,
     pub royalties: u64,
+    #[serde(with = "crate::serde_util::hex_option")]
     pub hash: Option<Vec<u8>>,
+    #[serde(with = "crate::serde_util::hex_vec")]
     pub uri: Vec<Vec<u8>>,
+    #[serde(with = "crate::serde_util::hex_bytes")]
     pub attributes: Vec<u8>,
 }
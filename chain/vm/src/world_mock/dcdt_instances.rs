@@ -2,12 +2,13 @@ use super::{DcdtInstance, DcdtInstanceMetadata};
 use crate::display_util::verbose_hex_list;
 use num_bigint::BigUint;
 use num_traits::Zero;
+use serde::{Deserialize, Serialize};
 use std::{
     collections::BTreeMap,
     fmt::{self, Write},
 };
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct DcdtInstances(BTreeMap<u64, DcdtInstance>);
 
 impl DcdtInstances {
@@ -44,6 +45,7 @@ impl DcdtInstances {
             nonce,
             balance: BigUint::zero(),
             metadata: metadata.clone(),
+            frozen: false,
         });
         if instance.balance.is_zero() {
             instance.metadata = metadata;
@@ -65,6 +67,7 @@ impl DcdtInstances {
                 nonce,
                 balance: value.clone(),
                 metadata,
+                frozen: false,
             });
     }
 
@@ -1,7 +1,9 @@
 use std::fmt::{self, Write};
 
-#[derive(Clone, Default, Debug)]
-pub struct DcdtRoles(Vec<Vec<u8>>);
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DcdtRoles(#[serde(with = "crate::serde_util::hex_vec")] Vec<Vec<u8>>);
 
 impl DcdtRoles {
     pub fn new(roles: Vec<Vec<u8>>) -> Self {
@@ -15,6 +17,10 @@ impl DcdtRoles {
     pub fn get(&self) -> Vec<Vec<u8>> {
         self.0.clone()
     }
+
+    pub fn contains(&self, role: &[u8]) -> bool {
+        self.0.iter().any(|r| r.as_slice() == role)
+    }
 }
 
 impl fmt::Display for DcdtRoles {
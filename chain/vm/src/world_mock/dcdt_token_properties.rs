@@ -0,0 +1,105 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{VMAddress, VMTokenType};
+
+/// Token-level data, as kept by the DCDT system SC.
+///
+/// Only tokens issued through the system SC have properties.
+/// Tokens that only exist in account balances (e.g. set up via `setState`) do not.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DcdtTokenProperties {
+    #[serde(with = "crate::serde_util::hex_bytes")]
+    pub name: Vec<u8>,
+    #[serde(with = "crate::serde_util::token_type")]
+    pub token_type: VMTokenType,
+    #[serde(with = "crate::serde_util::hex_bytes")]
+    pub owner: VMAddress,
+    pub num_decimals: u32,
+    #[serde(with = "crate::serde_util::big_uint")]
+    pub minted_value: BigUint,
+    #[serde(with = "crate::serde_util::big_uint")]
+    pub burnt_value: BigUint,
+    pub is_paused: bool,
+    pub can_upgrade: bool,
+    pub can_mint: bool,
+    pub can_burn: bool,
+    pub can_change_owner: bool,
+    pub can_pause: bool,
+    pub can_freeze: bool,
+    pub can_wipe: bool,
+    pub can_add_special_roles: bool,
+    pub can_transfer_nft_create_role: bool,
+    pub can_create_multi_shard: bool,
+    pub nft_create_stopped: bool,
+    pub burn_role_global: bool,
+    pub is_dynamic: bool,
+    pub num_wiped: u64,
+}
+
+impl DcdtTokenProperties {
+    /// Properties of a newly issued token, with the same defaults as on the real chain.
+    pub fn new(
+        name: Vec<u8>,
+        token_type: VMTokenType,
+        owner: VMAddress,
+        num_decimals: u32,
+    ) -> Self {
+        DcdtTokenProperties {
+            name,
+            token_type,
+            owner,
+            num_decimals,
+            minted_value: BigUint::zero(),
+            burnt_value: BigUint::zero(),
+            is_paused: false,
+            can_upgrade: true,
+            can_mint: false,
+            can_burn: false,
+            can_change_owner: false,
+            can_pause: false,
+            can_freeze: false,
+            can_wipe: false,
+            can_add_special_roles: true,
+            can_transfer_nft_create_role: false,
+            can_create_multi_shard: false,
+            nft_create_stopped: false,
+            burn_role_global: false,
+            is_dynamic: false,
+            num_wiped: 0,
+        }
+    }
+
+    /// Sets one of the properties that can be configured on issue or via `controlChanges`.
+    ///
+    /// Returns false if the property name is not recognized.
+    pub fn set_property(&mut self, name: &[u8], value: bool) -> bool {
+        let field = match name {
+            b"canUpgrade" => &mut self.can_upgrade,
+            b"canMint" => &mut self.can_mint,
+            b"canBurn" => &mut self.can_burn,
+            b"canChangeOwner" => &mut self.can_change_owner,
+            b"canPause" => &mut self.can_pause,
+            b"canFreeze" => &mut self.can_freeze,
+            b"canWipe" => &mut self.can_wipe,
+            b"canAddSpecialRoles" => &mut self.can_add_special_roles,
+            b"canTransferNFTCreateRole" => &mut self.can_transfer_nft_create_role,
+            b"canCreateMultiShard" => &mut self.can_create_multi_shard,
+            _ => return false,
+        };
+        *field = value;
+        true
+    }
+
+    /// The token type name, as it appears in the system SC output.
+    pub fn token_type_name(&self) -> &'static str {
+        match self.token_type {
+            VMTokenType::Fungible => "FungibleDCDT",
+            VMTokenType::SemiFungible => "SemiFungibleDCDT",
+            VMTokenType::Meta => "MetaDCDT",
+            VMTokenType::NonFungible => "NonFungibleDCDT",
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::VMAddress;
+
+/// Shard id of the metachain, where the system smart contracts live.
+pub const METACHAIN_SHARD_ID: u32 = u32::MAX;
+
+/// The metachain system SC addresses have these bytes zeroed, right after the SC address prefix.
+const METACHAIN_SC_ZEROS_START: usize = 10;
+const METACHAIN_SC_ZEROS_END: usize = 15;
+const METACHAIN_SC_LAST_BYTE: u8 = 0xff;
+
+/// Splits accounts between shards, with the same rule as the real chain.
+///
+/// The shard of an address is given by its last byte,
+/// masked with the smallest power of two that fits all shards.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShardConfig {
+    pub num_shards: u32,
+    mask_high: u32,
+    mask_low: u32,
+}
+
+impl ShardConfig {
+    pub fn new(num_shards: u32) -> Self {
+        assert!(
+            (1..=256).contains(&num_shards),
+            "the number of shards must be between 1 and 256"
+        );
+        let num_bits = u32::BITS - (num_shards - 1).leading_zeros();
+        ShardConfig {
+            num_shards,
+            mask_high: (1 << num_bits) - 1,
+            mask_low: (1 << num_bits.saturating_sub(1)) - 1,
+        }
+    }
+
+    pub fn shard_of(&self, address: &VMAddress) -> u32 {
+        if is_metachain_sc_address(address) {
+            return METACHAIN_SHARD_ID;
+        }
+
+        let last_byte = *address.as_bytes().last().unwrap() as u32;
+        let shard = last_byte & self.mask_high;
+        if shard < self.num_shards {
+            shard
+        } else {
+            last_byte & self.mask_low
+        }
+    }
+
+    pub fn is_cross_shard(&self, from: &VMAddress, to: &VMAddress) -> bool {
+        self.shard_of(from) != self.shard_of(to)
+    }
+}
+
+fn is_metachain_sc_address(address: &VMAddress) -> bool {
+    let bytes = address.as_bytes();
+    address.is_smart_contract_address()
+        && bytes[METACHAIN_SC_ZEROS_START..METACHAIN_SC_ZEROS_END]
+            .iter()
+            .all(|b| *b == 0)
+        && bytes[bytes.len() - 1] == METACHAIN_SC_LAST_BYTE
+}
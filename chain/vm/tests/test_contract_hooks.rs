@@ -0,0 +1,142 @@
+use std::{
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::Arc,
+};
+
+use dharitri_chain_vm::{
+    chain_core::builtin_func_names::UPGRADE_CONTRACT_FUNC_NAME,
+    executor::{MemPtr, VMHooks},
+    mem_conv,
+    tx_execution::BlockchainVMRef,
+    tx_mock::{TxCache, TxContext, TxInput},
+    types::{VMAddress, VMCodeMetadata},
+    vm_err_msg,
+    vm_hooks::{DebugApiVMHooksHandler, VMHooksDispatcher},
+    world_mock::{AccountData, BlockchainState, FailingExecutor},
+};
+
+const PARENT: VMAddress = VMAddress::new([1u8; 32]);
+const CHILD: VMAddress = VMAddress::new([2u8; 32]);
+
+fn contract_account(
+    address: VMAddress,
+    owner: VMAddress,
+    code_metadata: VMCodeMetadata,
+) -> AccountData {
+    let mut account = AccountData::new_empty(address);
+    account.contract_path = Some(b"contract-code".to_vec());
+    account.code_metadata = code_metadata;
+    account.contract_owner = Some(owner);
+    account.storage.insert(b"key".to_vec(), b"value".to_vec());
+    account
+}
+
+fn state(child_owner: VMAddress, child_code_metadata: VMCodeMetadata) -> BlockchainState {
+    let mut state = BlockchainState::default();
+    state.add_account(contract_account(
+        PARENT,
+        VMAddress::zero(),
+        VMCodeMetadata::UPGRADEABLE,
+    ));
+    state.add_account(contract_account(CHILD, child_owner, child_code_metadata));
+    state
+}
+
+fn tx_context(state: BlockchainState) -> Arc<TxContext> {
+    let tx_input = TxInput {
+        from: VMAddress::zero(),
+        to: PARENT,
+        ..Default::default()
+    };
+    Arc::new(TxContext::new(
+        BlockchainVMRef::new(Box::new(FailingExecutor)),
+        tx_input,
+        TxCache::new(Arc::new(state)),
+    ))
+}
+
+fn dispatcher(tx_context: &Arc<TxContext>) -> VMHooksDispatcher {
+    VMHooksDispatcher::new(Box::new(DebugApiVMHooksHandler::new(tx_context.clone())))
+}
+
+#[test]
+fn test_raw_delete_contract() {
+    let mut state = state(PARENT, VMCodeMetadata::UPGRADEABLE);
+    let tx_context = tx_context(state.clone());
+
+    mem_conv::with_mem_ptr(CHILD.as_bytes(), |offset, _| {
+        dispatcher(&tx_context).delete_contract(offset, 0, 0, 0, 0)
+    });
+
+    let updates = Arc::try_unwrap(tx_context)
+        .unwrap()
+        .into_blockchain_updates();
+    assert!(updates.deleted_accounts.contains(&CHILD));
+    updates.apply(&mut state);
+    assert!(!state.accounts.contains_key(&CHILD));
+    assert!(state.accounts.contains_key(&PARENT));
+}
+
+#[test]
+fn test_delete_contract_not_allowed() {
+    let not_owned = state(VMAddress::zero(), VMCodeMetadata::UPGRADEABLE);
+    let not_upgradeable = state(PARENT, VMCodeMetadata::PAYABLE);
+
+    for state in [not_owned, not_upgradeable] {
+        let tx_context = tx_context(state);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            mem_conv::with_mem_ptr(CHILD.as_bytes(), |offset, _| {
+                dispatcher(&tx_context).delete_contract(offset, 0, 0, 0, 0)
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(
+            tx_context.extract_result().result_message,
+            vm_err_msg::DELETE_NOT_ALLOWED
+        );
+        assert!(tx_context
+            .blockchain_cache()
+            .all_account_addresses()
+            .contains(&CHILD));
+    }
+}
+
+#[test]
+fn test_raw_upgrade_contract_arguments() {
+    let tx_context = tx_context(state(PARENT, VMCodeMetadata::UPGRADEABLE));
+
+    let value = [0u8; 32];
+    let code = b"new-code";
+    let code_metadata = VMCodeMetadata::UPGRADEABLE.to_byte_array();
+    let arg_lengths = [3i32.to_le_bytes(), 0i32.to_le_bytes(), 2i32.to_le_bytes()].concat();
+    let arg_data = b"abcde";
+
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        dispatcher(&tx_context).upgrade_contract(
+            CHILD.as_bytes().as_ptr() as MemPtr,
+            0,
+            value.as_ptr() as MemPtr,
+            code.as_ptr() as MemPtr,
+            code_metadata.as_ptr() as MemPtr,
+            code.len() as _,
+            3,
+            arg_lengths.as_ptr() as MemPtr,
+            arg_data.as_ptr() as MemPtr,
+        )
+    }));
+
+    let tx_result = tx_context.extract_result();
+    let async_call = &tx_result.pending_calls.async_calls[0];
+    assert_eq!(async_call.to, CHILD);
+    assert_eq!(async_call.endpoint_name, UPGRADE_CONTRACT_FUNC_NAME.into());
+    assert_eq!(
+        async_call.arguments,
+        vec![
+            code.to_vec(),
+            code_metadata.to_vec(),
+            b"abc".to_vec(),
+            Vec::new(),
+            b"de".to_vec(),
+        ]
+    );
+}
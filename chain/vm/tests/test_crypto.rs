@@ -1,5 +1,5 @@
-use hex::FromHex;
 use dharitri_chain_vm::crypto_functions;
+use hex::FromHex;
 
 #[test]
 fn test_verify_ed25519_basic() {
@@ -42,3 +42,172 @@ fn test_verify_ed25519_invalid_args() {
     let success = crypto_functions::verify_ed25519(&pub_bytes, &msg_bytes, &sig_bytes);
     assert!(!success);
 }
+
+#[test]
+fn test_ripemd160() {
+    let result = crypto_functions::ripemd160(b"abc");
+    assert_eq!(
+        hex::encode(result),
+        "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"
+    );
+}
+
+fn secp256k1_signing_key() -> k256::ecdsa::SigningKey {
+    k256::ecdsa::SigningKey::from_slice(&[7u8; 32]).unwrap()
+}
+
+#[test]
+fn test_verify_secp256k1() {
+    use k256::ecdsa::{signature::hazmat::PrehashSigner, Signature};
+
+    let signing_key = secp256k1_signing_key();
+    let public_key = signing_key.verifying_key().to_sec1_bytes();
+    let message = b"message";
+    let message_hash = crypto_functions::keccak256(message);
+    let signature: Signature = signing_key.sign_prehash(&message_hash).unwrap();
+    let der_signature = signature.to_der();
+
+    assert!(crypto_functions::verify_secp256k1(
+        &public_key,
+        message,
+        der_signature.as_bytes(),
+        crypto_functions::SECP256K1_HASH_TYPE_KECCAK256,
+    ));
+    assert!(crypto_functions::verify_secp256k1(
+        &public_key,
+        &message_hash,
+        der_signature.as_bytes(),
+        crypto_functions::SECP256K1_HASH_TYPE_PLAIN_MSG,
+    ));
+    assert!(!crypto_functions::verify_secp256k1(
+        &public_key,
+        message,
+        der_signature.as_bytes(),
+        crypto_functions::SECP256K1_HASH_TYPE_SHA256,
+    ));
+    assert_eq!(
+        crypto_functions::secp256k1_der_signature_len(der_signature.as_bytes()),
+        der_signature.as_bytes().len()
+    );
+}
+
+#[test]
+fn test_encode_secp256k1_der_signature() {
+    use k256::ecdsa::{signature::hazmat::PrehashSigner, Signature};
+
+    let signing_key = secp256k1_signing_key();
+    let message_hash = crypto_functions::sha256(&crypto_functions::sha256(b"message"));
+    let signature: Signature = signing_key.sign_prehash(&message_hash).unwrap();
+    let (r, s) = signature.split_bytes();
+
+    let der_signature = crypto_functions::encode_secp256k1_der_signature(&r, &s);
+    assert_eq!(der_signature, signature.to_der().as_bytes());
+}
+
+#[test]
+fn test_verify_secp256r1() {
+    use p256::ecdsa::{signature::Signer, Signature, SigningKey};
+
+    let signing_key = SigningKey::from_slice(&[7u8; 32]).unwrap();
+    let public_key = signing_key.verifying_key().to_sec1_bytes();
+    let signature: Signature = signing_key.sign(b"message");
+
+    assert!(crypto_functions::verify_secp256r1(
+        &public_key,
+        b"message",
+        &signature.to_bytes(),
+    ));
+    assert!(crypto_functions::verify_secp256r1(
+        &public_key,
+        b"message",
+        signature.to_der().as_bytes(),
+    ));
+    assert!(!crypto_functions::verify_secp256r1(
+        &public_key,
+        b"other message",
+        &signature.to_bytes(),
+    ));
+}
+
+const BLS_PUBLIC_KEY: &str = "b5823f6e564251cc03ce7bad3da83e72576e92795d3500bba1acb30ec9a94dce87bb8aa794d67b2d61d15c33f28f6c0c23ba1dfcbf21e8f8b46286ff871afabac925303ddcaddce6254fcff6d3155797db40b3d3b5865e8fc0bd770b3d79b381";
+const BLS_SIGNATURE: &str = "af32a2ddf341c08d1eb7232f05dc34e4454155e676b58c40fddf9a036562ac2c01533d2d557cb49d73aa9d7a89744696";
+
+const BLS_SHARE_PUBLIC_KEY: &str = "3e886a4c6e109a151f4105aee65a5192d150ef1fa68d3cd76964a0b086006dbe4324c989deb0e4416c6d6706db1b1910eb2732f08842fb4886067b9ed191109ac2188d76002d2e11da80a3f0ea89fee6b59c834cc478a6bd49cb8a193b1abb16";
+const BLS_SHARE_MESSAGE: &str = "e96bd0f36b70c5ccc0c4396343bd7d8255b8a526c55fa1e218511fafe6539b8e";
+const BLS_SHARE_SIGNATURE: &str = "04725db195e37aa237cdbbda76270d4a229b6e7a3651104dc58c4349c0388e8546976fe54a04240530b99064e434c90f";
+
+const BLS_AGGREGATED_PUBLIC_KEYS: [&str; 5] = [
+    "9723bb054e8c79ef18dc24d329f84c7e6dbd43ee1a1064f1f7ecaf98be5695b1a62c78b530cfecb69304f07cefb76b02cdaed63cb2f62214971174f603704212d690f5ef76f1718ec1e920b00ac0792949d9f7371bbc5c9e054f040775ee9d06",
+    "6402df92cad7c9f0fb06381f66940266193c865ba6e90f08adbccc504913d4b8005b74b3210e38ba644f41b8e0af1519c9013791aaa798dd19536e3ddef1f9c49a83bab0521503f9aedf105cf32af421cf41f77ea7d26db4650a87ad0178f387",
+    "a7bd70d9eeb4ec0baff870335c6da592cb77aa1efd4a0b140e5f263a7ba346474aa2b5db2c407b47354febfc8bc1ab18157ce8d9a55aadf37e1c4ae4c4d7b1ae8e0498c520aebd2efac32ca82267c24ff3132006d14ae514282512935bf81a06",
+    "408ee8ebc5269599c9ecafcce6d7876f5fc7bbe3e86cf0bfa11d34df91c67451df7275ae8e399d34dd42d7172fb8f41605e16880497e1238e2e0d0855c331f5b42347984b6da36c8819f13fec7a6a3a0b6a55a5b269f19b80586381fcedff297",
+    "e13f11461d0e11f78dedd6cabfb4114516338f037e1cf8121bc842e74d434a1b728855a15267f5dbab7e31a1e903ee0959567817ab743f5bac57b782e184c98a554d092659fb7236bf1f5113a424aa42625608ce5646cae067e1a76576e72a01",
+];
+const BLS_AGGREGATED_SIGNATURE: &str = "81c611c8ea8ba6c5f90207f9002e436e9cb97e927482fa755b46749dcf8d351c29756e34417e024687629c1cf0b4ec99";
+
+#[test]
+fn test_verify_bls() {
+    let public_key = hex::decode(BLS_PUBLIC_KEY).unwrap();
+    let signature = hex::decode(BLS_SIGNATURE).unwrap();
+
+    assert!(crypto_functions::verify_bls(
+        &public_key,
+        b"message to be signed",
+        &signature
+    ));
+    assert!(!crypto_functions::verify_bls(
+        &public_key,
+        b"other message",
+        &signature
+    ));
+    assert!(!crypto_functions::verify_bls(
+        &public_key[1..],
+        b"message to be signed",
+        &signature
+    ));
+}
+
+#[test]
+fn test_verify_bls_signature_share() {
+    let public_key = hex::decode(BLS_SHARE_PUBLIC_KEY).unwrap();
+    let message = hex::decode(BLS_SHARE_MESSAGE).unwrap();
+    let mut signature = hex::decode(BLS_SHARE_SIGNATURE).unwrap();
+
+    assert!(crypto_functions::verify_bls_signature_share(
+        &public_key,
+        &message,
+        &signature
+    ));
+
+    signature[0] = 0xff;
+    assert!(!crypto_functions::verify_bls_signature_share(
+        &public_key,
+        &message,
+        &signature
+    ));
+}
+
+#[test]
+fn test_verify_bls_aggregated_signature() {
+    let public_keys: Vec<Vec<u8>> = BLS_AGGREGATED_PUBLIC_KEYS
+        .iter()
+        .map(|key| hex::decode(key).unwrap())
+        .collect();
+    let signature = hex::decode(BLS_AGGREGATED_SIGNATURE).unwrap();
+
+    assert!(crypto_functions::verify_bls_aggregated_signature(
+        &public_keys,
+        b"message0",
+        &signature
+    ));
+    assert!(!crypto_functions::verify_bls_aggregated_signature(
+        &public_keys,
+        b"message1",
+        &signature
+    ));
+    assert!(!crypto_functions::verify_bls_aggregated_signature(
+        &public_keys[..4],
+        b"message0",
+        &signature
+    ));
+}
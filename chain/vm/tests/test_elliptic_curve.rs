@@ -0,0 +1,84 @@
+use dharitri_chain_vm::crypto_functions::EllipticCurve;
+use num_bigint::BigUint;
+
+#[test]
+fn test_ec_from_name() {
+    assert!(EllipticCurve::from_name(b"p224").is_some());
+    assert!(EllipticCurve::from_name(b"p256").is_some());
+    assert!(EllipticCurve::from_name(b"p384").is_some());
+    assert!(EllipticCurve::from_name(b"p521").is_some());
+    assert!(EllipticCurve::from_name(b"secp256k1").is_none());
+
+    let p521 = EllipticCurve::from_name(b"p521").unwrap();
+    assert_eq!(p521.bit_size, 521);
+    assert_eq!(p521.byte_len(), 66);
+    assert!(p521.is_on_curve(&p521.gx, &p521.gy));
+}
+
+#[test]
+fn test_ec_scalar_base_mult_matches_p256() {
+    use p256::elliptic_curve::sec1::ToEncodedPoint;
+
+    let curve = EllipticCurve::from_name(b"p256").unwrap();
+    let private_key = [3u8; 32];
+    let (x, y) = curve.scalar_base_mult(&private_key);
+
+    let expected = p256::SecretKey::from_slice(&private_key)
+        .unwrap()
+        .public_key()
+        .to_encoded_point(false);
+    assert_eq!(curve.marshal(&x, &y), expected.as_bytes());
+}
+
+#[test]
+fn test_ec_add_double() {
+    let curve = EllipticCurve::from_name(b"p384").unwrap();
+    let base = (curve.gx.clone(), curve.gy.clone());
+
+    let doubled = curve.double(&base);
+    assert_eq!(curve.add(&base, &base), doubled);
+    assert_eq!(curve.scalar_base_mult(&[2]), doubled);
+    assert_eq!(curve.add(&doubled, &base), curve.scalar_base_mult(&[3]));
+
+    // multiplying by the order of the base point yields the point at infinity
+    let order = curve.n.to_bytes_be();
+    assert_eq!(
+        curve.scalar_base_mult(&order),
+        (BigUint::default(), BigUint::default())
+    );
+}
+
+#[test]
+fn test_ec_marshal_unmarshal() {
+    for name in [&b"p224"[..], b"p256", b"p384", b"p521"] {
+        let curve = EllipticCurve::from_name(name).unwrap();
+        let (x, y) = curve.scalar_base_mult(&[5, 17, 123]);
+
+        let marshalled = curve.marshal(&x, &y);
+        assert_eq!(marshalled.len(), 1 + 2 * curve.byte_len());
+        assert_eq!(curve.unmarshal(&marshalled), Some((x.clone(), y.clone())));
+
+        let compressed = curve.marshal_compressed(&x, &y);
+        assert_eq!(compressed.len(), 1 + curve.byte_len());
+        assert_eq!(curve.unmarshal_compressed(&compressed), Some((x, y)));
+    }
+}
+
+#[test]
+fn test_ec_unmarshal_invalid() {
+    let curve = EllipticCurve::from_name(b"p256").unwrap();
+    let (x, y) = curve.scalar_base_mult(&[9]);
+    let mut marshalled = curve.marshal(&x, &y);
+    *marshalled.last_mut().unwrap() ^= 1;
+    assert_eq!(curve.unmarshal(&marshalled), None);
+    assert_eq!(curve.unmarshal(&marshalled[1..]), None);
+}
+
+#[test]
+fn test_ec_generate_key() {
+    let curve = EllipticCurve::from_name(b"p224").unwrap();
+    let (private_key, public_key) = curve.generate_key(|length| vec![0xaa; length]);
+    assert_eq!(private_key.len(), curve.private_key_byte_len());
+    assert_eq!(curve.scalar_base_mult(&private_key), public_key);
+    assert!(curve.is_on_curve(&public_key.0, &public_key.1));
+}
@@ -0,0 +1,58 @@
+use dharitri_chain_vm::tx_mock::{
+    CallType, ExecutionTracer, TraceEvent, TxFunctionName, TxInput, TxResult,
+};
+
+fn tx_input(func_name: &str, call_type: CallType) -> TxInput {
+    TxInput {
+        func_name: TxFunctionName::from(func_name),
+        call_type,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_tracer_nests_sync_calls() {
+    let tracer = ExecutionTracer::new();
+
+    tracer.begin_call(&tx_input("parent", CallType::DirectCall));
+    tracer.record_vm_hook("bi_add");
+    tracer.begin_call(&tx_input("child", CallType::ExecuteOnDestContext));
+    tracer.record_storage_write(b"key", b"value");
+    tracer.end_call(&TxResult::default());
+    tracer.end_call(&TxResult::from_vm_error("child failed"));
+
+    // async calls start after the parent has finished
+    tracer.begin_call(&tx_input("async", CallType::AsyncCall));
+    tracer.end_call(&TxResult::default());
+
+    let trace = tracer.trace();
+    assert_eq!(trace.calls.len(), 2);
+
+    let parent = &trace.calls[0];
+    assert_eq!(parent.function, "parent");
+    assert!(!parent.is_success());
+    assert!(matches!(&parent.events[0], TraceEvent::VMHook { name } if name == "bi_add"));
+    let TraceEvent::Call(child) = &parent.events[1] else {
+        panic!("expected nested call");
+    };
+    assert_eq!(child.function, "child");
+    assert!(child.is_success());
+    assert!(
+        matches!(&child.events[0], TraceEvent::StorageWrite { key, value }
+        if key == "0x6b6579" && value == "0x76616c7565")
+    );
+
+    assert_eq!(parent.failed_call().unwrap().function, "parent");
+    assert_eq!(trace.calls[1].function, "async");
+
+    tracer.clear();
+    assert!(tracer.trace().calls.is_empty());
+}
+
+#[test]
+fn test_tracer_ignores_events_outside_calls() {
+    let tracer = ExecutionTracer::new();
+    tracer.record_storage_write(b"key", b"value");
+    tracer.end_call(&TxResult::default());
+    assert!(tracer.trace().calls.is_empty());
+}
@@ -0,0 +1,289 @@
+use dharitri_chain_vm::{
+    executor::{
+        BreakpointValue, CompilationOptions, Executor, ExecutorError, Instance, MemLength, MemPtr,
+        OpcodeCost,
+    },
+    tx_execution::{execute_current_tx_context_input, BlockchainVMRef},
+    tx_mock::{AsyncCallTxData, Promise, TxContextStack, TxFunctionName, TxInput, TxLog, TxResult},
+    types::VMAddress,
+    with_shared::Shareable,
+    world_mock::{AccountData, BlockchainState},
+};
+
+const USER: VMAddress = VMAddress::new([1u8; 32]);
+const CALLER: VMAddress = sc_address(2);
+const CALLEE: VMAddress = sc_address(3);
+
+const fn sc_address(last_byte: u8) -> VMAddress {
+    let mut bytes = [0u8; 32];
+    bytes[31] = last_byte;
+    VMAddress::new(bytes)
+}
+
+/// Marks the logs written by the test contract, as opposed to the transfer logs written by the VM.
+const CALLED_TOPIC: &[u8] = b"called";
+
+/// Closure data that makes the promise callback register another promise.
+const CHAIN_CLOSURE_DATA: &[u8] = b"chain";
+
+/// Runs the same test contract code for all accounts, dispatching on the endpoint name.
+struct TestExecutor;
+
+impl Executor for TestExecutor {
+    fn set_vm_hooks_ptr(
+        &mut self,
+        _vm_hooks_ptr: *mut std::ffi::c_void,
+    ) -> Result<(), ExecutorError> {
+        Ok(())
+    }
+
+    fn set_opcode_cost(&mut self, _opcode_cost: &OpcodeCost) -> Result<(), ExecutorError> {
+        Ok(())
+    }
+
+    fn new_instance(
+        &self,
+        _wasm_bytes: &[u8],
+        _compilation_options: &CompilationOptions,
+    ) -> Result<Box<dyn Instance>, ExecutorError> {
+        Ok(Box::new(TestInstance))
+    }
+
+    fn new_instance_from_cache(
+        &self,
+        _cache_bytes: &[u8],
+        _compilation_options: &CompilationOptions,
+    ) -> Result<Box<dyn Instance>, ExecutorError> {
+        panic!("not supported by the test executor")
+    }
+}
+
+struct TestInstance;
+
+fn register_promise(endpoint: &str, argument: u8, success_callback: &str, closure_data: &[u8]) {
+    let tx_context = TxContextStack::static_peek();
+    let promise = Promise {
+        call: AsyncCallTxData {
+            from: tx_context.input_ref().to.clone(),
+            to: CALLEE,
+            call_value: 0u32.into(),
+            endpoint_name: TxFunctionName::from(endpoint),
+            arguments: vec![vec![argument]],
+            tx_hash: tx_context.input_ref().tx_hash.clone(),
+        },
+        success_callback: TxFunctionName::from(success_callback),
+        error_callback: TxFunctionName::from("error_callback"),
+        callback_closure_data: closure_data.to_vec(),
+    };
+    let mut tx_result = tx_context.result_lock();
+    tx_result.all_calls.push(promise.call.clone());
+    tx_result.pending_calls.promises.push(promise);
+}
+
+fn log_call(func_name: &str) {
+    let tx_context = TxContextStack::static_peek();
+    let input = tx_context.input_ref();
+    let data = if func_name == "echo" {
+        input.args.clone()
+    } else {
+        Vec::new()
+    };
+    let log = TxLog {
+        address: input.to.clone(),
+        endpoint: TxFunctionName::from(func_name),
+        topics: vec![CALLED_TOPIC.to_vec()],
+        data,
+    };
+    tx_context.result_lock().result_logs.push(log);
+}
+
+impl Instance for TestInstance {
+    fn call(&self, func_name: &str) -> Result<(), String> {
+        log_call(func_name);
+        match func_name {
+            "multiple_promises" => {
+                for argument in 1..=3 {
+                    register_promise("echo", argument, "promise_callback", b"");
+                }
+            },
+            "promise_in_callback" => {
+                register_promise("echo", 1, "promise_callback", CHAIN_CLOSURE_DATA);
+            },
+            "promise_callback" => {
+                let closure_data = TxContextStack::static_peek()
+                    .input_ref()
+                    .promise_callback_closure_data
+                    .clone();
+                if closure_data.as_deref() == Some(CHAIN_CLOSURE_DATA) {
+                    register_promise("echo", 2, "last_callback", b"");
+                }
+            },
+            "echo" | "last_callback" => {},
+            _ => return Err(format!("unknown endpoint {func_name}")),
+        }
+        Ok(())
+    }
+
+    fn check_signatures(&self) -> bool {
+        true
+    }
+
+    fn has_function(&self, _func_name: &str) -> bool {
+        true
+    }
+
+    fn get_exported_function_names(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn set_points_limit(&self, _limit: u64) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn set_points_used(&self, _points: u64) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn get_points_used(&self) -> Result<u64, String> {
+        Ok(0)
+    }
+
+    fn memory_length(&self) -> Result<u64, String> {
+        Ok(0)
+    }
+
+    fn memory_ptr(&self) -> Result<*mut u8, String> {
+        Err("no memory".to_string())
+    }
+
+    fn memory_load(
+        &self,
+        _mem_ptr: MemPtr,
+        _mem_length: MemLength,
+    ) -> Result<&[u8], ExecutorError> {
+        panic!("not supported by the test executor")
+    }
+
+    fn memory_store(&self, _mem_ptr: MemPtr, _data: &[u8]) -> Result<(), ExecutorError> {
+        panic!("not supported by the test executor")
+    }
+
+    fn memory_grow(&self, _by_num_pages: u32) -> Result<u32, ExecutorError> {
+        panic!("not supported by the test executor")
+    }
+
+    fn set_breakpoint_value(&self, _value: BreakpointValue) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn get_breakpoint_value(&self) -> Result<BreakpointValue, String> {
+        Ok(BreakpointValue::None)
+    }
+
+    fn reset(&self) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn cache(&self) -> Result<Vec<u8>, String> {
+        Err("not supported by the test executor".to_string())
+    }
+}
+
+fn state() -> Shareable<BlockchainState> {
+    let mut state = BlockchainState::default();
+    state.add_account(AccountData::new_empty(USER));
+    for address in [CALLER, CALLEE] {
+        let mut account = AccountData::new_empty(address);
+        account.contract_path = Some(b"test-contract".to_vec());
+        state.add_account(account);
+    }
+    Shareable::new(state)
+}
+
+fn call(func_name: &str) -> TxResult {
+    let vm = BlockchainVMRef::new(Box::new(TestExecutor));
+    let tx_input = TxInput {
+        from: USER,
+        to: CALLER,
+        func_name: TxFunctionName::from(func_name),
+        gas_limit: 1_000_000,
+        ..Default::default()
+    };
+    let tx_result = vm.sc_call_with_async_and_callback(
+        tx_input,
+        &mut state(),
+        execute_current_tx_context_input,
+    );
+    assert!(
+        tx_result.result_status.is_success(),
+        "{}",
+        tx_result.result_message
+    );
+    tx_result
+}
+
+/// The test contract calls, in execution order.
+fn calls(tx_result: &TxResult) -> Vec<(VMAddress, String, Vec<Vec<u8>>)> {
+    tx_result
+        .result_logs
+        .iter()
+        .filter(|log| log.topics == [CALLED_TOPIC.to_vec()])
+        .map(|log| {
+            (
+                log.address.clone(),
+                log.endpoint.as_str().to_string(),
+                log.data.clone(),
+            )
+        })
+        .collect()
+}
+
+fn expected(calls: &[(VMAddress, &str, &[u8])]) -> Vec<(VMAddress, String, Vec<Vec<u8>>)> {
+    calls
+        .iter()
+        .map(|(address, endpoint, argument)| {
+            let data = if argument.is_empty() {
+                Vec::new()
+            } else {
+                vec![argument.to_vec()]
+            };
+            (address.clone(), endpoint.to_string(), data)
+        })
+        .collect()
+}
+
+#[test]
+fn test_multiple_promises_from_one_endpoint() {
+    let tx_result = call("multiple_promises");
+
+    // each promise is followed by its callback, in the order in which they were registered
+    assert_eq!(
+        calls(&tx_result),
+        expected(&[
+            (CALLER, "multiple_promises", b""),
+            (CALLEE, "echo", &[1]),
+            (CALLER, "promise_callback", b""),
+            (CALLEE, "echo", &[2]),
+            (CALLER, "promise_callback", b""),
+            (CALLEE, "echo", &[3]),
+            (CALLER, "promise_callback", b""),
+        ])
+    );
+    assert_eq!(tx_result.all_calls.len(), 3);
+}
+
+#[test]
+fn test_promise_registered_in_callback() {
+    let tx_result = call("promise_in_callback");
+
+    assert_eq!(
+        calls(&tx_result),
+        expected(&[
+            (CALLER, "promise_in_callback", b""),
+            (CALLEE, "echo", &[1]),
+            (CALLER, "promise_callback", b""),
+            (CALLEE, "echo", &[2]),
+            (CALLER, "last_callback", b""),
+        ])
+    );
+}
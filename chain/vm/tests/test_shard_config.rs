@@ -0,0 +1,45 @@
+use dharitri_chain_vm::{
+    tx_execution::DCDT_SYSTEM_SC_ADDRESS_ARRAY,
+    types::VMAddress,
+    world_mock::{ShardConfig, METACHAIN_SHARD_ID},
+};
+
+fn address_ending_in(last_byte: u8) -> VMAddress {
+    let mut bytes = [0x11u8; 32];
+    bytes[31] = last_byte;
+    VMAddress::from(bytes)
+}
+
+#[test]
+fn test_shard_of_single_shard() {
+    let shard_config = ShardConfig::new(1);
+    assert_eq!(shard_config.shard_of(&address_ending_in(0x00)), 0);
+    assert_eq!(shard_config.shard_of(&address_ending_in(0xff)), 0);
+}
+
+#[test]
+fn test_shard_of_three_shards() {
+    let shard_config = ShardConfig::new(3);
+    assert_eq!(shard_config.shard_of(&address_ending_in(0x00)), 0);
+    assert_eq!(shard_config.shard_of(&address_ending_in(0x01)), 1);
+    assert_eq!(shard_config.shard_of(&address_ending_in(0x02)), 2);
+
+    // shard 3 does not exist, falls back to the lower mask
+    assert_eq!(shard_config.shard_of(&address_ending_in(0x03)), 1);
+    assert_eq!(shard_config.shard_of(&address_ending_in(0xfe)), 2);
+    assert_eq!(shard_config.shard_of(&address_ending_in(0xff)), 1);
+}
+
+#[test]
+fn test_shard_of_system_sc() {
+    let shard_config = ShardConfig::new(3);
+    let system_sc_address = VMAddress::from(DCDT_SYSTEM_SC_ADDRESS_ARRAY);
+    assert_eq!(
+        shard_config.shard_of(&system_sc_address),
+        METACHAIN_SHARD_ID
+    );
+
+    // a regular address with the same last byte stays in a regular shard
+    assert_eq!(shard_config.shard_of(&address_ending_in(0xff)), 1);
+    assert!(shard_config.is_cross_shard(&address_ending_in(0xff), &system_sc_address));
+}
@@ -0,0 +1,101 @@
+use std::{panic::AssertUnwindSafe, sync::Arc};
+
+use dharitri_chain_vm::{
+    executor::VMHooks,
+    mem_conv,
+    tx_execution::BlockchainVMRef,
+    tx_mock::{TxCache, TxContext, TxInput},
+    types::VMAddress,
+    vm_err_msg,
+    vm_hooks::{DebugApiVMHooksHandler, VMHooksDispatcher},
+    world_mock::{AccountData, BlockchainState, FailingExecutor},
+};
+
+const CONTRACT: VMAddress = VMAddress::new([1u8; 32]);
+const KEY: &[u8] = b"key";
+
+fn tx_context(block_timestamp: u64) -> Arc<TxContext> {
+    let mut state = BlockchainState::default();
+    let mut account = AccountData::new_empty(CONTRACT);
+    account.contract_path = Some(b"contract-code".to_vec());
+    state.add_account(account);
+    state.current_block_info.block_timestamp = block_timestamp;
+
+    let tx_input = TxInput {
+        from: VMAddress::zero(),
+        to: CONTRACT,
+        ..Default::default()
+    };
+    Arc::new(TxContext::new(
+        BlockchainVMRef::new(Box::new(FailingExecutor)),
+        tx_input,
+        TxCache::new(Arc::new(state)),
+    ))
+}
+
+fn dispatcher(block_timestamp: u64) -> VMHooksDispatcher {
+    VMHooksDispatcher::new(Box::new(DebugApiVMHooksHandler::new(tx_context(
+        block_timestamp,
+    ))))
+}
+
+fn set_lock(dispatcher: &VMHooksDispatcher, lock_timestamp: i64) -> i32 {
+    mem_conv::with_mem_ptr(KEY, |offset, length| {
+        dispatcher.set_storage_lock(offset, length, lock_timestamp)
+    })
+}
+
+fn get_lock(dispatcher: &VMHooksDispatcher) -> i64 {
+    mem_conv::with_mem_ptr(KEY, |offset, length| {
+        dispatcher.get_storage_lock(offset, length)
+    })
+}
+
+fn is_locked(dispatcher: &VMHooksDispatcher) -> i32 {
+    mem_conv::with_mem_ptr(KEY, |offset, length| {
+        dispatcher.is_storage_locked(offset, length)
+    })
+}
+
+#[test]
+fn test_storage_lock_status() {
+    let dispatcher = dispatcher(100);
+
+    assert_eq!(get_lock(&dispatcher), 0);
+    assert_eq!(set_lock(&dispatcher, 200), 2); // added
+    assert_eq!(set_lock(&dispatcher, 200), 0); // unchanged
+    assert_eq!(set_lock(&dispatcher, 300), 1); // modified
+    assert_eq!(get_lock(&dispatcher), 300);
+
+    let cleared = mem_conv::with_mem_ptr(KEY, |offset, length| {
+        dispatcher.clear_storage_lock(offset, length)
+    });
+    assert_eq!(cleared, 3); // deleted
+    assert_eq!(get_lock(&dispatcher), 0);
+    assert_eq!(set_lock(&dispatcher, 0), 0); // already deleted
+}
+
+#[test]
+fn test_storage_lock_negative_timestamp() {
+    let tx_context = tx_context(100);
+    let dispatcher =
+        VMHooksDispatcher::new(Box::new(DebugApiVMHooksHandler::new(tx_context.clone())));
+
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| set_lock(&dispatcher, -200)));
+    assert!(result.is_err());
+    assert_eq!(
+        tx_context.result_lock().result_message,
+        vm_err_msg::NEGATIVE_LOCK_TIMESTAMP
+    );
+    assert_eq!(get_lock(&dispatcher), 0);
+}
+
+#[test]
+fn test_storage_lock_expiry() {
+    for (block_timestamp, expected) in [(199, 1), (200, 0), (201, 0)] {
+        let dispatcher = dispatcher(block_timestamp);
+        assert_eq!(is_locked(&dispatcher), 0);
+        set_lock(&dispatcher, 200);
+        assert_eq!(is_locked(&dispatcher), expected);
+    }
+}
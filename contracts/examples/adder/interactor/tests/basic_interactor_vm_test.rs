@@ -0,0 +1,657 @@
+use adder::adder_proxy;
+use dharitri_sc_snippets::{
+    hex,
+    imports::*,
+    sdk::{
+        gateway::{GatewayAsyncService, GetAccountRequest, NetworkStatusRequest, SendTxRequest},
+        utils::base64_encode,
+    },
+};
+
+const ADDER_CODE_PATH: DrtscPath = DrtscPath::new("../../multisig/test-contracts/adder.drtsc.json");
+
+async fn vm_interactor(nonce_mode: NonceMode) -> VMInteractor {
+    let mut interactor = VMInteractor::new("")
+        .await
+        .use_chain_simulator(true)
+        .with_nonce_mode(nonce_mode);
+    interactor.set_current_dir_from_workspace("contracts/examples/adder/interactor");
+    interactor
+        .proxy
+        .set_current_dir_from_workspace("contracts/examples/adder/interactor")
+        .register_contract(ADDER_CODE_PATH, adder::ContractBuilder);
+    interactor
+}
+
+#[tokio::test]
+async fn vm_deploy_add_upgrade_test() {
+    let mut interactor = vm_interactor(NonceMode::Recall).await;
+    let owner_address = interactor.register_wallet(test_wallets::heidi()).await;
+    let other_address = interactor.register_wallet(test_wallets::ivan()).await;
+
+    let adder_address = interactor
+        .tx()
+        .from(&owner_address)
+        .gas(6_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .init(5u64)
+        .code(ADDER_CODE_PATH)
+        .code_metadata(CodeMetadata::UPGRADEABLE)
+        .returns(ReturnsNewAddress)
+        .run()
+        .await;
+
+    interactor
+        .tx()
+        .from(&other_address)
+        .to(&adder_address)
+        .gas(6_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .add(3u32)
+        .run()
+        .await;
+
+    let sum = interactor
+        .query()
+        .to(&adder_address)
+        .typed(adder_proxy::AdderProxy)
+        .sum()
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .await;
+    assert_eq!(sum, RustBigUint::from(8u32));
+
+    let upgrade_result = interactor
+        .tx()
+        .from(&other_address)
+        .to(&adder_address)
+        .gas(6_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .upgrade(10u32)
+        .code(ADDER_CODE_PATH)
+        .code_metadata(CodeMetadata::UPGRADEABLE)
+        .returns(ReturnsHandledOrError::new())
+        .run()
+        .await;
+    assert_eq!(upgrade_result.unwrap_err().message, "upgrade not allowed");
+
+    interactor
+        .tx()
+        .from(&owner_address)
+        .to(&adder_address)
+        .gas(6_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .upgrade(10u32)
+        .code(ADDER_CODE_PATH)
+        .code_metadata(CodeMetadata::UPGRADEABLE)
+        .run()
+        .await;
+
+    let sum = interactor
+        .query()
+        .to(&adder_address)
+        .typed(adder_proxy::AdderProxy)
+        .sum()
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .await;
+    assert_eq!(sum, RustBigUint::from(10u32));
+}
+
+async fn deploy_adder(interactor: &mut VMInteractor, owner_address: &Address) -> Address {
+    interactor
+        .tx()
+        .from(owner_address)
+        .gas(6_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .init(0u64)
+        .code(ADDER_CODE_PATH)
+        .returns(ReturnsNewAddress)
+        .run()
+        .await
+}
+
+async fn add(interactor: &mut VMInteractor, sender: &Address, adder_address: &Address, value: u32) {
+    interactor
+        .tx()
+        .from(sender)
+        .to(adder_address)
+        .gas(6_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .add(value)
+        .run()
+        .await;
+}
+
+async fn query_sum(interactor: &mut VMInteractor, adder_address: &Address) -> RustBigUint {
+    interactor
+        .query()
+        .to(adder_address)
+        .typed(adder_proxy::AdderProxy)
+        .sum()
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .await
+}
+
+async fn rewa_balance(interactor: &VMInteractor, address: &Address) -> RustBigUint {
+    let account = interactor
+        .proxy
+        .request(GetAccountRequest::new(address))
+        .await
+        .unwrap();
+    account.balance.parse().unwrap()
+}
+
+#[tokio::test]
+async fn vm_relayed_tx_test() {
+    let mut interactor = vm_interactor(NonceMode::Recall).await;
+    let owner_address = interactor.register_wallet(test_wallets::heidi()).await;
+    let sender_address = interactor.register_wallet(test_wallets::ivan()).await;
+    let relayer_address = interactor.register_wallet(test_wallets::judy()).await;
+    let adder_address = deploy_adder(&mut interactor, &owner_address).await;
+
+    let sender_balance = rewa_balance(&interactor, &sender_address).await;
+    let relayer_balance = rewa_balance(&interactor, &relayer_address).await;
+
+    interactor.set_sender_relayer(&sender_address, &relayer_address);
+    add(&mut interactor, &sender_address, &adder_address, 3).await;
+    assert_eq!(
+        query_sum(&mut interactor, &adder_address).await,
+        RustBigUint::from(3u32)
+    );
+
+    // the relayer pays for the gas, the sender pays nothing
+    assert_eq!(
+        rewa_balance(&interactor, &sender_address).await,
+        sender_balance
+    );
+    assert!(rewa_balance(&interactor, &relayer_address).await < relayer_balance);
+
+    // the relayer only covers the fee, the value must still come from the sender
+    let result = interactor
+        .tx()
+        .from(&sender_address)
+        .to(&owner_address)
+        .rewa(BigUint::<StaticApi>::from(sender_balance + 1u32))
+        .gas(50_000)
+        .try_run()
+        .await;
+    assert_eq!(
+        result.unwrap_err().gateway_message(),
+        Some(
+            format!(
+                "transaction generation failed: insufficient funds for address {}",
+                Bech32Address::from(&sender_address)
+            )
+            .as_str()
+        )
+    );
+}
+
+#[tokio::test]
+async fn vm_nonce_local_increment_pipelined_test() {
+    let mut interactor = vm_interactor(NonceMode::LocalIncrement).await;
+    let owner_address = interactor.register_wallet(test_wallets::heidi()).await;
+    let other_address = interactor.register_wallet(test_wallets::ivan()).await;
+    let adder_address = deploy_adder(&mut interactor, &owner_address).await;
+
+    let mut buffer = interactor.homogenous_call_buffer();
+    for value in 1..=6u32 {
+        let sender = if value % 2 == 0 {
+            &owner_address
+        } else {
+            &other_address
+        };
+        buffer.push_tx(|tx| {
+            tx.from(sender)
+                .to(&adder_address)
+                .gas(6_000_000)
+                .typed(adder_proxy::AdderProxy)
+                .add(value)
+        });
+    }
+    buffer.run().await;
+
+    assert_eq!(
+        query_sum(&mut interactor, &adder_address).await,
+        RustBigUint::from(21u32)
+    );
+    assert_eq!(interactor.sender_map[&owner_address].current_nonce, Some(4));
+    assert_eq!(interactor.sender_map[&other_address].current_nonce, Some(3));
+    assert_eq!(interactor.recall_nonce(&owner_address).await, 4);
+}
+
+#[tokio::test]
+async fn vm_mnemonic_sender_pool_test() {
+    let mut interactor = vm_interactor(NonceMode::LocalIncrement).await;
+    let owner_address = interactor.register_wallet(test_wallets::heidi()).await;
+    let adder_address = deploy_adder(&mut interactor, &owner_address).await;
+
+    let mnemonic_wallet = MnemonicWallet::generate();
+    let senders = interactor
+        .register_mnemonic_wallet(&mnemonic_wallet, 0..4)
+        .await;
+    assert_eq!(senders, mnemonic_wallet.addresses(0..4));
+
+    let mut buffer = interactor.homogenous_call_buffer();
+    for (index, sender) in senders.iter().enumerate() {
+        buffer.push_tx(|tx| {
+            tx.from(sender)
+                .to(&adder_address)
+                .gas(6_000_000)
+                .typed(adder_proxy::AdderProxy)
+                .add(index as u32 + 1)
+        });
+    }
+    buffer.run().await;
+
+    assert_eq!(
+        query_sum(&mut interactor, &adder_address).await,
+        RustBigUint::from(10u32)
+    );
+    for sender in &senders {
+        assert_eq!(interactor.sender_map[sender].current_nonce, Some(1));
+    }
+}
+
+#[tokio::test]
+async fn vm_nonce_periodic_resync_test() {
+    let mut interactor = vm_interactor(NonceMode::PeriodicResync { num_txs: 2 }).await;
+    let owner_address = interactor.register_wallet(test_wallets::heidi()).await;
+    let adder_address = deploy_adder(&mut interactor, &owner_address).await;
+
+    add(&mut interactor, &owner_address, &adder_address, 1).await;
+    assert_eq!(
+        interactor.sender_map[&owner_address].num_txs_since_recall,
+        2
+    );
+
+    // the wrong nonce gets overwritten by the resync, before it gets used
+    interactor
+        .sender_map
+        .get_mut(&owner_address)
+        .unwrap()
+        .current_nonce = Some(0);
+    add(&mut interactor, &owner_address, &adder_address, 2).await;
+    assert_eq!(
+        interactor.sender_map[&owner_address].num_txs_since_recall,
+        1
+    );
+
+    assert_eq!(
+        query_sum(&mut interactor, &adder_address).await,
+        RustBigUint::from(3u32)
+    );
+}
+
+#[tokio::test]
+async fn vm_nonce_detect_and_repair_test() {
+    let mut interactor = vm_interactor(NonceMode::DetectAndRepair).await;
+    let owner_address = interactor.register_wallet(test_wallets::heidi()).await;
+    let adder_address = deploy_adder(&mut interactor, &owner_address).await;
+
+    // nonce too low
+    interactor
+        .sender_map
+        .get_mut(&owner_address)
+        .unwrap()
+        .current_nonce = Some(0);
+    add(&mut interactor, &owner_address, &adder_address, 1).await;
+
+    // nonce too high
+    interactor
+        .sender_map
+        .get_mut(&owner_address)
+        .unwrap()
+        .current_nonce = Some(1_000);
+    add(&mut interactor, &owner_address, &adder_address, 2).await;
+
+    // nonce too low, in a batch
+    interactor
+        .sender_map
+        .get_mut(&owner_address)
+        .unwrap()
+        .current_nonce = Some(1);
+    let mut buffer = interactor.homogenous_call_buffer();
+    for value in [3u32, 4u32] {
+        buffer.push_tx(|tx| {
+            tx.from(&owner_address)
+                .to(&adder_address)
+                .gas(6_000_000)
+                .typed(adder_proxy::AdderProxy)
+                .add(value)
+        });
+    }
+    buffer.run().await;
+
+    assert_eq!(
+        query_sum(&mut interactor, &adder_address).await,
+        RustBigUint::from(10u32)
+    );
+    assert_eq!(interactor.recall_nonce(&owner_address).await, 5);
+}
+
+#[tokio::test]
+async fn vm_gas_estimate_test() {
+    let mut interactor = vm_interactor(NonceMode::Recall)
+        .await
+        .with_gas_mode(GasMode::Estimate {
+            safety_multiplier: 1.5,
+            max_gas_limit: 50_000_000,
+        });
+    interactor.proxy.gas_schedule(GasSchedule::default());
+    let owner_address = interactor.register_wallet(test_wallets::heidi()).await;
+    let other_address = interactor.register_wallet(test_wallets::ivan()).await;
+
+    // no explicit gas limit
+    let (adder_address, deploy_gas_estimate) = interactor
+        .tx()
+        .from(&owner_address)
+        .typed(adder_proxy::AdderProxy)
+        .init(5u64)
+        .code(ADDER_CODE_PATH)
+        .returns(ReturnsNewAddress)
+        .returns(ReturnsGasEstimate)
+        .run()
+        .await;
+    assert!(deploy_gas_estimate.is_some());
+
+    let (add_gas_estimate, add_gas_used) = interactor
+        .tx()
+        .from(&other_address)
+        .to(&adder_address)
+        .typed(adder_proxy::AdderProxy)
+        .add(3u32)
+        .returns(ReturnsGasEstimate)
+        .returns(ReturnsGasUsed)
+        .run()
+        .await;
+    let add_gas_estimate = add_gas_estimate.unwrap();
+    assert!(add_gas_estimate > add_gas_used);
+    assert!(add_gas_estimate < 50_000_000);
+
+    // the estimate never goes above the ceiling
+    interactor.gas_mode = GasMode::Estimate {
+        safety_multiplier: 1_000.0,
+        max_gas_limit: 10_000_000,
+    };
+    let capped_gas_estimate = interactor
+        .tx()
+        .from(&other_address)
+        .to(&adder_address)
+        .typed(adder_proxy::AdderProxy)
+        .add(4u32)
+        .returns(ReturnsGasEstimate)
+        .run()
+        .await;
+    assert_eq!(capped_gas_estimate, Some(10_000_000));
+
+    // failing transactions cannot be estimated, so they keep their own gas limit
+    let (upgrade_result, upgrade_gas_estimate) = interactor
+        .tx()
+        .from(&other_address)
+        .to(&adder_address)
+        .typed(adder_proxy::AdderProxy)
+        .upgrade(10u32)
+        .code(ADDER_CODE_PATH)
+        .returns(ReturnsHandledOrError::new())
+        .returns(ReturnsGasEstimate)
+        .run()
+        .await;
+    assert_eq!(upgrade_result.unwrap_err().message, "upgrade not allowed");
+    assert_eq!(upgrade_gas_estimate, None);
+
+    // explicit gas limits are kept, even in estimate gas mode
+    let explicit_gas_estimate = interactor
+        .tx()
+        .from(&other_address)
+        .to(&adder_address)
+        .gas(6_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .add(5u32)
+        .returns(ReturnsGasEstimate)
+        .run()
+        .await;
+    assert_eq!(explicit_gas_estimate, None);
+
+    assert_eq!(
+        query_sum(&mut interactor, &adder_address).await,
+        RustBigUint::from(17u32)
+    );
+}
+
+#[tokio::test]
+async fn vm_simulate_test() {
+    let mut interactor = vm_interactor(NonceMode::LocalIncrement).await;
+    interactor.proxy.gas_schedule(GasSchedule::default());
+    let owner_address = interactor.register_wallet(test_wallets::heidi()).await;
+    let other_address = interactor.register_wallet(test_wallets::ivan()).await;
+
+    // the simulated deploy predicts the address, without using up the nonce
+    let simulated_address = interactor
+        .tx()
+        .from(&owner_address)
+        .gas(6_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .init(5u64)
+        .code(ADDER_CODE_PATH)
+        .returns(ReturnsNewAddress)
+        .simulate()
+        .await;
+    let adder_address = deploy_adder(&mut interactor, &owner_address).await;
+    assert_eq!(simulated_address, adder_address);
+    add(&mut interactor, &owner_address, &adder_address, 5).await;
+
+    let (sum, gas_used) = interactor
+        .tx()
+        .from(&other_address)
+        .to(&adder_address)
+        .gas(6_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .sum()
+        .returns(ReturnsResultUnmanaged)
+        .returns(ReturnsGasUsed)
+        .simulate()
+        .await;
+    assert_eq!(sum, RustBigUint::from(5u32));
+    assert!(gas_used > 0);
+
+    interactor
+        .tx()
+        .from(&other_address)
+        .to(&adder_address)
+        .gas(6_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .add(3u32)
+        .simulate()
+        .await;
+    assert_eq!(
+        query_sum(&mut interactor, &adder_address).await,
+        RustBigUint::from(5u32)
+    );
+
+    // failures go through the result handlers, same as when running
+    let upgrade_result = interactor
+        .tx()
+        .from(&other_address)
+        .to(&adder_address)
+        .gas(6_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .upgrade(10u32)
+        .code(ADDER_CODE_PATH)
+        .returns(ReturnsHandledOrError::new())
+        .try_simulate()
+        .await
+        .unwrap();
+    assert_eq!(upgrade_result.unwrap_err().message, "upgrade not allowed");
+
+    assert_eq!(interactor.try_recall_nonce(&owner_address).await, Ok(2));
+    assert_eq!(interactor.try_recall_nonce(&other_address).await, Ok(0));
+}
+
+#[tokio::test]
+async fn vm_offline_signing_test() {
+    let mut interactor = vm_interactor(NonceMode::LocalIncrement).await;
+    let owner_address = interactor.register_wallet(test_wallets::heidi()).await;
+    let other_address = interactor.register_wallet(test_wallets::ivan()).await;
+
+    // the unsigned transaction gets signed with the wallet only, e.g. on another machine
+    let mut signed_tx = interactor
+        .tx()
+        .from(&owner_address)
+        .gas(6_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .init(5u64)
+        .code(ADDER_CODE_PATH)
+        .export_unsigned()
+        .await
+        .unwrap();
+    assert_eq!(signed_tx.nonce, 0);
+    assert!(signed_tx.signature.is_none());
+    signed_tx.signature = Some(hex::encode(test_wallets::heidi().sign_tx(&signed_tx)));
+
+    let adder_address = interactor
+        .tx()
+        .from(&owner_address)
+        .gas(6_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .init(5u64)
+        .code(ADDER_CODE_PATH)
+        .returns(ReturnsNewAddress)
+        .broadcast_signed(signed_tx)
+        .await
+        .unwrap();
+
+    // sent by someone else, only tracked by the interactor
+    let mut signed_tx = interactor
+        .tx()
+        .from(&other_address)
+        .to(&adder_address)
+        .gas(6_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .add(3u32)
+        .export_unsigned()
+        .await
+        .unwrap();
+    signed_tx.signature = Some(hex::encode(test_wallets::ivan().sign_tx(&signed_tx)));
+    let tx_hash = interactor
+        .proxy
+        .request(SendTxRequest(&signed_tx))
+        .await
+        .unwrap();
+    let gas_used = interactor
+        .tx()
+        .from(&other_address)
+        .to(&adder_address)
+        .gas(6_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .add(3u32)
+        .returns(ReturnsGasUsed)
+        .track(tx_hash)
+        .await
+        .unwrap();
+    assert!(gas_used > 0);
+    assert_eq!(
+        query_sum(&mut interactor, &adder_address).await,
+        RustBigUint::from(8u32)
+    );
+}
+
+#[tokio::test]
+async fn vm_gateway_error_test() {
+    let mut interactor = vm_interactor(NonceMode::LocalIncrement).await;
+    let owner_address = interactor.register_wallet(test_wallets::heidi()).await;
+
+    // rejected by the gateway, so the transaction never gets processed
+    let result = interactor
+        .tx()
+        .from(&owner_address)
+        .gas(1)
+        .typed(adder_proxy::AdderProxy)
+        .init(5u64)
+        .code(ADDER_CODE_PATH)
+        .returns(ReturnsNewAddress)
+        .try_run()
+        .await;
+    let err = result.unwrap_err();
+    assert!(!err.is_retryable());
+    assert_eq!(
+        err.gateway_message(),
+        Some("transaction generation failed: insufficient gas limit in tx")
+    );
+
+    // the nonce was not used up
+    let adder_address = interactor
+        .tx()
+        .from(&owner_address)
+        .gas(6_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .init(5u64)
+        .code(ADDER_CODE_PATH)
+        .returns(ReturnsNewAddress)
+        .try_run()
+        .await
+        .unwrap();
+    assert_eq!(interactor.try_recall_nonce(&owner_address).await, Ok(1));
+    assert_eq!(
+        query_sum(&mut interactor, &adder_address).await,
+        RustBigUint::from(5u32)
+    );
+}
+
+#[tokio::test]
+async fn vm_block_stream_test() {
+    let mut interactor = vm_interactor(NonceMode::LocalIncrement).await;
+    let owner_address = interactor.register_wallet(test_wallets::heidi()).await;
+    let adder_address = deploy_adder(&mut interactor, &owner_address).await;
+    add(&mut interactor, &owner_address, &adder_address, 1).await;
+    add(&mut interactor, &owner_address, &adder_address, 2).await;
+
+    let latest_nonce = interactor
+        .proxy
+        .request(NetworkStatusRequest::default())
+        .await
+        .unwrap()
+        .nonce;
+    let mut stream = BlockStream::new(&interactor.proxy, 0)
+        .with_filter(EventFilter::new().address(&adder_address));
+    let mut tx_data = Vec::new();
+    while stream.next_nonce() <= latest_nonce {
+        for tx in stream.next_transactions().await.unwrap() {
+            tx_data.push(tx.data.unwrap_or_default());
+        }
+    }
+
+    assert_eq!(
+        tx_data,
+        vec![base64_encode("add@01"), base64_encode("add@02")]
+    );
+}
+
+#[tokio::test]
+async fn vm_run_scenario_file_test() {
+    let mut interactor = vm_interactor(NonceMode::Recall).await;
+    let owner_address = interactor.register_wallet(test_wallets::heidi()).await;
+    interactor.bind_scenario_address("address:owner", &owner_address);
+
+    interactor
+        .run_scenario_file("scenarios/adder_network.scen.json")
+        .await;
+
+    // the contract was bound to the address it got on deploy
+    let scenario_adder_address = TestSCAddress::new("adder").to_address();
+    let adder_address = interactor
+        .scenario_addresses
+        .to_network(&scenario_adder_address);
+    assert_ne!(adder_address, scenario_adder_address);
+    let sum = interactor
+        .query()
+        .to(&adder_address)
+        .typed(adder_proxy::AdderProxy)
+        .sum()
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .await;
+    assert_eq!(sum, RustBigUint::from(8u32));
+}
@@ -0,0 +1,137 @@
+use dharitri_sc_scenario::*;
+
+const BLESS_PATH: &str = "bless-adder.scen.json";
+
+const STALE_SCENARIO: &str = r#"{
+    "name": "adder bless",
+    "gasSchedule": "v3",
+    "steps": [
+        {
+            "step": "setState",
+            "accounts": {
+                "address:owner": {
+                    "nonce": "1",
+                    "balance": "0"
+                }
+            },
+            "newAddresses": [
+                {
+                    "creatorAddress": "address:owner",
+                    "creatorNonce": "1",
+                    "newAddress": "sc:adder"
+                }
+            ]
+        },
+        {
+            "step": "scDeploy",
+            "id": "deploy",
+            "tx": {
+                "from": "address:owner",
+                "contractCode": "drtsc:output/adder.drtsc.json",
+                "arguments": [
+                    "5"
+                ],
+                "gasLimit": "5,000,000",
+                "gasPrice": "0"
+            },
+            "expect": {
+                "out": [],
+                "status": "0"
+            }
+        },
+        {
+            "step": "scCall",
+            "id": "add",
+            "tx": {
+                "from": "address:owner",
+                "to": "sc:adder",
+                "function": "add",
+                "arguments": [
+                    "3"
+                ],
+                "gasLimit": "5,000,000",
+                "gasPrice": "0"
+            },
+            "expect": {
+                "out": [],
+                "status": "4",
+                "message": "str:stale error"
+            }
+        },
+        {
+            "step": "scQuery",
+            "id": "getSum",
+            "tx": {
+                "to": "sc:adder",
+                "function": "getSum",
+                "arguments": []
+            },
+            "expect": {
+                "out": [
+                    "0x07"
+                ],
+                "status": "0"
+            }
+        },
+        {
+            "step": "checkState",
+            "accounts": {
+                "address:owner": {
+                    "nonce": "*",
+                    "balance": "0",
+                    "storage": {},
+                    "code": ""
+                },
+                "sc:adder": {
+                    "nonce": "0",
+                    "balance": "1,000",
+                    "storage": {
+                        "str:sum": "7"
+                    },
+                    "code": "drtsc:output/adder.drtsc.json"
+                }
+            }
+        }
+    ]
+}
+"#;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("contracts/examples/adder");
+    blockchain.register_contract("drtsc:output/adder.drtsc.json", adder::ContractBuilder);
+    blockchain
+}
+
+fn bless_path() -> std::path::PathBuf {
+    world().current_dir().join(BLESS_PATH)
+}
+
+#[test]
+fn adder_bless_rs() {
+    std::fs::write(bless_path(), STALE_SCENARIO).unwrap();
+
+    let mut blessing_world = world();
+    blessing_world.bless();
+    blessing_world.run(BLESS_PATH);
+
+    let blessed = std::fs::read_to_string(bless_path()).unwrap();
+    assert!(blessed.contains(
+        r#""status": "0",
+                "message": """#
+    ));
+    assert!(blessed.contains(r#""0x08""#));
+    assert!(blessed.contains(r#""balance": "0""#));
+    assert!(!blessed.contains(r#""balance": "1,000""#));
+    assert!(blessed.contains(r#""str:sum": "8""#));
+    assert!(blessed.contains(r#""gasSchedule": "v3""#));
+    assert!(blessed.contains(r#""nonce": "*""#));
+
+    // passes without blessing, and blessing again changes nothing
+    world().run(BLESS_PATH);
+    let mut blessing_world = world();
+    blessing_world.bless();
+    blessing_world.run(BLESS_PATH);
+    assert_eq!(std::fs::read_to_string(bless_path()).unwrap(), blessed);
+}
@@ -0,0 +1,72 @@
+use dharitri_sc_scenario::imports::*;
+
+use adder::*;
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const ADDER_ADDRESS: TestSCAddress = TestSCAddress::new("adder");
+const CODE_PATH: DrtscPath = DrtscPath::new("output/adder.drtsc.json");
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("contracts/examples/adder");
+    blockchain.register_contract(CODE_PATH, adder::ContractBuilder);
+    blockchain.gas_schedule(GasSchedule::default());
+    blockchain
+}
+
+fn deploy(world: &mut ScenarioWorld) {
+    world.account(OWNER_ADDRESS).nonce(1);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(adder_proxy::AdderProxy)
+        .init(5u32)
+        .code(CODE_PATH)
+        .new_address(ADDER_ADDRESS)
+        .run();
+}
+
+#[test]
+fn adder_gas_used() {
+    let mut world = world();
+    deploy(&mut world);
+
+    let gas_used = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ADDER_ADDRESS)
+        .typed(adder_proxy::AdderProxy)
+        .add(1u32)
+        .gas(5_000_000)
+        .returns(ReturnsGasUsed)
+        .run();
+
+    assert!(gas_used > 0);
+    assert!(gas_used < 5_000_000);
+
+    world
+        .check_account(ADDER_ADDRESS)
+        .check_storage("str:sum", "6");
+}
+
+#[test]
+fn adder_out_of_gas() {
+    let mut world = world();
+    deploy(&mut world);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ADDER_ADDRESS)
+        .typed(adder_proxy::AdderProxy)
+        .add(1u32)
+        .gas(10_000)
+        .with_result(ExpectError(5, "out of gas"))
+        .run();
+
+    world
+        .check_account(ADDER_ADDRESS)
+        .check_storage("str:sum", "5");
+}
@@ -0,0 +1,105 @@
+use dharitri_sc_scenario::imports::*;
+
+use adder::*;
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const ADDER_ADDRESS: TestSCAddress = TestSCAddress::new("adder");
+const CODE_PATH: DrtscPath = DrtscPath::new("output/adder.drtsc.json");
+const NFT_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("NFT-123456");
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("contracts/examples/adder");
+    blockchain.register_contract(CODE_PATH, adder::ContractBuilder);
+    blockchain
+}
+
+fn setup(world: &mut ScenarioWorld) {
+    world
+        .account(OWNER_ADDRESS)
+        .nonce(1)
+        .balance(1_000)
+        .dcdt_nft_all_properties(
+            NFT_TOKEN_ID,
+            1,
+            2,
+            managed_buffer!(b"attributes"),
+            1_000,
+            Some(OWNER_ADDRESS),
+            managed_buffer!(b"hash"),
+            vec![managed_buffer!(b"uri")],
+        )
+        .dcdt_roles(NFT_TOKEN_ID, vec!["DCDTRoleNFTCreate".to_string()]);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(adder_proxy::AdderProxy)
+        .init(5u32)
+        .code(CODE_PATH)
+        .new_address(ADDER_ADDRESS)
+        .run();
+}
+
+fn add(world: &mut ScenarioWorld, value: u32) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ADDER_ADDRESS)
+        .typed(adder_proxy::AdderProxy)
+        .add(value)
+        .run();
+}
+
+#[test]
+fn adder_snapshot_branches() {
+    let mut world = world();
+    setup(&mut world);
+    let snapshot = world.snapshot_state();
+
+    add(&mut world, 1);
+    world
+        .check_account(ADDER_ADDRESS)
+        .check_storage("str:sum", "6");
+
+    world.restore_state(&snapshot);
+    world
+        .check_account(ADDER_ADDRESS)
+        .check_storage("str:sum", "5");
+
+    add(&mut world, 10);
+    world
+        .check_account(ADDER_ADDRESS)
+        .check_storage("str:sum", "15");
+    world.check_account(OWNER_ADDRESS).nonce(3);
+}
+
+#[test]
+fn adder_snapshot_file() {
+    let mut world = world();
+    setup(&mut world);
+    add(&mut world, 1);
+    world.current_block().block_round(7);
+    let snapshot_path = std::env::temp_dir().join("adder_snapshot.json");
+    world.write_state_snapshot(&snapshot_path);
+
+    let mut loaded_world = self::world();
+    loaded_world.load_state_snapshot(&snapshot_path);
+
+    assert_eq!(loaded_world.state(), world.state());
+
+    // the loaded state is fully functional
+    add(&mut loaded_world, 2);
+    loaded_world
+        .check_account(ADDER_ADDRESS)
+        .check_storage("str:sum", "8");
+    loaded_world
+        .check_account(OWNER_ADDRESS)
+        .dcdt_nft_balance_and_attributes(
+            NFT_TOKEN_ID,
+            1,
+            2,
+            ManagedBuffer::<StaticApi>::from(b"attributes"),
+        );
+}
@@ -0,0 +1,89 @@
+use dharitri_sc_scenario::imports::*;
+
+use adder::*;
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const ADDER_ADDRESS: TestSCAddress = TestSCAddress::new("adder");
+const CODE_PATH: DrtscPath = DrtscPath::new("output/adder.drtsc.json");
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("contracts/examples/adder");
+    blockchain.register_contract(CODE_PATH, adder::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn adder_execution_trace() {
+    let mut world = world();
+    world.start_execution_trace();
+
+    world.account(OWNER_ADDRESS).nonce(1);
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(adder_proxy::AdderProxy)
+        .init(5u32)
+        .code(CODE_PATH)
+        .new_address(ADDER_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ADDER_ADDRESS)
+        .typed(adder_proxy::AdderProxy)
+        .add(1u32)
+        .run();
+
+    let trace = world.execution_trace();
+    assert_eq!(trace.calls.len(), 2);
+
+    let add_call = &trace.calls[1];
+    assert_eq!(add_call.from, "address:owner");
+    assert_eq!(add_call.to, "sc:adder");
+    assert_eq!(add_call.function, "add");
+    assert_eq!(add_call.arguments, vec!["0x01".to_string()]);
+    assert!(add_call.is_success());
+
+    let call_tree = trace.call_tree();
+    assert!(call_tree.contains("DirectCall address:owner -> sc:adder add(0x01)"));
+    assert!(call_tree.contains("storage write 0x73756d = 0x06"));
+    assert!(call_tree.contains("vm hooks: "));
+
+    let json = trace.to_json();
+    assert!(json.contains("\"function\": \"add\""));
+    assert!(json.contains("\"type\": \"storageWrite\""));
+}
+
+#[test]
+fn adder_execution_trace_error() {
+    let mut world = world();
+    world.start_execution_trace();
+
+    world.account(OWNER_ADDRESS).nonce(1);
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(adder_proxy::AdderProxy)
+        .init(5u32)
+        .code(CODE_PATH)
+        .new_address(ADDER_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ADDER_ADDRESS)
+        .raw_call("add")
+        .with_result(ExpectError(4, "wrong number of arguments"))
+        .run();
+
+    let trace = world.execution_trace();
+    let failed_call = trace.calls[1].failed_call().unwrap();
+    assert_eq!(failed_call.function, "add");
+    assert!(trace
+        .call_tree()
+        .contains("error 4: wrong number of arguments"));
+}
@@ -0,0 +1,212 @@
+use crowdfunding_dcdt::crowdfunding_dcdt_proxy;
+
+use dharitri_sc_scenario::{dharitri_chain_vm::world_mock::BlockchainState, imports::*};
+
+const CF_DEADLINE: u64 = 7 * 24 * 60 * 60; // 1 week in seconds
+const CF_TARGET: u64 = 1_000;
+const CF_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("CROWD-123456");
+const OTHER_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("OTHER-123456");
+const FIRST_USER_ADDRESS: TestAddress = TestAddress::new("first-user");
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const SECOND_USER_ADDRESS: TestAddress = TestAddress::new("second-user");
+const CODE_PATH: DrtscPath = DrtscPath::new("output/crowdfunding-dcdt.drtsc.json");
+const CROWDFUNDING_ADDRESS: TestSCAddress = TestSCAddress::new("crowdfunding-dcdt");
+
+const CALLERS: [TestAddress; 3] = [OWNER_ADDRESS, FIRST_USER_ADDRESS, SECOND_USER_ADDRESS];
+const TOTAL_SUPPLY: u64 = 2_000;
+
+#[derive(Clone, Debug)]
+enum CrowdfundingAction {
+    Fund {
+        caller: TestAddress<'static>,
+        payment: RewaOrDcdtTokenPayment<StaticApi>,
+    },
+    Claim {
+        caller: TestAddress<'static>,
+    },
+    PassDeadline,
+}
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("contracts/examples/crowdfunding-dcdt");
+    blockchain.register_contract(CODE_PATH, crowdfunding_dcdt::ContractBuilder);
+    blockchain
+}
+
+fn setup(world: &mut ScenarioWorld) {
+    world.account(OWNER_ADDRESS).nonce(1);
+    world
+        .account(FIRST_USER_ADDRESS)
+        .nonce(1)
+        .balance(1000)
+        .dcdt_balance(CF_TOKEN_ID, 1000)
+        .dcdt_balance(OTHER_TOKEN_ID, 1000);
+    world
+        .account(SECOND_USER_ADDRESS)
+        .nonce(1)
+        .dcdt_balance(CF_TOKEN_ID, 1000);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(crowdfunding_dcdt_proxy::CrowdfundingProxy)
+        .init(
+            CF_TARGET,
+            CF_DEADLINE,
+            RewaOrDcdtTokenIdentifier::dcdt(CF_TOKEN_ID),
+        )
+        .code(CODE_PATH)
+        .new_address(CROWDFUNDING_ADDRESS)
+        .run();
+}
+
+fn generate_action(rng: &mut FuzzRng) -> CrowdfundingAction {
+    let caller = *rng.choose(&CALLERS);
+    match rng.gen_range(0..10) {
+        0 => CrowdfundingAction::PassDeadline,
+        1..=2 => CrowdfundingAction::Claim { caller },
+        _ => {
+            let tokens = [
+                RewaOrDcdtTokenIdentifier::dcdt(CF_TOKEN_ID),
+                RewaOrDcdtTokenIdentifier::dcdt(OTHER_TOKEN_ID),
+                RewaOrDcdtTokenIdentifier::rewa(),
+            ];
+            CrowdfundingAction::Fund {
+                caller,
+                payment: rng.rewa_or_dcdt_payment(&tokens, 600),
+            }
+        },
+    }
+}
+
+fn execute_action(world: &mut ScenarioWorld, action: &CrowdfundingAction) {
+    match action {
+        CrowdfundingAction::Fund { caller, payment } => {
+            // payments above the caller balance are rejected before reaching the contract
+            let _ = world
+                .tx()
+                .from(*caller)
+                .to(CROWDFUNDING_ADDRESS)
+                .typed(crowdfunding_dcdt_proxy::CrowdfundingProxy)
+                .fund()
+                .payment(payment)
+                .returns(ReturnsHandledOrError::new())
+                .run();
+        },
+        CrowdfundingAction::Claim { caller } => {
+            let _ = world
+                .tx()
+                .from(*caller)
+                .to(CROWDFUNDING_ADDRESS)
+                .typed(crowdfunding_dcdt_proxy::CrowdfundingProxy)
+                .claim()
+                .returns(ReturnsHandledOrError::new())
+                .run();
+        },
+        CrowdfundingAction::PassDeadline => {
+            world.current_block().block_timestamp(CF_DEADLINE);
+        },
+    }
+}
+
+fn simplify_action(action: &CrowdfundingAction) -> Vec<CrowdfundingAction> {
+    match action {
+        CrowdfundingAction::Fund { caller, payment } if payment.amount > 1u32 => {
+            let mut smaller_payment = payment.clone();
+            smaller_payment.amount /= 2u32;
+            vec![CrowdfundingAction::Fund {
+                caller: *caller,
+                payment: smaller_payment,
+            }]
+        },
+        _ => Vec::new(),
+    }
+}
+
+fn cf_token_balance(state: &BlockchainState, address: &Address) -> num_bigint::BigUint {
+    state
+        .accounts
+        .get(address)
+        .map(|account| account.dcdt.get_dcdt_balance(CF_TOKEN_ID.as_bytes(), 0))
+        .unwrap_or_default()
+}
+
+fn query_deposit(world: &mut ScenarioWorld, donor: TestAddress) -> RustBigUint {
+    world
+        .query()
+        .to(CROWDFUNDING_ADDRESS)
+        .typed(crowdfunding_dcdt_proxy::CrowdfundingProxy)
+        .deposit(donor)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+}
+
+fn query_status(world: &mut ScenarioWorld) -> crowdfunding_dcdt_proxy::Status {
+    world
+        .query()
+        .to(CROWDFUNDING_ADDRESS)
+        .typed(crowdfunding_dcdt_proxy::CrowdfundingProxy)
+        .status()
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn fuzzer() -> ScenarioFuzzer<CrowdfundingAction> {
+    ScenarioFuzzer::new(world, generate_action, execute_action)
+        .setup(setup)
+        .simplifier(simplify_action)
+        .invariant("token supply is conserved", |world| {
+            let state = world.state();
+            let total: num_bigint::BigUint = CALLERS
+                .iter()
+                .map(|address| address.to_address())
+                .chain([CROWDFUNDING_ADDRESS.to_address()])
+                .map(|address| cf_token_balance(state, &address))
+                .sum();
+            total == TOTAL_SUPPLY.into()
+        })
+        .invariant("deposits match the balance while funding", |world| {
+            if query_status(world) != crowdfunding_dcdt_proxy::Status::FundingPeriod {
+                return true;
+            }
+            let deposits = query_deposit(world, FIRST_USER_ADDRESS)
+                + query_deposit(world, SECOND_USER_ADDRESS)
+                + query_deposit(world, OWNER_ADDRESS);
+            let state = world.state();
+            deposits == cf_token_balance(state, &CROWDFUNDING_ADDRESS.to_address())
+        })
+}
+
+#[test]
+fn crowdfunding_fuzz_test() {
+    fuzzer().seed(1).num_runs(30).run();
+}
+
+#[test]
+fn crowdfunding_fuzz_shrink_test() {
+    let failure = fuzzer()
+        .invariant("owner never claims", |world| {
+            let state = world.state();
+            cf_token_balance(state, &OWNER_ADDRESS.to_address()) == 0u32.into()
+        })
+        .seed(1)
+        .num_runs(30)
+        .trace_path("trace-fuzz.scen.json")
+        .find_failure()
+        .expect("the owner should eventually claim the funds");
+
+    assert!(failure.reason.contains("owner never claims"));
+    assert!(matches!(
+        failure.actions.last(),
+        Some(CrowdfundingAction::Claim {
+            caller: OWNER_ADDRESS
+        })
+    ));
+    // reaching the target takes at least 2 payments, the deadline and the claim
+    assert!(failure.actions.len() <= 4, "not shrunk:\n{failure}");
+
+    // the exported scenario replays the minimal sequence
+    world().run("trace-fuzz.scen.json");
+}
@@ -20,4 +20,24 @@ pub trait StorageRawApiFeatures {
     ) -> ManagedBuffer {
         self.storage_raw().read_from_address(&address, storage_key)
     }
+
+    #[endpoint]
+    fn storage_set_lock(&self, storage_key: ManagedBuffer, lock_timestamp: u64) {
+        self.storage_raw().set_lock(storage_key, lock_timestamp);
+    }
+
+    #[endpoint]
+    fn storage_get_lock(&self, storage_key: ManagedBuffer) -> u64 {
+        self.storage_raw().get_lock(storage_key)
+    }
+
+    #[endpoint]
+    fn storage_is_locked(&self, storage_key: ManagedBuffer) -> bool {
+        self.storage_raw().is_locked(storage_key)
+    }
+
+    #[endpoint]
+    fn storage_clear_lock(&self, storage_key: ManagedBuffer) {
+        self.storage_raw().clear_lock(storage_key);
+    }
 }
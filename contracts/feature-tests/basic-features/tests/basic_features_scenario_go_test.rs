@@ -479,6 +479,11 @@ fn storage_raw_api_features_go() {
     world().run("scenarios/storage_raw_api_features.scen.json");
 }
 
+#[test]
+fn storage_raw_api_lock_go() {
+    world().run("scenarios/storage_raw_api_lock.scen.json");
+}
+
 #[test]
 fn storage_reserved_go() {
     world().run("scenarios/storage_reserved.scen.json");
@@ -76,7 +76,6 @@ fn count_ones_rs() {
 }
 
 #[test]
-#[ignore]
 fn crypto_elliptic_curves_rs() {
     world().run("scenarios/crypto_elliptic_curves.scen.json");
 }
@@ -87,7 +86,6 @@ fn crypto_keccak_256_rs() {
 }
 
 #[test]
-#[ignore]
 fn crypto_ripemd_160_rs() {
     world().run("scenarios/crypto_ripemd160.scen.json");
 }
@@ -98,19 +96,16 @@ fn crypto_sha_256_rs() {
 }
 
 #[test]
-#[ignore]
 fn crypto_verify_bls_rs() {
     world().run("scenarios/crypto_verify_bls.scen.json");
 }
 
 #[test]
-#[ignore]
 fn crypto_verify_bls_aggregated_signature_rs() {
     world().run("scenarios/crypto_verify_bls_aggregated_signature.scen.json");
 }
 
 #[test]
-#[ignore]
 fn crypto_verify_bls_share_rs() {
     world().run("scenarios/crypto_verify_bls_share.scen.json");
 }
@@ -121,13 +116,11 @@ fn crypto_verify_ed_25519_rs() {
 }
 
 #[test]
-#[ignore]
 fn crypto_verify_secp_256_k_1_rs() {
     world().run("scenarios/crypto_verify_secp256k1.scen.json");
 }
 
 #[test]
-#[ignore]
 fn crypto_verify_secp_256_r_1_rs() {
     world().run("scenarios/crypto_verify_secp256r1.scen.json");
 }
@@ -502,6 +495,11 @@ fn storage_raw_api_features_rs() {
     world().run("scenarios/storage_raw_api_features.scen.json");
 }
 
+#[test]
+fn storage_raw_api_lock_rs() {
+    world().run("scenarios/storage_raw_api_lock.scen.json");
+}
+
 #[test]
 fn storage_reserved_rs() {
     world().run("scenarios/storage_reserved.scen.json");
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                          414
+// Endpoints:                          418
 // Async Callback:                       1
-// Total number of exported functions: 416
+// Total number of exported functions: 420
 
 #![no_std]
 
@@ -225,6 +225,10 @@ dharitri_sc_wasm_adapter::endpoints! {
         storage_read_raw => storage_read_raw
         storage_write_raw => storage_write_raw
         storage_read_from_address => storage_read_from_address
+        storage_set_lock => storage_set_lock
+        storage_get_lock => storage_get_lock
+        storage_is_locked => storage_is_locked
+        storage_clear_lock => storage_clear_lock
         load_bytes => load_bytes
         load_big_uint => load_big_uint
         load_big_int => load_big_int
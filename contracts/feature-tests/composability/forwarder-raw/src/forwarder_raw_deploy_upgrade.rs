@@ -72,4 +72,17 @@ pub trait ForwarderRawDeployUpgrade {
             .gas(self.blockchain().get_gas_left())
             .upgrade_async_call_and_exit();
     }
+
+    #[endpoint]
+    fn call_delete(
+        &self,
+        child_sc_address: ManagedAddress,
+        args: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        self.send_raw().delete_contract(
+            &child_sc_address,
+            self.blockchain().get_gas_left(),
+            &args.to_arg_buffer(),
+        );
+    }
 }
@@ -545,6 +545,11 @@ fn recursive_caller_dcdt_1_go() {
     world().run("scenarios/recursive_caller_dcdt_1.scen.json");
 }
 
+#[test]
+fn recursive_caller_rewa_x_go() {
+    world().run("scenarios/recursive_caller_rewa_x.scen.json");
+}
+
 #[test]
 fn send_rewa_go() {
     world().run("scenarios/send_rewa.scen.json");
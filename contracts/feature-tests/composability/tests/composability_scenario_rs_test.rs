@@ -102,6 +102,11 @@ fn forw_raw_call_async_retrieve_multi_transfer_rs() {
     world().run("scenarios/forw_raw_call_async_retrieve_multi_transfer.scen.json");
 }
 
+#[test]
+fn forw_raw_contract_delete_rs() {
+    world().run("scenarios/forw_raw_contract_delete.scen.json");
+}
+
 #[test]
 fn forw_raw_contract_deploy_rs() {
     world().run("scenarios/forw_raw_contract_deploy.scen.json");
@@ -117,6 +122,11 @@ fn forw_raw_contract_upgrade_self_rs() {
     world().run("scenarios/forw_raw_contract_upgrade_self.scen.json");
 }
 
+#[test]
+fn forw_raw_contract_upgrade_not_allowed_rs() {
+    world().run("scenarios/forw_raw_contract_upgrade_not_allowed.scen.json");
+}
+
 #[test]
 fn forw_raw_direct_rewa_rs() {
     world().run("scenarios/forw_raw_direct_rewa.scen.json");
@@ -597,6 +607,11 @@ fn recursive_caller_dcdt_1_rs() {
     world().run("scenarios/recursive_caller_dcdt_1.scen.json");
 }
 
+#[test]
+fn recursive_caller_rewa_x_rs() {
+    world().run("scenarios/recursive_caller_rewa_x.scen.json");
+}
+
 #[test]
 fn send_rewa_rs() {
     world().run("scenarios/send_rewa.scen.json");
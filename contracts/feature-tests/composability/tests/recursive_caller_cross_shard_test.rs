@@ -0,0 +1,131 @@
+use dharitri_sc_scenario::imports::*;
+
+const USER: &str = "address:a_user#00";
+const RECURSIVE_CALLER: &str = "sc:recursive-caller#00";
+const VAULT: &str = "sc:vault#01";
+const OTHER_RECURSIVE_CALLER: &str = "sc:other-recursive-caller#01";
+
+const RECURSIVE_CALLER_PATH_EXPR: &str =
+    "drtsc:recursive-caller/output/recursive-caller.drtsc.json";
+const VAULT_PATH_EXPR: &str = "drtsc:vault/output/vault.drtsc.json";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+    blockchain.register_contract(
+        RECURSIVE_CALLER_PATH_EXPR,
+        recursive_caller::ContractBuilder,
+    );
+    blockchain.register_contract(VAULT_PATH_EXPR, vault::ContractBuilder);
+    blockchain.num_shards(2);
+
+    let recursive_caller_code = blockchain.code_expression(RECURSIVE_CALLER_PATH_EXPR);
+    let vault_code = blockchain.code_expression(VAULT_PATH_EXPR);
+    blockchain.set_state_step(
+        SetStateStep::new()
+            .put_account(USER, Account::new())
+            .put_account(
+                RECURSIVE_CALLER,
+                Account::new()
+                    .balance("1000")
+                    .code(recursive_caller_code.clone()),
+            )
+            .put_account(VAULT, Account::new().code(vault_code))
+            .put_account(
+                OTHER_RECURSIVE_CALLER,
+                Account::new().code(recursive_caller_code),
+            ),
+    );
+
+    blockchain
+}
+
+fn recursive_send_funds(world: &mut ScenarioWorld, to: &str, counter: &str) {
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER)
+            .to(RECURSIVE_CALLER)
+            .function("recursive_send_funds")
+            .argument(to)
+            .argument("str:REWA")
+            .argument("1")
+            .argument(counter)
+            .expect(TxExpect::ok().no_result()),
+    );
+}
+
+fn check_balances(world: &mut ScenarioWorld, recursive_caller_balance: &str, vault_balance: &str) {
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(
+                RECURSIVE_CALLER,
+                CheckAccount::new().balance(recursive_caller_balance),
+            )
+            .put_account(VAULT, CheckAccount::new().balance(vault_balance)),
+    );
+}
+
+#[test]
+fn recursive_caller_cross_shard_test() {
+    let mut world = world();
+
+    recursive_send_funds(&mut world, VAULT, "2");
+
+    // the funds left the caller, but did not reach the vault yet
+    check_balances(&mut world, "999", "0");
+
+    // first async call delivered to the vault
+    world.advance_rounds(1);
+    check_balances(&mut world, "999", "1");
+
+    // callback delivered, it sends the second async call
+    world.advance_rounds(1);
+    check_balances(&mut world, "998", "1");
+
+    // second async call delivered to the vault
+    world.advance_rounds(1);
+    check_balances(&mut world, "998", "2");
+
+    // last callback, nothing else gets sent
+    world.advance_rounds(2);
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(RECURSIVE_CALLER, CheckAccount::new().balance("998"))
+            .put_account(
+                VAULT,
+                CheckAccount::new()
+                    .balance("2")
+                    .check_storage("str:call_counts|nested:str:accept_funds", "2"),
+            ),
+    );
+}
+
+#[test]
+fn recursive_caller_cross_shard_refund_test() {
+    let mut world = world();
+
+    // the destination has no `accept_funds` endpoint, so the async call fails
+    recursive_send_funds(&mut world, OTHER_RECURSIVE_CALLER, "1");
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(RECURSIVE_CALLER, CheckAccount::new().balance("999"))
+            .put_account(OTHER_RECURSIVE_CALLER, CheckAccount::new().balance("0")),
+    );
+
+    // the call fails in the destination shard, the refund travels back with the callback
+    world.advance_rounds(1);
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(RECURSIVE_CALLER, CheckAccount::new().balance("999"))
+            .put_account(OTHER_RECURSIVE_CALLER, CheckAccount::new().balance("0")),
+    );
+
+    // the callback brings the funds back
+    world.advance_rounds(1);
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(RECURSIVE_CALLER, CheckAccount::new().balance("1000"))
+            .put_account(OTHER_RECURSIVE_CALLER, CheckAccount::new().balance("0")),
+    );
+}
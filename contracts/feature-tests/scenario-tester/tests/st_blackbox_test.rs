@@ -1,3 +1,4 @@
+use dharitri_sc::derive_imports::*;
 use dharitri_sc_scenario::{imports::*, scenario_model::TxResponseStatus};
 
 use scenario_tester::*;
@@ -12,6 +13,14 @@ const ST_ADDRESS: TestSCAddress = TestSCAddress::new("scenario-tester");
 const CODE_PATH: DrtscPath = DrtscPath::new("output/scenario-tester.drtsc.json");
 const TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("TOKEN-123456");
 const NFT_ID: TestTokenIdentifier = TestTokenIdentifier::new("NFT-123456");
+const NFT_CREATOR_ADDRESS: TestAddress = TestAddress::new("nft-creator");
+const NFT_HASH: &[u8] = b"NftHash";
+
+#[derive(TopEncode)]
+struct NftAttributes {
+    level: u32,
+    power: u64,
+}
 
 fn world() -> ScenarioWorld {
     let mut blockchain = ScenarioWorld::new();
@@ -246,6 +255,101 @@ fn set_state_test() {
         .dcdt_balance(TOKEN_ID, 60);
 }
 
+fn set_nft_all_properties(world: &mut ScenarioWorld, address: TestAddress) {
+    let attributes = NftAttributes {
+        level: 3,
+        power: 500,
+    };
+    world
+        .account(address)
+        .nonce(1)
+        .dcdt_balance(TOKEN_ID, 20)
+        .dcdt_nft_all_properties(
+            NFT_ID,
+            2,
+            1,
+            ManagedBuffer::<StaticApi>::new_from_bytes(&top_encode_to_vec_u8_or_panic(&attributes)),
+            1000,
+            Some(NFT_CREATOR_ADDRESS),
+            managed_buffer!(NFT_HASH),
+            FOURTH_URIS.iter().map(|uri| managed_buffer!(uri)).collect(),
+        )
+        .dcdt_nft_last_nonce(NFT_ID, 2)
+        .commit();
+    world.set_dcdt_local_roles(
+        address,
+        NFT_ID.as_bytes(),
+        &[DcdtLocalRole::NftCreate, DcdtLocalRole::NftBurn],
+    );
+}
+
+#[test]
+fn check_dcdt_instances_test() {
+    let mut world = world();
+    let holder = TestAddress::new("holder");
+    set_nft_all_properties(&mut world, holder);
+
+    world
+        .check_account(holder)
+        .dcdt_nft_balance(NFT_ID, 2, 1)
+        .dcdt_nft_attributes(
+            NFT_ID,
+            2,
+            NftAttributes {
+                level: 3,
+                power: 500,
+            },
+        )
+        .dcdt_nft_royalties(NFT_ID, 2, 1000)
+        .dcdt_nft_creator(NFT_ID, 2, NFT_CREATOR_ADDRESS)
+        .dcdt_nft_hash(NFT_ID, 2, managed_buffer!(NFT_HASH))
+        .dcdt_nft_uris(
+            NFT_ID,
+            2,
+            FOURTH_URIS.iter().map(|uri| managed_buffer!(uri)).collect(),
+        )
+        .dcdt_last_nonce(NFT_ID, 2)
+        .dcdt_frozen(NFT_ID, false)
+        .dcdt_roles(NFT_ID, &[DcdtLocalRole::NftBurn, DcdtLocalRole::NftCreate])
+        .commit();
+
+    // mixing fungible and instance checks on the same token
+    world
+        .check_account(holder)
+        .dcdt_nft_balance(TOKEN_ID, 0, 20)
+        .dcdt_balance(TOKEN_ID, 20)
+        .commit();
+}
+
+#[test]
+#[should_panic(
+    expected = "bad dcdt instance. Address: address:holder. Token NFT-123456. Nonce 2.\n  royalties:\n    want: \"500\"\n    have: 1000\n  uris:\n    want: [\"0x4669727374557269\"]\n    have: [0x4669727374557269,0x5365636f6e64557269]"
+)]
+fn check_dcdt_instances_mismatch_test() {
+    let mut world = world();
+    let holder = TestAddress::new("holder");
+    set_nft_all_properties(&mut world, holder);
+
+    world
+        .check_account(holder)
+        .dcdt_nft_royalties(NFT_ID, 2, 500)
+        .dcdt_nft_uris(NFT_ID, 2, vec![managed_buffer!(b"FirstUri")])
+        .commit();
+}
+
+#[test]
+#[should_panic(expected = "bad roles")]
+fn check_dcdt_roles_mismatch_test() {
+    let mut world = world();
+    let holder = TestAddress::new("holder");
+    set_nft_all_properties(&mut world, holder);
+
+    world
+        .check_account(holder)
+        .dcdt_roles(NFT_ID, &[DcdtLocalRole::NftCreate])
+        .commit();
+}
+
 #[test]
 fn st_blackbox_tx_hash() {
     let mut world = world();
@@ -0,0 +1,32 @@
+use std::fmt;
+
+/// Explains why a value could not be encoded or decoded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AbiCodecError(String);
+
+impl AbiCodecError {
+    pub fn new(message: impl Into<String>) -> Self {
+        AbiCodecError(message.into())
+    }
+
+    pub fn message(&self) -> &str {
+        &self.0
+    }
+
+    /// Adds the location of the error, e.g. the argument or field name.
+    pub(crate) fn in_context(self, context: &str) -> Self {
+        AbiCodecError(format!("{context}: {}", self.0))
+    }
+}
+
+impl fmt::Display for AbiCodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for AbiCodecError {}
+
+pub(crate) fn codec_err<T>(message: impl Into<String>) -> Result<T, AbiCodecError> {
+    Err(AbiCodecError::new(message))
+}
@@ -0,0 +1,144 @@
+use bech32::{Bech32, Hrp};
+use num_bigint::{BigInt, BigUint, Sign};
+use serde_json::{Map, Value};
+
+use crate::{abi_codec_error::codec_err, AbiCodecError};
+
+const ADDRESS_HRP: &str = "drt";
+const ADDRESS_LEN: usize = 32;
+
+pub(crate) fn json_to_biguint(value: &Value) -> Result<BigUint, AbiCodecError> {
+    let parsed = match value {
+        Value::Number(number) => number.as_u64().map(BigUint::from),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    };
+    match parsed {
+        Some(parsed) => Ok(parsed),
+        None => codec_err(format!("expected an unsigned number, got {value}")),
+    }
+}
+
+pub(crate) fn json_to_bigint(value: &Value) -> Result<BigInt, AbiCodecError> {
+    let parsed = match value {
+        Value::Number(number) => number.as_i64().map(BigInt::from),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    };
+    match parsed {
+        Some(parsed) => Ok(parsed),
+        None => codec_err(format!("expected a number, got {value}")),
+    }
+}
+
+pub(crate) fn json_to_bool(value: &Value) -> Result<bool, AbiCodecError> {
+    match value {
+        Value::Bool(b) => Ok(*b),
+        _ => codec_err(format!("expected a bool, got {value}")),
+    }
+}
+
+pub(crate) fn json_to_str(value: &Value) -> Result<&str, AbiCodecError> {
+    match value {
+        Value::String(s) => Ok(s),
+        _ => codec_err(format!("expected a string, got {value}")),
+    }
+}
+
+pub(crate) fn json_to_array(value: &Value) -> Result<&[Value], AbiCodecError> {
+    match value {
+        Value::Array(items) => Ok(items),
+        _ => codec_err(format!("expected an array, got {value}")),
+    }
+}
+
+pub(crate) fn json_to_object(value: &Value) -> Result<&Map<String, Value>, AbiCodecError> {
+    match value {
+        Value::Object(fields) => Ok(fields),
+        _ => codec_err(format!("expected an object, got {value}")),
+    }
+}
+
+pub(crate) fn json_to_hex_bytes(value: &Value) -> Result<Vec<u8>, AbiCodecError> {
+    let s = json_to_str(value)?;
+    hex::decode(s.strip_prefix("0x").unwrap_or(s))
+        .map_err(|err| AbiCodecError::new(format!("invalid hex {s}: {err}")))
+}
+
+pub(crate) fn json_to_address(value: &Value) -> Result<Vec<u8>, AbiCodecError> {
+    let s = json_to_str(value)?;
+    let (_hrp, bytes) = bech32::decode(s)
+        .map_err(|err| AbiCodecError::new(format!("invalid address {s}: {err}")))?;
+    if bytes.len() != ADDRESS_LEN {
+        return codec_err(format!("invalid address length: {s}"));
+    }
+    Ok(bytes)
+}
+
+pub(crate) fn address_to_json(bytes: &[u8]) -> Result<Value, AbiCodecError> {
+    if bytes.len() != ADDRESS_LEN {
+        return codec_err(format!("invalid address length: {}", bytes.len()));
+    }
+    let hrp = Hrp::parse(ADDRESS_HRP).expect("invalid hrp");
+    let encoded = bech32::encode::<Bech32>(hrp, bytes).expect("bech32 encode error");
+    Ok(Value::String(encoded))
+}
+
+/// Parses a decimal string, e.g. `"-12.50"`, into its raw units and number of decimals.
+///
+/// With a fixed number of decimals, missing trailing zeros get added.
+/// Otherwise, the number of decimals is the number of digits after the point.
+pub(crate) fn json_to_decimal(
+    value: &Value,
+    decimals: Option<usize>,
+) -> Result<(BigInt, usize), AbiCodecError> {
+    let s = match value {
+        Value::Number(number) => number.to_string(),
+        Value::String(s) => s.clone(),
+        _ => return codec_err(format!("expected a decimal number, got {value}")),
+    };
+    let (integer_part, fractional_part) = s.split_once('.').unwrap_or((&s, ""));
+    let num_decimals = decimals.unwrap_or(fractional_part.len());
+    if fractional_part.len() > num_decimals || !fractional_part.bytes().all(|b| b.is_ascii_digit())
+    {
+        return codec_err(format!(
+            "invalid decimal number {s}, expected {num_decimals} decimals"
+        ));
+    }
+
+    let digits = format!("{integer_part}{fractional_part:0<num_decimals$}");
+    match digits.parse() {
+        Ok(raw) => Ok((raw, num_decimals)),
+        Err(_) => codec_err(format!("invalid decimal number: {s}")),
+    }
+}
+
+/// Formats raw units as a decimal string, keeping all the decimals.
+pub(crate) fn decimal_to_json(raw: &BigInt, decimals: usize) -> Value {
+    let digits = format!("{:0>width$}", raw.magnitude(), width = decimals + 1);
+    let (integer_part, fractional_part) = digits.split_at(digits.len() - decimals);
+    let sign = if raw.sign() == Sign::Minus { "-" } else { "" };
+    if decimals == 0 {
+        Value::String(format!("{sign}{integer_part}"))
+    } else {
+        Value::String(format!("{sign}{integer_part}.{fractional_part}"))
+    }
+}
+
+/// Big-endian, without leading zeros, so zero is empty.
+pub(crate) fn biguint_to_bytes(value: &BigUint) -> Vec<u8> {
+    if value.bits() == 0 {
+        Vec::new()
+    } else {
+        value.to_bytes_be()
+    }
+}
+
+/// Big-endian two's complement, as short as possible, so zero is empty.
+pub(crate) fn bigint_to_bytes(value: &BigInt) -> Vec<u8> {
+    if value.sign() == Sign::NoSign {
+        Vec::new()
+    } else {
+        value.to_signed_bytes_be()
+    }
+}
@@ -0,0 +1,96 @@
+use serde_json::Value;
+
+use crate::{
+    abi_codec_error::codec_err, abi_json_value::*, AbiCodecError, AbiType, AbiTypeRegistry,
+};
+
+impl AbiTypeRegistry {
+    /// Encodes a value as arguments or results, appending them to `args`.
+    ///
+    /// Regular values take up exactly one argument, multi-values any number of them.
+    pub fn multi_encode(
+        &self,
+        abi_type: &AbiType,
+        value: &Value,
+        args: &mut Vec<Vec<u8>>,
+    ) -> Result<(), AbiCodecError> {
+        match abi_type {
+            AbiType::Multi(item_types) => {
+                let items = json_to_array(value)?;
+                if items.len() != item_types.len() {
+                    return codec_err(format!(
+                        "expected {} items, got {}",
+                        item_types.len(),
+                        items.len()
+                    ));
+                }
+                for (item_type, item) in item_types.iter().zip(items) {
+                    self.multi_encode(item_type, item, args)?;
+                }
+            },
+            AbiType::Variadic(item_type) => {
+                for item in json_to_array(value)? {
+                    self.multi_encode(item_type, item, args)?;
+                }
+            },
+            AbiType::CountedVariadic(item_type) => {
+                let items = json_to_array(value)?;
+                args.push(self.top_encode(&AbiType::Unsigned(4), &Value::from(items.len()))?);
+                for item in items {
+                    self.multi_encode(item_type, item, args)?;
+                }
+            },
+            AbiType::Optional(item_type) => {
+                if !value.is_null() {
+                    self.multi_encode(item_type, value, args)?;
+                }
+            },
+            _ => args.push(self.top_encode(abi_type, value)?),
+        }
+        Ok(())
+    }
+
+    /// Decodes a value from arguments or results, consuming as many of them as needed.
+    pub fn multi_decode(
+        &self,
+        abi_type: &AbiType,
+        args: &mut &[Vec<u8>],
+    ) -> Result<Value, AbiCodecError> {
+        match abi_type {
+            AbiType::Multi(item_types) => {
+                let items = item_types
+                    .iter()
+                    .map(|item_type| self.multi_decode(item_type, args))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Value::Array(items))
+            },
+            AbiType::Variadic(item_type) => {
+                let mut items = Vec::new();
+                while !args.is_empty() {
+                    items.push(self.multi_decode(item_type, args)?);
+                }
+                Ok(Value::Array(items))
+            },
+            AbiType::CountedVariadic(item_type) => {
+                let count = self.multi_decode(&AbiType::Unsigned(4), args)?;
+                let items = (0..count.as_u64().unwrap_or_default())
+                    .map(|_| self.multi_decode(item_type, args))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Value::Array(items))
+            },
+            AbiType::Optional(item_type) => {
+                if args.is_empty() {
+                    return Ok(Value::Null);
+                }
+                self.multi_decode(item_type, args)
+            },
+            _ => {
+                let Some((arg, rest)) = args.split_first() else {
+                    return codec_err("not enough arguments");
+                };
+                *args = rest;
+                self.top_decode(abi_type, arg)
+            },
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{abi_codec_error::codec_err, AbiCodecError};
+
+/// The dynamic model of an ABI type name, e.g. `variadic<multi<Address,BigUint>>`.
+///
+/// Structs and enums only appear by name, their description is looked up in the [`crate::AbiTypeRegistry`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AbiType {
+    /// `()`, encoded as nothing.
+    Unit,
+    Bool,
+    /// Fixed-size unsigned integer, with its size in bytes.
+    Unsigned(usize),
+    /// Fixed-size signed integer, with its size in bytes.
+    Signed(usize),
+    BigUint,
+    BigInt,
+    /// Raw bytes, such as `bytes` or `CodeMetadata`.
+    Bytes,
+    /// Raw bytes that consume the rest of the input when nested, instead of being length-prefixed.
+    BytesReadToEnd,
+    /// Bytes holding text, such as strings and token identifiers.
+    Utf8String,
+    Address,
+    ManagedDecimal {
+        signed: bool,
+        /// `None` if the number of decimals is only known at runtime, in which case it is encoded along with the value.
+        decimals: Option<usize>,
+    },
+    Option(Box<AbiType>),
+    List(Box<AbiType>),
+    Array(usize, Box<AbiType>),
+    Tuple(Vec<AbiType>),
+    /// A struct or enum declared in the ABI.
+    Custom(String),
+
+    /// Multi-values, each of their items is a separate argument or result.
+    Multi(Vec<AbiType>),
+    Variadic(Box<AbiType>),
+    /// Variadic, preceded by the number of items.
+    CountedVariadic(Box<AbiType>),
+    Optional(Box<AbiType>),
+}
+
+impl AbiType {
+    pub fn parse(type_name: &str) -> Result<Self, AbiCodecError> {
+        let type_name = type_name.trim();
+        let (base, args) = split_type_name(type_name)?;
+        let abi_type = match (base, args.as_slice()) {
+            ("()", []) => AbiType::Unit,
+            ("bool", []) => AbiType::Bool,
+            ("u8", []) => AbiType::Unsigned(1),
+            ("u16", []) => AbiType::Unsigned(2),
+            ("u32" | "usize", []) => AbiType::Unsigned(4),
+            ("u64", []) => AbiType::Unsigned(8),
+            ("i8", []) => AbiType::Signed(1),
+            ("i16", []) => AbiType::Signed(2),
+            ("i32" | "isize", []) => AbiType::Signed(4),
+            ("i64", []) => AbiType::Signed(8),
+            ("BigUint", []) => AbiType::BigUint,
+            ("BigInt", []) => AbiType::BigInt,
+            ("bytes", []) => AbiType::Bytes,
+            ("bytes-read-to-end", []) => AbiType::BytesReadToEnd,
+            ("utf-8 string" | "TokenIdentifier" | "RewaOrDcdtTokenIdentifier", []) => {
+                AbiType::Utf8String
+            },
+            ("Address", []) => AbiType::Address,
+            ("H256", []) => AbiType::Array(32, Box::new(AbiType::Unsigned(1))),
+            ("CodeMetadata", []) => AbiType::Array(2, Box::new(AbiType::Unsigned(1))),
+            ("ManagedDecimal" | "ManagedDecimalSigned", [decimals]) => AbiType::ManagedDecimal {
+                signed: base == "ManagedDecimalSigned",
+                decimals: parse_decimals(decimals)?,
+            },
+            ("Option", [item]) => AbiType::Option(Box::new(AbiType::parse(item)?)),
+            ("List", [item]) => AbiType::List(Box::new(AbiType::parse(item)?)),
+            ("tuple", items) => AbiType::Tuple(parse_all(items)?),
+            ("multi", items) => AbiType::Multi(parse_all(items)?),
+            ("variadic", [item]) => AbiType::Variadic(Box::new(AbiType::parse(item)?)),
+            ("counted-variadic", [item]) => {
+                AbiType::CountedVariadic(Box::new(AbiType::parse(item)?))
+            },
+            ("optional", [item]) => AbiType::Optional(Box::new(AbiType::parse(item)?)),
+            (array, [item]) if array.starts_with("array") => {
+                let Ok(len) = array["array".len()..].parse() else {
+                    return codec_err(format!("invalid array type: {type_name}"));
+                };
+                AbiType::Array(len, Box::new(AbiType::parse(item)?))
+            },
+            (name, []) => AbiType::Custom(name.to_string()),
+            _ => return codec_err(format!("unsupported type: {type_name}")),
+        };
+        Ok(abi_type)
+    }
+
+    /// Multi-values stand for several arguments or results, they cannot be nested in a regular value.
+    pub fn is_multi(&self) -> bool {
+        matches!(
+            self,
+            AbiType::Multi(_)
+                | AbiType::Variadic(_)
+                | AbiType::CountedVariadic(_)
+                | AbiType::Optional(_)
+        )
+    }
+}
+
+fn parse_all(type_names: &[&str]) -> Result<Vec<AbiType>, AbiCodecError> {
+    type_names.iter().map(|item| AbiType::parse(item)).collect()
+}
+
+fn parse_decimals(decimals: &str) -> Result<Option<usize>, AbiCodecError> {
+    if decimals == "usize" {
+        return Ok(None);
+    }
+    match decimals.parse() {
+        Ok(decimals) => Ok(Some(decimals)),
+        Err(_) => codec_err(format!("invalid number of decimals: {decimals}")),
+    }
+}
+
+/// Splits `Base<Arg1,Arg2>` into its base name and its type arguments.
+fn split_type_name(type_name: &str) -> Result<(&str, Vec<&str>), AbiCodecError> {
+    let Some(start) = type_name.find('<') else {
+        return Ok((type_name, Vec::new()));
+    };
+    if !type_name.ends_with('>') {
+        return codec_err(format!("invalid type: {type_name}"));
+    }
+
+    let inner = &type_name[start + 1..type_name.len() - 1];
+    let mut args = Vec::new();
+    let mut depth = 0;
+    let mut arg_start = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(inner[arg_start..i].trim());
+                arg_start = i + 1;
+            },
+            _ => {},
+        }
+    }
+    if depth != 0 {
+        return codec_err(format!("invalid type: {type_name}"));
+    }
+    if !inner.trim().is_empty() {
+        args.push(inner[arg_start..].trim());
+    }
+    Ok((&type_name[..start], args))
+}
@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+
+use dharitri_sc_meta_lib::abi_json::{
+    StructFieldDescriptionJson, TypeDescriptionJson, TYPE_DESCRIPTION_JSON_TYPE_ENUM,
+    TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM, TYPE_DESCRIPTION_JSON_TYPE_STRUCT,
+};
+
+use crate::{abi_codec_error::codec_err, AbiCodecError, AbiType};
+
+/// A struct or enum, as declared in the `types` section of the ABI.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CustomType {
+    Struct(Vec<FieldType>),
+    Enum(Vec<EnumVariantType>),
+    /// Enums encoded as the names of their variants.
+    ExplicitEnum(Vec<String>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldType {
+    pub name: String,
+    pub field_type: AbiType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnumVariantType {
+    pub name: String,
+    pub discriminant: u8,
+    pub fields: Vec<FieldType>,
+}
+
+/// Holds all the custom types of a contract, with their type names already parsed.
+///
+/// Encoding and decoding happen here, since any value might contain custom types.
+#[derive(Clone, Debug, Default)]
+pub struct AbiTypeRegistry {
+    types: BTreeMap<String, CustomType>,
+}
+
+impl AbiTypeRegistry {
+    /// No custom types, only the built-in ones can be encoded.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_type_descriptions(
+        type_descriptions: &BTreeMap<String, TypeDescriptionJson>,
+    ) -> Result<Self, AbiCodecError> {
+        let mut types = BTreeMap::new();
+        for (name, type_description) in type_descriptions {
+            let custom_type =
+                parse_custom_type(type_description).map_err(|err| err.in_context(name))?;
+            types.insert(name.clone(), custom_type);
+        }
+        Ok(AbiTypeRegistry { types })
+    }
+
+    pub fn get(&self, name: &str) -> Result<&CustomType, AbiCodecError> {
+        match self.types.get(name) {
+            Some(custom_type) => Ok(custom_type),
+            None => codec_err(format!("unknown type: {name}")),
+        }
+    }
+}
+
+fn parse_custom_type(type_description: &TypeDescriptionJson) -> Result<CustomType, AbiCodecError> {
+    match type_description.content_type.as_str() {
+        TYPE_DESCRIPTION_JSON_TYPE_STRUCT => {
+            Ok(CustomType::Struct(parse_fields(&type_description.fields)?))
+        },
+        TYPE_DESCRIPTION_JSON_TYPE_ENUM => {
+            let mut variants = Vec::new();
+            for (index, variant) in type_description.variants.iter().enumerate() {
+                let discriminant = variant.discriminant.unwrap_or(index);
+                let Ok(discriminant) = u8::try_from(discriminant) else {
+                    return codec_err(format!("discriminant too large: {discriminant}"));
+                };
+                variants.push(EnumVariantType {
+                    name: variant.name.clone(),
+                    discriminant,
+                    fields: parse_fields(&variant.fields)?,
+                });
+            }
+            Ok(CustomType::Enum(variants))
+        },
+        TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM => Ok(CustomType::ExplicitEnum(
+            type_description
+                .variants
+                .iter()
+                .map(|variant| variant.name.clone())
+                .collect(),
+        )),
+        other => codec_err(format!("unsupported type description: {other}")),
+    }
+}
+
+fn parse_fields(fields: &[StructFieldDescriptionJson]) -> Result<Vec<FieldType>, AbiCodecError> {
+    fields
+        .iter()
+        .map(|field| {
+            Ok(FieldType {
+                name: field.name.clone(),
+                field_type: AbiType::parse(&field.field_type)
+                    .map_err(|err| err.in_context(&field.name))?,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,236 @@
+use num_bigint::{BigInt, BigUint, Sign};
+use serde_json::{Map, Value};
+
+use crate::{
+    abi_codec_error::codec_err,
+    abi_json_value::*,
+    abi_type_registry::{CustomType, FieldType},
+    AbiCodecError, AbiType, AbiTypeRegistry,
+};
+
+impl AbiTypeRegistry {
+    /// Decodes a single value, such as an argument, a result, an event topic or a storage value.
+    pub fn top_decode(&self, abi_type: &AbiType, bytes: &[u8]) -> Result<Value, AbiCodecError> {
+        match abi_type {
+            AbiType::Bool => match bytes {
+                [] => Ok(Value::Bool(false)),
+                [1] => Ok(Value::Bool(true)),
+                _ => codec_err(format!("invalid bool: {}", hex::encode(bytes))),
+            },
+            AbiType::Unsigned(size) => {
+                check_max_len(*size, bytes)?;
+                Ok(unsigned_to_json(bytes))
+            },
+            AbiType::Signed(size) => {
+                check_max_len(*size, bytes)?;
+                Ok(signed_to_json(bytes))
+            },
+            AbiType::BigUint => Ok(Value::String(BigUint::from_bytes_be(bytes).to_string())),
+            AbiType::BigInt => Ok(Value::String(
+                BigInt::from_signed_bytes_be(bytes).to_string(),
+            )),
+            AbiType::Bytes | AbiType::BytesReadToEnd => Ok(Value::String(hex::encode(bytes))),
+            AbiType::Utf8String => utf8_to_json(bytes),
+            AbiType::ManagedDecimal {
+                signed,
+                decimals: Some(decimals),
+            } => Ok(decimal_to_json(&decimal_raw(*signed, bytes), *decimals)),
+            AbiType::Option(_) if bytes.is_empty() => Ok(Value::Null),
+            AbiType::List(item_type) => {
+                let mut input = bytes;
+                let mut items = Vec::new();
+                while !input.is_empty() {
+                    items.push(self.nested_decode(item_type, &mut input)?);
+                }
+                Ok(Value::Array(items))
+            },
+            AbiType::Custom(name) => match self.get(name)? {
+                CustomType::ExplicitEnum(_) => utf8_to_json(bytes),
+                // enums top-encode the variants without fields as their discriminant, so the first one as empty bytes
+                CustomType::Enum(_) if bytes.len() <= 1 => {
+                    self.nested_decode_all(abi_type, if bytes.is_empty() { &[0] } else { bytes })
+                },
+                _ => self.nested_decode_all(abi_type, bytes),
+            },
+            _ => self.nested_decode_all(abi_type, bytes),
+        }
+    }
+
+    /// Decodes the whole input as a nested value.
+    fn nested_decode_all(&self, abi_type: &AbiType, bytes: &[u8]) -> Result<Value, AbiCodecError> {
+        let mut input = bytes;
+        let value = self.nested_decode(abi_type, &mut input)?;
+        if !input.is_empty() {
+            return codec_err("input too long");
+        }
+        Ok(value)
+    }
+
+    /// Decodes a value that is part of a larger one, advancing the input.
+    pub fn nested_decode(
+        &self,
+        abi_type: &AbiType,
+        input: &mut &[u8],
+    ) -> Result<Value, AbiCodecError> {
+        match abi_type {
+            AbiType::Unit => Ok(Value::Null),
+            AbiType::Bool => match take(input, 1)? {
+                [0] => Ok(Value::Bool(false)),
+                [1] => Ok(Value::Bool(true)),
+                other => codec_err(format!("invalid bool: {}", hex::encode(other))),
+            },
+            AbiType::Unsigned(size) => Ok(unsigned_to_json(take(input, *size)?)),
+            AbiType::Signed(size) => Ok(signed_to_json(take(input, *size)?)),
+            AbiType::BigUint => Ok(Value::String(
+                BigUint::from_bytes_be(take_with_len(input)?).to_string(),
+            )),
+            AbiType::BigInt => Ok(Value::String(
+                BigInt::from_signed_bytes_be(take_with_len(input)?).to_string(),
+            )),
+            AbiType::Bytes => Ok(Value::String(hex::encode(take_with_len(input)?))),
+            AbiType::BytesReadToEnd => {
+                let bytes = take(input, input.len())?;
+                Ok(Value::String(hex::encode(bytes)))
+            },
+            AbiType::Utf8String => utf8_to_json(take_with_len(input)?),
+            AbiType::Address => address_to_json(take(input, 32)?),
+            AbiType::ManagedDecimal { signed, decimals } => {
+                let raw = decimal_raw(*signed, take_with_len(input)?);
+                let decimals = match decimals {
+                    Some(decimals) => *decimals,
+                    None => take_u32(input)?,
+                };
+                Ok(decimal_to_json(&raw, decimals))
+            },
+            AbiType::Option(item_type) => match take(input, 1)? {
+                [0] => Ok(Value::Null),
+                [1] => self.nested_decode(item_type, input),
+                other => codec_err(format!("invalid Option: {}", hex::encode(other))),
+            },
+            AbiType::List(item_type) => {
+                let len = take_u32(input)?;
+                self.nested_decode_items(item_type, len, input)
+            },
+            AbiType::Array(len, item_type) => {
+                if **item_type == AbiType::Unsigned(1) {
+                    return Ok(Value::String(hex::encode(take(input, *len)?)));
+                }
+                self.nested_decode_items(item_type, *len, input)
+            },
+            AbiType::Tuple(item_types) => {
+                let items = item_types
+                    .iter()
+                    .map(|item_type| self.nested_decode(item_type, input))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Value::Array(items))
+            },
+            AbiType::Custom(name) => self.nested_decode_custom(name, input),
+            multi => codec_err(format!(
+                "multi-value {multi:?} cannot be nested in another value"
+            )),
+        }
+    }
+
+    fn nested_decode_items(
+        &self,
+        item_type: &AbiType,
+        len: usize,
+        input: &mut &[u8],
+    ) -> Result<Value, AbiCodecError> {
+        let items = (0..len)
+            .map(|_| self.nested_decode(item_type, input))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Value::Array(items))
+    }
+
+    fn nested_decode_custom(&self, name: &str, input: &mut &[u8]) -> Result<Value, AbiCodecError> {
+        match self.get(name)? {
+            CustomType::Struct(fields) => self.nested_decode_fields(fields, input),
+            CustomType::Enum(variants) => {
+                let discriminant = take(input, 1)?[0];
+                let Some(variant) = variants
+                    .iter()
+                    .find(|variant| variant.discriminant == discriminant)
+                else {
+                    return codec_err(format!("invalid {name} discriminant: {discriminant}"));
+                };
+                if variant.fields.is_empty() {
+                    return Ok(Value::String(variant.name.clone()));
+                }
+
+                let mut object = Map::new();
+                object.insert(
+                    variant.name.clone(),
+                    self.nested_decode_fields(&variant.fields, input)?,
+                );
+                Ok(Value::Object(object))
+            },
+            CustomType::ExplicitEnum(_) => utf8_to_json(take_with_len(input)?),
+        }
+    }
+
+    fn nested_decode_fields(
+        &self,
+        fields: &[FieldType],
+        input: &mut &[u8],
+    ) -> Result<Value, AbiCodecError> {
+        let mut object = Map::new();
+        for field in fields {
+            let value = self
+                .nested_decode(&field.field_type, input)
+                .map_err(|err| err.in_context(&field.name))?;
+            object.insert(field.name.clone(), value);
+        }
+        Ok(Value::Object(object))
+    }
+}
+
+fn unsigned_to_json(bytes: &[u8]) -> Value {
+    Value::from(bytes.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64))
+}
+
+fn signed_to_json(bytes: &[u8]) -> Value {
+    let n = BigInt::from_signed_bytes_be(bytes);
+    Value::from(i64::try_from(n).unwrap_or_default())
+}
+
+fn utf8_to_json(bytes: &[u8]) -> Result<Value, AbiCodecError> {
+    match String::from_utf8(bytes.to_vec()) {
+        Ok(s) => Ok(Value::String(s)),
+        Err(_) => codec_err(format!("invalid UTF-8 string: {}", hex::encode(bytes))),
+    }
+}
+
+fn decimal_raw(signed: bool, bytes: &[u8]) -> BigInt {
+    if signed {
+        BigInt::from_signed_bytes_be(bytes)
+    } else {
+        BigInt::from_bytes_be(Sign::Plus, bytes)
+    }
+}
+
+fn check_max_len(size: usize, bytes: &[u8]) -> Result<(), AbiCodecError> {
+    if bytes.len() > size {
+        return codec_err(format!("value too large: {}", hex::encode(bytes)));
+    }
+    Ok(())
+}
+
+fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], AbiCodecError> {
+    if input.len() < len {
+        return codec_err("input too short");
+    }
+    let (taken, rest) = input.split_at(len);
+    *input = rest;
+    Ok(taken)
+}
+
+fn take_u32(input: &mut &[u8]) -> Result<usize, AbiCodecError> {
+    let bytes = take(input, 4)?;
+    Ok(u32::from_be_bytes(bytes.try_into().unwrap()) as usize)
+}
+
+fn take_with_len<'a>(input: &mut &'a [u8]) -> Result<&'a [u8], AbiCodecError> {
+    let len = take_u32(input)?;
+    take(input, len)
+}
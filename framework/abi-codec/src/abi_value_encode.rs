@@ -0,0 +1,264 @@
+use num_bigint::{BigInt, BigUint, Sign};
+use serde_json::Value;
+
+use crate::{
+    abi_codec_error::codec_err,
+    abi_json_value::*,
+    abi_type_registry::{CustomType, EnumVariantType, FieldType},
+    AbiCodecError, AbiType, AbiTypeRegistry,
+};
+
+impl AbiTypeRegistry {
+    /// Encodes a single value, the way it is sent as an argument or saved in storage.
+    pub fn top_encode(&self, abi_type: &AbiType, value: &Value) -> Result<Vec<u8>, AbiCodecError> {
+        let bytes = match abi_type {
+            AbiType::Bool => {
+                if json_to_bool(value)? {
+                    vec![1]
+                } else {
+                    Vec::new()
+                }
+            },
+            AbiType::Unsigned(size) => biguint_to_bytes(&unsigned_from_json(*size, value)?),
+            AbiType::Signed(size) => bigint_to_bytes(&signed_from_json(*size, value)?),
+            AbiType::BigUint => biguint_to_bytes(&json_to_biguint(value)?),
+            AbiType::BigInt => bigint_to_bytes(&json_to_bigint(value)?),
+            AbiType::Bytes | AbiType::BytesReadToEnd => json_to_hex_bytes(value)?,
+            AbiType::Utf8String => json_to_str(value)?.as_bytes().to_vec(),
+            AbiType::ManagedDecimal {
+                signed,
+                decimals: Some(decimals),
+            } => {
+                let (raw, _) = json_to_decimal(value, Some(*decimals))?;
+                decimal_raw_bytes(*signed, &raw)?
+            },
+            AbiType::Option(item_type) => {
+                if value.is_null() {
+                    return Ok(Vec::new());
+                }
+                let mut bytes = vec![1];
+                self.nested_encode(item_type, value, &mut bytes)?;
+                bytes
+            },
+            AbiType::List(item_type) => {
+                let mut bytes = Vec::new();
+                for item in json_to_array(value)? {
+                    self.nested_encode(item_type, item, &mut bytes)?;
+                }
+                bytes
+            },
+            AbiType::Custom(name) => match self.get(name)? {
+                // variants without fields are given by name, and top-encoded as their discriminant
+                CustomType::Enum(variants) if value.is_string() => {
+                    let variant = find_variant(name, variants, value)?;
+                    biguint_to_bytes(&BigUint::from(variant.discriminant))
+                },
+                CustomType::ExplicitEnum(_) => json_to_str(value)?.as_bytes().to_vec(),
+                _ => self.nested_encode_to_vec(abi_type, value)?,
+            },
+            _ => self.nested_encode_to_vec(abi_type, value)?,
+        };
+        Ok(bytes)
+    }
+
+    fn nested_encode_to_vec(
+        &self,
+        abi_type: &AbiType,
+        value: &Value,
+    ) -> Result<Vec<u8>, AbiCodecError> {
+        let mut bytes = Vec::new();
+        self.nested_encode(abi_type, value, &mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Encodes a value as part of a larger one, appending it to `dest`.
+    pub fn nested_encode(
+        &self,
+        abi_type: &AbiType,
+        value: &Value,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), AbiCodecError> {
+        match abi_type {
+            AbiType::Unit => {},
+            AbiType::Bool => dest.push(json_to_bool(value)? as u8),
+            AbiType::Unsigned(size) => {
+                let bytes = biguint_to_bytes(&unsigned_from_json(*size, value)?);
+                dest.resize(dest.len() + size - bytes.len(), 0);
+                dest.extend_from_slice(&bytes);
+            },
+            AbiType::Signed(size) => {
+                let n = signed_from_json(*size, value)?;
+                let padding = if n.sign() == Sign::Minus { 0xff } else { 0 };
+                let bytes = bigint_to_bytes(&n);
+                dest.resize(dest.len() + size - bytes.len(), padding);
+                dest.extend_from_slice(&bytes);
+            },
+            AbiType::BigUint => push_with_len(dest, &biguint_to_bytes(&json_to_biguint(value)?)),
+            AbiType::BigInt => push_with_len(dest, &bigint_to_bytes(&json_to_bigint(value)?)),
+            AbiType::Bytes => push_with_len(dest, &json_to_hex_bytes(value)?),
+            AbiType::BytesReadToEnd => dest.extend_from_slice(&json_to_hex_bytes(value)?),
+            AbiType::Utf8String => push_with_len(dest, json_to_str(value)?.as_bytes()),
+            AbiType::Address => dest.extend_from_slice(&json_to_address(value)?),
+            AbiType::ManagedDecimal { signed, decimals } => {
+                let (raw, num_decimals) = json_to_decimal(value, *decimals)?;
+                push_with_len(dest, &decimal_raw_bytes(*signed, &raw)?);
+                if decimals.is_none() {
+                    push_u32(dest, num_decimals);
+                }
+            },
+            AbiType::Option(item_type) => {
+                if value.is_null() {
+                    dest.push(0);
+                } else {
+                    dest.push(1);
+                    self.nested_encode(item_type, value, dest)?;
+                }
+            },
+            AbiType::List(item_type) => {
+                let items = json_to_array(value)?;
+                push_u32(dest, items.len());
+                for item in items {
+                    self.nested_encode(item_type, item, dest)?;
+                }
+            },
+            AbiType::Array(len, item_type) => {
+                if **item_type == AbiType::Unsigned(1) {
+                    let bytes = json_to_hex_bytes(value)?;
+                    check_len(*len, bytes.len())?;
+                    dest.extend_from_slice(&bytes);
+                } else {
+                    let items = json_to_array(value)?;
+                    check_len(*len, items.len())?;
+                    for item in items {
+                        self.nested_encode(item_type, item, dest)?;
+                    }
+                }
+            },
+            AbiType::Tuple(item_types) => {
+                let items = json_to_array(value)?;
+                check_len(item_types.len(), items.len())?;
+                for (item_type, item) in item_types.iter().zip(items) {
+                    self.nested_encode(item_type, item, dest)?;
+                }
+            },
+            AbiType::Custom(name) => self.nested_encode_custom(name, value, dest)?,
+            multi => {
+                return codec_err(format!(
+                    "multi-value {multi:?} cannot be nested in another value"
+                ))
+            },
+        }
+        Ok(())
+    }
+
+    fn nested_encode_custom(
+        &self,
+        name: &str,
+        value: &Value,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), AbiCodecError> {
+        match self.get(name)? {
+            CustomType::Struct(fields) => self.nested_encode_fields(fields, value, dest),
+            CustomType::Enum(variants) => {
+                let variant = find_variant(name, variants, value)?;
+                dest.push(variant.discriminant);
+                if variant.fields.is_empty() {
+                    return Ok(());
+                }
+                let fields_value = &json_to_object(value)?[&variant.name];
+                self.nested_encode_fields(&variant.fields, fields_value, dest)
+            },
+            CustomType::ExplicitEnum(variant_names) => {
+                let variant_name = json_to_str(value)?;
+                if !variant_names.iter().any(|name| name == variant_name) {
+                    return codec_err(format!("unknown {name} variant: {variant_name}"));
+                }
+                push_with_len(dest, variant_name.as_bytes());
+                Ok(())
+            },
+        }
+    }
+
+    fn nested_encode_fields(
+        &self,
+        fields: &[FieldType],
+        value: &Value,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), AbiCodecError> {
+        let object = json_to_object(value)?;
+        for field in fields {
+            let Some(field_value) = object.get(&field.name) else {
+                return codec_err(format!("missing field: {}", field.name));
+            };
+            self.nested_encode(&field.field_type, field_value, dest)
+                .map_err(|err| err.in_context(&field.name))?;
+        }
+        Ok(())
+    }
+}
+
+/// Variants without fields are given by name, the others as an object with the variant name as single key.
+fn find_variant<'a>(
+    name: &str,
+    variants: &'a [EnumVariantType],
+    value: &Value,
+) -> Result<&'a EnumVariantType, AbiCodecError> {
+    let variant_name = match value {
+        Value::String(variant_name) => variant_name,
+        Value::Object(object) if object.len() == 1 => object.keys().next().unwrap(),
+        _ => return codec_err(format!("expected a {name} variant, got {value}")),
+    };
+    let Some(variant) = variants
+        .iter()
+        .find(|variant| variant.name == *variant_name)
+    else {
+        return codec_err(format!("unknown {name} variant: {variant_name}"));
+    };
+    if variant.fields.is_empty() != value.is_string() {
+        return codec_err(format!("invalid fields for {name} variant {variant_name}"));
+    }
+    Ok(variant)
+}
+
+fn unsigned_from_json(size: usize, value: &Value) -> Result<BigUint, AbiCodecError> {
+    let n = json_to_biguint(value)?;
+    if n.bits() > size as u64 * 8 {
+        return codec_err(format!("{n} does not fit in {size} bytes"));
+    }
+    Ok(n)
+}
+
+fn signed_from_json(size: usize, value: &Value) -> Result<BigInt, AbiCodecError> {
+    let n = json_to_bigint(value)?;
+    if bigint_to_bytes(&n).len() > size {
+        return codec_err(format!("{n} does not fit in {size} bytes"));
+    }
+    Ok(n)
+}
+
+/// The raw units are a `BigInt` for signed decimals, and a `BigUint` otherwise.
+fn decimal_raw_bytes(signed: bool, raw: &BigInt) -> Result<Vec<u8>, AbiCodecError> {
+    if signed {
+        return Ok(bigint_to_bytes(raw));
+    }
+    if raw.sign() == Sign::Minus {
+        return codec_err(format!("unexpected negative value: {raw}"));
+    }
+    Ok(biguint_to_bytes(raw.magnitude()))
+}
+
+fn check_len(expected: usize, actual: usize) -> Result<(), AbiCodecError> {
+    if expected != actual {
+        return codec_err(format!("expected {expected} items, got {actual}"));
+    }
+    Ok(())
+}
+
+fn push_u32(dest: &mut Vec<u8>, n: usize) {
+    dest.extend_from_slice(&(n as u32).to_be_bytes());
+}
+
+fn push_with_len(dest: &mut Vec<u8>, bytes: &[u8]) {
+    push_u32(dest, bytes.len());
+    dest.extend_from_slice(bytes);
+}
@@ -0,0 +1,181 @@
+use std::path::Path;
+
+use dharitri_sc_meta_lib::abi_json::{
+    deserialize_abi_from_json, ContractAbiJson, EndpointAbiJson, InputAbiJson, OutputAbiJson,
+};
+use serde_json::Value;
+
+use crate::{abi_codec_error::codec_err, AbiCodecError, AbiType, AbiTypeRegistry};
+
+const CONSTRUCTOR_NAME: &str = "init";
+const UPGRADE_CONSTRUCTOR_NAME: &str = "upgrade";
+
+/// Encodes and decodes the arguments and results of the endpoints of a contract, based on its ABI.
+///
+/// Arguments and results are given as one JSON value per input or output declared in the ABI.
+/// Trailing `optional` and `variadic` inputs can be left out.
+pub struct ContractAbiCodec {
+    abi: ContractAbiJson,
+    registry: AbiTypeRegistry,
+}
+
+impl ContractAbiCodec {
+    pub fn new(abi: ContractAbiJson) -> Result<Self, AbiCodecError> {
+        let registry = AbiTypeRegistry::from_type_descriptions(&abi.types)?;
+        Ok(ContractAbiCodec { abi, registry })
+    }
+
+    /// Loads the ABI from a `.abi.json` file, as generated by the contract build.
+    pub fn from_abi_file(path: impl AsRef<Path>) -> Result<Self, AbiCodecError> {
+        let path = path.as_ref();
+        let abi_json = std::fs::read_to_string(path)
+            .map_err(|err| AbiCodecError::new(format!("cannot read {}: {err}", path.display())))?;
+        Self::new(deserialize_abi_from_json(&abi_json).map_err(AbiCodecError::new)?)
+    }
+
+    pub fn abi(&self) -> &ContractAbiJson {
+        &self.abi
+    }
+
+    pub fn registry(&self) -> &AbiTypeRegistry {
+        &self.registry
+    }
+
+    /// The constructor is called `init`, and the upgrade constructor `upgrade`.
+    pub fn encode_args(
+        &self,
+        endpoint_name: &str,
+        args: &[Value],
+    ) -> Result<Vec<Vec<u8>>, AbiCodecError> {
+        let inputs = self.endpoint_inputs(endpoint_name)?;
+        if args.len() > inputs.len() {
+            return codec_err(format!(
+                "{endpoint_name} expects at most {} arguments, got {}",
+                inputs.len(),
+                args.len()
+            ));
+        }
+
+        let mut raw_args = Vec::new();
+        for (index, input) in inputs.iter().enumerate() {
+            let abi_type = AbiType::parse(&input.type_name)?;
+            let arg = match args.get(index) {
+                Some(arg) => arg,
+                None => match abi_type {
+                    AbiType::Optional(_) => &Value::Null,
+                    AbiType::Variadic(_) | AbiType::CountedVariadic(_) => &Value::Array(Vec::new()),
+                    _ => return codec_err(format!("missing argument: {}", input.arg_name)),
+                },
+            };
+            self.registry
+                .multi_encode(&abi_type, arg, &mut raw_args)
+                .map_err(|err| err.in_context(&input.arg_name))?;
+        }
+        Ok(raw_args)
+    }
+
+    pub fn decode_args(
+        &self,
+        endpoint_name: &str,
+        raw_args: &[Vec<u8>],
+    ) -> Result<Vec<Value>, AbiCodecError> {
+        let types = self
+            .endpoint_inputs(endpoint_name)?
+            .iter()
+            .map(|input| (input.arg_name.as_str(), input.type_name.as_str()));
+        self.multi_decode_all(types, raw_args)
+    }
+
+    pub fn encode_results(
+        &self,
+        endpoint_name: &str,
+        results: &[Value],
+    ) -> Result<Vec<Vec<u8>>, AbiCodecError> {
+        let outputs = self.endpoint_outputs(endpoint_name)?;
+        if results.len() != outputs.len() {
+            return codec_err(format!(
+                "{endpoint_name} returns {} results, got {}",
+                outputs.len(),
+                results.len()
+            ));
+        }
+
+        let mut raw_results = Vec::new();
+        for (output, result) in outputs.iter().zip(results) {
+            let abi_type = AbiType::parse(&output.type_name)?;
+            self.registry
+                .multi_encode(&abi_type, result, &mut raw_results)
+                .map_err(|err| err.in_context(&output.output_name))?;
+        }
+        Ok(raw_results)
+    }
+
+    pub fn decode_results(
+        &self,
+        endpoint_name: &str,
+        raw_results: &[Vec<u8>],
+    ) -> Result<Vec<Value>, AbiCodecError> {
+        let types = self
+            .endpoint_outputs(endpoint_name)?
+            .iter()
+            .map(|output| (output.output_name.as_str(), output.type_name.as_str()));
+        self.multi_decode_all(types, raw_results)
+    }
+
+    fn multi_decode_all<'a>(
+        &self,
+        types: impl Iterator<Item = (&'a str, &'a str)>,
+        raw_values: &[Vec<u8>],
+    ) -> Result<Vec<Value>, AbiCodecError> {
+        let mut remaining = raw_values;
+        let mut values = Vec::new();
+        for (name, type_name) in types {
+            let abi_type = AbiType::parse(type_name)?;
+            let value = self
+                .registry
+                .multi_decode(&abi_type, &mut remaining)
+                .map_err(|err| err.in_context(name))?;
+            values.push(value);
+        }
+        if !remaining.is_empty() {
+            return codec_err(format!("{} unexpected trailing values", remaining.len()));
+        }
+        Ok(values)
+    }
+
+    fn endpoint_inputs(&self, endpoint_name: &str) -> Result<&[InputAbiJson], AbiCodecError> {
+        let inputs = match endpoint_name {
+            CONSTRUCTOR_NAME => self.abi.constructor.as_ref().map(|c| &c.inputs),
+            UPGRADE_CONSTRUCTOR_NAME => self.abi.upgrade_constructor.as_ref().map(|c| &c.inputs),
+            _ => None,
+        };
+        match inputs {
+            Some(inputs) => Ok(inputs),
+            None => Ok(&self.find_endpoint(endpoint_name)?.inputs),
+        }
+    }
+
+    fn endpoint_outputs(&self, endpoint_name: &str) -> Result<&[OutputAbiJson], AbiCodecError> {
+        let outputs = match endpoint_name {
+            CONSTRUCTOR_NAME => self.abi.constructor.as_ref().map(|c| &c.outputs),
+            UPGRADE_CONSTRUCTOR_NAME => self.abi.upgrade_constructor.as_ref().map(|c| &c.outputs),
+            _ => None,
+        };
+        match outputs {
+            Some(outputs) => Ok(outputs),
+            None => Ok(&self.find_endpoint(endpoint_name)?.outputs),
+        }
+    }
+
+    fn find_endpoint(&self, endpoint_name: &str) -> Result<&EndpointAbiJson, AbiCodecError> {
+        match self
+            .abi
+            .endpoints
+            .iter()
+            .find(|endpoint| endpoint.name == endpoint_name)
+        {
+            Some(endpoint) => Ok(endpoint),
+            None => codec_err(format!("endpoint not found: {endpoint_name}")),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+mod abi_codec_error;
+mod abi_json_value;
+mod abi_multi_codec;
+mod abi_type;
+mod abi_type_registry;
+mod abi_value_decode;
+mod abi_value_encode;
+mod contract_abi_codec;
+
+pub use abi_codec_error::AbiCodecError;
+pub use abi_type::AbiType;
+pub use abi_type_registry::{AbiTypeRegistry, CustomType, EnumVariantType, FieldType};
+pub use contract_abi_codec::ContractAbiCodec;
+pub use dharitri_sc_meta_lib::abi_json;
+pub use serde_json;
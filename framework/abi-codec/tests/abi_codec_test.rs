@@ -0,0 +1,287 @@
+use dharitri_sc::{
+    codec::{top_encode_to_vec_u8_or_panic, TopEncode},
+    types::{BigUint, ConstDecimals, ManagedBuffer, ManagedDecimal, NumDecimals},
+};
+use dharitri_sc_abi_codec::{
+    abi_json::deserialize_abi_from_json,
+    serde_json::{json, Value},
+    AbiType, AbiTypeRegistry, ContractAbiCodec,
+};
+use dharitri_sc_scenario::api::StaticApi;
+
+dharitri_sc::derive_imports!();
+
+const ABI_TESTER_ABI_PATH: &str =
+    "../../contracts/feature-tests/abi-tester/abi_tester_expected_main.abi.json";
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+struct Sample {
+    id: u64,
+    delta: i32,
+    amount: BigUint<StaticApi>,
+    maybe: Option<u8>,
+    list: Vec<u16>,
+    kind: SampleKind,
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+enum SampleKind {
+    Plain,
+    Tagged(u32),
+    Named {
+        flag: bool,
+        name: ManagedBuffer<StaticApi>,
+    },
+}
+
+const SAMPLE_ABI_JSON: &str = r#"{
+    "name": "sample",
+    "endpoints": [],
+    "events": [],
+    "types": {
+        "Sample": {
+            "type": "struct",
+            "fields": [
+                { "name": "id", "type": "u64" },
+                { "name": "delta", "type": "i32" },
+                { "name": "amount", "type": "BigUint" },
+                { "name": "maybe", "type": "Option<u8>" },
+                { "name": "list", "type": "List<u16>" },
+                { "name": "kind", "type": "SampleKind" }
+            ]
+        },
+        "SampleKind": {
+            "type": "enum",
+            "variants": [
+                { "name": "Plain", "discriminant": 0 },
+                { "name": "Tagged", "discriminant": 1, "fields": [{ "name": "0", "type": "u32" }] },
+                {
+                    "name": "Named",
+                    "discriminant": 2,
+                    "fields": [
+                        { "name": "flag", "type": "bool" },
+                        { "name": "name", "type": "bytes" }
+                    ]
+                }
+            ]
+        }
+    }
+}"#;
+
+fn sample_registry() -> AbiTypeRegistry {
+    let abi = deserialize_abi_from_json(SAMPLE_ABI_JSON).unwrap();
+    AbiTypeRegistry::from_type_descriptions(&abi.types).unwrap()
+}
+
+/// Checks that the value encodes exactly like the framework does, and that it decodes back to the same JSON.
+fn check_top_encoding<T: TopEncode>(
+    registry: &AbiTypeRegistry,
+    type_name: &str,
+    json_value: Value,
+    framework_value: T,
+) {
+    let abi_type = AbiType::parse(type_name).unwrap();
+    let expected = top_encode_to_vec_u8_or_panic(&framework_value);
+    let encoded = registry.top_encode(&abi_type, &json_value).unwrap();
+    assert_eq!(encoded, expected, "{type_name}: {json_value}");
+    assert_eq!(
+        registry.top_decode(&abi_type, &encoded).unwrap(),
+        json_value
+    );
+}
+
+#[test]
+fn abi_type_parse_test() {
+    assert_eq!(
+        AbiType::parse("variadic<multi<OnlyShowsUpAsNested04,i32>>").unwrap(),
+        AbiType::Variadic(Box::new(AbiType::Multi(vec![
+            AbiType::Custom("OnlyShowsUpAsNested04".to_string()),
+            AbiType::Signed(4),
+        ])))
+    );
+    assert_eq!(
+        AbiType::parse("array3<u8>").unwrap(),
+        AbiType::Array(3, Box::new(AbiType::Unsigned(1)))
+    );
+    assert_eq!(
+        AbiType::parse("ManagedDecimal<usize>").unwrap(),
+        AbiType::ManagedDecimal {
+            signed: false,
+            decimals: None,
+        }
+    );
+    assert!(AbiType::parse("Option<u8").is_err());
+}
+
+#[test]
+fn top_encode_basic_types_test() {
+    let registry = AbiTypeRegistry::new();
+    check_top_encoding(&registry, "u64", json!(0), 0u64);
+    check_top_encoding(&registry, "u64", json!(1_000_000), 1_000_000u64);
+    check_top_encoding(&registry, "i32", json!(-3), -3i32);
+    check_top_encoding(&registry, "bool", json!(true), true);
+    check_top_encoding(&registry, "bool", json!(false), false);
+    check_top_encoding(
+        &registry,
+        "BigUint",
+        json!("123456789012345678901234567890"),
+        BigUint::<StaticApi>::from(123456789012345678901234567890u128),
+    );
+    check_top_encoding(&registry, "Option<u16>", json!(null), Option::<u16>::None);
+    check_top_encoding(&registry, "Option<u16>", json!(7), Some(7u16));
+    check_top_encoding(&registry, "List<i8>", json!([1, -1]), vec![1i8, -1i8]);
+    check_top_encoding(
+        &registry,
+        "tuple<u8,utf-8 string>",
+        json!([2, "abc"]),
+        (2u8, ManagedBuffer::<StaticApi>::from("abc")),
+    );
+}
+
+#[test]
+fn top_encode_managed_decimal_test() {
+    let registry = AbiTypeRegistry::new();
+    check_top_encoding(
+        &registry,
+        "ManagedDecimal<2>",
+        json!("12.50"),
+        ManagedDecimal::<StaticApi, ConstDecimals<2>>::from_raw_units(
+            BigUint::from(1250u32),
+            ConstDecimals,
+        ),
+    );
+    check_top_encoding(
+        &registry,
+        "ManagedDecimal<usize>",
+        json!("0.005"),
+        ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(BigUint::from(5u32), 3),
+    );
+
+    // missing decimals are filled in when the number of decimals is fixed
+    let encoded = registry
+        .top_encode(&AbiType::parse("ManagedDecimal<2>").unwrap(), &json!("3"))
+        .unwrap();
+    assert_eq!(encoded, vec![1, 44]);
+}
+
+#[test]
+fn top_encode_custom_types_test() {
+    let registry = sample_registry();
+    check_top_encoding(
+        &registry,
+        "Sample",
+        json!({
+            "id": 5,
+            "delta": -1,
+            "amount": "1000",
+            "maybe": null,
+            "list": [1, 2],
+            "kind": { "Named": { "flag": true, "name": "616263" } },
+        }),
+        Sample {
+            id: 5,
+            delta: -1,
+            amount: BigUint::from(1000u32),
+            maybe: None,
+            list: vec![1, 2],
+            kind: SampleKind::Named {
+                flag: true,
+                name: ManagedBuffer::from("abc"),
+            },
+        },
+    );
+    check_top_encoding(&registry, "SampleKind", json!("Plain"), SampleKind::Plain);
+    check_top_encoding(
+        &registry,
+        "SampleKind",
+        json!({ "Tagged": { "0": 4 } }),
+        SampleKind::Tagged(4),
+    );
+    check_top_encoding(
+        &registry,
+        "List<SampleKind>",
+        json!(["Plain", { "Tagged": { "0": 9 } }]),
+        vec![SampleKind::Plain, SampleKind::Tagged(9)],
+    );
+}
+
+#[test]
+fn encode_errors_test() {
+    let registry = sample_registry();
+    let u8_type = AbiType::parse("u8").unwrap();
+    assert!(registry.top_encode(&u8_type, &json!(256)).is_err());
+    assert!(registry.top_encode(&u8_type, &json!("x")).is_err());
+
+    let err = registry
+        .top_encode(&AbiType::parse("SampleKind").unwrap(), &json!("Unknown"))
+        .unwrap_err();
+    assert_eq!(err.message(), "unknown SampleKind variant: Unknown");
+
+    assert!(registry
+        .top_encode(&AbiType::parse("Missing").unwrap(), &json!({}))
+        .is_err());
+}
+
+#[test]
+fn contract_abi_codec_args_test() {
+    let codec = ContractAbiCodec::from_abi_file(ABI_TESTER_ABI_PATH).unwrap();
+
+    let args = codec
+        .encode_args("var_args", &[json!(5), json!([[{}, -3], [{}, 7]])])
+        .unwrap();
+    assert_eq!(args, vec![vec![5], vec![], vec![0xfd], vec![], vec![7]]);
+    assert_eq!(
+        codec.decode_args("var_args", &args).unwrap(),
+        vec![json!(5), json!([[{}, -3], [{}, 7]])]
+    );
+
+    // trailing optional and variadic arguments can be left out
+    let args = codec.encode_args("optional_arg", &[json!(3)]).unwrap();
+    assert_eq!(args, vec![vec![3]]);
+    assert_eq!(
+        codec.decode_args("optional_arg", &args).unwrap(),
+        vec![json!(3), json!(null)]
+    );
+
+    let args = codec
+        .encode_args("init", &[json!(-2), json!({ "something": null })])
+        .unwrap();
+    assert_eq!(args, vec![vec![0xfe], vec![]]);
+
+    assert!(codec.encode_args("var_args", &[]).is_err());
+    assert!(codec.encode_args("no_such_endpoint", &[]).is_err());
+}
+
+#[test]
+fn contract_abi_codec_results_test() {
+    let codec = ContractAbiCodec::from_abi_file(ABI_TESTER_ABI_PATH).unwrap();
+
+    let results = vec![vec![0xff], vec![1, 2, 3], vec![0xab]];
+    let decoded = codec.decode_results("multi_result_3", &results).unwrap();
+    assert_eq!(decoded, vec![json!(-1), json!("010203"), json!("ab")]);
+    assert_eq!(
+        codec.encode_results("multi_result_3", &decoded).unwrap(),
+        results
+    );
+
+    let err = codec
+        .decode_results("multi_result_3", &[vec![1], vec![1, 2, 3], vec![], vec![]])
+        .unwrap_err();
+    assert_eq!(err.message(), "1 unexpected trailing values");
+
+    let raw_decimal = top_encode_to_vec_u8_or_panic(
+        &ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(BigUint::from(1234u32), 2),
+    );
+    assert_eq!(
+        codec
+            .decode_results("process_managed_decimal", &[raw_decimal])
+            .unwrap(),
+        vec![json!("12.34")]
+    );
+    assert_eq!(
+        codec
+            .encode_args("process_managed_decimal", &[json!("1.5")])
+            .unwrap(),
+        vec![top_encode_to_vec_u8_or_panic(&15_000_000_000u64)]
+    );
+}
@@ -1,12 +1,16 @@
 use crate::api::{
-    const_handles, managed_types::HandleConstraints, use_raw_handle, ManagedBufferApiImpl,
-    StaticVarApiImpl, StorageReadApi, StorageReadApiImpl, VMApi,
+    const_handles, managed_types::HandleConstraints, use_raw_handle, BigIntApiImpl,
+    BlockchainApiImpl, ManagedBufferApiImpl, ManagedTypeApiImpl, StaticVarApiImpl, StorageReadApi,
+    StorageReadApiImpl, VMApi,
 };
 
 use super::ExternalViewApi;
 
 pub const EXTERNAL_VIEW_TARGET_ADRESS_KEY: &[u8] = b"external-view-target-address";
 
+/// Storage locks are kept in the contract storage, under this prefix, followed by the locked key.
+const TIMELOCK_KEY_PREFIX: &[u8] = b"timelock";
+
 impl<A> StorageReadApi for ExternalViewApi<A>
 where
     A: VMApi,
@@ -70,4 +74,32 @@ impl<A: VMApi> StorageReadApiImpl for ExternalViewApi<A> {
     ) {
         A::storage_read_api_impl().storage_load_from_address(address_handle, key_handle, dest);
     }
+
+    /// The lock hooks only work on the current contract, so the lock entry of the target gets read directly.
+    fn storage_get_lock(&self, key_handle: Self::ManagedBufferHandle) -> i64 {
+        let lock_key_handle: A::ManagedBufferHandle =
+            use_raw_handle(A::static_var_api_impl().next_handle());
+        A::managed_type_impl().mb_overwrite(lock_key_handle.clone(), TIMELOCK_KEY_PREFIX);
+        A::managed_type_impl().mb_append(lock_key_handle.clone(), key_handle);
+
+        let lock_handle: A::ManagedBufferHandle =
+            use_raw_handle(A::static_var_api_impl().next_handle());
+        self.storage_load_managed_buffer_raw(lock_key_handle, lock_handle.clone());
+
+        let lock_timestamp_handle: A::BigIntHandle =
+            use_raw_handle(A::static_var_api_impl().next_handle());
+        A::managed_type_impl().mb_to_big_int_unsigned(lock_handle, lock_timestamp_handle.clone());
+        A::managed_type_impl()
+            .bi_to_i64(lock_timestamp_handle)
+            .unwrap_or(i64::MAX)
+    }
+
+    fn storage_is_locked(&self, key_handle: Self::ManagedBufferHandle) -> i32 {
+        let lock_timestamp = self.storage_get_lock(key_handle) as u64;
+        if lock_timestamp > A::blockchain_api_impl().get_block_timestamp() {
+            1
+        } else {
+            0
+        }
+    }
 }
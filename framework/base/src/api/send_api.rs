@@ -112,6 +112,10 @@ pub trait SendApiImpl: ManagedTypeApiImpl {
         arg_buffer_handle: RawHandle,
     );
 
+    /// Deletes a child contract of the currently executing contract, together with all its storage.
+    /// Only works if the child contract is upgradeable.
+    fn delete_contract(&self, sc_address_handle: RawHandle, gas: u64, arg_buffer_handle: RawHandle);
+
     /// Same shard, in-line execution of another contract.
     fn execute_on_dest_context_raw(
         &self,
@@ -27,6 +27,12 @@ pub trait StorageReadApiImpl: HandleTypeInfo {
         key_handle: Self::ManagedBufferHandle,
         dest: Self::ManagedBufferHandle,
     );
+
+    /// The block timestamp until which the key is locked, 0 if it is not locked.
+    fn storage_get_lock(&self, key_handle: Self::ManagedBufferHandle) -> i64;
+
+    /// Returns 1 if the key is locked at the current block timestamp, 0 otherwise.
+    fn storage_is_locked(&self, key_handle: Self::ManagedBufferHandle) -> i32;
 }
 
 pub trait StorageWriteApi: HandleTypeInfo {
@@ -47,4 +53,10 @@ pub trait StorageWriteApiImpl: HandleTypeInfo {
         key_handle: Self::ManagedBufferHandle,
         value_handle: Self::ManagedBufferHandle,
     );
+
+    /// Locks the key until the given block timestamp. Returns the storage status of the lock entry.
+    fn storage_set_lock(&self, key_handle: Self::ManagedBufferHandle, lock_timestamp: i64) -> i32;
+
+    /// Removes the lock of the key. Returns the storage status of the lock entry.
+    fn storage_clear_lock(&self, key_handle: Self::ManagedBufferHandle) -> i32;
 }
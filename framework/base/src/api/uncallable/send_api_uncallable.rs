@@ -108,6 +108,10 @@ impl SendApiImpl for UncallableApi {
         unreachable!()
     }
 
+    fn delete_contract(&self, _sc_address: RawHandle, _gas: u64, _arg_buffer_handle: RawHandle) {
+        unreachable!()
+    }
+
     fn execute_on_dest_context_raw(
         &self,
         _gas: u64,
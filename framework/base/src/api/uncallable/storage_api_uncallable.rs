@@ -18,6 +18,14 @@ impl StorageReadApiImpl for UncallableApi {
     fn storage_load_from_address(&self, _address_handle: i32, _key_handle: i32, _dest: i32) {
         unreachable!()
     }
+
+    fn storage_get_lock(&self, _key_handle: i32) -> i64 {
+        unreachable!()
+    }
+
+    fn storage_is_locked(&self, _key_handle: i32) -> i32 {
+        unreachable!()
+    }
 }
 
 impl StorageWriteApi for UncallableApi {
@@ -36,4 +44,12 @@ impl StorageWriteApiImpl for super::UncallableApi {
     ) {
         unreachable!()
     }
+
+    fn storage_set_lock(&self, _key_handle: i32, _lock_timestamp: i64) -> i32 {
+        unreachable!()
+    }
+
+    fn storage_clear_lock(&self, _key_handle: i32) -> i32 {
+        unreachable!()
+    }
 }
@@ -305,6 +305,21 @@ where
         )
     }
 
+    /// Deletes a child contract of the currently executing contract, together with all its storage.
+    /// Only works if the child contract is upgradeable.
+    pub fn delete_contract(
+        &self,
+        sc_address: &ManagedAddress<A>,
+        gas: u64,
+        arg_buffer: &ManagedArgBuffer<A>,
+    ) {
+        A::send_api_impl().delete_contract(
+            sc_address.get_handle().get_raw_handle(),
+            gas,
+            arg_buffer.get_handle().get_raw_handle(),
+        )
+    }
+
     /// Same shard, in-line execution of another contract.
     pub fn execute_on_dest_context_raw(
         &self,
@@ -8,7 +8,7 @@ use crate::codec::{TopDecode, TopEncode};
 use crate::{
     api::{
         const_handles::MBUF_TEMPORARY_1, use_raw_handle, ErrorApi, ManagedTypeApi, StorageReadApi,
-        StorageReadApiImpl, StorageWriteApi,
+        StorageReadApiImpl, StorageWriteApi, StorageWriteApiImpl,
     },
     storage::StorageKey,
     storage_get,
@@ -86,4 +86,41 @@ where
         let key: StorageKey<A> = storage_key.into();
         storage_set(key.as_ref(), value);
     }
+
+    /// Locks the storage key until the given block timestamp.
+    ///
+    /// The lock is only informative, the VM does not prevent writing to a locked key.
+    pub fn set_lock<K>(&self, storage_key: K, lock_timestamp: u64)
+    where
+        K: Into<StorageKey<A>>,
+    {
+        let key: StorageKey<A> = storage_key.into();
+        A::storage_write_api_impl().storage_set_lock(key.get_handle(), lock_timestamp as i64);
+    }
+
+    /// The block timestamp until which the storage key is locked, 0 if no lock was set.
+    pub fn get_lock<K>(&self, storage_key: K) -> u64
+    where
+        K: Into<StorageKey<A>>,
+    {
+        let key: StorageKey<A> = storage_key.into();
+        A::storage_read_api_impl().storage_get_lock(key.get_handle()) as u64
+    }
+
+    /// True while the current block timestamp is before the lock timestamp.
+    pub fn is_locked<K>(&self, storage_key: K) -> bool
+    where
+        K: Into<StorageKey<A>>,
+    {
+        let key: StorageKey<A> = storage_key.into();
+        A::storage_read_api_impl().storage_is_locked(key.get_handle()) > 0
+    }
+
+    pub fn clear_lock<K>(&self, storage_key: K)
+    where
+        K: Into<StorageKey<A>>,
+    {
+        let key: StorageKey<A> = storage_key.into();
+        A::storage_write_api_impl().storage_clear_lock(key.get_handle());
+    }
 }
@@ -85,11 +85,20 @@ pub enum StandaloneCliAction {
     )]
     Wallet(WalletArgs),
 
+    #[command(
+        name = "tx",
+        about = "Builds, signs, sends and tracks transactions in separate steps, for offline signing."
+    )]
+    Tx(TxArgs),
+
     #[command(
         name = "cs",
         about = "Can install, start and stop a chain simulator configuration."
     )]
     ChainSimulator(ChainSimulatorArgs),
+
+    #[command(name = "scen", about = "Formats and lints scenario JSON files.")]
+    Scen(ScenArgs),
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Args)]
@@ -472,6 +481,12 @@ pub enum WalletAction {
     Bech32(WalletBech32Args),
     #[command(name = "convert", about = "Converts a wallet")]
     Convert(WalletConvertArgs),
+
+    #[command(
+        name = "derive",
+        about = "Lists and exports the addresses derived from a mnemonic"
+    )]
+    Derive(WalletDeriveArgs),
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Parser)]
@@ -484,12 +499,18 @@ pub struct WalletArgs {
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
 pub struct WalletNewArgs {
     /// The type of wallet to create.
+    /// Supported: pem, keystore-secret, keystore-mnemonic.
     #[arg(long = "format", verbatim_doc_comment)]
     pub wallet_format: Option<String>,
 
     /// The name of the wallet to create.
     #[arg(long = "outfile", verbatim_doc_comment)]
     pub outfile: Option<String>,
+
+    /// The number of addresses to derive from the new mnemonic.
+    /// With the pem format, all their keys are written in the same file.
+    #[arg(long = "num-addresses", default_value = "1", verbatim_doc_comment)]
+    pub num_addresses: u32,
 }
 
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
@@ -507,6 +528,34 @@ pub struct WalletConvertArgs {
     pub outfile: Option<String>,
 }
 
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct WalletDeriveArgs {
+    /// Where the mnemonic comes from.
+    /// Supported: mnemonic (plain text), keystore-mnemonic.
+    #[arg(long = "in-format", default_value = "mnemonic", verbatim_doc_comment)]
+    pub from: String,
+
+    /// If missing, the plain text mnemonic is read from the standard input.
+    #[arg(long = "infile", verbatim_doc_comment)]
+    pub infile: Option<String>,
+
+    /// The account in the derivation path.
+    #[arg(long = "account", default_value = "0", verbatim_doc_comment)]
+    pub account: u32,
+
+    /// The first address index to derive.
+    #[arg(long = "start", default_value = "0", verbatim_doc_comment)]
+    pub start: u32,
+
+    /// The number of addresses to derive.
+    #[arg(long = "count", default_value = "1", verbatim_doc_comment)]
+    pub count: u32,
+
+    /// If set, the keys of all the derived addresses are exported to this PEM file.
+    #[arg(long = "outfile", verbatim_doc_comment)]
+    pub outfile: Option<String>,
+}
+
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
 pub struct WalletBech32Args {
     #[arg(long = "encode", verbatim_doc_comment)]
@@ -514,3 +563,179 @@ pub struct WalletBech32Args {
     #[arg(long = "decode", verbatim_doc_comment)]
     pub bech32_address: Option<String>,
 }
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum TxAction {
+    #[command(
+        name = "new",
+        about = "Builds an unsigned transaction, with the nonce and network settings retrieved from the gateway"
+    )]
+    New(TxNewArgs),
+
+    #[command(
+        name = "sign",
+        about = "Signs a transaction, without any network access"
+    )]
+    Sign(TxSignArgs),
+
+    #[command(
+        name = "send",
+        about = "Sends a signed transaction, then waits for it to be processed"
+    )]
+    Send(TxSendArgs),
+
+    #[command(
+        name = "track",
+        about = "Waits for a transaction to be processed and prints its results"
+    )]
+    Track(TxTrackArgs),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Parser)]
+#[command(propagate_version = true)]
+pub struct TxArgs {
+    #[command(subcommand)]
+    pub command: TxAction,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct TxNewArgs {
+    /// The gateway URL.
+    #[arg(long = "proxy", verbatim_doc_comment)]
+    pub proxy: String,
+
+    /// The bech32 address of the sender.
+    #[arg(long = "sender", verbatim_doc_comment)]
+    pub sender: String,
+
+    /// The bech32 address of the receiver.
+    /// If missing, the transaction is a deploy.
+    #[arg(long = "receiver", verbatim_doc_comment)]
+    pub receiver: Option<String>,
+
+    /// The REWA value, in denominated units.
+    #[arg(long = "value", default_value = "0", verbatim_doc_comment)]
+    pub value: String,
+
+    /// The data field, as plain text, e.g. "add@03".
+    #[arg(long = "data", verbatim_doc_comment)]
+    pub data: Option<String>,
+
+    #[arg(long = "gas-limit", verbatim_doc_comment)]
+    pub gas_limit: u64,
+
+    /// If missing, the current nonce of the sender is retrieved from the gateway.
+    #[arg(long = "nonce", verbatim_doc_comment)]
+    pub nonce: Option<u64>,
+
+    /// The bech32 address of the guardian, if the sender account is guarded.
+    #[arg(long = "guardian", verbatim_doc_comment)]
+    pub guardian: Option<String>,
+
+    /// The bech32 address of the relayer, for relayed transactions.
+    #[arg(long = "relayer", verbatim_doc_comment)]
+    pub relayer: Option<String>,
+
+    /// If missing, the transaction JSON is printed.
+    #[arg(long = "outfile", verbatim_doc_comment)]
+    pub outfile: Option<String>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct TxSignArgs {
+    /// The transaction JSON, as produced by "tx new" or exported by an interactor.
+    #[arg(long = "infile", verbatim_doc_comment)]
+    pub infile: String,
+
+    /// If missing, the signed transaction JSON is printed.
+    #[arg(long = "outfile", verbatim_doc_comment)]
+    pub outfile: Option<String>,
+
+    /// The signing wallet, as PEM file.
+    #[arg(long = "pem", verbatim_doc_comment)]
+    pub pem: Option<String>,
+
+    /// The signing wallet, as keystore file. The password is read from the standard input.
+    #[arg(long = "keystore", verbatim_doc_comment)]
+    pub keystore: Option<String>,
+
+    /// Who signs the transaction.
+    /// Supported: sender, guardian, relayer.
+    #[arg(long = "role", default_value = "sender", verbatim_doc_comment)]
+    pub role: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct TxSendArgs {
+    /// The gateway URL.
+    #[arg(long = "proxy", verbatim_doc_comment)]
+    pub proxy: String,
+
+    /// The signed transaction JSON.
+    #[arg(long = "infile", verbatim_doc_comment)]
+    pub infile: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct TxTrackArgs {
+    /// The gateway URL.
+    #[arg(long = "proxy", verbatim_doc_comment)]
+    pub proxy: String,
+
+    /// The hash of the transaction.
+    #[arg(long = "hash", verbatim_doc_comment)]
+    pub hash: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Parser)]
+#[command(propagate_version = true)]
+pub struct ScenArgs {
+    #[command(subcommand)]
+    pub command: ScenAction,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum ScenAction {
+    #[command(
+        name = "fmt",
+        about = "Rewrites scenario files in the canonical format. Comments are kept."
+    )]
+    Fmt(ScenFmtArgs),
+
+    #[command(
+        name = "lint",
+        about = "Reports unknown keys, undefined accounts, unparsable values and missing files in scenario files."
+    )]
+    Lint(ScenLintArgs),
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ScenFmtArgs {
+    /// Scenario file, or directory to search for *.scen.json, *.step.json and *.steps.json files.
+    /// Will be current directory if not specified.
+    #[arg(long, verbatim_doc_comment)]
+    pub path: Option<String>,
+
+    /// Ignore all directories with these names.
+    #[arg(long, verbatim_doc_comment)]
+    #[clap(global = true, default_value = "target")]
+    pub ignore: Vec<String>,
+
+    /// Only lists the files that are not formatted, without changing them.
+    /// Fails if there are any.
+    #[arg(long, verbatim_doc_comment)]
+    pub check: bool,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ScenLintArgs {
+    /// Scenario file, or directory to search for *.scen.json, *.step.json and *.steps.json files.
+    /// Will be current directory if not specified.
+    #[arg(long, verbatim_doc_comment)]
+    pub path: Option<String>,
+
+    /// Ignore all directories with these names.
+    #[arg(long, verbatim_doc_comment)]
+    #[clap(global = true, default_value = "target")]
+    pub ignore: Vec<String>,
+}
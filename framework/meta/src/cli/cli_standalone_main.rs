@@ -1,6 +1,8 @@
 use crate::cli::{StandaloneCliAction, StandaloneCliArgs};
 use crate::cmd::chain_simulator::chain_simulator;
 use crate::cmd::retrieve_address::retrieve_address;
+use crate::cmd::scen::scen;
+use crate::cmd::tx::tx;
 use crate::cmd::wallet::wallet;
 use clap::Parser;
 
@@ -51,9 +53,15 @@ pub async fn cli_main_standalone() {
         Some(StandaloneCliAction::Wallet(args)) => {
             wallet(args);
         },
+        Some(StandaloneCliAction::Tx(args)) => {
+            tx(args).await;
+        },
         Some(StandaloneCliAction::ChainSimulator(args)) => {
             chain_simulator(args);
         },
+        Some(StandaloneCliAction::Scen(args)) => {
+            scen(args);
+        },
         None => {},
     }
 }
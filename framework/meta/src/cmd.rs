@@ -6,9 +6,11 @@ pub mod install;
 pub mod local_deps;
 pub mod print_util;
 pub mod retrieve_address;
+pub mod scen;
 pub mod scen_test_gen;
 pub mod template;
 pub mod test;
 pub mod test_coverage;
+pub mod tx;
 pub mod upgrade;
 pub mod wallet;
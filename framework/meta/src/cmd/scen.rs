@@ -0,0 +1,17 @@
+mod scen_files;
+mod scen_fmt;
+mod scen_lint;
+mod scen_parse;
+
+use crate::cli::{ScenAction, ScenArgs};
+
+pub fn scen(args: &ScenArgs) {
+    match &args.command {
+        ScenAction::Fmt(fmt_args) => scen_fmt::fmt_all(fmt_args),
+        ScenAction::Lint(lint_args) => scen_lint::lint_all(lint_args),
+    }
+}
+
+// Good for testing.
+pub use scen_fmt::format_scenario_json;
+pub use scen_lint::{lint_scenario_file, ScenLintIssue};
@@ -0,0 +1,48 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const SCENARIO_FILE_SUFFIXES: &[&str] = &[".scen.json", ".step.json", ".steps.json"];
+
+pub fn is_scenario_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| {
+            SCENARIO_FILE_SUFFIXES
+                .iter()
+                .any(|suffix| name.ends_with(suffix))
+        })
+}
+
+/// The scenario files under the given path, sorted. The path can also be a single file.
+pub fn find_scenario_files(path: Option<&String>, ignore: &[String]) -> Vec<PathBuf> {
+    let root = Path::new(path.map(String::as_str).unwrap_or("./"));
+    let mut result = Vec::new();
+    if root.is_file() {
+        result.push(root.to_path_buf());
+    } else {
+        populate_scenario_files(root, ignore, &mut result);
+    }
+    result.sort();
+    result
+}
+
+fn populate_scenario_files(dir: &Path, ignore: &[String], result: &mut Vec<PathBuf>) {
+    let read_dir = fs::read_dir(dir).expect("error reading directory");
+    for child_result in read_dir {
+        let child = child_result.unwrap();
+        let child_path = child.path();
+        if child_path.is_dir() {
+            let ignored = child
+                .file_name()
+                .to_str()
+                .is_some_and(|name| name.starts_with('.') || ignore.iter().any(|i| i == name));
+            if !ignored {
+                populate_scenario_files(&child_path, ignore, result);
+            }
+        } else if is_scenario_file(&child_path) {
+            result.push(child_path);
+        }
+    }
+}
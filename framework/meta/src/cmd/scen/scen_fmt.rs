@@ -0,0 +1,52 @@
+use std::{fs, process};
+
+use colored::Colorize;
+
+use crate::cli::ScenFmtArgs;
+
+use super::{scen_files::find_scenario_files, scen_parse::ParsedScenario};
+
+/// Formats a scenario file the way the scenario format writes it.
+///
+/// Fails instead of dropping keys that the format does not know.
+/// The `comment` fields are part of the format, so they are kept.
+pub fn format_scenario_json(contents: &str) -> Result<String, String> {
+    let parsed = ParsedScenario::parse(contents)?;
+    let unknown_keys = parsed.unknown_keys();
+    if !unknown_keys.is_empty() {
+        return Err(format!(
+            "unknown keys, formatting would remove them: {}",
+            unknown_keys.join(", ")
+        ));
+    }
+
+    Ok(parsed.raw.to_json_string())
+}
+
+pub fn fmt_all(args: &ScenFmtArgs) {
+    let mut num_unformatted = 0;
+    let mut num_errors = 0;
+    for path in find_scenario_files(args.path.as_ref(), &args.ignore) {
+        let contents = fs::read_to_string(&path).expect("error reading scenario file");
+        match format_scenario_json(&contents) {
+            Ok(formatted) if formatted == contents => {},
+            Ok(formatted) => {
+                num_unformatted += 1;
+                if args.check {
+                    println!("{}", format!("Not formatted: {}", path.display()).yellow());
+                } else {
+                    fs::write(&path, formatted).expect("error writing scenario file");
+                    println!("{}", format!("Formatted: {}", path.display()).green());
+                }
+            },
+            Err(err) => {
+                num_errors += 1;
+                println!("{}", format!("{}: {err}", path.display()).red());
+            },
+        }
+    }
+
+    if num_errors > 0 || (args.check && num_unformatted > 0) {
+        process::exit(1);
+    }
+}
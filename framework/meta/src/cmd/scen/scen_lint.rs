@@ -0,0 +1,342 @@
+use std::{
+    collections::BTreeSet,
+    fmt, fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process,
+};
+
+use colored::Colorize;
+use dharitri_chain_scenario_format::{
+    interpret_trait::InterpreterContext,
+    serde_raw::{ScenarioRaw, StepRaw, ValueSubTree},
+    value_interpreter::{interpret_string, interpret_subtree},
+};
+use serde_json::Value;
+
+use crate::cli::ScenLintArgs;
+
+use super::{
+    scen_files::find_scenario_files,
+    scen_parse::{escape_pointer_token, ParsedScenario},
+};
+
+/// Fields holding names or plain text, rather than value expressions.
+const NON_EXPRESSION_KEYS: &[&str] = &[
+    "step",
+    "comment",
+    "name",
+    "gasSchedule",
+    "id",
+    "txId",
+    "function",
+    "path",
+    "roles",
+    "newTokenIdentifiers",
+];
+
+/// Fields holding maps where the keys are value expressions too.
+const EXPRESSION_KEY_MAPS: &[&str] = &["accounts", "storage", "dcdt"];
+
+/// Stands for any value, in checks.
+const STAR: &str = "*";
+
+/// Marks that other entries are allowed, in checks.
+const PLUS: &str = "+";
+
+const ADDRESS_PREFIXES: &[&str] = &["address:", "sc:"];
+const FILE_PREFIXES: &[&str] = &["file:", "drtsc:"];
+
+/// Only whole scenarios are checked for accounts, steps files are usually run after other steps.
+const SCEN_FILE_SUFFIX: &str = ".scen.json";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScenLintIssue {
+    /// JSON pointer to the offending key or value, empty for the whole file.
+    pub location: String,
+    pub message: String,
+}
+
+impl fmt::Display for ScenLintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.location.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.location, self.message)
+        }
+    }
+}
+
+pub fn lint_all(args: &ScenLintArgs) {
+    // the value interpreter panics on bad input, these panics are reported as issues
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut num_issues = 0;
+    for path in find_scenario_files(args.path.as_ref(), &args.ignore) {
+        let issues = lint_scenario_file(&path);
+        for issue in &issues {
+            println!("{}", format!("{}: {issue}", path.display()).yellow());
+        }
+        num_issues += issues.len();
+    }
+
+    panic::set_hook(default_hook);
+
+    if num_issues > 0 {
+        println!("{}", format!("{num_issues} issue(s) found.").red());
+        process::exit(1);
+    }
+}
+
+pub fn lint_scenario_file(path: &Path) -> Vec<ScenLintIssue> {
+    let mut linter = ScenLinter {
+        context: InterpreterContext::new()
+            .with_dir(parent_dir(path))
+            .with_allowed_missing_files(),
+        accounts: None,
+        issues: Vec::new(),
+    };
+
+    let parsed = match fs::read_to_string(path)
+        .map_err(|err| err.to_string())
+        .and_then(|contents| ParsedScenario::parse(&contents))
+    {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            linter.report(String::new(), err);
+            return linter.issues;
+        },
+    };
+
+    for pointer in parsed.unknown_keys() {
+        linter.report(pointer, "unknown key".to_string());
+    }
+
+    if path.to_string_lossy().ends_with(SCEN_FILE_SUFFIX) {
+        let mut accounts = BTreeSet::new();
+        linter.collect_accounts(
+            &parsed.raw,
+            &parent_dir(path),
+            &mut accounts,
+            &mut Vec::new(),
+        );
+        linter.accounts = Some(accounts);
+        linter.lint_checked_accounts(&parsed.raw);
+    }
+
+    linter.lint_external_steps(&parsed.raw);
+    linter.lint_json_value(&parsed.json, "", "");
+
+    linter.issues
+}
+
+struct ScenLinter {
+    context: InterpreterContext,
+    /// The accounts set up in the scenario, if they are checked.
+    accounts: Option<BTreeSet<Vec<u8>>>,
+    issues: Vec<ScenLintIssue>,
+}
+
+impl ScenLinter {
+    fn report(&mut self, location: String, message: String) {
+        self.issues.push(ScenLintIssue { location, message });
+    }
+
+    /// Gathers the accounts from the set state steps, including those in external steps.
+    fn collect_accounts(
+        &self,
+        raw: &ScenarioRaw,
+        dir: &Path,
+        accounts: &mut BTreeSet<Vec<u8>>,
+        visited: &mut Vec<PathBuf>,
+    ) {
+        for step in &raw.steps {
+            match step {
+                StepRaw::SetState {
+                    accounts: set_accounts,
+                    new_addresses,
+                    ..
+                } => {
+                    for address_expr in set_accounts.keys() {
+                        if let Ok(address) = try_interpret_string(address_expr, &self.context) {
+                            accounts.insert(address);
+                        }
+                    }
+                    for new_address in new_addresses {
+                        if let Ok(address) =
+                            try_interpret_subtree(&new_address.new_address, &self.context)
+                        {
+                            accounts.insert(address);
+                        }
+                    }
+                },
+                StepRaw::ExternalSteps { path, .. } => {
+                    let external_path = dir.join(path);
+                    if visited.contains(&external_path) {
+                        continue;
+                    }
+                    visited.push(external_path.clone());
+                    if let Ok(external) = load_scenario_raw(&external_path) {
+                        self.collect_accounts(
+                            &external,
+                            &parent_dir(&external_path),
+                            accounts,
+                            visited,
+                        );
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+
+    fn lint_checked_accounts(&mut self, raw: &ScenarioRaw) {
+        for (step_index, step) in raw.steps.iter().enumerate() {
+            let StepRaw::CheckState { accounts, .. } = step else {
+                continue;
+            };
+            for address_expr in accounts.accounts.keys() {
+                let Ok(address) = try_interpret_string(address_expr, &self.context) else {
+                    // reported as unparsable value
+                    continue;
+                };
+                if !self.is_account_set_up(&address) {
+                    self.report(
+                        format!(
+                            "/steps/{step_index}/accounts/{}",
+                            escape_pointer_token(address_expr)
+                        ),
+                        format!("checks account `{address_expr}`, which is never set up"),
+                    );
+                }
+            }
+        }
+    }
+
+    fn lint_external_steps(&mut self, raw: &ScenarioRaw) {
+        for (step_index, step) in raw.steps.iter().enumerate() {
+            let StepRaw::ExternalSteps { path, .. } = step else {
+                continue;
+            };
+            if let Err(err) = load_scenario_raw(&self.context.context_path.join(path)) {
+                self.report(
+                    format!("/steps/{step_index}/path"),
+                    format!("bad external steps file `{path}`: {err}"),
+                );
+            }
+        }
+    }
+
+    fn is_account_set_up(&self, address: &[u8]) -> bool {
+        self.accounts
+            .as_ref()
+            .is_none_or(|accounts| accounts.contains(address))
+    }
+
+    /// Walks the JSON, checking all value expressions. The key is the name of the enclosing field.
+    fn lint_json_value(&mut self, value: &Value, key: &str, pointer: &str) {
+        if NON_EXPRESSION_KEYS.contains(&key) {
+            return;
+        }
+
+        match value {
+            Value::String(expr) => self.lint_expression(expr, pointer, true),
+            Value::Array(items) => {
+                for (index, item) in items.iter().enumerate() {
+                    self.lint_json_value(item, key, &format!("{pointer}/{index}"));
+                }
+            },
+            Value::Object(map) => {
+                let expression_keys = EXPRESSION_KEY_MAPS.contains(&key);
+                for (child_key, child_value) in map {
+                    let child_pointer = format!("{pointer}/{}", escape_pointer_token(child_key));
+                    if expression_keys {
+                        if child_key == PLUS {
+                            continue;
+                        }
+                        // account keys either set up or check accounts, both handled separately
+                        let check_addresses = key != "accounts";
+                        self.lint_expression(child_key, &child_pointer, check_addresses);
+                        // the entry contents are not named fields, but the storage value tree is still checked
+                        let child_field = if key == "storage" { "" } else { child_key };
+                        self.lint_json_value(child_value, child_field, &child_pointer);
+                    } else {
+                        self.lint_json_value(child_value, child_key, &child_pointer);
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn lint_expression(&mut self, expr: &str, pointer: &str, check_addresses: bool) {
+        if expr == STAR || expr == PLUS {
+            return;
+        }
+
+        let mut missing_file = false;
+        for part in expr.split('|') {
+            if let Some(file_path) = strip_any_prefix(part, FILE_PREFIXES) {
+                if !self.context.context_path.join(file_path).is_file() {
+                    missing_file = true;
+                    self.report(pointer.to_string(), format!("file not found: `{part}`"));
+                }
+            }
+
+            if check_addresses && strip_any_prefix(part, ADDRESS_PREFIXES).is_some() {
+                if let Ok(address) = try_interpret_string(part, &self.context) {
+                    if !self.is_account_set_up(&address) {
+                        self.report(pointer.to_string(), format!("undefined account `{part}`"));
+                    }
+                }
+            }
+        }
+
+        if missing_file {
+            return;
+        }
+        if let Err(err) = try_interpret_string(expr, &self.context) {
+            self.report(
+                pointer.to_string(),
+                format!("unparsable value `{expr}`: {err}"),
+            );
+        }
+    }
+}
+
+fn parent_dir(path: &Path) -> PathBuf {
+    path.parent().map(Path::to_path_buf).unwrap_or_default()
+}
+
+fn strip_any_prefix<'a>(s: &'a str, prefixes: &[&str]) -> Option<&'a str> {
+    prefixes.iter().find_map(|prefix| s.strip_prefix(prefix))
+}
+
+fn load_scenario_raw(path: &Path) -> Result<ScenarioRaw, String> {
+    let contents = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    ParsedScenario::parse(&contents).map(|parsed| parsed.raw)
+}
+
+fn try_interpret_string(expr: &str, context: &InterpreterContext) -> Result<Vec<u8>, String> {
+    catch_interpreter_panic(|| interpret_string(expr, context))
+}
+
+fn try_interpret_subtree(
+    value: &ValueSubTree,
+    context: &InterpreterContext,
+) -> Result<Vec<u8>, String> {
+    catch_interpreter_panic(|| interpret_subtree(value, context))
+}
+
+fn catch_interpreter_panic(f: impl FnOnce() -> Vec<u8>) -> Result<Vec<u8>, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else {
+            "invalid value".to_string()
+        }
+    })
+}
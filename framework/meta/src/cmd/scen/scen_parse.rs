@@ -0,0 +1,81 @@
+use dharitri_chain_scenario_format::serde_raw::ScenarioRaw;
+use serde_json::Value;
+
+/// A scenario file, parsed both as plain JSON and as [`ScenarioRaw`].
+pub struct ParsedScenario {
+    pub json: Value,
+    pub raw: ScenarioRaw,
+}
+
+impl ParsedScenario {
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let json: Value =
+            serde_json::from_str(contents).map_err(|err| format!("invalid JSON: {err}"))?;
+        let raw = serde_json::from_value(json.clone())
+            .map_err(|err| format!("invalid scenario: {err}"))?;
+        Ok(ParsedScenario { json, raw })
+    }
+
+    /// The keys not known to the scenario format, as JSON pointers.
+    ///
+    /// The parser skips them, so they would get lost when writing the scenario back.
+    pub fn unknown_keys(&self) -> Vec<String> {
+        let written = serde_json::to_value(&self.raw).unwrap();
+        let mut dropped = Vec::new();
+        collect_dropped_keys(&self.json, &written, "", &mut dropped);
+
+        // known keys are also dropped when they hold the default value, e.g. an empty map
+        dropped
+            .into_iter()
+            .filter(|pointer| self.is_skipped_by_parser(pointer))
+            .collect()
+    }
+
+    /// The parser only skips a key if it also accepts any value in its place.
+    fn is_skipped_by_parser(&self, pointer: &str) -> bool {
+        let mut probe = self.json.clone();
+        *probe.pointer_mut(pointer).unwrap() = Value::Array(vec![Value::Bool(true)]);
+        serde_json::from_value::<ScenarioRaw>(probe).is_ok()
+    }
+}
+
+fn collect_dropped_keys(
+    original: &Value,
+    written: &Value,
+    pointer: &str,
+    result: &mut Vec<String>,
+) {
+    match (original, written) {
+        (Value::Object(original_map), Value::Object(written_map)) => {
+            for (key, original_value) in original_map {
+                let child_pointer = format!("{pointer}/{}", escape_pointer_token(key));
+                match written_map.get(key) {
+                    Some(written_value) => {
+                        collect_dropped_keys(original_value, written_value, &child_pointer, result)
+                    },
+                    None => result.push(child_pointer),
+                }
+            }
+        },
+        (Value::Array(original_items), Value::Array(written_items))
+            if original_items.len() == written_items.len() =>
+        {
+            for (index, (original_item, written_item)) in
+                original_items.iter().zip(written_items).enumerate()
+            {
+                collect_dropped_keys(
+                    original_item,
+                    written_item,
+                    &format!("{pointer}/{index}"),
+                    result,
+                );
+            }
+        },
+        _ => {},
+    }
+}
+
+/// As specified in RFC 6901.
+pub fn escape_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
@@ -0,0 +1,188 @@
+use std::fs;
+
+use crate::cli::{TxAction, TxArgs, TxNewArgs, TxSendArgs, TxSignArgs, TxTrackArgs};
+use dharitri_sc_snippets::{
+    hex,
+    imports::{Address, Bech32Address, GatewayHttpProxy},
+    network_response,
+    sdk::{
+        bech32,
+        data::{keystore::InsertPassword, transaction::Transaction},
+        gateway::{GatewayAsyncService, GetAccountRequest, NetworkConfigRequest, SendTxRequest},
+        retrieve_tx_on_network,
+        utils::base64_encode,
+        wallet::Wallet,
+    },
+};
+
+const ROLE_SENDER: &str = "sender";
+const ROLE_GUARDIAN: &str = "guardian";
+const ROLE_RELAYER: &str = "relayer";
+
+pub async fn tx(args: &TxArgs) {
+    match &args.command {
+        TxAction::New(new_args) => new(new_args).await,
+        TxAction::Sign(sign_args) => sign(sign_args),
+        TxAction::Send(send_args) => send(send_args).await,
+        TxAction::Track(track_args) => track(track_args).await,
+    }
+}
+
+async fn new(new_args: &TxNewArgs) {
+    let proxy = GatewayHttpProxy::new(new_args.proxy.clone());
+    let network_config = proxy
+        .request(NetworkConfigRequest)
+        .await
+        .expect("failed to retrieve the network config");
+
+    let sender = bech32::decode(&new_args.sender);
+    let nonce = match new_args.nonce {
+        Some(nonce) => nonce,
+        None => {
+            proxy
+                .request(GetAccountRequest::new(&sender))
+                .await
+                .expect("failed to retrieve the sender account")
+                .nonce
+        },
+    };
+    let receiver = new_args
+        .receiver
+        .as_ref()
+        .map(|receiver| bech32::decode(receiver))
+        .unwrap_or_else(Address::zero);
+
+    let mut transaction = Transaction {
+        nonce,
+        value: new_args.value.clone(),
+        receiver: receiver.into(),
+        sender: sender.into(),
+        gas_price: network_config.min_gas_price,
+        gas_limit: new_args.gas_limit,
+        data: new_args.data.as_ref().map(base64_encode),
+        signature: None,
+        chain_id: network_config.chain_id,
+        version: network_config.min_transaction_version,
+        options: 0,
+        guardian: None,
+        guardian_signature: None,
+        relayer: None,
+        relayer_signature: None,
+    };
+    if let Some(guardian) = &new_args.guardian {
+        transaction.set_guardian(bech32::decode(guardian));
+    }
+    if let Some(relayer) = &new_args.relayer {
+        transaction.set_relayer(bech32::decode(relayer));
+    }
+
+    write_tx(&transaction, new_args.outfile.as_ref());
+}
+
+fn sign(sign_args: &TxSignArgs) {
+    let mut transaction = read_tx(&sign_args.infile);
+    let wallet = match (&sign_args.pem, &sign_args.keystore) {
+        (Some(pem), None) => Wallet::from_pem_file(pem).expect("invalid PEM file"),
+        (None, Some(keystore)) => {
+            Wallet::from_keystore_secret(keystore, InsertPassword::StandardInput)
+                .expect("invalid keystore file")
+        },
+        _ => panic!("exactly one of --pem or --keystore is required"),
+    };
+
+    let expected_signer = match sign_args.role.as_str() {
+        ROLE_SENDER => Some(&transaction.sender),
+        ROLE_GUARDIAN => transaction.guardian.as_ref(),
+        ROLE_RELAYER => transaction.relayer.as_ref(),
+        other => panic!("unsupported role: {other}"),
+    };
+    assert!(
+        expected_signer.is_some_and(|expected_signer| expected_signer.0 == wallet.to_address()),
+        "the wallet is not the {} of the transaction",
+        sign_args.role
+    );
+
+    // the signatures are not part of the signed content, so the parties can sign in any order
+    match sign_args.role.as_str() {
+        ROLE_GUARDIAN => {
+            let signature = wallet
+                .sign_tx_as_guardian(&transaction)
+                .expect("failed to co-sign the transaction");
+            transaction.guardian_signature = Some(hex::encode(signature));
+        },
+        ROLE_RELAYER => {
+            let signature = wallet
+                .sign_tx_as_relayer(&transaction)
+                .expect("failed to co-sign the transaction");
+            transaction.relayer_signature = Some(hex::encode(signature));
+        },
+        _ => {
+            let signature = wallet.sign_tx(&transaction);
+            transaction.signature = Some(hex::encode(signature));
+        },
+    }
+
+    write_tx(&transaction, sign_args.outfile.as_ref());
+}
+
+async fn send(send_args: &TxSendArgs) {
+    let transaction = read_tx(&send_args.infile);
+    Wallet::verify_tx_signatures(&transaction).expect("the transaction is not fully signed");
+
+    let proxy = GatewayHttpProxy::new(send_args.proxy.clone());
+    let tx_hash = proxy
+        .request(SendTxRequest(&transaction))
+        .await
+        .expect("the gateway rejected the transaction");
+    println!("tx hash: {tx_hash}");
+
+    print_tx_results(&proxy, tx_hash).await;
+}
+
+async fn track(track_args: &TxTrackArgs) {
+    let proxy = GatewayHttpProxy::new(track_args.proxy.clone());
+    print_tx_results(&proxy, track_args.hash.clone()).await;
+}
+
+async fn print_tx_results(proxy: &GatewayHttpProxy, tx_hash: String) {
+    let (tx, return_code) = retrieve_tx_on_network(proxy, tx_hash).await;
+    let response = network_response::parse_tx_response(tx, return_code);
+
+    if !response.tx_error.is_success() {
+        println!(
+            "status: fail ({}: {})",
+            response.tx_error.status.as_u64(),
+            response.tx_error.message
+        );
+        return;
+    }
+
+    println!("status: success");
+    println!("gas used: {}", response.gas_used);
+    if let Some(new_address) = &response.new_deployed_address {
+        println!("new address: {}", Bech32Address::from(new_address));
+    }
+    for result in &response.out {
+        println!("result: 0x{}", hex::encode(result));
+    }
+    for log in &response.logs {
+        println!(
+            "log: {} from {}",
+            log.endpoint,
+            Bech32Address::from(&log.address)
+        );
+    }
+}
+
+fn read_tx(infile: &str) -> Transaction {
+    let json = fs::read_to_string(infile).expect("failed to read the transaction file");
+    serde_json::from_str(&json).expect("invalid transaction JSON")
+}
+
+fn write_tx(transaction: &Transaction, outfile: Option<&String>) {
+    let json = serde_json::to_string_pretty(transaction).unwrap();
+    match outfile {
+        Some(outfile) => fs::write(outfile, json).unwrap(),
+        None => println!("{json}"),
+    }
+}
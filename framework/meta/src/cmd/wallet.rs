@@ -1,13 +1,19 @@
 use core::str;
 
-use crate::cli::{WalletAction, WalletArgs, WalletBech32Args, WalletConvertArgs, WalletNewArgs};
+use crate::cli::{
+    WalletAction, WalletArgs, WalletBech32Args, WalletConvertArgs, WalletDeriveArgs, WalletNewArgs,
+};
 use dharitri_sc::types::{self, Address};
 use dharitri_sc_snippets::sdk::bech32;
-use dharitri_sc_snippets::sdk::{crypto::public_key::PublicKey, wallet::Wallet};
+use dharitri_sc_snippets::sdk::{
+    crypto::public_key::PublicKey,
+    wallet::{MnemonicWallet, Wallet},
+};
 use dharitri_sc_snippets::{hex, imports::Bech32Address};
 use std::{
     fs::{self, File},
     io::{self, Read, Write},
+    ops::Range,
 };
 
 pub fn wallet(args: &WalletArgs) {
@@ -16,6 +22,7 @@ pub fn wallet(args: &WalletArgs) {
         WalletAction::New(new_args) => new(new_args),
         WalletAction::Bech32(bech32_args) => bech32_conversion(bech32_args),
         WalletAction::Convert(convert_args) => convert(convert_args),
+        WalletAction::Derive(derive_args) => derive(derive_args),
     }
 }
 
@@ -100,6 +107,61 @@ fn write_resulted_pem(public_key: &str, private_key: &str, outfile: Option<&Stri
     }
 }
 
+fn derive(derive_args: &WalletDeriveArgs) {
+    let mnemonic_wallet = match (derive_args.from.as_str(), derive_args.infile.as_ref()) {
+        ("mnemonic", Some(file)) => {
+            MnemonicWallet::from_mnemonic_str(&fs::read_to_string(file).unwrap()).unwrap()
+        },
+        ("mnemonic", None) => {
+            let mut mnemonic_str = String::new();
+            println!("Insert text below. Press 'Ctrl-D' (Linux / MacOS) or 'Ctrl-Z' (Windows) when done.");
+            _ = io::stdin().read_to_string(&mut mnemonic_str).unwrap();
+            MnemonicWallet::from_mnemonic_str(&mnemonic_str).unwrap()
+        },
+        ("keystore-mnemonic", Some(file)) => {
+            MnemonicWallet::from_keystore(file, &Wallet::get_keystore_password()).unwrap()
+        },
+        ("keystore-mnemonic", None) => {
+            panic!("Input file is required for keystore-mnemonic format");
+        },
+        _ => {
+            println!("Unsupported format");
+            return;
+        },
+    }
+    .with_account(derive_args.account);
+
+    let address_indices = derive_args.start..derive_args.start + derive_args.count;
+    print_derived_addresses(&mnemonic_wallet, address_indices.clone());
+    if derive_args.outfile.is_some() {
+        write_resulted_multi_pem(
+            mnemonic_wallet.to_multi_pem(address_indices),
+            derive_args.outfile.as_ref(),
+        );
+    }
+}
+
+fn print_derived_addresses(mnemonic_wallet: &MnemonicWallet, address_indices: Range<u32>) {
+    for (index, address) in address_indices
+        .clone()
+        .zip(mnemonic_wallet.addresses(address_indices))
+    {
+        println!("{index}: {}", bech32::encode(&address));
+    }
+}
+
+fn write_resulted_multi_pem(pem_content: String, outfile: Option<&String>) {
+    match outfile {
+        Some(outfile) => {
+            let mut file = File::create(outfile).unwrap();
+            file.write_all(pem_content.as_bytes()).unwrap();
+        },
+        None => {
+            print!("{}", pem_content);
+        },
+    }
+}
+
 fn write_resulted_keystore(json_result: String, outfile: Option<&String>) {
     match outfile {
         Some(outfile) => {
@@ -152,10 +214,25 @@ fn new(new_args: &WalletNewArgs) {
 
     println!("Wallet address: {}", bech32::encode(&address));
 
+    let mnemonic_wallet = MnemonicWallet::new(mnemonic);
+    if new_args.num_addresses > 1 {
+        print_derived_addresses(&mnemonic_wallet, 0..new_args.num_addresses);
+    }
+
     match format {
+        Some("pem") if new_args.num_addresses > 1 => {
+            write_resulted_multi_pem(
+                mnemonic_wallet.to_multi_pem(0..new_args.num_addresses),
+                outfile,
+            );
+        },
         Some("pem") => {
             write_resulted_pem(public_key_str.as_str(), private_key_str.as_str(), outfile);
         },
+        Some("keystore-mnemonic") => {
+            let json_result = mnemonic_wallet.encrypt_keystore(&Wallet::get_keystore_password());
+            write_resulted_keystore(json_result, outfile);
+        },
         Some("keystore-secret") => {
             let concatenated_keys = format!("{}{}", private_key_str, public_key_str);
             let hex_decoded_keys = hex::decode(concatenated_keys).unwrap();
@@ -0,0 +1,151 @@
+use std::fs;
+
+use dharitri_sc_meta::cmd::scen::{format_scenario_json, lint_scenario_file, ScenLintIssue};
+
+const UNFORMATTED: &str = r#"{
+  "steps": [
+    { "step": "setState", "comment": "accounts get sorted",
+      "accounts": { "sc:adder": { "balance": "0", "nonce": "0" }, "address:owner": { "nonce": "1" } } },
+    { "step": "checkState", "comment": "the + entry comes last",
+      "accounts": { "+": "", "address:owner": { "nonce": "*", "storage": { "+": "" } } } }
+  ],
+  "name": "fmt test"
+}"#;
+
+const FORMATTED: &str = r#"{
+    "name": "fmt test",
+    "steps": [
+        {
+            "step": "setState",
+            "comment": "accounts get sorted",
+            "accounts": {
+                "address:owner": {
+                    "nonce": "1"
+                },
+                "sc:adder": {
+                    "nonce": "0",
+                    "balance": "0"
+                }
+            }
+        },
+        {
+            "step": "checkState",
+            "comment": "the + entry comes last",
+            "accounts": {
+                "address:owner": {
+                    "nonce": "*",
+                    "storage": {
+                        "+": ""
+                    }
+                },
+                "+": ""
+            }
+        }
+    ]
+}
+"#;
+
+#[test]
+fn scen_fmt_canonical_test() {
+    let formatted = format_scenario_json(UNFORMATTED).unwrap();
+    assert_eq!(formatted, FORMATTED);
+    assert_eq!(format_scenario_json(&formatted).unwrap(), FORMATTED);
+}
+
+#[test]
+fn scen_fmt_unknown_keys_test() {
+    let err = format_scenario_json(
+        r#"{ "traceGas": true, "steps": [ { "step": "dumpState", "id": "dump" } ] }"#,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        "unknown keys, formatting would remove them: /traceGas, /steps/0/id"
+    );
+}
+
+const LINTED: &str = r#"{
+    "steps": [
+        {
+            "step": "setState",
+            "accounts": {
+                "address:owner": {
+                    "nonce": "0",
+                    "owner": "address:nobody"
+                },
+                "sc:adder": {
+                    "code": "file:missing.wasm",
+                    "storage": {
+                        "str:sum": "u8:300"
+                    }
+                }
+            }
+        },
+        {
+            "step": "scCall",
+            "id": "add",
+            "tx": {
+                "from": "address:owner",
+                "to": "sc:adder",
+                "function": "add",
+                "arguments": ["5"],
+                "gasLimit": "5,000,000"
+            },
+            "expect": {
+                "out": [],
+                "status": "0",
+                "gas": "*",
+                "unexpected": "1"
+            }
+        },
+        {
+            "step": "checkState",
+            "accounts": {
+                "address:owner": {
+                    "nonce": "1"
+                },
+                "address:other": {
+                    "nonce": "0"
+                },
+                "+": ""
+            }
+        }
+    ]
+}
+"#;
+
+#[test]
+fn scen_lint_test() {
+    let dir = std::env::temp_dir().join("scen-lint-test");
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("lint.scen.json");
+    fs::write(&path, LINTED).unwrap();
+
+    let issues = lint_scenario_file(&path);
+    let expected = [
+        ("/steps/1/expect/unexpected", "unknown key"),
+        (
+            "/steps/2/accounts/address:other",
+            "checks account `address:other`, which is never set up",
+        ),
+        (
+            "/steps/0/accounts/address:owner/owner",
+            "undefined account `address:nobody`",
+        ),
+        (
+            "/steps/0/accounts/sc:adder/code",
+            "file not found: `file:missing.wasm`",
+        ),
+        (
+            "/steps/0/accounts/sc:adder/storage/str:sum",
+            "unparsable value `u8:300`: representation of 300 does not fit in 1 bytes",
+        ),
+    ]
+    .map(|(location, message)| ScenLintIssue {
+        location: location.to_string(),
+        message: message.to_string(),
+    });
+    assert_eq!(issues, expected);
+
+    fs::remove_file(&path).unwrap();
+}
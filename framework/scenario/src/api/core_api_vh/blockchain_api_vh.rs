@@ -20,7 +20,9 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
 
     fn load_caller_managed(&self, dest: Self::ManagedBufferHandle) {
         self.assert_live_handle(&dest);
-        self.with_vm_hooks(|vh| vh.managed_caller(dest.get_raw_handle_unchecked()));
+        self.with_vm_hooks("load_caller_managed", |vh| {
+            vh.managed_caller(dest.get_raw_handle_unchecked())
+        });
     }
 
     fn get_sc_address_legacy(&self) -> Address {
@@ -29,11 +31,15 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
 
     fn load_sc_address_managed(&self, dest: Self::ManagedBufferHandle) {
         self.assert_live_handle(&dest);
-        self.with_vm_hooks(|vh| vh.managed_sc_address(dest.get_raw_handle_unchecked()));
+        self.with_vm_hooks("load_sc_address_managed", |vh| {
+            vh.managed_sc_address(dest.get_raw_handle_unchecked())
+        });
     }
 
     fn load_owner_address_managed(&self, dest: Self::ManagedBufferHandle) {
-        self.with_vm_hooks(|vh| vh.managed_owner_address(dest.get_raw_handle_unchecked()));
+        self.with_vm_hooks("load_owner_address_managed", |vh| {
+            vh.managed_owner_address(dest.get_raw_handle_unchecked())
+        });
     }
 
     fn get_shard_of_address_legacy(&self, _address: &Address) -> u32 {
@@ -43,7 +49,9 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
     fn get_shard_of_address(&self, address_handle: Self::ManagedBufferHandle) -> u32 {
         self.assert_live_handle(&address_handle);
         self.with_temp_address_ptr(address_handle, |address_ptr| {
-            self.with_vm_hooks(|vh| vh.get_shard_of_address(address_ptr))
+            self.with_vm_hooks("get_shard_of_address", |vh| {
+                vh.get_shard_of_address(address_ptr)
+            })
         }) as u32
     }
 
@@ -54,7 +62,7 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
     fn is_smart_contract(&self, address_handle: Self::ManagedBufferHandle) -> bool {
         self.assert_live_handle(&address_handle);
         let result = self.with_temp_address_ptr(address_handle, |address_ptr| {
-            self.with_vm_hooks(|vh| vh.is_smart_contract(address_ptr))
+            self.with_vm_hooks("is_smart_contract", |vh| vh.is_smart_contract(address_ptr))
         });
         i32_to_bool(result)
     }
@@ -67,7 +75,7 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
         self.assert_live_handle(&dest);
         self.assert_live_handle(&address_handle);
         self.with_temp_address_ptr(address_handle, |address_ptr: isize| {
-            self.with_vm_hooks(|vh| {
+            self.with_vm_hooks("load_balance", |vh| {
                 vh.big_int_get_external_balance(address_ptr, dest.get_raw_handle_unchecked())
             })
         });
@@ -83,48 +91,54 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
 
     fn load_tx_hash_managed(&self, dest: Self::ManagedBufferHandle) {
         self.assert_live_handle(&dest);
-        self.with_vm_hooks(|vh| vh.managed_get_original_tx_hash(dest.get_raw_handle_unchecked()));
+        self.with_vm_hooks("load_tx_hash_managed", |vh| {
+            vh.managed_get_original_tx_hash(dest.get_raw_handle_unchecked())
+        });
     }
 
     fn get_gas_left(&self) -> u64 {
-        self.with_vm_hooks(|vh| vh.get_gas_left()) as u64
+        self.with_vm_hooks("get_gas_left", |vh| vh.get_gas_left()) as u64
     }
 
     fn get_block_timestamp(&self) -> u64 {
-        self.with_vm_hooks(|vh| vh.get_block_timestamp()) as u64
+        self.with_vm_hooks("get_block_timestamp", |vh| vh.get_block_timestamp()) as u64
     }
 
     fn get_block_nonce(&self) -> u64 {
-        self.with_vm_hooks(|vh| vh.get_block_nonce()) as u64
+        self.with_vm_hooks("get_block_nonce", |vh| vh.get_block_nonce()) as u64
     }
 
     fn get_block_round(&self) -> u64 {
-        self.with_vm_hooks(|vh| vh.get_block_round()) as u64
+        self.with_vm_hooks("get_block_round", |vh| vh.get_block_round()) as u64
     }
 
     fn get_block_epoch(&self) -> u64 {
-        self.with_vm_hooks(|vh| vh.get_block_epoch()) as u64
+        self.with_vm_hooks("get_block_epoch", |vh| vh.get_block_epoch()) as u64
     }
 
     fn load_block_random_seed_managed(&self, dest: Self::ManagedBufferHandle) {
         self.assert_live_handle(&dest);
-        self.with_vm_hooks(|vh| vh.managed_get_block_random_seed(dest.get_raw_handle_unchecked()));
+        self.with_vm_hooks("load_block_random_seed_managed", |vh| {
+            vh.managed_get_block_random_seed(dest.get_raw_handle_unchecked())
+        });
     }
 
     fn get_prev_block_timestamp(&self) -> u64 {
-        self.with_vm_hooks(|vh| vh.get_prev_block_timestamp()) as u64
+        self.with_vm_hooks("get_prev_block_timestamp", |vh| {
+            vh.get_prev_block_timestamp()
+        }) as u64
     }
 
     fn get_prev_block_nonce(&self) -> u64 {
-        self.with_vm_hooks(|vh| vh.get_prev_block_nonce()) as u64
+        self.with_vm_hooks("get_prev_block_nonce", |vh| vh.get_prev_block_nonce()) as u64
     }
 
     fn get_prev_block_round(&self) -> u64 {
-        self.with_vm_hooks(|vh| vh.get_prev_block_round()) as u64
+        self.with_vm_hooks("get_prev_block_round", |vh| vh.get_prev_block_round()) as u64
     }
 
     fn get_prev_block_epoch(&self) -> u64 {
-        self.with_vm_hooks(|vh| vh.get_prev_block_epoch()) as u64
+        self.with_vm_hooks("get_prev_block_epoch", |vh| vh.get_prev_block_epoch()) as u64
     }
 
     fn get_prev_block_random_seed_legacy(&self) -> Box<[u8; 48]> {
@@ -133,7 +147,7 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
 
     fn load_prev_block_random_seed_managed(&self, dest: Self::ManagedBufferHandle) {
         self.assert_live_handle(&dest);
-        self.with_vm_hooks(|vh| {
+        self.with_vm_hooks("load_prev_block_random_seed_managed", |vh| {
             vh.managed_get_prev_block_random_seed(dest.get_raw_handle_unchecked())
         });
     }
@@ -148,7 +162,7 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
         let token_id_len = self.mb_len(token_id_handle.clone());
         let result = self.with_temp_address_ptr(address_handle, |address_ptr| {
             self.with_temp_buffer_ptr(token_id_handle, token_id_len, |token_id_ptr| {
-                self.with_vm_hooks(|vh| {
+                self.with_vm_hooks("get_current_dcdt_nft_nonce", |vh| {
                     vh.get_current_dcdt_nft_nonce(address_ptr, token_id_ptr, token_id_len as isize)
                 })
             })
@@ -168,7 +182,7 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
         let token_id_len = self.mb_len(token_id_handle.clone());
         self.with_temp_address_ptr(address_handle, |address_ptr| {
             self.with_temp_buffer_ptr(token_id_handle, token_id_len, |token_id_ptr| {
-                self.with_vm_hooks(|vh| {
+                self.with_vm_hooks("load_dcdt_balance", |vh| {
                     vh.big_int_get_dcdt_external_balance(
                         address_ptr,
                         token_id_ptr,
@@ -195,7 +209,7 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
         royalties_handle: RawHandle,
         uris_handle: RawHandle,
     ) {
-        self.with_vm_hooks(|vh| {
+        self.with_vm_hooks("managed_get_dcdt_token_data", |vh| {
             vh.managed_get_dcdt_token_data(
                 address_handle,
                 token_id_handle,
@@ -217,7 +231,7 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
         dcdt_transfer_value_handle: RawHandle,
         call_value_handle: RawHandle,
     ) {
-        self.with_vm_hooks(|vh| {
+        self.with_vm_hooks("managed_get_back_transfers", |vh| {
             vh.managed_get_back_transfers(dcdt_transfer_value_handle, call_value_handle)
         });
     }
@@ -230,7 +244,7 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
     ) -> bool {
         self.assert_live_handle(&address_handle);
         self.assert_live_handle(&token_id_handle);
-        let result = self.with_vm_hooks(|vh| {
+        let result = self.with_vm_hooks("check_dcdt_frozen", |vh| {
             vh.managed_is_dcdt_frozen(
                 address_handle.get_raw_handle_unchecked(),
                 token_id_handle.get_raw_handle_unchecked(),
@@ -242,7 +256,7 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
 
     fn check_dcdt_paused(&self, token_id_handle: Self::ManagedBufferHandle) -> bool {
         self.assert_live_handle(&token_id_handle);
-        let result = self.with_vm_hooks(|vh| {
+        let result = self.with_vm_hooks("check_dcdt_paused", |vh| {
             vh.managed_is_dcdt_paused(token_id_handle.get_raw_handle_unchecked())
         });
         i32_to_bool(result)
@@ -250,7 +264,7 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
 
     fn check_dcdt_limited_transfer(&self, token_id_handle: Self::ManagedBufferHandle) -> bool {
         self.assert_live_handle(&token_id_handle);
-        let result = self.with_vm_hooks(|vh| {
+        let result = self.with_vm_hooks("check_dcdt_limited_transfer", |vh| {
             vh.managed_is_dcdt_limited_transfer(token_id_handle.get_raw_handle_unchecked())
         });
         i32_to_bool(result)
@@ -261,7 +275,7 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
         token_id_handle: Self::ManagedBufferHandle,
     ) -> DcdtLocalRoleFlags {
         self.assert_live_handle(&token_id_handle);
-        let result = self.with_vm_hooks(|vh| {
+        let result = self.with_vm_hooks("load_dcdt_local_roles", |vh| {
             vh.get_dcdt_local_roles(token_id_handle.get_raw_handle_unchecked())
         });
 
@@ -269,7 +283,7 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
     }
 
     fn managed_is_builtin_function(&self, function_name_handle: Self::ManagedBufferHandle) -> bool {
-        i32_to_bool(self.with_vm_hooks(|vh| {
+        i32_to_bool(self.with_vm_hooks("managed_is_builtin_function", |vh| {
             vh.managed_is_builtin_function(function_name_handle.get_raw_handle_unchecked())
         }))
     }
@@ -279,7 +293,7 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
         address_handle: Self::ManagedBufferHandle,
         response_handle: Self::ManagedBufferHandle,
     ) {
-        self.with_vm_hooks(|vh| {
+        self.with_vm_hooks("managed_get_code_metadata", |vh| {
             vh.managed_get_code_metadata(
                 address_handle.get_raw_handle_unchecked(),
                 response_handle.get_raw_handle_unchecked(),
@@ -12,22 +12,24 @@ impl<VHB: VMHooksApiBackend> CallValueApi for VMHooksApi<VHB> {
 
 impl<VHB: VMHooksApiBackend> CallValueApiImpl for VMHooksApi<VHB> {
     fn check_not_payable(&self) {
-        self.with_vm_hooks(|vh| vh.check_no_payment())
+        self.with_vm_hooks("check_not_payable", |vh| vh.check_no_payment())
     }
 
     fn load_rewa_value(&self, dest: Self::BigIntHandle) {
         self.assert_live_handle(&dest);
-        self.with_vm_hooks(|vh| vh.big_int_get_call_value(dest.get_raw_handle_unchecked()));
+        self.with_vm_hooks("load_rewa_value", |vh| {
+            vh.big_int_get_call_value(dest.get_raw_handle_unchecked())
+        });
     }
 
     fn load_all_dcdt_transfers(&self, dest_handle: Self::ManagedBufferHandle) {
         self.assert_live_handle(&dest_handle);
-        self.with_vm_hooks(|vh| {
+        self.with_vm_hooks("load_all_dcdt_transfers", |vh| {
             vh.managed_get_multi_dcdt_call_value(dest_handle.get_raw_handle_unchecked())
         });
     }
 
     fn dcdt_num_transfers(&self) -> usize {
-        self.with_vm_hooks(|vh| vh.get_num_dcdt_transfers()) as usize
+        self.with_vm_hooks("dcdt_num_transfers", |vh| vh.get_num_dcdt_transfers()) as usize
     }
 }
@@ -13,7 +13,7 @@ impl<VHB: VMHooksApiBackend> EndpointFinishApi for VMHooksApi<VHB> {
 
 impl<VHB: VMHooksApiBackend> EndpointFinishApiImpl for VMHooksApi<VHB> {
     fn finish_slice_u8(&self, bytes: &[u8]) {
-        self.with_vm_hooks(|vh| {
+        self.with_vm_hooks("finish_slice_u8", |vh| {
             mem_conv::with_mem_ptr(bytes, |offset, length| {
                 vh.finish(offset, length);
             })
@@ -22,24 +22,32 @@ impl<VHB: VMHooksApiBackend> EndpointFinishApiImpl for VMHooksApi<VHB> {
 
     fn finish_big_int_raw(&self, handle: Self::BigIntHandle) {
         self.assert_live_handle(&handle);
-        self.with_vm_hooks(|vh| vh.big_int_finish_signed(handle.get_raw_handle_unchecked()));
+        self.with_vm_hooks("finish_big_int_raw", |vh| {
+            vh.big_int_finish_signed(handle.get_raw_handle_unchecked())
+        });
     }
 
     fn finish_big_uint_raw(&self, handle: Self::BigIntHandle) {
         self.assert_live_handle(&handle);
-        self.with_vm_hooks(|vh| vh.big_int_finish_unsigned(handle.get_raw_handle_unchecked()));
+        self.with_vm_hooks("finish_big_uint_raw", |vh| {
+            vh.big_int_finish_unsigned(handle.get_raw_handle_unchecked())
+        });
     }
 
     fn finish_managed_buffer_raw(&self, handle: Self::ManagedBufferHandle) {
         self.assert_live_handle(&handle);
-        self.with_vm_hooks(|vh| vh.mbuffer_finish(handle.get_raw_handle_unchecked()));
+        self.with_vm_hooks("finish_managed_buffer_raw", |vh| {
+            vh.mbuffer_finish(handle.get_raw_handle_unchecked())
+        });
     }
 
     fn finish_u64(&self, value: u64) {
-        self.with_vm_hooks(|vh| vh.small_int_finish_unsigned(value as i64));
+        self.with_vm_hooks("finish_u64", |vh| {
+            vh.small_int_finish_unsigned(value as i64)
+        });
     }
 
     fn finish_i64(&self, value: i64) {
-        self.with_vm_hooks(|vh| vh.small_int_finish_signed(value));
+        self.with_vm_hooks("finish_i64", |vh| vh.small_int_finish_signed(value));
     }
 }
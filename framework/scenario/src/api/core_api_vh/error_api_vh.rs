@@ -13,7 +13,7 @@ impl<VHB: VMHooksApiBackend> ErrorApi for VMHooksApi<VHB> {
 
 impl<VHB: VMHooksApiBackend> ErrorApiImpl for VMHooksApi<VHB> {
     fn signal_error(&self, message: &[u8]) -> ! {
-        self.with_vm_hooks(|vh| {
+        self.with_vm_hooks("signal_error", |vh| {
             mem_conv::with_mem_ptr(message, |offset, length| {
                 vh.signal_error(offset, length);
             })
@@ -26,7 +26,9 @@ impl<VHB: VMHooksApiBackend> ErrorApiImpl for VMHooksApi<VHB> {
 
     fn signal_error_from_buffer(&self, message_handle: Self::ManagedBufferHandle) -> ! {
         self.assert_live_handle(&message_handle);
-        self.with_vm_hooks(|vh| vh.managed_signal_error(message_handle.get_raw_handle()));
+        self.with_vm_hooks("signal_error_from_buffer", |vh| {
+            vh.managed_signal_error(message_handle.get_raw_handle())
+        });
 
         // even though not explicitly stated in the VM hooks definition,
         // `managed_signal_error` is expected to terminate execution
@@ -16,7 +16,7 @@ impl<VHB: VMHooksApiBackend> LogApiImpl for VMHooksApi<VHB> {
         topics_handle: Self::ManagedBufferHandle,
         data_handle: Self::ManagedBufferHandle,
     ) {
-        self.with_vm_hooks(|vh| {
+        self.with_vm_hooks("managed_write_log", |vh| {
             vh.managed_write_log(
                 topics_handle.get_raw_handle_unchecked(),
                 data_handle.get_raw_handle_unchecked(),
@@ -20,7 +20,7 @@ impl<VHB: VMHooksApiBackend> SendApiImpl for VMHooksApi<VHB> {
         endpoint_name_handle: RawHandle,
         arg_buffer_handle: RawHandle,
     ) -> Result<(), &'static [u8]> {
-        let result = self.with_vm_hooks(|vh| {
+        let result = self.with_vm_hooks("transfer_value_execute", |vh| {
             vh.managed_transfer_value_execute(
                 to_handle,
                 amount_handle,
@@ -44,7 +44,7 @@ impl<VHB: VMHooksApiBackend> SendApiImpl for VMHooksApi<VHB> {
         endpoint_name_handle: RawHandle,
         arg_buffer_handle: RawHandle,
     ) -> Result<(), &'static [u8]> {
-        let result = self.with_vm_hooks(|vh| {
+        let result = self.with_vm_hooks("multi_transfer_dcdt_nft_execute", |vh| {
             vh.managed_multi_transfer_dcdt_nft_execute(
                 to_handle,
                 payments_handle,
@@ -67,7 +67,7 @@ impl<VHB: VMHooksApiBackend> SendApiImpl for VMHooksApi<VHB> {
         endpoint_name_handle: RawHandle,
         arg_buffer_handle: RawHandle,
     ) -> ! {
-        self.with_vm_hooks(|vh| {
+        self.with_vm_hooks("async_call_raw", |vh| {
             vh.managed_async_call(
                 to_handle,
                 rewa_value_handle,
@@ -92,7 +92,7 @@ impl<VHB: VMHooksApiBackend> SendApiImpl for VMHooksApi<VHB> {
         extra_gas_for_callback: u64,
         callback_closure_handle: RawHandle,
     ) {
-        self.with_vm_hooks(|vh| {
+        self.with_vm_hooks("create_async_call_raw", |vh| {
             mem_conv::with_mem_ptr(
                 success_callback.as_bytes(),
                 |success_offset, success_length| {
@@ -129,7 +129,7 @@ impl<VHB: VMHooksApiBackend> SendApiImpl for VMHooksApi<VHB> {
         new_address_handle: RawHandle,
         result_handle: RawHandle,
     ) {
-        self.with_vm_hooks(|vh| {
+        self.with_vm_hooks("deploy_contract", |vh| {
             vh.managed_create_contract(
                 gas as i64,
                 rewa_value_handle,
@@ -152,7 +152,7 @@ impl<VHB: VMHooksApiBackend> SendApiImpl for VMHooksApi<VHB> {
         new_address_handle: RawHandle,
         result_handle: RawHandle,
     ) {
-        self.with_vm_hooks(|vh| {
+        self.with_vm_hooks("deploy_from_source_contract", |vh| {
             vh.managed_deploy_from_source_contract(
                 gas as i64,
                 rewa_value_handle,
@@ -178,7 +178,7 @@ impl<VHB: VMHooksApiBackend> SendApiImpl for VMHooksApi<VHB> {
         // The upgrade contract operation is an async call, so no results can be returned.
         let unused_result_handle = const_handles::MBUF_TEMPORARY_1;
 
-        self.with_vm_hooks(|vh| {
+        self.with_vm_hooks("upgrade_from_source_contract", |vh| {
             vh.managed_upgrade_from_source_contract(
                 sc_address_handle,
                 gas as i64,
@@ -204,7 +204,7 @@ impl<VHB: VMHooksApiBackend> SendApiImpl for VMHooksApi<VHB> {
         // The upgrade contract operation is an async call, so no results can be returned.
         let unused_result_handle = const_handles::MBUF_TEMPORARY_1;
 
-        self.with_vm_hooks(|vh| {
+        self.with_vm_hooks("upgrade_contract", |vh| {
             vh.managed_upgrade_contract(
                 sc_address_handle,
                 gas as i64,
@@ -217,6 +217,17 @@ impl<VHB: VMHooksApiBackend> SendApiImpl for VMHooksApi<VHB> {
         });
     }
 
+    fn delete_contract(
+        &self,
+        sc_address_handle: RawHandle,
+        gas: u64,
+        arg_buffer_handle: RawHandle,
+    ) {
+        self.with_vm_hooks("delete_contract", |vh| {
+            vh.managed_delete_contract(sc_address_handle, gas as i64, arg_buffer_handle)
+        });
+    }
+
     fn execute_on_dest_context_raw(
         &self,
         gas: u64,
@@ -226,7 +237,7 @@ impl<VHB: VMHooksApiBackend> SendApiImpl for VMHooksApi<VHB> {
         arg_buffer_handle: RawHandle,
         result_handle: RawHandle,
     ) {
-        self.with_vm_hooks(|vh| {
+        self.with_vm_hooks("execute_on_dest_context_raw", |vh| {
             vh.managed_execute_on_dest_context(
                 gas as i64,
                 to_handle,
@@ -262,10 +273,12 @@ impl<VHB: VMHooksApiBackend> SendApiImpl for VMHooksApi<VHB> {
     }
 
     fn clean_return_data(&self) {
-        self.with_vm_hooks(|vh| vh.clean_return_data());
+        self.with_vm_hooks("clean_return_data", |vh| vh.clean_return_data());
     }
 
     fn delete_from_return_data(&self, index: usize) {
-        self.with_vm_hooks(|vh| vh.delete_from_return_data(index as i32));
+        self.with_vm_hooks("delete_from_return_data", |vh| {
+            vh.delete_from_return_data(index as i32)
+        });
     }
 }
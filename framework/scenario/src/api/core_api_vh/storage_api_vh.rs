@@ -1,7 +1,10 @@
 use dharitri_sc::api::{
-    HandleConstraints, StorageReadApi, StorageReadApiImpl, StorageWriteApi, StorageWriteApiImpl,
+    HandleConstraints, ManagedBufferApiImpl, StorageReadApi, StorageReadApiImpl, StorageWriteApi,
+    StorageWriteApiImpl,
 };
 
+use dharitri_chain_vm::mem_conv;
+
 use crate::api::{VMHooksApi, VMHooksApiBackend};
 
 impl<VHB: VMHooksApiBackend> StorageReadApi for VMHooksApi<VHB> {
@@ -20,7 +23,7 @@ impl<VHB: VMHooksApiBackend> StorageReadApiImpl for VMHooksApi<VHB> {
     ) {
         self.assert_live_handle(&key_handle);
         self.assert_live_handle(&dest);
-        self.with_vm_hooks(|vh| {
+        self.with_vm_hooks("storage_load_managed_buffer_raw", |vh| {
             vh.mbuffer_storage_load(
                 key_handle.get_raw_handle_unchecked(),
                 dest.get_raw_handle_unchecked(),
@@ -37,7 +40,7 @@ impl<VHB: VMHooksApiBackend> StorageReadApiImpl for VMHooksApi<VHB> {
         self.assert_live_handle(&address_handle);
         self.assert_live_handle(&key_handle);
         self.assert_live_handle(&dest);
-        self.with_vm_hooks(|vh| {
+        self.with_vm_hooks("storage_load_from_address", |vh| {
             vh.mbuffer_storage_load_from_address(
                 address_handle.get_raw_handle_unchecked(),
                 key_handle.get_raw_handle_unchecked(),
@@ -45,6 +48,24 @@ impl<VHB: VMHooksApiBackend> StorageReadApiImpl for VMHooksApi<VHB> {
             );
         })
     }
+
+    fn storage_get_lock(&self, key_handle: Self::ManagedBufferHandle) -> i64 {
+        let key = self.mb_to_boxed_bytes(key_handle);
+        self.with_vm_hooks("storage_get_lock", |vh| {
+            mem_conv::with_mem_ptr(key.as_slice(), |offset, length| {
+                vh.get_storage_lock(offset, length)
+            })
+        })
+    }
+
+    fn storage_is_locked(&self, key_handle: Self::ManagedBufferHandle) -> i32 {
+        let key = self.mb_to_boxed_bytes(key_handle);
+        self.with_vm_hooks("storage_is_locked", |vh| {
+            mem_conv::with_mem_ptr(key.as_slice(), |offset, length| {
+                vh.is_storage_locked(offset, length)
+            })
+        })
+    }
 }
 
 impl<VHB: VMHooksApiBackend> StorageWriteApi for VMHooksApi<VHB> {
@@ -63,11 +84,29 @@ impl<VHB: VMHooksApiBackend> StorageWriteApiImpl for VMHooksApi<VHB> {
     ) {
         self.assert_live_handle(&key_handle);
         self.assert_live_handle(&value_handle);
-        self.with_vm_hooks(|vh| {
+        self.with_vm_hooks("storage_store_managed_buffer_raw", |vh| {
             vh.mbuffer_storage_store(
                 key_handle.get_raw_handle_unchecked(),
                 value_handle.get_raw_handle_unchecked(),
             );
         });
     }
+
+    fn storage_set_lock(&self, key_handle: Self::ManagedBufferHandle, lock_timestamp: i64) -> i32 {
+        let key = self.mb_to_boxed_bytes(key_handle);
+        self.with_vm_hooks("storage_set_lock", |vh| {
+            mem_conv::with_mem_ptr(key.as_slice(), |offset, length| {
+                vh.set_storage_lock(offset, length, lock_timestamp)
+            })
+        })
+    }
+
+    fn storage_clear_lock(&self, key_handle: Self::ManagedBufferHandle) -> i32 {
+        let key = self.mb_to_boxed_bytes(key_handle);
+        self.with_vm_hooks("storage_clear_lock", |vh| {
+            mem_conv::with_mem_ptr(key.as_slice(), |offset, length| {
+                vh.clear_storage_lock(offset, length)
+            })
+        })
+    }
 }
@@ -17,34 +17,44 @@ pub struct DebugApiBackend;
 impl VMHooksApiBackend for DebugApiBackend {
     type HandleType = DebugHandle;
 
-    fn with_vm_hooks<R, F>(f: F) -> R
+    fn with_vm_hooks<R, F>(hook_name: &'static str, f: F) -> R
     where
         F: FnOnce(&dyn VMHooks) -> R,
     {
         let top_context = TxContextStack::static_peek();
         let wrapper = DebugApiVMHooksHandler::new(top_context);
+        wrapper.use_gas_for_vm_hook();
+        wrapper.trace_vm_hook(hook_name);
         let dispatcher = VMHooksDispatcher::new(Box::new(wrapper));
         f(&dispatcher)
     }
 
-    fn with_vm_hooks_ctx_1<R, F>(handle: Self::HandleType, f: F) -> R
+    fn with_vm_hooks_ctx_1<R, F>(hook_name: &'static str, handle: Self::HandleType, f: F) -> R
     where
         F: FnOnce(&dyn VMHooks) -> R,
     {
         let wrapper = DebugApiVMHooksHandler::new(handle.context);
+        wrapper.use_gas_for_vm_hook();
+        wrapper.trace_vm_hook(hook_name);
         let dispatcher = VMHooksDispatcher::new(Box::new(wrapper));
         f(&dispatcher)
     }
 
-    fn with_vm_hooks_ctx_2<R, F>(handle1: Self::HandleType, handle2: Self::HandleType, f: F) -> R
+    fn with_vm_hooks_ctx_2<R, F>(
+        hook_name: &'static str,
+        handle1: Self::HandleType,
+        handle2: Self::HandleType,
+        f: F,
+    ) -> R
     where
         F: FnOnce(&dyn VMHooks) -> R,
     {
         assert_handles_on_same_context(&handle1, &handle2);
-        Self::with_vm_hooks_ctx_1(handle1, f)
+        Self::with_vm_hooks_ctx_1(hook_name, handle1, f)
     }
 
     fn with_vm_hooks_ctx_3<R, F>(
+        hook_name: &'static str,
         handle1: Self::HandleType,
         handle2: Self::HandleType,
         handle3: Self::HandleType,
@@ -55,7 +65,7 @@ impl VMHooksApiBackend for DebugApiBackend {
     {
         assert_handles_on_same_context(&handle1, &handle2);
         assert_handles_on_same_context(&handle1, &handle3);
-        Self::with_vm_hooks_ctx_1(handle1, f)
+        Self::with_vm_hooks_ctx_1(hook_name, handle1, f)
     }
 
     fn assert_live_handle(handle: &Self::HandleType) {
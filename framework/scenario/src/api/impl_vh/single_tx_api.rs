@@ -24,7 +24,7 @@ pub struct SingleTxApiBackend;
 impl VMHooksApiBackend for SingleTxApiBackend {
     type HandleType = RawHandle;
 
-    fn with_vm_hooks<R, F>(f: F) -> R
+    fn with_vm_hooks<R, F>(_hook_name: &'static str, f: F) -> R
     where
         F: FnOnce(&dyn VMHooks) -> R,
     {
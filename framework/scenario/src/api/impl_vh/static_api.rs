@@ -26,7 +26,7 @@ pub struct StaticApiBackend;
 impl VMHooksApiBackend for StaticApiBackend {
     type HandleType = RawHandle;
 
-    fn with_vm_hooks<R, F>(f: F) -> R
+    fn with_vm_hooks<R, F>(_hook_name: &'static str, f: F) -> R
     where
         F: FnOnce(&dyn VMHooks) -> R,
     {
@@ -20,24 +20,32 @@ impl<VHB: VMHooksApiBackend> VMHooksApi<VHB> {
     }
 
     /// All communication with the VM happens via this method.
-    pub fn with_vm_hooks<R, F>(&self, f: F) -> R
+    ///
+    /// The hook name is the name of the API method calling the VM hooks, it shows up in execution traces.
+    pub fn with_vm_hooks<R, F>(&self, hook_name: &'static str, f: F) -> R
     where
         F: FnOnce(&dyn VMHooks) -> R,
     {
-        VHB::with_vm_hooks(f)
+        VHB::with_vm_hooks(hook_name, f)
     }
 
     /// Works with the VM hooks given by the context of 1 handle.
-    pub fn with_vm_hooks_ctx_1<R, F>(&self, handle: &VHB::HandleType, f: F) -> R
+    pub fn with_vm_hooks_ctx_1<R, F>(
+        &self,
+        hook_name: &'static str,
+        handle: &VHB::HandleType,
+        f: F,
+    ) -> R
     where
         F: FnOnce(&dyn VMHooks) -> R,
     {
-        VHB::with_vm_hooks_ctx_1(handle.clone(), f)
+        VHB::with_vm_hooks_ctx_1(hook_name, handle.clone(), f)
     }
 
     /// Works with the VM hooks given by the context of 2 handles.
     pub fn with_vm_hooks_ctx_2<R, F>(
         &self,
+        hook_name: &'static str,
         handle1: &VHB::HandleType,
         handle2: &VHB::HandleType,
         f: F,
@@ -45,12 +53,13 @@ impl<VHB: VMHooksApiBackend> VMHooksApi<VHB> {
     where
         F: FnOnce(&dyn VMHooks) -> R,
     {
-        VHB::with_vm_hooks_ctx_2(handle1.clone(), handle2.clone(), f)
+        VHB::with_vm_hooks_ctx_2(hook_name, handle1.clone(), handle2.clone(), f)
     }
 
     /// Works with the VM hooks given by the context of 3 handles.
     pub fn with_vm_hooks_ctx_3<R, F>(
         &self,
+        hook_name: &'static str,
         handle1: &VHB::HandleType,
         handle2: &VHB::HandleType,
         handle3: &VHB::HandleType,
@@ -59,7 +68,13 @@ impl<VHB: VMHooksApiBackend> VMHooksApi<VHB> {
     where
         F: FnOnce(&dyn VMHooks) -> R,
     {
-        VHB::with_vm_hooks_ctx_3(handle1.clone(), handle2.clone(), handle3.clone(), f)
+        VHB::with_vm_hooks_ctx_3(
+            hook_name,
+            handle1.clone(),
+            handle2.clone(),
+            handle3.clone(),
+            f,
+        )
     }
 
     /// Checks that the handle refers to the current active context (if possible).
@@ -8,25 +8,33 @@ pub trait VMHooksApiBackend: Clone + Send + Sync + 'static {
     type HandleType: HandleConstraints;
 
     /// All communication with the VM happens via this method.
-    fn with_vm_hooks<R, F>(f: F) -> R
+    ///
+    /// The hook name is only used for tracing, backends that do not trace can ignore it.
+    fn with_vm_hooks<R, F>(hook_name: &'static str, f: F) -> R
     where
         F: FnOnce(&dyn VMHooks) -> R;
 
-    fn with_vm_hooks_ctx_1<R, F>(_handle: Self::HandleType, f: F) -> R
+    fn with_vm_hooks_ctx_1<R, F>(hook_name: &'static str, _handle: Self::HandleType, f: F) -> R
     where
         F: FnOnce(&dyn VMHooks) -> R,
     {
-        Self::with_vm_hooks(f)
+        Self::with_vm_hooks(hook_name, f)
     }
 
-    fn with_vm_hooks_ctx_2<R, F>(_handle1: Self::HandleType, _handle2: Self::HandleType, f: F) -> R
+    fn with_vm_hooks_ctx_2<R, F>(
+        hook_name: &'static str,
+        _handle1: Self::HandleType,
+        _handle2: Self::HandleType,
+        f: F,
+    ) -> R
     where
         F: FnOnce(&dyn VMHooks) -> R,
     {
-        Self::with_vm_hooks(f)
+        Self::with_vm_hooks(hook_name, f)
     }
 
     fn with_vm_hooks_ctx_3<R, F>(
+        hook_name: &'static str,
         _handle1: Self::HandleType,
         _handle2: Self::HandleType,
         _handle3: Self::HandleType,
@@ -35,7 +43,7 @@ pub trait VMHooksApiBackend: Clone + Send + Sync + 'static {
     where
         F: FnOnce(&dyn VMHooks) -> R,
     {
-        Self::with_vm_hooks(f)
+        Self::with_vm_hooks(hook_name, f)
     }
 
     fn assert_live_handle(_handle: &Self::HandleType) {
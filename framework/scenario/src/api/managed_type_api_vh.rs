@@ -22,12 +22,17 @@ impl<VHB: VMHooksApiBackend> ManagedTypeApiImpl for VMHooksApi<VHB> {
         buffer_handle: Self::ManagedBufferHandle,
         big_int_handle: Self::BigIntHandle,
     ) {
-        self.with_vm_hooks_ctx_2(&buffer_handle, &big_int_handle, |vh| {
-            vh.mbuffer_to_big_int_unsigned(
-                buffer_handle.get_raw_handle_unchecked(),
-                big_int_handle.get_raw_handle_unchecked(),
-            )
-        });
+        self.with_vm_hooks_ctx_2(
+            "mb_to_big_int_unsigned",
+            &buffer_handle,
+            &big_int_handle,
+            |vh| {
+                vh.mbuffer_to_big_int_unsigned(
+                    buffer_handle.get_raw_handle_unchecked(),
+                    big_int_handle.get_raw_handle_unchecked(),
+                )
+            },
+        );
     }
 
     fn mb_to_big_int_signed(
@@ -35,12 +40,17 @@ impl<VHB: VMHooksApiBackend> ManagedTypeApiImpl for VMHooksApi<VHB> {
         buffer_handle: Self::ManagedBufferHandle,
         big_int_handle: Self::BigIntHandle,
     ) {
-        self.with_vm_hooks_ctx_2(&buffer_handle, &big_int_handle, |vh| {
-            vh.mbuffer_to_big_int_signed(
-                buffer_handle.get_raw_handle_unchecked(),
-                big_int_handle.get_raw_handle_unchecked(),
-            )
-        });
+        self.with_vm_hooks_ctx_2(
+            "mb_to_big_int_signed",
+            &buffer_handle,
+            &big_int_handle,
+            |vh| {
+                vh.mbuffer_to_big_int_signed(
+                    buffer_handle.get_raw_handle_unchecked(),
+                    big_int_handle.get_raw_handle_unchecked(),
+                )
+            },
+        );
     }
 
     fn mb_from_big_int_unsigned(
@@ -48,12 +58,17 @@ impl<VHB: VMHooksApiBackend> ManagedTypeApiImpl for VMHooksApi<VHB> {
         big_int_handle: Self::BigIntHandle,
         buffer_handle: Self::ManagedBufferHandle,
     ) {
-        self.with_vm_hooks_ctx_2(&buffer_handle, &big_int_handle, |vh| {
-            vh.mbuffer_from_big_int_unsigned(
-                buffer_handle.get_raw_handle_unchecked(),
-                big_int_handle.get_raw_handle_unchecked(),
-            )
-        });
+        self.with_vm_hooks_ctx_2(
+            "mb_from_big_int_unsigned",
+            &buffer_handle,
+            &big_int_handle,
+            |vh| {
+                vh.mbuffer_from_big_int_unsigned(
+                    buffer_handle.get_raw_handle_unchecked(),
+                    big_int_handle.get_raw_handle_unchecked(),
+                )
+            },
+        );
     }
 
     fn mb_from_big_int_signed(
@@ -61,12 +76,17 @@ impl<VHB: VMHooksApiBackend> ManagedTypeApiImpl for VMHooksApi<VHB> {
         big_int_handle: Self::BigIntHandle,
         buffer_handle: Self::ManagedBufferHandle,
     ) {
-        self.with_vm_hooks_ctx_2(&buffer_handle, &big_int_handle, |vh| {
-            vh.mbuffer_from_big_int_signed(
-                buffer_handle.get_raw_handle_unchecked(),
-                big_int_handle.get_raw_handle_unchecked(),
-            )
-        });
+        self.with_vm_hooks_ctx_2(
+            "mb_from_big_int_signed",
+            &buffer_handle,
+            &big_int_handle,
+            |vh| {
+                vh.mbuffer_from_big_int_signed(
+                    buffer_handle.get_raw_handle_unchecked(),
+                    big_int_handle.get_raw_handle_unchecked(),
+                )
+            },
+        );
     }
 
     fn mb_to_big_float(
@@ -74,7 +94,7 @@ impl<VHB: VMHooksApiBackend> ManagedTypeApiImpl for VMHooksApi<VHB> {
         buffer_handle: Self::ManagedBufferHandle,
         big_float_handle: Self::BigFloatHandle,
     ) {
-        self.with_vm_hooks_ctx_2(&buffer_handle, &big_float_handle, |vh| {
+        self.with_vm_hooks_ctx_2("mb_to_big_float", &buffer_handle, &big_float_handle, |vh| {
             vh.mbuffer_to_big_float(
                 buffer_handle.get_raw_handle_unchecked(),
                 big_float_handle.get_raw_handle_unchecked(),
@@ -87,11 +107,16 @@ impl<VHB: VMHooksApiBackend> ManagedTypeApiImpl for VMHooksApi<VHB> {
         big_float_handle: Self::BigFloatHandle,
         buffer_handle: Self::ManagedBufferHandle,
     ) {
-        self.with_vm_hooks_ctx_2(&buffer_handle, &big_float_handle, |vh| {
-            vh.mbuffer_from_big_float(
-                buffer_handle.get_raw_handle_unchecked(),
-                big_float_handle.get_raw_handle_unchecked(),
-            )
-        });
+        self.with_vm_hooks_ctx_2(
+            "mb_from_big_float",
+            &buffer_handle,
+            &big_float_handle,
+            |vh| {
+                vh.mbuffer_from_big_float(
+                    buffer_handle.get_raw_handle_unchecked(),
+                    big_float_handle.get_raw_handle_unchecked(),
+                )
+            },
+        );
     }
 }
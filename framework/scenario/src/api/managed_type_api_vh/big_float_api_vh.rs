@@ -12,7 +12,7 @@ macro_rules! binary_op_wrapper {
             x: Self::BigFloatHandle,
             y: Self::BigFloatHandle,
         ) {
-            self.with_vm_hooks_ctx_3(&dest, &x, &y, |vh| {
+            self.with_vm_hooks_ctx_3(stringify!($method_name), &dest, &x, &y, |vh| {
                 vh.$hook_name(
                     dest.get_raw_handle_unchecked(),
                     x.get_raw_handle_unchecked(),
@@ -26,7 +26,7 @@ macro_rules! binary_op_wrapper {
 macro_rules! unary_op_wrapper {
     ($method_name:ident, $hook_name:ident) => {
         fn $method_name(&self, dest: Self::BigFloatHandle, x: Self::BigFloatHandle) {
-            self.with_vm_hooks_ctx_2(&dest, &x, |vh| {
+            self.with_vm_hooks_ctx_2(stringify!($method_name), &dest, &x, |vh| {
                 vh.$hook_name(
                     dest.get_raw_handle_unchecked(),
                     x.get_raw_handle_unchecked(),
@@ -39,7 +39,7 @@ macro_rules! unary_op_wrapper {
 macro_rules! unary_op_method_big_int_handle {
     ($method_name:ident, $hook_name:ident) => {
         fn $method_name(&self, dest: Self::BigIntHandle, x: Self::BigFloatHandle) {
-            self.with_vm_hooks_ctx_2(&dest, &x, |vh| {
+            self.with_vm_hooks_ctx_2(stringify!($method_name), &dest, &x, |vh| {
                 vh.$hook_name(
                     dest.get_raw_handle_unchecked(),
                     x.get_raw_handle_unchecked(),
@@ -56,21 +56,23 @@ impl<VHB: VMHooksApiBackend> BigFloatApiImpl for VMHooksApi<VHB> {
         fractional_part_value: i32,
         exponent_value: i32,
     ) -> Self::BigFloatHandle {
-        let raw_handle = self.with_vm_hooks(|vh| {
+        let raw_handle = self.with_vm_hooks("bf_from_parts", |vh| {
             vh.big_float_new_from_parts(integral_part_value, fractional_part_value, exponent_value)
         });
         use_raw_handle(raw_handle)
     }
 
     fn bf_from_frac(&self, numerator_value: i64, denominator_value: i64) -> Self::BigFloatHandle {
-        let raw_handle =
-            self.with_vm_hooks(|vh| vh.big_float_new_from_frac(numerator_value, denominator_value));
+        let raw_handle = self.with_vm_hooks("bf_from_frac", |vh| {
+            vh.big_float_new_from_frac(numerator_value, denominator_value)
+        });
         use_raw_handle(raw_handle)
     }
 
     fn bf_from_sci(&self, significand_value: i64, exponent_value: i64) -> Self::BigFloatHandle {
-        let raw_handle =
-            self.with_vm_hooks(|vh| vh.big_float_new_from_sci(significand_value, exponent_value));
+        let raw_handle = self.with_vm_hooks("bf_from_sci", |vh| {
+            vh.big_float_new_from_sci(significand_value, exponent_value)
+        });
         use_raw_handle(raw_handle)
     }
 
@@ -83,15 +85,16 @@ impl<VHB: VMHooksApiBackend> BigFloatApiImpl for VMHooksApi<VHB> {
     unary_op_wrapper! {bf_abs, big_float_abs}
 
     fn bf_cmp(&self, x: Self::BigFloatHandle, y: Self::BigFloatHandle) -> Ordering {
-        let result = self.with_vm_hooks_ctx_2(&x, &y, |vh| {
+        let result = self.with_vm_hooks_ctx_2("bf_cmp", &x, &y, |vh| {
             vh.big_float_cmp(x.get_raw_handle_unchecked(), y.get_raw_handle_unchecked())
         });
         result.cmp(&0)
     }
 
     fn bf_sign(&self, x: Self::BigFloatHandle) -> Sign {
-        let result =
-            self.with_vm_hooks_ctx_1(&x, |vh| vh.big_float_sign(x.get_raw_handle_unchecked()));
+        let result = self.with_vm_hooks_ctx_1("bf_sign", &x, |vh| {
+            vh.big_float_sign(x.get_raw_handle_unchecked())
+        });
         match result.cmp(&0) {
             Ordering::Greater => Sign::Plus,
             Ordering::Equal => Sign::NoSign,
@@ -103,7 +106,7 @@ impl<VHB: VMHooksApiBackend> BigFloatApiImpl for VMHooksApi<VHB> {
     unary_op_wrapper! {bf_sqrt, big_float_sqrt}
 
     fn bf_pow(&self, dest: Self::BigFloatHandle, x: Self::BigFloatHandle, exp: i32) {
-        self.with_vm_hooks_ctx_2(&dest, &x, |vh| {
+        self.with_vm_hooks_ctx_2("bf_pow", &dest, &x, |vh| {
             vh.big_float_pow(
                 dest.get_raw_handle_unchecked(),
                 x.get_raw_handle_unchecked(),
@@ -117,19 +120,19 @@ impl<VHB: VMHooksApiBackend> BigFloatApiImpl for VMHooksApi<VHB> {
     unary_op_method_big_int_handle! {bf_trunc , big_float_truncate}
 
     fn bf_is_bi(&self, x: Self::BigFloatHandle) -> bool {
-        i32_to_bool(
-            self.with_vm_hooks_ctx_1(&x, |vh| vh.big_float_is_int(x.get_raw_handle_unchecked())),
-        )
+        i32_to_bool(self.with_vm_hooks_ctx_1("bf_is_bi", &x, |vh| {
+            vh.big_float_is_int(x.get_raw_handle_unchecked())
+        }))
     }
 
     fn bf_set_i64(&self, dest: Self::BigFloatHandle, value: i64) {
-        self.with_vm_hooks_ctx_1(&dest, |vh| {
+        self.with_vm_hooks_ctx_1("bf_set_i64", &dest, |vh| {
             vh.big_float_set_int64(dest.get_raw_handle_unchecked(), value)
         });
     }
 
     fn bf_set_bi(&self, dest: Self::BigFloatHandle, x: Self::BigIntHandle) {
-        self.with_vm_hooks_ctx_2(&dest, &x, |vh| {
+        self.with_vm_hooks_ctx_2("bf_set_bi", &dest, &x, |vh| {
             vh.big_float_set_big_int(
                 dest.get_raw_handle_unchecked(),
                 x.get_raw_handle_unchecked(),
@@ -138,13 +141,13 @@ impl<VHB: VMHooksApiBackend> BigFloatApiImpl for VMHooksApi<VHB> {
     }
 
     fn bf_get_const_e(&self, dest: Self::BigFloatHandle) {
-        self.with_vm_hooks_ctx_1(&dest, |vh| {
+        self.with_vm_hooks_ctx_1("bf_get_const_e", &dest, |vh| {
             vh.big_float_get_const_e(dest.get_raw_handle_unchecked())
         });
     }
 
     fn bf_get_const_pi(&self, dest: Self::BigFloatHandle) {
-        self.with_vm_hooks_ctx_1(&dest, |vh| {
+        self.with_vm_hooks_ctx_1("bf_get_const_pi", &dest, |vh| {
             vh.big_float_get_const_pi(dest.get_raw_handle_unchecked())
         });
     }
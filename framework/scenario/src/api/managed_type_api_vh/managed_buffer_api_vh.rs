@@ -7,12 +7,12 @@ use dharitri_sc::{
 
 impl<VHB: VMHooksApiBackend> ManagedBufferApiImpl for VMHooksApi<VHB> {
     fn mb_new_empty(&self) -> Self::ManagedBufferHandle {
-        let raw_handle = self.with_vm_hooks(|vh| vh.mbuffer_new());
+        let raw_handle = self.with_vm_hooks("mb_new_empty", |vh| vh.mbuffer_new());
         use_raw_handle(raw_handle)
     }
 
     fn mb_new_from_bytes(&self, bytes: &[u8]) -> Self::ManagedBufferHandle {
-        let raw_handle = self.with_vm_hooks(|vh| {
+        let raw_handle = self.with_vm_hooks("mb_new_from_bytes", |vh| {
             mem_conv::with_mem_ptr(bytes, |offset, length| {
                 vh.mbuffer_new_from_bytes(offset, length)
             })
@@ -21,13 +21,13 @@ impl<VHB: VMHooksApiBackend> ManagedBufferApiImpl for VMHooksApi<VHB> {
     }
 
     fn mb_len(&self, handle: Self::ManagedBufferHandle) -> usize {
-        self.with_vm_hooks_ctx_1(&handle, |vh| {
+        self.with_vm_hooks_ctx_1("mb_len", &handle, |vh| {
             vh.mbuffer_get_length(handle.get_raw_handle_unchecked()) as usize
         })
     }
 
     fn mb_to_boxed_bytes(&self, handle: Self::ManagedBufferHandle) -> BoxedBytes {
-        self.with_vm_hooks_ctx_1(&handle, |vh| {
+        self.with_vm_hooks_ctx_1("mb_to_boxed_bytes", &handle, |vh| {
             let len = vh.mbuffer_get_length(handle.get_raw_handle_unchecked()) as usize;
             let mut res = BoxedBytes::zeros(len);
             if len > 0 {
@@ -46,7 +46,7 @@ impl<VHB: VMHooksApiBackend> ManagedBufferApiImpl for VMHooksApi<VHB> {
         starting_position: usize,
         dest_slice: &mut [u8],
     ) -> Result<(), InvalidSliceError> {
-        let err = self.with_vm_hooks_ctx_1(&source_handle, |vh| {
+        let err = self.with_vm_hooks_ctx_1("mb_load_slice", &source_handle, |vh| {
             mem_conv::with_mem_ptr_mut(dest_slice, |offset, length| {
                 vh.mbuffer_get_byte_slice(
                     source_handle.get_raw_handle_unchecked(),
@@ -70,7 +70,7 @@ impl<VHB: VMHooksApiBackend> ManagedBufferApiImpl for VMHooksApi<VHB> {
         slice_len: usize,
         dest_handle: Self::ManagedBufferHandle,
     ) -> Result<(), InvalidSliceError> {
-        let err = self.with_vm_hooks_ctx_2(&source_handle, &dest_handle, |vh| {
+        let err = self.with_vm_hooks_ctx_2("mb_copy_slice", &source_handle, &dest_handle, |vh| {
             vh.mbuffer_copy_byte_slice(
                 source_handle.get_raw_handle_unchecked(),
                 starting_pos as i32,
@@ -86,7 +86,7 @@ impl<VHB: VMHooksApiBackend> ManagedBufferApiImpl for VMHooksApi<VHB> {
     }
 
     fn mb_overwrite(&self, handle: Self::ManagedBufferHandle, value: &[u8]) {
-        self.with_vm_hooks_ctx_1(&handle, |vh| {
+        self.with_vm_hooks_ctx_1("mb_overwrite", &handle, |vh| {
             mem_conv::with_mem_ptr(value, |offset, length| {
                 vh.mbuffer_set_bytes(handle.get_raw_handle_unchecked(), offset, length);
             })
@@ -99,7 +99,7 @@ impl<VHB: VMHooksApiBackend> ManagedBufferApiImpl for VMHooksApi<VHB> {
         starting_position: usize,
         source_slice: &[u8],
     ) -> Result<(), InvalidSliceError> {
-        let err = self.with_vm_hooks_ctx_1(&dest_handle, |vh| {
+        let err = self.with_vm_hooks_ctx_1("mb_set_slice", &dest_handle, |vh| {
             mem_conv::with_mem_ptr(source_slice, |offset, length| {
                 vh.mbuffer_set_byte_slice(
                     dest_handle.get_raw_handle_unchecked(),
@@ -117,7 +117,7 @@ impl<VHB: VMHooksApiBackend> ManagedBufferApiImpl for VMHooksApi<VHB> {
     }
 
     fn mb_set_random(&self, dest_handle: Self::ManagedBufferHandle, length: usize) {
-        self.with_vm_hooks_ctx_1(&dest_handle, |vh| {
+        self.with_vm_hooks_ctx_1("mb_set_random", &dest_handle, |vh| {
             vh.mbuffer_set_random(dest_handle.get_raw_handle_unchecked(), length as i32)
         });
     }
@@ -127,7 +127,7 @@ impl<VHB: VMHooksApiBackend> ManagedBufferApiImpl for VMHooksApi<VHB> {
         accumulator_handle: Self::ManagedBufferHandle,
         data_handle: Self::ManagedBufferHandle,
     ) {
-        self.with_vm_hooks_ctx_2(&accumulator_handle, &data_handle, |vh| {
+        self.with_vm_hooks_ctx_2("mb_append", &accumulator_handle, &data_handle, |vh| {
             vh.mbuffer_append(
                 accumulator_handle.get_raw_handle_unchecked(),
                 data_handle.get_raw_handle_unchecked(),
@@ -136,7 +136,7 @@ impl<VHB: VMHooksApiBackend> ManagedBufferApiImpl for VMHooksApi<VHB> {
     }
 
     fn mb_append_bytes(&self, accumulator_handle: Self::ManagedBufferHandle, bytes: &[u8]) {
-        self.with_vm_hooks_ctx_1(&accumulator_handle, |vh| {
+        self.with_vm_hooks_ctx_1("mb_append_bytes", &accumulator_handle, |vh| {
             mem_conv::with_mem_ptr(bytes, |offset, length| {
                 let _ = vh.mbuffer_append_bytes(
                     accumulator_handle.get_raw_handle_unchecked(),
@@ -152,7 +152,7 @@ impl<VHB: VMHooksApiBackend> ManagedBufferApiImpl for VMHooksApi<VHB> {
         handle1: Self::ManagedBufferHandle,
         handle2: Self::ManagedBufferHandle,
     ) -> bool {
-        i32_to_bool(self.with_vm_hooks_ctx_2(&handle1, &handle2, |vh| {
+        i32_to_bool(self.with_vm_hooks_ctx_2("mb_eq", &handle1, &handle2, |vh| {
             vh.mbuffer_eq(
                 handle1.get_raw_handle_unchecked(),
                 handle2.get_raw_handle_unchecked(),
@@ -165,7 +165,7 @@ impl<VHB: VMHooksApiBackend> ManagedBufferApiImpl for VMHooksApi<VHB> {
         source_handle: Self::ManagedBufferHandle,
         dest_handle: Self::ManagedBufferHandle,
     ) {
-        self.with_vm_hooks_ctx_2(&source_handle, &dest_handle, |vh| {
+        self.with_vm_hooks_ctx_2("mb_to_hex", &source_handle, &dest_handle, |vh| {
             vh.managed_buffer_to_hex(
                 source_handle.get_raw_handle_unchecked(),
                 dest_handle.get_raw_handle_unchecked(),
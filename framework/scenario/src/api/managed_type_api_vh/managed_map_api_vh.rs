@@ -4,7 +4,7 @@ use crate::api::{i32_to_bool, VMHooksApi, VMHooksApiBackend};
 
 impl<VHB: VMHooksApiBackend> ManagedMapApiImpl for VMHooksApi<VHB> {
     fn mm_new(&self) -> Self::ManagedMapHandle {
-        let raw_handle = self.with_vm_hooks(|vh| vh.managed_map_new());
+        let raw_handle = self.with_vm_hooks("mm_new", |vh| vh.managed_map_new());
         use_raw_handle(raw_handle)
     }
 
@@ -14,13 +14,19 @@ impl<VHB: VMHooksApiBackend> ManagedMapApiImpl for VMHooksApi<VHB> {
         key_handle: Self::ManagedBufferHandle,
         out_value_handle: Self::ManagedBufferHandle,
     ) {
-        self.with_vm_hooks_ctx_3(&map_handle, &key_handle, &out_value_handle, |vh| {
-            vh.managed_map_get(
-                map_handle.get_raw_handle_unchecked(),
-                key_handle.get_raw_handle_unchecked(),
-                out_value_handle.get_raw_handle_unchecked(),
-            )
-        });
+        self.with_vm_hooks_ctx_3(
+            "mm_get",
+            &map_handle,
+            &key_handle,
+            &out_value_handle,
+            |vh| {
+                vh.managed_map_get(
+                    map_handle.get_raw_handle_unchecked(),
+                    key_handle.get_raw_handle_unchecked(),
+                    out_value_handle.get_raw_handle_unchecked(),
+                )
+            },
+        );
     }
 
     fn mm_put(
@@ -29,7 +35,7 @@ impl<VHB: VMHooksApiBackend> ManagedMapApiImpl for VMHooksApi<VHB> {
         key_handle: Self::ManagedBufferHandle,
         value_handle: Self::ManagedBufferHandle,
     ) {
-        self.with_vm_hooks_ctx_3(&map_handle, &key_handle, &value_handle, |vh| {
+        self.with_vm_hooks_ctx_3("mm_put", &map_handle, &key_handle, &value_handle, |vh| {
             vh.managed_map_put(
                 map_handle.get_raw_handle_unchecked(),
                 key_handle.get_raw_handle_unchecked(),
@@ -44,13 +50,19 @@ impl<VHB: VMHooksApiBackend> ManagedMapApiImpl for VMHooksApi<VHB> {
         key_handle: Self::ManagedBufferHandle,
         out_value_handle: Self::ManagedBufferHandle,
     ) {
-        self.with_vm_hooks_ctx_3(&map_handle, &key_handle, &out_value_handle, |vh| {
-            vh.managed_map_remove(
-                map_handle.get_raw_handle_unchecked(),
-                key_handle.get_raw_handle_unchecked(),
-                out_value_handle.get_raw_handle_unchecked(),
-            )
-        });
+        self.with_vm_hooks_ctx_3(
+            "mm_remove",
+            &map_handle,
+            &key_handle,
+            &out_value_handle,
+            |vh| {
+                vh.managed_map_remove(
+                    map_handle.get_raw_handle_unchecked(),
+                    key_handle.get_raw_handle_unchecked(),
+                    out_value_handle.get_raw_handle_unchecked(),
+                )
+            },
+        );
     }
 
     fn mm_contains(
@@ -58,11 +70,13 @@ impl<VHB: VMHooksApiBackend> ManagedMapApiImpl for VMHooksApi<VHB> {
         map_handle: Self::ManagedMapHandle,
         key_handle: Self::ManagedBufferHandle,
     ) -> bool {
-        i32_to_bool(self.with_vm_hooks_ctx_2(&map_handle, &key_handle, |vh| {
-            vh.managed_map_contains(
-                map_handle.get_raw_handle_unchecked(),
-                key_handle.get_raw_handle_unchecked(),
-            )
-        }))
+        i32_to_bool(
+            self.with_vm_hooks_ctx_2("mm_contains", &map_handle, &key_handle, |vh| {
+                vh.managed_map_contains(
+                    map_handle.get_raw_handle_unchecked(),
+                    key_handle.get_raw_handle_unchecked(),
+                )
+            }),
+        )
     }
 }
@@ -2,9 +2,11 @@ mod contract_info;
 mod debugger_backend;
 pub mod expr;
 pub mod result_handlers;
+pub mod scenario_fuzz;
 mod scenario_world;
 mod scenario_world_register;
 mod scenario_world_runner;
+mod scenario_world_snapshot;
 mod scenario_world_steps;
 mod scenario_world_steps_deprecated;
 mod scenario_world_whitebox;
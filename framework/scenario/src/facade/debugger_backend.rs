@@ -1,5 +1,12 @@
-use crate::scenario::{
-    self, model::*, run_trace::ScenarioTrace, run_vm::ScenarioVMRunner, ScenarioRunner,
+use crate::{
+    scenario::{
+        self,
+        model::*,
+        run_trace::ScenarioTrace,
+        run_vm::{bless_tx_expect, BlessChange, BlessReport, ScenarioVMRunner},
+        ScenarioRunner,
+    },
+    scenario_format::serde_raw::{StepRaw, TxExpectRaw},
 };
 use std::path::Path;
 
@@ -8,6 +15,8 @@ use std::path::Path;
 pub(crate) struct DebuggerBackend {
     pub vm_runner: ScenarioVMRunner,
     pub trace: Option<ScenarioTrace>,
+    /// Set in bless mode, collects the expectations rewritten in the scenario files.
+    pub bless: Option<BlessReport>,
 }
 
 impl DebuggerBackend {
@@ -91,6 +100,11 @@ impl ScenarioRunner for DebuggerBackend {
 
 impl DebuggerBackend {
     pub(super) fn run_scenario_file(&mut self, steps_path: &Path) {
+        if self.bless.is_some() {
+            self.bless_scenario_file(steps_path);
+            return;
+        }
+
         let mut scenario = scenario::parse_scenario(steps_path);
 
         for step in &mut scenario.steps {
@@ -128,3 +142,110 @@ impl DebuggerBackend {
         }
     }
 }
+
+impl DebuggerBackend {
+    /// Runs the scenario, rewriting the expectations that do not match the actual results,
+    /// instead of failing on them.
+    ///
+    /// Only the steps that changed are rewritten, the rest of the file is kept as parsed.
+    fn bless_scenario_file(&mut self, steps_path: &Path) {
+        let mut scenario = scenario::parse_scenario(steps_path);
+        let mut raw = scenario::parse_scenario_raw(steps_path);
+        let mut changes = Vec::new();
+
+        for (step, raw_step) in scenario.steps.iter_mut().zip(raw.steps.iter_mut()) {
+            match step {
+                Step::ExternalSteps(external_steps_step) => {
+                    let parent_path = steps_path.parent().unwrap();
+                    let new_path = parent_path.join(external_steps_step.path.as_str());
+                    self.bless_scenario_file(new_path.as_path());
+                },
+                Step::SetState(set_state_step) => {
+                    self.run_set_state_step(set_state_step);
+                },
+                Step::ScCall(sc_call_step) => {
+                    let tx_expect = sc_call_step.expect.take();
+                    self.run_sc_call_step(sc_call_step);
+                    bless_step_expect(
+                        &sc_call_step.id,
+                        tx_expect,
+                        sc_call_step.response.as_ref(),
+                        raw_step,
+                        &mut changes,
+                    );
+                },
+                Step::ScQuery(sc_query_step) => {
+                    let tx_expect = sc_query_step.expect.take();
+                    self.run_sc_query_step(sc_query_step);
+                    bless_step_expect(
+                        &sc_query_step.id,
+                        tx_expect,
+                        sc_query_step.response.as_ref(),
+                        raw_step,
+                        &mut changes,
+                    );
+                },
+                Step::ScDeploy(sc_deploy_step) => {
+                    let tx_expect = sc_deploy_step.expect.take();
+                    self.run_sc_deploy_step(sc_deploy_step);
+                    bless_step_expect(
+                        &sc_deploy_step.id,
+                        tx_expect,
+                        sc_deploy_step.response.as_ref(),
+                        raw_step,
+                        &mut changes,
+                    );
+                },
+                Step::Transfer(transfer_step) => {
+                    self.run_transfer_step(transfer_step);
+                },
+                Step::ValidatorReward(validator_reward_step) => {
+                    self.run_validator_reward_step(validator_reward_step);
+                },
+                Step::CheckState(check_state_step) => {
+                    if let StepRaw::CheckState { accounts, .. } = raw_step {
+                        changes.extend(
+                            self.vm_runner
+                                .bless_check_state(&mut check_state_step.accounts, accounts),
+                        );
+                    }
+                    self.run_check_state_step(check_state_step);
+                },
+                Step::DumpState(_) => {
+                    self.run_dump_state_step();
+                },
+            }
+        }
+
+        if !changes.is_empty() {
+            raw.save_to_file(steps_path);
+            if let Some(report) = &mut self.bless {
+                report.files.push((steps_path.to_path_buf(), changes));
+            }
+        }
+    }
+}
+
+fn bless_step_expect(
+    tx_id: &str,
+    tx_expect: Option<TxExpect>,
+    tx_response: Option<&TxResponse>,
+    raw_step: &mut StepRaw,
+    changes: &mut Vec<BlessChange>,
+) {
+    let (Some(tx_expect), Some(tx_response), Some(raw_expect)) =
+        (tx_expect, tx_response, raw_step_expect(raw_step))
+    else {
+        return;
+    };
+    changes.extend(bless_tx_expect(tx_id, &tx_expect, tx_response, raw_expect));
+}
+
+fn raw_step_expect(raw_step: &mut StepRaw) -> Option<&mut TxExpectRaw> {
+    match raw_step {
+        StepRaw::ScCall { expect, .. }
+        | StepRaw::ScQuery { expect, .. }
+        | StepRaw::ScDeploy { expect, .. } => expect.as_mut(),
+        _ => None,
+    }
+}
@@ -2,6 +2,7 @@ mod expect_error;
 mod expect_message;
 mod expect_status;
 mod expect_value;
+mod returns_gas_estimate;
 mod returns_gas_used;
 mod returns_handled_or_err;
 mod returns_logs;
@@ -16,6 +17,7 @@ pub use expect_error::ExpectError;
 pub use expect_message::ExpectMessage;
 pub use expect_status::ExpectStatus;
 pub use expect_value::ExpectValue;
+pub use returns_gas_estimate::ReturnsGasEstimate;
 pub use returns_gas_used::ReturnsGasUsed;
 pub use returns_handled_or_err::ReturnsHandledOrError;
 pub use returns_logs::ReturnsLogs;
@@ -0,0 +1,25 @@
+use dharitri_sc::types::{RHListItem, RHListItemExec, TxEnv};
+
+use crate::scenario_model::TxResponse;
+
+/// Indicates that the gas limit estimated before sending the transaction will be returned.
+///
+/// It is `None` if the transaction was sent with its own gas limit: because the gas limit was set explicitly,
+/// because the interactor is not in estimate gas mode, or because the estimation failed.
+pub struct ReturnsGasEstimate;
+
+impl<Env, Original> RHListItem<Env, Original> for ReturnsGasEstimate
+where
+    Env: TxEnv,
+{
+    type Returns = Option<u64>;
+}
+
+impl<Env, Original> RHListItemExec<TxResponse, Env, Original> for ReturnsGasEstimate
+where
+    Env: TxEnv,
+{
+    fn item_process_result(self, tx_response: &TxResponse) -> Self::Returns {
+        tx_response.gas_estimate
+    }
+}
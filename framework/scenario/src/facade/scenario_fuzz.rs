@@ -0,0 +1,7 @@
+mod fuzz_failure;
+mod fuzz_rng;
+mod scenario_fuzzer;
+
+pub use fuzz_failure::FuzzFailure;
+pub use fuzz_rng::FuzzRng;
+pub use scenario_fuzzer::ScenarioFuzzer;
@@ -0,0 +1,28 @@
+use std::fmt;
+
+/// A failing action sequence, as found by the [`ScenarioFuzzer`](super::ScenarioFuzzer), after shrinking.
+#[derive(Clone, Debug)]
+pub struct FuzzFailure<A> {
+    /// Seed of the run that first failed. Setting it on the fuzzer reproduces the original sequence.
+    pub seed: u64,
+    /// The minimal sequence found. The last action is the one that fails.
+    pub actions: Vec<A>,
+    /// The broken invariant, or the panic message of the failing action.
+    pub reason: String,
+}
+
+impl<A: fmt::Debug> fmt::Display for FuzzFailure<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "fuzzing failed (seed {}) after {} actions: {}",
+            self.seed,
+            self.actions.len(),
+            self.reason
+        )?;
+        for (index, action) in self.actions.iter().enumerate() {
+            writeln!(f, "  {}. {action:?}", index + 1)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use core::ops::Range;
+
+use dharitri_sc::types::{BigUint, RewaOrDcdtTokenIdentifier, RewaOrDcdtTokenPayment};
+
+use crate::api::StaticApi;
+
+/// Small deterministic pseudo-random generator (SplitMix64), used to generate fuzzing actions.
+///
+/// The same seed always produces the same sequence, so any failing run can be reproduced from its seed.
+#[derive(Clone, Debug)]
+pub struct FuzzRng {
+    state: u64,
+}
+
+impl FuzzRng {
+    pub fn new(seed: u64) -> Self {
+        FuzzRng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniformly distributed in the range, the end is excluded.
+    pub fn gen_range(&mut self, range: Range<u64>) -> u64 {
+        assert!(range.start < range.end, "empty fuzzing range");
+        range.start + self.next_u64() % (range.end - range.start)
+    }
+
+    /// True with a probability of `numerator / denominator`.
+    pub fn gen_ratio(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.gen_range(0..denominator) < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        assert!(!items.is_empty(), "cannot choose from an empty list");
+        &items[self.gen_range(0..items.len() as u64) as usize]
+    }
+
+    /// A fungible payment of one of the given tokens, of at most `max_amount`, possibly zero.
+    pub fn rewa_or_dcdt_payment(
+        &mut self,
+        tokens: &[RewaOrDcdtTokenIdentifier<StaticApi>],
+        max_amount: u64,
+    ) -> RewaOrDcdtTokenPayment<StaticApi> {
+        let token_identifier = self.choose(tokens).clone();
+        let amount = self.gen_range(0..max_amount + 1);
+        RewaOrDcdtTokenPayment::new(token_identifier, 0, BigUint::from(amount))
+    }
+}
@@ -0,0 +1,310 @@
+use std::{
+    any::Any,
+    fmt,
+    panic::{catch_unwind, AssertUnwindSafe},
+    path::{Path, PathBuf},
+};
+
+use dharitri_chain_vm::world_mock::BlockchainState;
+
+use crate::ScenarioWorld;
+
+use super::{FuzzFailure, FuzzRng};
+
+const DEFAULT_NUM_RUNS: usize = 100;
+const DEFAULT_MAX_STEPS: usize = 20;
+
+type ActionGenerator<A> = Box<dyn Fn(&mut FuzzRng) -> A>;
+type ActionExecutor<A> = Box<dyn Fn(&mut ScenarioWorld, &A)>;
+type ActionSimplifier<A> = Box<dyn Fn(&A) -> Vec<A>>;
+type Invariant = Box<dyn Fn(&mut ScenarioWorld) -> bool>;
+
+/// Runs random sequences of actions against a `ScenarioWorld`, checking invariants after each of them.
+///
+/// The actions are plain data, produced by the generator from a seeded random source,
+/// and applied to the world by the executor, usually as typed proxy calls.
+/// Keeping them as data allows any failing sequence to be replayed and shrunk:
+/// actions are removed, and optionally simplified, for as long as it still fails the same way.
+///
+/// An action fails if it panics, e.g. on an unexpected tx result,
+/// or if any invariant returns false or panics right after it.
+/// Transactions that are allowed to fail should be run with `ReturnsHandledOrError`.
+pub struct ScenarioFuzzer<A> {
+    world: Box<dyn Fn() -> ScenarioWorld>,
+    setup: Box<dyn Fn(&mut ScenarioWorld)>,
+    generator: ActionGenerator<A>,
+    executor: ActionExecutor<A>,
+    simplifier: Option<ActionSimplifier<A>>,
+    invariants: Vec<(String, Invariant)>,
+    seed: u64,
+    num_runs: usize,
+    max_steps: usize,
+    trace_path: Option<PathBuf>,
+}
+
+impl<A> ScenarioFuzzer<A>
+where
+    A: Clone + fmt::Debug,
+{
+    /// The world function creates an empty world, with the contracts registered.
+    pub fn new<W, G, E>(world: W, generator: G, executor: E) -> Self
+    where
+        W: Fn() -> ScenarioWorld + 'static,
+        G: Fn(&mut FuzzRng) -> A + 'static,
+        E: Fn(&mut ScenarioWorld, &A) + 'static,
+    {
+        ScenarioFuzzer {
+            world: Box::new(world),
+            setup: Box::new(|_| {}),
+            generator: Box::new(generator),
+            executor: Box::new(executor),
+            simplifier: None,
+            invariants: Vec::new(),
+            seed: 0,
+            num_runs: DEFAULT_NUM_RUNS,
+            max_steps: DEFAULT_MAX_STEPS,
+            trace_path: None,
+        }
+    }
+
+    /// Initial accounts and deployments, run once before every sequence.
+    pub fn setup<F>(mut self, setup: F) -> Self
+    where
+        F: Fn(&mut ScenarioWorld) + 'static,
+    {
+        self.setup = Box::new(setup);
+        self
+    }
+
+    /// Checked after the setup and after every action.
+    pub fn invariant<F>(mut self, name: &str, invariant: F) -> Self
+    where
+        F: Fn(&mut ScenarioWorld) -> bool + 'static,
+    {
+        self.invariants
+            .push((name.to_string(), Box::new(invariant)));
+        self
+    }
+
+    /// Used while shrinking, to replace an action with simpler ones, e.g. smaller amounts.
+    ///
+    /// The returned actions must be strictly simpler than the original, otherwise shrinking might not end.
+    pub fn simplifier<F>(mut self, simplifier: F) -> Self
+    where
+        F: Fn(&A) -> Vec<A> + 'static,
+    {
+        self.simplifier = Some(Box::new(simplifier));
+        self
+    }
+
+    /// Seed of the first run, the following runs use the next seeds.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn num_runs(mut self, num_runs: usize) -> Self {
+        self.num_runs = num_runs;
+        self
+    }
+
+    pub fn max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// If a failure is found, the minimal sequence gets exported to this scenario file.
+    pub fn trace_path<P: AsRef<Path>>(mut self, trace_path: P) -> Self {
+        self.trace_path = Some(trace_path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Runs the fuzzer, panicking with the minimal failing sequence, if any is found.
+    pub fn run(&self) {
+        if let Some(failure) = self.find_failure() {
+            panic!("{failure}");
+        }
+    }
+
+    /// Runs the fuzzer, returning the minimal failing sequence, if any is found.
+    ///
+    /// Stops at the first failing run. The failure is also exported, if a trace path was configured.
+    pub fn find_failure(&self) -> Option<FuzzFailure<A>> {
+        let mut world = (self.world)();
+        (self.setup)(&mut world);
+        if let Err(reason) = self.check_invariants(&mut world) {
+            return Some(self.failure(self.seed, Vec::new(), reason));
+        }
+        let initial_state = world.snapshot_state();
+
+        for run_index in 0..self.num_runs {
+            let seed = self.seed.wrapping_add(run_index as u64);
+            let mut rng = FuzzRng::new(seed);
+            world.restore_state(&initial_state);
+
+            let mut actions = Vec::new();
+            for _ in 0..self.max_steps {
+                let action = (self.generator)(&mut rng);
+                let result = self.run_action(&mut world, &action);
+                actions.push(action);
+                if result.is_err() {
+                    let (actions, reason) = self.shrink(&mut world, &initial_state, actions);
+                    return Some(self.failure(seed, actions, reason));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn failure(&self, seed: u64, actions: Vec<A>, reason: String) -> FuzzFailure<A> {
+        let failure = FuzzFailure {
+            seed,
+            actions,
+            reason,
+        };
+        if let Some(trace_path) = &self.trace_path {
+            self.write_trace(&failure, trace_path);
+        }
+        failure
+    }
+
+    fn run_action(&self, world: &mut ScenarioWorld, action: &A) -> Result<(), String> {
+        catch_unwind(AssertUnwindSafe(|| (self.executor)(world, action)))
+            .map_err(|panic_any| format!("action panicked: {}", panic_message(&*panic_any)))?;
+        self.check_invariants(world)
+    }
+
+    fn check_invariants(&self, world: &mut ScenarioWorld) -> Result<(), String> {
+        for (name, invariant) in &self.invariants {
+            match catch_unwind(AssertUnwindSafe(|| invariant(world))) {
+                Ok(true) => {},
+                Ok(false) => return Err(format!("invariant `{name}` broken")),
+                Err(panic_any) => {
+                    return Err(format!(
+                        "invariant `{name}` panicked: {}",
+                        panic_message(&*panic_any)
+                    ))
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs the actions from the initial state, stopping at the first failure.
+    ///
+    /// Returns the number of actions run, including the failing one, and the failure reason.
+    fn replay(
+        &self,
+        world: &mut ScenarioWorld,
+        initial_state: &BlockchainState,
+        actions: &[A],
+    ) -> Option<(usize, String)> {
+        world.restore_state(initial_state);
+        for (index, action) in actions.iter().enumerate() {
+            if let Err(reason) = self.run_action(world, action) {
+                return Some((index + 1, reason));
+            }
+        }
+        None
+    }
+
+    /// Only a candidate that fails for the same reason can replace the sequence being shrunk,
+    /// otherwise the minimal sequence could reproduce a different failure than the one found.
+    ///
+    /// Returns the number of actions run, including the failing one.
+    fn replay_same_failure(
+        &self,
+        world: &mut ScenarioWorld,
+        initial_state: &BlockchainState,
+        candidate: &[A],
+        reason: &str,
+    ) -> Option<usize> {
+        self.replay(world, initial_state, candidate)
+            .filter(|(_, candidate_reason)| candidate_reason == reason)
+            .map(|(len, _)| len)
+    }
+
+    fn shrink(
+        &self,
+        world: &mut ScenarioWorld,
+        initial_state: &BlockchainState,
+        mut actions: Vec<A>,
+    ) -> (Vec<A>, String) {
+        let (_, reason) = self
+            .replay(world, initial_state, &actions)
+            .expect("fuzzing failure is not reproducible");
+
+        // removing chunks of actions, from large to small
+        let mut chunk_len = actions.len() / 2;
+        while chunk_len > 0 {
+            let mut start = 0;
+            while start < actions.len() {
+                let mut candidate = actions.clone();
+                candidate.drain(start..(start + chunk_len).min(actions.len()));
+                if let Some(len) =
+                    self.replay_same_failure(world, initial_state, &candidate, &reason)
+                {
+                    candidate.truncate(len);
+                    actions = candidate;
+                } else {
+                    start += chunk_len;
+                }
+            }
+            chunk_len /= 2;
+        }
+
+        // simplifying the remaining actions, one at a time
+        if let Some(simplifier) = &self.simplifier {
+            let mut index = 0;
+            while index < actions.len() {
+                let mut simplified = false;
+                for simpler_action in simplifier(&actions[index]) {
+                    let mut candidate = actions.clone();
+                    candidate[index] = simpler_action;
+                    if let Some(len) =
+                        self.replay_same_failure(world, initial_state, &candidate, &reason)
+                    {
+                        candidate.truncate(len);
+                        actions = candidate;
+                        simplified = true;
+                        break;
+                    }
+                }
+                if !simplified {
+                    index += 1;
+                }
+            }
+        }
+
+        (actions, reason)
+    }
+
+    /// Replays the failing sequence on a new traced world, to export it as a scenario.
+    fn write_trace(&self, failure: &FuzzFailure<A>, trace_path: &Path) {
+        let mut world = (self.world)();
+        world.start_trace();
+        (self.setup)(&mut world);
+        for action in &failure.actions {
+            let _ = self.run_action(&mut world, action);
+        }
+
+        if let Some(trace) = &mut world.get_mut_debugger_backend().trace {
+            trace.scenario_trace.comment = Some(format!(
+                "fuzzing failure, seed {}: {}",
+                failure.seed, failure.reason
+            ));
+        }
+        world.write_scenario_trace(trace_path);
+    }
+}
+
+fn panic_message(panic_any: &(dyn Any + Send)) -> String {
+    if let Some(message) = panic_any.downcast_ref::<String>() {
+        message.clone()
+    } else if let Some(message) = panic_any.downcast_ref::<&str>() {
+        message.to_string()
+    } else {
+        "unknown panic object".to_string()
+    }
+}
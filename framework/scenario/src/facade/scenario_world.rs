@@ -1,11 +1,21 @@
-use dharitri_chain_vm::world_mock::BlockchainState;
+use dharitri_chain_vm::{
+    tx_execution::GasSchedule,
+    tx_mock::{ExecutionTrace, ExecutionTracer},
+    world_mock::{BlockchainState, ShardConfig},
+};
 
 use crate::{
-    scenario::{run_trace::ScenarioTrace, run_vm::ScenarioVMRunner},
+    scenario::{
+        run_trace::ScenarioTrace,
+        run_vm::{bless_requested_by_env, BlessReport, ScenarioVMRunner},
+    },
     vm_go_tool::run_drt_scenario_go,
 };
 use dharitri_sc_meta_lib::tools::find_current_workspace;
-use std::path::{Path, PathBuf};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use super::debugger_backend::DebuggerBackend;
 
@@ -38,6 +48,7 @@ impl ScenarioWorld {
             backend: Backend::Debugger(Box::new(DebuggerBackend {
                 vm_runner: ScenarioVMRunner::new(),
                 trace: None,
+                bless: None,
             })),
         }
     }
@@ -57,12 +68,20 @@ impl ScenarioWorld {
     /// Runs a scenario file (`.scen.json`) with the configured backend.
     ///
     /// Will crash and produce an output if the test failed for any reason.
+    ///
+    /// In bless mode, see [`ScenarioWorld::bless`], mismatching expectations are rewritten instead.
     pub fn run<P: AsRef<Path>>(self, relative_path: P) {
         let mut absolute_path = self.current_dir.clone();
         absolute_path.push(relative_path);
         match self.backend {
             Backend::Debugger(mut debugger) => {
+                if debugger.bless.is_none() && bless_requested_by_env() {
+                    debugger.bless = Some(BlessReport::default());
+                }
                 debugger.run_scenario_file(&absolute_path);
+                if let Some(report) = &debugger.bless {
+                    report.print_summary();
+                }
             },
             Backend::VmGoBackend => {
                 run_drt_scenario_go(&absolute_path);
@@ -98,11 +117,87 @@ impl ScenarioWorld {
             .state
     }
 
+    /// Turns on bless mode for `run`: the `out`, `status`, `message` and `logs` of the transaction expectations,
+    /// as well as the `checkState` steps, get rewritten in place from the actual results, instead of failing.
+    ///
+    /// Can also be turned on for all tests by setting the `SCENARIO_BLESS` environment variable to `1`.
+    /// Review the rewritten files before committing them.
+    pub fn bless(&mut self) -> &mut Self {
+        self.get_mut_debugger_backend().bless = Some(BlessReport::default());
+        self
+    }
+
     pub fn start_trace(&mut self) -> &mut Self {
         self.get_mut_debugger_backend().trace = Some(ScenarioTrace::default());
         self
     }
 
+    /// Enables gas metering in the debugger, using the given gas schedule.
+    ///
+    /// Once enabled, the `gas` field of the transaction expectations is also checked,
+    /// unused gas is refunded to the sender, and `ReturnsGasUsed` returns the actual gas consumed.
+    pub fn gas_schedule(&mut self, gas_schedule: GasSchedule) -> &mut Self {
+        self.get_mut_debugger_backend()
+            .vm_runner
+            .blockchain_mock
+            .vm
+            .set_gas_schedule(Some(gas_schedule));
+        self
+    }
+
+    /// Enables execution tracing in the debugger.
+    ///
+    /// From here on, every call gets recorded, together with the VM hooks, storage accesses,
+    /// logs and nested calls that it performed. See `execution_trace`.
+    pub fn start_execution_trace(&mut self) -> &mut Self {
+        self.get_mut_debugger_backend()
+            .vm_runner
+            .blockchain_mock
+            .vm
+            .set_tracer(Some(Arc::new(ExecutionTracer::new())));
+        self
+    }
+
+    /// Everything recorded since `start_execution_trace` was called.
+    ///
+    /// Prints as a call tree, and can also be exported as JSON.
+    pub fn execution_trace(&mut self) -> ExecutionTrace {
+        self.get_mut_debugger_backend()
+            .vm_runner
+            .blockchain_mock
+            .vm
+            .tracer
+            .as_ref()
+            .expect("execution trace not started")
+            .trace()
+    }
+
+    /// Exports the execution trace to a JSON file.
+    pub fn write_execution_trace<P: AsRef<Path>>(&mut self, file_path: P) {
+        self.execution_trace().write_json_file(file_path);
+    }
+
+    /// Enables the sharded mode of the debugger.
+    ///
+    /// Accounts get assigned to shards the same way as on the real chain.
+    /// Async calls, promises and transfers between shards no longer execute right away,
+    /// they get delivered in the following rounds instead, see `advance_rounds`.
+    pub fn num_shards(&mut self, num_shards: u32) -> &mut Self {
+        self.get_mut_state().shard_config = Some(ShardConfig::new(num_shards));
+        self
+    }
+
+    /// Moves on by the given number of rounds.
+    ///
+    /// In sharded mode, each round delivers the cross-shard calls sent in the previous one.
+    pub fn advance_rounds(&mut self, num_rounds: u64) -> &mut Self {
+        let blockchain_mock = &mut self.get_mut_debugger_backend().vm_runner.blockchain_mock;
+        for _ in 0..num_rounds {
+            blockchain_mock.vm.advance_round(&mut blockchain_mock.state);
+        }
+        self
+    }
+
     /// Tells the tests where the crate lies relative to the workspace.
     /// This ensures that the paths are set correctly, including in debug mode.
     pub fn set_current_dir_from_workspace(&mut self, relative_path: &str) -> &mut Self {
@@ -0,0 +1,54 @@
+use std::{fs, path::Path};
+
+use dharitri_chain_vm::world_mock::BlockchainState;
+
+use crate::ScenarioWorld;
+
+impl ScenarioWorld {
+    /// The current blockchain state, to be inspected without copying it.
+    pub fn state(&self) -> &BlockchainState {
+        self.get_state()
+    }
+
+    /// A copy of the entire blockchain state, to be restored later via `restore_state`.
+    pub fn snapshot_state(&self) -> BlockchainState {
+        self.get_state().clone()
+    }
+
+    /// Replaces the blockchain state with a snapshot.
+    ///
+    /// The same snapshot can be restored any number of times,
+    /// so several independent branches can be run from a single setup.
+    pub fn restore_state(&mut self, snapshot: &BlockchainState) -> &mut Self {
+        *self.get_mut_state() = snapshot.clone();
+        self
+    }
+
+    /// Saves the entire blockchain state to a JSON file, to be loaded via `load_state_snapshot`,
+    /// possibly from another test binary.
+    pub fn write_state_snapshot<P: AsRef<Path>>(&self, file_path: P) {
+        let file_path = file_path.as_ref();
+        let json = serde_json::to_string_pretty(self.get_state())
+            .expect("failed to serialize the blockchain state");
+        fs::write(file_path, json).unwrap_or_else(|err| {
+            panic!(
+                "failed to write state snapshot {}: {err}",
+                file_path.display()
+            )
+        });
+    }
+
+    /// Replaces the blockchain state with one saved via `write_state_snapshot`.
+    pub fn load_state_snapshot<P: AsRef<Path>>(&mut self, file_path: P) -> &mut Self {
+        let file_path = file_path.as_ref();
+        let json = fs::read_to_string(file_path).unwrap_or_else(|err| {
+            panic!(
+                "failed to read state snapshot {}: {err}",
+                file_path.display()
+            )
+        });
+        *self.get_mut_state() = serde_json::from_str(&json)
+            .unwrap_or_else(|err| panic!("invalid state snapshot {}: {err}", file_path.display()));
+        self
+    }
+}
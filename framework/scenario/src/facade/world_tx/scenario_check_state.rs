@@ -1,9 +1,11 @@
-use std::collections::{btree_map::Entry, BTreeMap};
+use std::collections::btree_map::Entry;
 
 use dharitri_chain_scenario_format::interpret_trait::{InterpretableFrom, InterpreterContext};
 use dharitri_sc::{
     codec::{top_encode_to_vec_u8, TopEncode},
-    types::{AnnotatedValue, BigUint, ManagedAddress, ManagedBuffer, TokenIdentifier},
+    types::{
+        AnnotatedValue, BigUint, DcdtLocalRole, ManagedAddress, ManagedBuffer, TokenIdentifier,
+    },
 };
 
 use crate::{
@@ -17,8 +19,7 @@ use crate::{
     },
     scenario_model::{
         AddressKey, BytesKey, BytesValue, CheckAccount, CheckDcdt, CheckDcdtData,
-        CheckDcdtInstances, CheckDcdtMap, CheckDcdtMapContents, CheckStateStep, CheckStorage,
-        CheckStorageDetails, CheckValue,
+        CheckDcdtInstance, CheckStateStep, CheckStorage, CheckStorageDetails, CheckValue, U64Value,
     },
     ScenarioTxEnvData, ScenarioWorld,
 };
@@ -142,35 +143,37 @@ impl<'w> CheckStateBuilder<'w> {
         let token_id_key = token_identifier_annotated(&env, token_id);
         let balance_value = big_uint_annotated(&env, &balance);
 
-        match &mut self.current_account.dcdt {
-            CheckDcdtMap::Unspecified | CheckDcdtMap::Star => {
-                let mut new_dcdt_map = BTreeMap::new();
-                let _ = new_dcdt_map.insert(token_id_key, CheckDcdt::Short(balance_value));
-
-                let new_check_dcdt_map = CheckDcdtMapContents {
-                    contents: new_dcdt_map,
-                    other_dcdts_allowed: true,
-                };
-
-                self.current_account.dcdt = CheckDcdtMap::Equal(new_check_dcdt_map);
+        match self
+            .current_account
+            .dcdt
+            .contents_mut()
+            .contents
+            .entry(token_id_key)
+        {
+            Entry::Vacant(entry) => {
+                entry.insert(CheckDcdt::Short(balance_value));
             },
-            CheckDcdtMap::Equal(check_dcdt_map) => {
-                if check_dcdt_map.contents.contains_key(&token_id_key) {
-                    let prev_entry = check_dcdt_map.contents.get_mut(&token_id_key).unwrap();
-                    match prev_entry {
-                        CheckDcdt::Short(prev_balance_check) => *prev_balance_check = balance_value,
-                        CheckDcdt::Full(prev_dcdt_check) => match prev_dcdt_check.instances {
-                            CheckDcdtInstances::Star => todo!(),
-                            CheckDcdtInstances::Equal(_) => todo!(),
-                        },
-                    }
-                }
+            Entry::Occupied(mut entry) => match entry.get_mut() {
+                CheckDcdt::Short(prev_balance_check) => *prev_balance_check = balance_value,
+                CheckDcdt::Full(_) => entry.get_mut().add_balance_check(0u64, balance_value),
             },
         }
 
         self
     }
 
+    pub fn dcdt_nft_balance<K, N, V>(mut self, token_id: K, nonce: N, balance: V) -> Self
+    where
+        K: AnnotatedValue<ScenarioTxEnvData, TokenIdentifier<StaticApi>>,
+        N: AnnotatedValue<ScenarioTxEnvData, u64>,
+        V: AnnotatedValue<ScenarioTxEnvData, BigUint<StaticApi>>,
+    {
+        let env = self.new_env_data();
+        let balance_value = big_uint_annotated(&env, &balance);
+        self.instance_check_mut(token_id, nonce).balance = CheckValue::Equal(balance_value);
+        self
+    }
+
     pub fn dcdt_nft_balance_and_attributes<K, N, V, T>(
         mut self,
         token_id: K,
@@ -190,29 +193,142 @@ impl<'w> CheckStateBuilder<'w> {
         let balance_value = big_uint_annotated(&env, &balance);
         let attributes_value = top_encode_to_vec_u8(&attributes).unwrap();
 
-        if let CheckDcdtMap::Unspecified = &self.current_account.dcdt {
-            let mut check_dcdt = CheckDcdt::Full(CheckDcdtData::default());
+        let instance_check = self
+            .current_account
+            .dcdt
+            .dcdt_check_mut(token_id_key)
+            .instance_check_mut(nonce_value);
+        instance_check.balance = CheckValue::Equal(balance_value);
+        instance_check.attributes = CheckValue::Equal(BytesValue::from(attributes_value));
+        self
+    }
 
-            check_dcdt.add_balance_and_attributes_check(
-                nonce_value,
-                balance_value,
-                attributes_value,
-            );
+    /// Checks the attributes of an NFT/SFT instance, given as the typed struct, which gets top-encoded.
+    pub fn dcdt_nft_attributes<K, N, T>(mut self, token_id: K, nonce: N, attributes: T) -> Self
+    where
+        K: AnnotatedValue<ScenarioTxEnvData, TokenIdentifier<StaticApi>>,
+        N: AnnotatedValue<ScenarioTxEnvData, u64>,
+        T: TopEncode,
+    {
+        let attributes_value = top_encode_to_vec_u8(&attributes).unwrap();
+        self.instance_check_mut(token_id, nonce).attributes =
+            CheckValue::Equal(BytesValue::from(attributes_value));
+        self
+    }
 
-            let mut new_dcdt_map = BTreeMap::new();
-            let _ = new_dcdt_map.insert(token_id_key, check_dcdt);
+    pub fn dcdt_nft_royalties<K, N, R>(mut self, token_id: K, nonce: N, royalties: R) -> Self
+    where
+        K: AnnotatedValue<ScenarioTxEnvData, TokenIdentifier<StaticApi>>,
+        N: AnnotatedValue<ScenarioTxEnvData, u64>,
+        R: AnnotatedValue<ScenarioTxEnvData, u64>,
+    {
+        let env = self.new_env_data();
+        let royalties_value = u64_annotated(&env, &royalties);
+        self.instance_check_mut(token_id, nonce).royalties = CheckValue::Equal(royalties_value);
+        self
+    }
 
-            let new_check_dcdt_map = CheckDcdtMapContents {
-                contents: new_dcdt_map,
-                other_dcdts_allowed: true,
-            };
+    pub fn dcdt_nft_creator<K, N, C>(mut self, token_id: K, nonce: N, creator: C) -> Self
+    where
+        K: AnnotatedValue<ScenarioTxEnvData, TokenIdentifier<StaticApi>>,
+        N: AnnotatedValue<ScenarioTxEnvData, u64>,
+        C: AnnotatedValue<ScenarioTxEnvData, ManagedAddress<StaticApi>>,
+    {
+        let env = self.new_env_data();
+        let creator_value = address_annotated(&env, &creator);
+        self.instance_check_mut(token_id, nonce).creator =
+            CheckValue::Equal(BytesValue::from(creator_value.value.as_bytes()));
+        self
+    }
 
-            self.current_account.dcdt = CheckDcdtMap::Equal(new_check_dcdt_map);
-        }
+    pub fn dcdt_nft_hash<K, N, H>(mut self, token_id: K, nonce: N, hash: H) -> Self
+    where
+        K: AnnotatedValue<ScenarioTxEnvData, TokenIdentifier<StaticApi>>,
+        N: AnnotatedValue<ScenarioTxEnvData, u64>,
+        H: AnnotatedValue<ScenarioTxEnvData, ManagedBuffer<StaticApi>>,
+    {
+        let env = self.new_env_data();
+        let hash_value = bytes_annotated(&env, hash);
+        self.instance_check_mut(token_id, nonce).hash = CheckValue::Equal(hash_value);
+        self
+    }
+
+    /// Checks all the URIs of an NFT/SFT instance, in order.
+    pub fn dcdt_nft_uris<K, N, U>(mut self, token_id: K, nonce: N, uris: Vec<U>) -> Self
+    where
+        K: AnnotatedValue<ScenarioTxEnvData, TokenIdentifier<StaticApi>>,
+        N: AnnotatedValue<ScenarioTxEnvData, u64>,
+        U: AnnotatedValue<ScenarioTxEnvData, ManagedBuffer<StaticApi>>,
+    {
+        let env = self.new_env_data();
+        let uris_value = uris
+            .into_iter()
+            .map(|uri| CheckValue::Equal(bytes_annotated(&env, uri)))
+            .collect();
+        self.instance_check_mut(token_id, nonce).uri = CheckValue::Equal(uris_value);
+        self
+    }
+
+    pub fn dcdt_last_nonce<K, N>(mut self, token_id: K, last_nonce: N) -> Self
+    where
+        K: AnnotatedValue<ScenarioTxEnvData, TokenIdentifier<StaticApi>>,
+        N: AnnotatedValue<ScenarioTxEnvData, u64>,
+    {
+        let env = self.new_env_data();
+        let last_nonce_value = u64_annotated(&env, &last_nonce);
+        self.dcdt_data_check_mut(token_id).last_nonce = CheckValue::Equal(last_nonce_value);
+        self
+    }
+
+    pub fn dcdt_frozen<K>(mut self, token_id: K, frozen: bool) -> Self
+    where
+        K: AnnotatedValue<ScenarioTxEnvData, TokenIdentifier<StaticApi>>,
+    {
+        self.dcdt_data_check_mut(token_id).frozen =
+            CheckValue::Equal(U64Value::from(frozen as u64));
+        self
+    }
 
+    /// Checks the exact set of local roles of the account for a token, in any order.
+    pub fn dcdt_roles<K>(mut self, token_id: K, roles: &[DcdtLocalRole]) -> Self
+    where
+        K: AnnotatedValue<ScenarioTxEnvData, TokenIdentifier<StaticApi>>,
+    {
+        self.dcdt_data_check_mut(token_id).roles = roles
+            .iter()
+            .map(|role| String::from_utf8(role.as_role_name().to_vec()).unwrap())
+            .collect();
         self
     }
 
+    fn dcdt_data_check_mut<K>(&mut self, token_id: K) -> &mut CheckDcdtData
+    where
+        K: AnnotatedValue<ScenarioTxEnvData, TokenIdentifier<StaticApi>>,
+    {
+        let env = self.new_env_data();
+        let token_id_key = token_identifier_annotated(&env, token_id);
+        let dcdt_check = self.current_account.dcdt.dcdt_check_mut(token_id_key);
+        dcdt_check.convert_to_full();
+        let CheckDcdt::Full(dcdt_data_check) = dcdt_check else {
+            unreachable!()
+        };
+        dcdt_data_check
+    }
+
+    fn instance_check_mut<K, N>(&mut self, token_id: K, nonce: N) -> &mut CheckDcdtInstance
+    where
+        K: AnnotatedValue<ScenarioTxEnvData, TokenIdentifier<StaticApi>>,
+        N: AnnotatedValue<ScenarioTxEnvData, u64>,
+    {
+        let env = self.new_env_data();
+        let token_id_key = token_identifier_annotated(&env, token_id);
+        let nonce_value = u64_annotated(&env, &nonce);
+        self.current_account
+            .dcdt
+            .dcdt_check_mut(token_id_key)
+            .instance_check_mut(nonce_value)
+    }
+
     pub fn check_storage(mut self, key: &str, value: &str) -> Self {
         let mut details = match &self.current_account.storage {
             CheckStorage::Star => CheckStorageDetails::default(),
@@ -6,7 +6,8 @@ pub use crate::{
     api::{DebugApi, DebugHandle, StaticApi},
     assert_values_eq, bech32,
     facade::{
-        expr::*, result_handlers::*, world_tx::*, ContractInfo, ScenarioWorld, WhiteboxContract,
+        expr::*, result_handlers::*, scenario_fuzz::*, world_tx::*, ContractInfo, ScenarioWorld,
+        WhiteboxContract,
     },
     managed_address, managed_biguint, managed_buffer, managed_token_id, num_bigint,
     num_bigint::BigInt as RustBigInt,
@@ -26,3 +27,5 @@ pub use crate::{
 };
 
 pub use crate::dharitri_sc::chain_core::types::ReturnCode;
+
+pub use crate::dharitri_chain_vm::tx_execution::GasSchedule;
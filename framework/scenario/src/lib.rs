@@ -36,7 +36,10 @@ pub use crate::scenario as denali_system;
 // Re-exporting the whole denali crate for easier use in tests.
 pub use dharitri_chain_scenario_format as scenario_format;
 
-pub use facade::{result_handlers::*, world_tx::*, ContractInfo, ScenarioWorld, WhiteboxContract};
+pub use facade::{
+    result_handlers::*, scenario_fuzz::*, world_tx::*, ContractInfo, ScenarioWorld,
+    WhiteboxContract,
+};
 
 use std::path::Path;
 
@@ -2,16 +2,15 @@ use dharitri_sc::codec::{top_encode_to_vec_u8_or_panic, TopEncode};
 
 use crate::{
     scenario::model::{
-        BigUintValue, BytesKey, BytesValue, CheckDcdt, CheckDcdtInstances, CheckDcdtMap,
-        CheckDcdtMapContents, CheckStorage, CheckStorageDetails, CheckValue, U64Value,
+        BigUintValue, BytesKey, BytesValue, CheckDcdt, CheckDcdtMap, CheckStorage,
+        CheckStorageDetails, CheckValue, U64Value,
     },
     scenario_format::{
         interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
         serde_raw::CheckAccountRaw,
     },
-    scenario_model::CheckDcdtData,
 };
-use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
 
 #[derive(Debug, Default, Clone)]
 pub struct CheckAccount {
@@ -85,29 +84,13 @@ impl CheckAccount {
         let token_id = BytesKey::from(token_id_expr);
         let balance = BigUintValue::from(balance_expr);
 
-        match &mut self.dcdt {
-            CheckDcdtMap::Unspecified | CheckDcdtMap::Star => {
-                let mut new_dcdt_map = BTreeMap::new();
-                let _ = new_dcdt_map.insert(token_id, CheckDcdt::Short(balance));
-
-                let new_check_dcdt_map = CheckDcdtMapContents {
-                    contents: new_dcdt_map,
-                    other_dcdts_allowed: true,
-                };
-
-                self.dcdt = CheckDcdtMap::Equal(new_check_dcdt_map);
+        match self.dcdt.contents_mut().contents.entry(token_id) {
+            Entry::Vacant(entry) => {
+                entry.insert(CheckDcdt::Short(balance));
             },
-            CheckDcdtMap::Equal(check_dcdt_map) => {
-                if check_dcdt_map.contents.contains_key(&token_id) {
-                    let prev_entry = check_dcdt_map.contents.get_mut(&token_id).unwrap();
-                    match prev_entry {
-                        CheckDcdt::Short(prev_balance_check) => *prev_balance_check = balance,
-                        CheckDcdt::Full(prev_dcdt_check) => match prev_dcdt_check.instances {
-                            CheckDcdtInstances::Star => todo!(),
-                            CheckDcdtInstances::Equal(_) => todo!(),
-                        },
-                    }
-                }
+            Entry::Occupied(mut entry) => match entry.get_mut() {
+                CheckDcdt::Short(prev_balance_check) => *prev_balance_check = balance,
+                CheckDcdt::Full(_) => entry.get_mut().add_balance_check(0u64, balance),
             },
         }
 
@@ -128,34 +111,13 @@ impl CheckAccount {
         T: TopEncode,
     {
         let token_id = BytesKey::from(token_id_expr);
+        let attributes = attributes_expr
+            .map(|attributes_expr| top_encode_to_vec_u8_or_panic(&attributes_expr))
+            .unwrap_or_default();
 
-        if let CheckDcdtMap::Unspecified = &self.dcdt {
-            let mut check_dcdt = CheckDcdt::Full(CheckDcdtData::default());
-
-            if let Some(attributes_expr) = attributes_expr {
-                check_dcdt.add_balance_and_attributes_check(
-                    nonce_expr,
-                    balance_expr,
-                    top_encode_to_vec_u8_or_panic(&attributes_expr),
-                );
-            } else {
-                check_dcdt.add_balance_and_attributes_check(
-                    nonce_expr,
-                    balance_expr,
-                    Vec::<u8>::new(),
-                );
-            }
-
-            let mut new_dcdt_map = BTreeMap::new();
-            let _ = new_dcdt_map.insert(token_id, check_dcdt);
-
-            let new_check_dcdt_map = CheckDcdtMapContents {
-                contents: new_dcdt_map,
-                other_dcdts_allowed: true,
-            };
-
-            self.dcdt = CheckDcdtMap::Equal(new_check_dcdt_map);
-        }
+        self.dcdt
+            .dcdt_check_mut(token_id)
+            .add_balance_and_attributes_check(nonce_expr, balance_expr, attributes);
 
         self
     }
@@ -27,6 +27,7 @@ impl CheckDcdt {
 
             if has_single_fungible_instance
                 && dcdt_check.frozen.is_star()
+                && dcdt_check.roles.is_empty()
                 && dcdt_check.last_nonce.is_star()
             {
                 let balance =
@@ -62,6 +63,36 @@ impl CheckDcdt {
         }
     }
 
+    /// Provides the check of the instance with the given nonce, adding an empty one if it is not yet checked.
+    pub fn instance_check_mut(&mut self, nonce: U64Value) -> &mut CheckDcdtInstance {
+        self.convert_to_full();
+        let CheckDcdt::Full(dcdt_check) = self else {
+            unreachable!()
+        };
+
+        if dcdt_check.instances.is_star() {
+            dcdt_check.instances = CheckDcdtInstances::Equal(Vec::new());
+        }
+        let CheckDcdtInstances::Equal(instance_checks) = &mut dcdt_check.instances else {
+            unreachable!()
+        };
+
+        let index = match instance_checks
+            .iter()
+            .position(|item| item.nonce.value == nonce.value)
+        {
+            Some(index) => index,
+            None => {
+                instance_checks.push(CheckDcdtInstance {
+                    nonce,
+                    ..Default::default()
+                });
+                instance_checks.len() - 1
+            },
+        };
+        &mut instance_checks[index]
+    }
+
     pub fn add_balance_check<N, V>(&mut self, nonce_expr: N, balance_expr: V)
     where
         U64Value: InterpretableFrom<N>,
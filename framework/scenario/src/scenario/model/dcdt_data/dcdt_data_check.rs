@@ -13,6 +13,8 @@ pub struct CheckDcdtData {
     pub instances: CheckDcdtInstances,
     pub last_nonce: CheckValue<U64Value>,
     pub frozen: CheckValue<U64Value>,
+    /// Role names, e.g. `DCDTRoleNFTCreate` or `str:DCDTRoleNFTCreate`, in any order. Not checked if empty.
+    pub roles: Vec<String>,
 }
 
 impl InterpretableFrom<CheckDcdtDataRaw> for CheckDcdtData {
@@ -21,6 +23,7 @@ impl InterpretableFrom<CheckDcdtDataRaw> for CheckDcdtData {
             instances: CheckDcdtInstances::interpret_from(from.instances, context),
             last_nonce: CheckValue::<U64Value>::interpret_from(from.last_nonce, context),
             frozen: CheckValue::<U64Value>::interpret_from(from.frozen, context),
+            roles: from.roles,
         }
     }
 }
@@ -30,7 +33,7 @@ impl IntoRaw<CheckDcdtDataRaw> for CheckDcdtData {
         CheckDcdtDataRaw {
             instances: self.instances.into_raw(),
             last_nonce: self.last_nonce.into_raw(),
-            roles: Vec::new(),
+            roles: self.roles,
             frozen: self.frozen.into_raw(),
         }
     }
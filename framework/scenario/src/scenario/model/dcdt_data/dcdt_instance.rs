@@ -16,6 +16,7 @@ pub struct DcdtInstance {
     pub hash: Option<BytesValue>,
     pub uri: Vec<BytesValue>,
     pub attributes: Option<BytesValue>,
+    pub frozen: Option<U64Value>,
 }
 
 impl DcdtInstance {
@@ -32,6 +33,7 @@ impl DcdtInstance {
             && self.hash.is_none()
             && self.uri.is_empty()
             && self.attributes.is_none()
+            && self.frozen.is_none()
     }
 }
 
@@ -55,6 +57,7 @@ impl InterpretableFrom<DcdtInstanceRaw> for DcdtInstance {
             attributes: from
                 .attributes
                 .map(|b| BytesValue::interpret_from(b, context)),
+            frozen: from.frozen.map(|b| U64Value::interpret_from(b, context)),
         }
     }
 }
@@ -69,6 +72,7 @@ impl IntoRaw<DcdtInstanceRaw> for DcdtInstance {
             hash: self.hash.map(|n| n.original),
             uri: self.uri.into_iter().map(|b| b.original).collect(),
             attributes: self.attributes.map(|n| n.original),
+            frozen: self.frozen.map(|n| n.original),
         }
     }
 }
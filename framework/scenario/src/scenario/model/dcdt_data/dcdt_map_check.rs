@@ -3,7 +3,9 @@ use crate::scenario_format::{
     serde_raw::CheckDcdtMapRaw,
 };
 
-use super::CheckDcdtMapContents;
+use crate::scenario::model::BytesKey;
+
+use super::{CheckDcdt, CheckDcdtData, CheckDcdtMapContents};
 
 #[derive(Debug, Clone, Default)]
 pub enum CheckDcdtMap {
@@ -17,6 +19,32 @@ impl CheckDcdtMap {
     pub fn is_star(&self) -> bool {
         matches!(self, CheckDcdtMap::Star)
     }
+
+    /// Switches to an explicit list of token checks, if not already the case.
+    ///
+    /// Tokens not in the list are still allowed.
+    pub fn contents_mut(&mut self) -> &mut CheckDcdtMapContents {
+        if !matches!(self, CheckDcdtMap::Equal(_)) {
+            *self = CheckDcdtMap::Equal(CheckDcdtMapContents {
+                contents: Default::default(),
+                other_dcdts_allowed: true,
+            });
+        }
+
+        if let CheckDcdtMap::Equal(contents) = self {
+            contents
+        } else {
+            unreachable!()
+        }
+    }
+
+    /// Provides the check of a token, adding an empty one if it is not yet checked.
+    pub fn dcdt_check_mut(&mut self, token_id: BytesKey) -> &mut CheckDcdt {
+        self.contents_mut()
+            .contents
+            .entry(token_id)
+            .or_insert_with(|| CheckDcdt::Full(CheckDcdtData::default()))
+    }
 }
 
 impl InterpretableFrom<CheckDcdtMapRaw> for CheckDcdtMap {
@@ -18,6 +18,8 @@ pub struct TxResponse {
     pub logs: Vec<Log>,
     /// The gas used by the transaction.
     pub gas_used: u64,
+    /// The gas limit estimated before sending the transaction, if gas estimation was enabled.
+    pub gas_estimate: Option<u64>,
     /// The refund of the transaction.
     pub refund: u64,
     /// The transaction hash, if available.
@@ -43,6 +45,7 @@ impl TxResponse {
                     data: tx_log.data.clone(),
                 })
                 .collect(),
+            gas_used: tx_result.gas_used,
             ..Default::default()
         }
     }
@@ -0,0 +1,522 @@
+use std::{fmt, path::PathBuf};
+
+use dharitri_chain_vm::world_mock::BlockchainState;
+use num_bigint::BigUint;
+use serde::Serialize;
+
+use crate::{
+    scenario::model::{
+        BigUintValue, BytesKey, BytesValue, CheckAccounts, CheckDcdt, CheckDcdtInstances,
+        CheckDcdtMap, CheckLog, CheckLogList, CheckLogs, CheckStorage, CheckValue, CheckValueList,
+        Checkable, Log, TxExpect, TxResponse, U64Value,
+    },
+    scenario_format::{
+        interpret_trait::IntoRaw,
+        serde_raw::{
+            CheckAccountsRaw, CheckDcdtMapRaw, CheckStorageRaw, TxExpectRaw, ValueSubTree,
+        },
+    },
+};
+
+use super::ScenarioVMRunner;
+
+/// Environment variable that turns on bless mode for all scenario runs, e.g. `SCENARIO_BLESS=1`.
+pub const SCENARIO_BLESS_ENV_VAR: &str = "SCENARIO_BLESS";
+
+/// Whether bless mode was requested through the environment.
+pub fn bless_requested_by_env() -> bool {
+    std::env::var(SCENARIO_BLESS_ENV_VAR).is_ok_and(|value| !value.is_empty() && value != "0")
+}
+
+/// A single expectation rewritten in bless mode.
+///
+/// The old and new values are the JSON representations, as they appear in the scenario file.
+#[derive(Clone, Debug)]
+pub struct BlessChange {
+    pub location: String,
+    pub field: String,
+    pub old: String,
+    pub new: String,
+}
+
+impl fmt::Display for BlessChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "  {}, {}:", self.location, self.field)?;
+        writeln!(f, "    - {}", self.old)?;
+        write!(f, "    + {}", self.new)
+    }
+}
+
+/// All the expectations rewritten in bless mode, grouped by scenario file.
+#[derive(Clone, Debug, Default)]
+pub struct BlessReport {
+    pub files: Vec<(PathBuf, Vec<BlessChange>)>,
+}
+
+impl BlessReport {
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    pub fn num_changes(&self) -> usize {
+        self.files.iter().map(|(_, changes)| changes.len()).sum()
+    }
+
+    pub fn print_summary(&self) {
+        if self.is_empty() {
+            println!("bless: all expectations already up to date");
+            return;
+        }
+
+        for (path, changes) in &self.files {
+            println!("bless: rewrote {}", path.display());
+            for change in changes {
+                println!("{change}");
+            }
+        }
+        println!(
+            "bless: {} expectations rewritten in {} files",
+            self.num_changes(),
+            self.files.len()
+        );
+    }
+}
+
+/// Rewrites the `out`, `status`, `message` and `logs` fields of the raw expectation that do not match the response.
+///
+/// Fields that match are left untouched, and so are the individual values that still match,
+/// so the original expressions are preserved wherever possible.
+pub fn bless_tx_expect(
+    tx_id: &str,
+    tx_expect: &TxExpect,
+    tx_response: &TxResponse,
+    raw: &mut TxExpectRaw,
+) -> Vec<BlessChange> {
+    let location = format!("tx '{tx_id}'");
+    let mut changes = Vec::new();
+
+    let status = tx_response.tx_error.status.as_u64();
+    if !tx_expect.status.check(status) {
+        let new_status = CheckValue::Equal(bless_u64(&tx_expect.status, status));
+        patch(
+            &mut changes,
+            &location,
+            "status",
+            &mut raw.status,
+            new_status.into_raw_explicit(),
+        );
+    }
+
+    if !tx_expect.out.check(tx_response.out.as_slice()) {
+        let new_out = bless_list(Some(&tx_expect.out), &tx_response.out);
+        patch(
+            &mut changes,
+            &location,
+            "out",
+            &mut raw.out,
+            new_out.into_raw(),
+        );
+    }
+
+    let message = tx_response.tx_error.message.as_bytes();
+    if !tx_expect.message.check(message) {
+        let new_message = bless_check_value(Some(&tx_expect.message), message, "str:");
+        patch(
+            &mut changes,
+            &location,
+            "message",
+            &mut raw.message,
+            new_message.into_raw(),
+        );
+    }
+
+    if let CheckLogs::List(expected_logs) = &tx_expect.logs {
+        if !logs_match(expected_logs, &tx_response.logs) {
+            let new_logs = CheckLogs::List(CheckLogList {
+                list: tx_response
+                    .logs
+                    .iter()
+                    .enumerate()
+                    .map(|(i, log)| bless_log(expected_logs.list.get(i), log))
+                    .collect(),
+                more_allowed_at_end: expected_logs.more_allowed_at_end,
+            });
+            patch(
+                &mut changes,
+                &location,
+                "logs",
+                &mut raw.logs,
+                new_logs.into_raw(),
+            );
+        }
+    }
+
+    changes
+}
+
+impl ScenarioVMRunner {
+    /// Rewrites the account checks that do not match the current state, both in the model and in the raw step.
+    ///
+    /// Covers the nonce, balance, username, developer rewards, storage and DCDT balances.
+    /// Missing accounts and the other fields are left as they are, for the regular check to report.
+    pub fn bless_check_state(
+        &self,
+        accounts: &mut CheckAccounts,
+        raw: &mut CheckAccountsRaw,
+    ) -> Vec<BlessChange> {
+        bless_accounts(&self.blockchain_mock.state, accounts, raw)
+    }
+}
+
+fn bless_accounts(
+    state: &BlockchainState,
+    accounts: &mut CheckAccounts,
+    raw: &mut CheckAccountsRaw,
+) -> Vec<BlessChange> {
+    let mut changes = Vec::new();
+    for (address, expected) in accounts.accounts.iter_mut() {
+        let Some(account) = state.accounts.get(&address.to_address()) else {
+            continue;
+        };
+        let Some(raw_account) = raw.accounts.get_mut(&address.original) else {
+            continue;
+        };
+        let location = format!("checkState '{address}'");
+
+        if !expected.nonce.check(account.nonce) {
+            expected.nonce = CheckValue::Equal(bless_u64(&expected.nonce, account.nonce));
+            let new_raw = expected.nonce.clone().into_raw();
+            patch(
+                &mut changes,
+                &location,
+                "nonce",
+                &mut raw_account.nonce,
+                new_raw,
+            );
+        }
+
+        if !expected.balance.check(&account.rewa_balance) {
+            expected.balance =
+                CheckValue::Equal(bless_big_uint(&expected.balance, &account.rewa_balance));
+            let new_raw = expected.balance.clone().into_raw();
+            patch(
+                &mut changes,
+                &location,
+                "balance",
+                &mut raw_account.balance,
+                new_raw,
+            );
+        }
+
+        if !expected.username.check(&account.username) {
+            expected.username =
+                bless_check_value(Some(&expected.username), &account.username, "str:");
+            let new_raw = expected.username.clone().into_raw();
+            patch(
+                &mut changes,
+                &location,
+                "username",
+                &mut raw_account.username,
+                new_raw,
+            );
+        }
+
+        if !expected.developer_rewards.check(&account.developer_rewards) {
+            expected.developer_rewards = CheckValue::Equal(bless_big_uint(
+                &expected.developer_rewards,
+                &account.developer_rewards,
+            ));
+            let new_raw = expected.developer_rewards.clone().into_raw();
+            patch(
+                &mut changes,
+                &location,
+                "developerRewards",
+                &mut raw_account.developer_rewards,
+                new_raw,
+            );
+        }
+
+        if let (CheckStorage::Equal(details), CheckStorageRaw::Equal(raw_details)) =
+            (&mut expected.storage, &mut raw_account.storage)
+        {
+            for (key, expected_value) in details.storages.iter_mut() {
+                let actual_value = account.storage.get(&key.value).cloned().unwrap_or_default();
+                if !expected_value.check(&actual_value) {
+                    *expected_value = bless_check_value(Some(expected_value), &actual_value, "");
+                    let raw_value = raw_details
+                        .storages
+                        .entry(key.original.clone())
+                        .or_default();
+                    patch(
+                        &mut changes,
+                        &location,
+                        &format!("storage '{}'", key.original),
+                        raw_value,
+                        expected_value.clone().into_raw_explicit(),
+                    );
+                }
+            }
+
+            if !details.other_storages_allowed {
+                let mut missing_keys: Vec<&Vec<u8>> = account
+                    .storage
+                    .iter()
+                    .filter(|(key, value)| {
+                        !value.is_empty()
+                            && !details
+                                .storages
+                                .contains_key(&BytesKey::from((*key).clone()))
+                    })
+                    .map(|(key, _)| key)
+                    .collect();
+                missing_keys.sort();
+                for key in missing_keys {
+                    let key = BytesKey::from(bytes_expr(None, key, "str:").as_str());
+                    let new_value = bless_check_value(None, &account.storage[&key.value], "");
+                    details.storages.insert(key.clone(), new_value.clone());
+                    let raw_value = raw_details
+                        .storages
+                        .entry(key.original.clone())
+                        .or_default();
+                    patch(
+                        &mut changes,
+                        &location,
+                        &format!("storage '{}'", key.original),
+                        raw_value,
+                        new_value.into_raw_explicit(),
+                    );
+                }
+            }
+        }
+
+        if let (CheckDcdtMap::Equal(contents), CheckDcdtMapRaw::Equal(raw_contents)) =
+            (&mut expected.dcdt, &mut raw_account.dcdt)
+        {
+            for (token, expected_dcdt) in contents.contents.iter_mut() {
+                let actual = account.dcdt.get_by_identifier_or_default(&token.value);
+                if !bless_dcdt_balances(expected_dcdt, |nonce| {
+                    actual
+                        .instances
+                        .get_by_nonce(nonce)
+                        .map(|instance| instance.balance.clone())
+                        .unwrap_or_default()
+                }) {
+                    continue;
+                }
+                let Some(raw_dcdt) = raw_contents.contents.get_mut(&token.original) else {
+                    continue;
+                };
+                patch(
+                    &mut changes,
+                    &location,
+                    &format!("dcdt '{}'", token.original),
+                    raw_dcdt,
+                    expected_dcdt.clone().into_raw(),
+                );
+            }
+        }
+    }
+    changes
+}
+
+/// Only the balances are blessed, per nonce. Returns true if anything changed.
+fn bless_dcdt_balances<F>(expected: &mut CheckDcdt, actual_balance: F) -> bool
+where
+    F: Fn(u64) -> BigUint,
+{
+    match expected {
+        CheckDcdt::Short(expected_balance) => {
+            let actual = actual_balance(0);
+            if expected_balance.value == actual {
+                return false;
+            }
+            *expected_balance = bless_big_uint_value(Some(&*expected_balance), &actual);
+            true
+        },
+        CheckDcdt::Full(expected_data) => {
+            let CheckDcdtInstances::Equal(instances) = &mut expected_data.instances else {
+                return false;
+            };
+            let mut changed = false;
+            for instance in instances.iter_mut() {
+                let actual = actual_balance(instance.nonce.value);
+                if !instance.balance.check(&actual) {
+                    instance.balance =
+                        CheckValue::Equal(bless_big_uint(&instance.balance, &actual));
+                    changed = true;
+                }
+            }
+            changed
+        },
+    }
+}
+
+fn patch<R: Serialize>(
+    changes: &mut Vec<BlessChange>,
+    location: &str,
+    field: &str,
+    raw: &mut R,
+    new_raw: R,
+) {
+    let old = raw_json(raw);
+    *raw = new_raw;
+    changes.push(BlessChange {
+        location: location.to_string(),
+        field: field.to_string(),
+        old,
+        new: raw_json(raw),
+    });
+}
+
+fn raw_json<R: Serialize>(raw: &R) -> String {
+    serde_json::to_string(raw).unwrap_or_default()
+}
+
+fn logs_match(expected: &CheckLogList, actual: &[Log]) -> bool {
+    if actual.len() < expected.list.len()
+        || (actual.len() > expected.list.len() && !expected.more_allowed_at_end)
+    {
+        return false;
+    }
+    expected
+        .list
+        .iter()
+        .zip(actual)
+        .all(|(expected_log, actual_log)| log_matches(expected_log, actual_log))
+}
+
+fn log_matches(expected: &CheckLog, actual: &Log) -> bool {
+    expected.address.check(actual.address.as_bytes())
+        && expected.endpoint.check(actual.endpoint.as_bytes())
+        && expected.topics.check(actual.topics.as_slice())
+        && expected.data.check(actual.data.as_slice())
+}
+
+fn bless_log(old: Option<&CheckLog>, actual: &Log) -> CheckLog {
+    CheckLog {
+        address: bless_check_value(old.map(|log| &log.address), actual.address.as_bytes(), ""),
+        endpoint: bless_check_value(
+            old.map(|log| &log.endpoint),
+            actual.endpoint.as_bytes(),
+            "str:",
+        ),
+        topics: bless_list(old.map(|log| &log.topics), &actual.topics),
+        data: bless_list(old.map(|log| &log.data), &actual.data),
+    }
+}
+
+fn bless_list(old: Option<&CheckValueList>, actual: &[Vec<u8>]) -> CheckValueList {
+    if let Some(old) = old {
+        if old.check(actual) {
+            return old.clone();
+        }
+    }
+
+    let old_items = match old {
+        Some(CheckValue::Equal(old_items)) => old_items.as_slice(),
+        _ => &[],
+    };
+    CheckValue::Equal(
+        actual
+            .iter()
+            .enumerate()
+            .map(|(i, value)| bless_check_value(old_items.get(i), value, ""))
+            .collect(),
+    )
+}
+
+/// Keeps the old check if it still matches, otherwise builds a new one in the style of the old expression.
+fn bless_check_value(
+    old: Option<&CheckValue<BytesValue>>,
+    actual: &[u8],
+    default_prefix: &str,
+) -> CheckValue<BytesValue> {
+    match old {
+        Some(old) if old.check(actual) => old.clone(),
+        Some(CheckValue::Equal(old)) => CheckValue::Equal(BytesValue::from(
+            bytes_expr(Some(&old.original), actual, default_prefix).as_str(),
+        )),
+        _ => CheckValue::Equal(BytesValue::from(
+            bytes_expr(None, actual, default_prefix).as_str(),
+        )),
+    }
+}
+
+/// Formats bytes the same way as the original expression: `str:`, decimal or `0x` hex.
+///
+/// New values, with no original, use the default prefix if it applies, `0x` otherwise.
+fn bytes_expr(original: Option<&ValueSubTree>, actual: &[u8], default_prefix: &str) -> String {
+    if actual.is_empty() {
+        return String::new();
+    }
+
+    let like = match original {
+        Some(ValueSubTree::Str(original)) => original.as_str(),
+        Some(_) => "",
+        None => default_prefix,
+    };
+    if like.starts_with("str:") {
+        if let Some(s) = printable_str(actual) {
+            return format!("str:{s}");
+        }
+    }
+    if is_decimal(like) && actual[0] != 0 {
+        return decimal_expr(&BigUint::from_bytes_be(actual), like);
+    }
+    format!("0x{}", hex::encode(actual))
+}
+
+fn printable_str(bytes: &[u8]) -> Option<&str> {
+    std::str::from_utf8(bytes)
+        .ok()
+        .filter(|s| !s.chars().any(char::is_control))
+}
+
+fn bless_u64(old: &CheckValue<U64Value>, actual: u64) -> U64Value {
+    let like = match old {
+        CheckValue::Equal(U64Value {
+            original: ValueSubTree::Str(original),
+            ..
+        }) => original.as_str(),
+        _ => "",
+    };
+    U64Value::from(decimal_expr(&BigUint::from(actual), like).as_str())
+}
+
+fn bless_big_uint(old: &CheckValue<BigUintValue>, actual: &BigUint) -> BigUintValue {
+    match old {
+        CheckValue::Equal(old) => bless_big_uint_value(Some(old), actual),
+        CheckValue::Star => bless_big_uint_value(None, actual),
+    }
+}
+
+fn bless_big_uint_value(old: Option<&BigUintValue>, actual: &BigUint) -> BigUintValue {
+    let like = match old.map(|old| &old.original) {
+        Some(ValueSubTree::Str(original)) => original.as_str(),
+        _ => "",
+    };
+    BigUintValue::from(decimal_expr(actual, like).as_str())
+}
+
+fn is_decimal(s: &str) -> bool {
+    s.chars().any(|c| c.is_ascii_digit())
+        && s.chars()
+            .all(|c| c.is_ascii_digit() || c == ',' || c == '_')
+}
+
+/// Keeps the digit separator of the original expression, if it had one.
+fn decimal_expr(value: &BigUint, like: &str) -> String {
+    let digits = value.to_string();
+    let Some(separator) = like.chars().find(|c| *c == ',' || *c == '_') else {
+        return digits;
+    };
+
+    let mut result = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            result.push(separator);
+        }
+        result.push(digit);
+    }
+    result
+}
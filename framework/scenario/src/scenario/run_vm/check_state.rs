@@ -3,6 +3,7 @@ use crate::scenario::model::{
     CheckDcdtInstances, CheckDcdtMap, CheckStateStep, CheckStorage, CheckValue, Checkable,
 };
 use num_traits::Zero;
+use std::fmt::Display;
 
 use dharitri_chain_vm::{
     display_util::{bytes_to_string, verbose_hex, verbose_hex_list},
@@ -221,11 +222,39 @@ pub fn check_dcdt_data(
 
     if !expected.frozen.check(u64::from(actual.frozen)) {
         errors.push(format!(
-            "bad last nonce. Address: {}. Token Name: {}. Want: {}. Have: {}\n",
-            address, token, expected.frozen, &actual.frozen
+            "bad frozen state. Address: {}. Token Name: {}. Want: {}. Have: {}\n",
+            address,
+            token,
+            expected.frozen,
+            u64::from(actual.frozen)
         ));
     }
 
+    if !expected.roles.is_empty() {
+        // roles can also be written as `str:` expressions
+        let mut expected_roles: Vec<String> = expected
+            .roles
+            .iter()
+            .map(|role| role.strip_prefix("str:").unwrap_or(role).to_string())
+            .collect();
+        expected_roles.sort();
+        let mut actual_roles: Vec<String> = actual
+            .get_roles()
+            .iter()
+            .map(|role| bytes_to_string(role))
+            .collect();
+        actual_roles.sort();
+        if expected_roles != actual_roles {
+            errors.push(format!(
+                "bad roles. Address: {}. Token Name: {}. Want: [{}]. Have: [{}]\n",
+                address,
+                token,
+                expected_roles.join(", "),
+                actual_roles.join(", ")
+            ));
+        }
+    }
+
     errors.push("".to_string());
     assert!(errors.len() == 2, "{}", errors.join("\n"));
 }
@@ -263,6 +292,7 @@ pub fn check_token_instances(
     }
 }
 
+/// All the mismatching fields of an instance are reported together, one per line.
 pub fn check_token_instance(
     address: &AddressKey,
     token: &str,
@@ -270,86 +300,78 @@ pub fn check_token_instance(
     actual_value: &DcdtInstance,
     errors: &mut Vec<String>,
 ) {
-    if !expected_value.balance.check(&actual_value.balance) {
-        errors.push(format!(
-            "bad dcdt balance. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
-            address,
-            token,
-            expected_value.nonce.value,
-            expected_value.balance,
-            &actual_value.balance,
-        ))
-    }
+    let mut diffs = Vec::new();
 
     if !expected_value.balance.check(&actual_value.balance) {
-        errors.push(format!(
-            "bad dcdt balance. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
-            address,
-            token,
-            expected_value.nonce.value,
-            expected_value.balance,
+        diffs.push(field_diff(
+            "balance",
+            &expected_value.balance,
             &actual_value.balance,
-        ))
+        ));
     }
+
     let actual_creator = if let Some(creator) = &actual_value.metadata.creator {
         creator.as_ref()
     } else {
         &[]
     };
     if !expected_value.creator.check(actual_creator) {
-        errors.push(format!(
-            "bad dcdt creator. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
-            address,
-            token,
-            expected_value.nonce.value,
-            expected_value.creator,
+        diffs.push(field_diff(
+            "creator",
+            &expected_value.creator,
             verbose_hex(actual_creator),
-        ))
+        ));
     }
 
     let actual_royalties = actual_value.metadata.royalties;
     if !expected_value.royalties.check(actual_royalties) {
-        errors.push(format!(
-            "bad dcdt royalties. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
-            address, token, expected_value.nonce.value, expected_value.royalties, actual_royalties
-        ))
+        diffs.push(field_diff(
+            "royalties",
+            &expected_value.royalties,
+            actual_royalties,
+        ));
     }
 
     let actual_hash = actual_value.metadata.hash.clone().unwrap_or_default();
     if !expected_value.hash.check(&actual_hash) {
-        errors.push(format!(
-            "bad dcdt hash. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
-            address,
-            token,
-            expected_value.nonce.value,
-            expected_value.hash,
+        diffs.push(field_diff(
+            "hash",
+            &expected_value.hash,
             verbose_hex(&actual_hash),
-        ))
+        ));
     }
 
     let actual_uri = actual_value.metadata.uri.as_slice();
     if !expected_value.uri.check(actual_uri) {
-        errors.push(format!(
-            "bad dcdt uri. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
-            address,
-            token,
-            expected_value.nonce.value,
+        diffs.push(field_diff(
+            "uris",
             expected_value.uri.pretty_str(),
             verbose_hex_list(actual_uri),
-        ))
+        ));
     }
 
     if !expected_value
         .attributes
         .check(&actual_value.metadata.attributes)
     {
+        diffs.push(field_diff(
+            "attributes",
+            &expected_value.attributes,
+            verbose_hex(&actual_value.metadata.attributes),
+        ));
+    }
+
+    if !diffs.is_empty() {
         errors.push(format!(
-            "bad dcdt attributes. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
+            "bad dcdt instance. Address: {}. Token {}. Nonce {}.\n{}",
             address,
             token,
             expected_value.nonce.value,
-            expected_value.attributes,
-            verbose_hex(&actual_value.metadata.attributes),
-        ))
+            diffs.join("\n"),
+        ));
     }
 }
+
+fn field_diff(field: &str, want: impl Display, have: impl Display) -> String {
+    format!("  {field}:\n    want: {want}\n    have: {have}")
+}
@@ -1,3 +1,4 @@
+mod bless;
 mod check_state;
 pub mod sc_call;
 pub mod sc_deploy;
@@ -8,5 +9,9 @@ mod tx_input_util;
 mod tx_output_check;
 mod vm_runner;
 
+pub use bless::{
+    bless_requested_by_env, bless_tx_expect, BlessChange, BlessReport, SCENARIO_BLESS_ENV_VAR,
+};
+pub use tx_output_check::check_tx_response;
 use tx_output_check::*;
 pub use vm_runner::ScenarioVMRunner;
@@ -77,7 +77,8 @@ impl ScenarioVMRunner {
     {
         let tx_result = self.perform_sc_call_lambda(sc_call_step, f);
         if let Some(tx_expect) = &sc_call_step.expect {
-            check_tx_output(&sc_call_step.id, tx_expect, &tx_result);
+            let gas_remaining = self.gas_remaining(sc_call_step.tx.gas_limit.value, &tx_result);
+            check_tx_output(&sc_call_step.id, tx_expect, &tx_result, gas_remaining);
         }
         tx_result
     }
@@ -56,7 +56,8 @@ impl ScenarioVMRunner {
     {
         let (new_address, tx_result) = self.perform_sc_deploy_lambda(sc_deploy_step, f);
         if let Some(tx_expect) = &sc_deploy_step.expect {
-            check_tx_output(&sc_deploy_step.id, tx_expect, &tx_result);
+            let gas_remaining = self.gas_remaining(sc_deploy_step.tx.gas_limit.value, &tx_result);
+            check_tx_output(&sc_deploy_step.id, tx_expect, &tx_result, gas_remaining);
         }
         (new_address, tx_result)
     }
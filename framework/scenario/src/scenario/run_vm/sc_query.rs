@@ -40,7 +40,7 @@ impl ScenarioVMRunner {
     {
         let tx_result = self.perform_sc_query_lambda(step, f);
         if let Some(tx_expect) = &step.expect {
-            check_tx_output(&step.id, tx_expect, &tx_result);
+            check_tx_output(&step.id, tx_expect, &tx_result, None);
         }
         tx_result
     }
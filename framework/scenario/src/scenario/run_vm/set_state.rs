@@ -173,6 +173,11 @@ fn convert_scenario_dcdt_instance_to_world_mock(
                 .map(|attributes| attributes.value.clone())
                 .unwrap_or_default(),
         },
+        frozen: scenario_dcdt
+            .frozen
+            .as_ref()
+            .map(|frozen| frozen.value > 0)
+            .unwrap_or_default(),
     }
 }
 
@@ -1,11 +1,17 @@
-use crate::scenario::model::{CheckLogs, Checkable, TxExpect};
+use crate::scenario::model::{CheckLogs, Checkable, TxExpect, TxResponse};
 
 use dharitri_chain_vm::{
     display_util::{address_hex, verbose_hex_list},
     tx_mock::{TxLog, TxResult},
 };
 
-pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult) {
+/// The `gas` field is only checked if the gas remaining is known, i.e. when gas is metered.
+pub fn check_tx_output(
+    tx_id: &str,
+    tx_expect: &TxExpect,
+    tx_result: &TxResult,
+    gas_remaining: Option<u64>,
+) {
     let have_str = tx_result.result_message.as_str();
     assert!(
         tx_expect.status.check(tx_result.result_status.as_u64()),
@@ -32,6 +38,17 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
         have_str,
     );
 
+    if let Some(gas_remaining) = gas_remaining {
+        assert!(
+            tx_expect.gas.check(gas_remaining),
+            "gas remaining mismatch. Tx id: '{}'. Want: {}. Have: {}. Gas used: {}",
+            tx_id,
+            tx_expect.gas,
+            gas_remaining,
+            tx_result.gas_used,
+        );
+    }
+
     match &tx_expect.logs {
         CheckLogs::Star => {},
         CheckLogs::List(expected_logs) => {
@@ -65,6 +82,29 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
     }
 }
 
+/// Same as [`check_tx_output`], for responses that do not come from the VM, e.g. retrieved from the network.
+///
+/// The gas is not checked, since the remaining gas is not reported by the network.
+pub fn check_tx_response(tx_id: &str, tx_expect: &TxExpect, tx_response: &TxResponse) {
+    let tx_result = TxResult {
+        result_status: tx_response.tx_error.status,
+        result_message: tx_response.tx_error.message.clone(),
+        result_values: tx_response.out.clone(),
+        result_logs: tx_response
+            .logs
+            .iter()
+            .map(|log| TxLog {
+                address: log.address.clone(),
+                endpoint: log.endpoint.clone().into(),
+                topics: log.topics.clone(),
+                data: log.data.clone(),
+            })
+            .collect(),
+        ..Default::default()
+    };
+    check_tx_output(tx_id, tx_expect, &tx_result, None);
+}
+
 fn scenario_check(
     actual_log: &TxLog,
     expected_log: &crate::scenario::model::CheckLog,
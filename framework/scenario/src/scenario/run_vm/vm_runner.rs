@@ -1,6 +1,6 @@
 use crate::{
     debug_executor::ContractMapRef,
-    dharitri_chain_vm::BlockchainMock,
+    dharitri_chain_vm::{tx_mock::TxResult, BlockchainMock},
     scenario::{model::*, ScenarioRunner},
 };
 
@@ -21,6 +21,15 @@ impl ScenarioVMRunner {
             blockchain_mock,
         }
     }
+
+    /// Gas left unused by a transaction. Only known when gas is metered.
+    pub(super) fn gas_remaining(&self, gas_limit: u64, tx_result: &TxResult) -> Option<u64> {
+        if self.blockchain_mock.vm.is_gas_metered() {
+            Some(gas_limit.saturating_sub(tx_result.gas_used))
+        } else {
+            None
+        }
+    }
 }
 
 impl ScenarioRunner for ScenarioVMRunner {
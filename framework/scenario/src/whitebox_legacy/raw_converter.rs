@@ -82,6 +82,7 @@ pub(crate) fn dcdt_data_as_raw(dcdt: &DcdtData) -> DcdtRaw {
             nonce: Some(u64_as_raw(inst.nonce)),
             royalties: Some(u64_as_raw(inst.metadata.royalties)),
             uri: inst.metadata.uri.iter().map(bytes_as_raw).collect(),
+            frozen: inst.frozen.then(|| u64_as_raw(1)),
         };
 
         instances_raw.push(inst_raw);
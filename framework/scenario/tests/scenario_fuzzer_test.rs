@@ -0,0 +1,61 @@
+use dharitri_sc_scenario::imports::*;
+
+const SOURCE_ADDRESS: TestAddress = TestAddress::new("source");
+const ACCOUNT_ADDRESS: TestAddress = TestAddress::new("account");
+const MAX_BALANCE: u64 = 30;
+
+/// Only reachable by simplifying an action, the generator never produces it.
+const PANICKING_VALUE: u64 = 7;
+
+fn balance(world: &ScenarioWorld) -> u64 {
+    let account = &world.state().accounts[&ACCOUNT_ADDRESS.to_address()];
+    u64::try_from(&account.rewa_balance).unwrap()
+}
+
+fn fuzzer() -> ScenarioFuzzer<u64> {
+    ScenarioFuzzer::new(
+        ScenarioWorld::new,
+        |rng| *rng.choose(&[4, 5, 6, 8, 9]),
+        |world, value| {
+            assert_ne!(*value, PANICKING_VALUE, "unlucky value");
+            world.transfer_step(
+                TransferStep::new()
+                    .from(SOURCE_ADDRESS)
+                    .to(ACCOUNT_ADDRESS)
+                    .rewa_value(*value),
+            );
+        },
+    )
+    .setup(|world| {
+        world.account(SOURCE_ADDRESS).nonce(1).balance(1_000_000u64);
+        world.account(ACCOUNT_ADDRESS).nonce(1);
+    })
+    .simplifier(|value| {
+        if *value > 0 {
+            vec![value - 1]
+        } else {
+            Vec::new()
+        }
+    })
+    .invariant("balance stays low", |world| balance(world) < MAX_BALANCE)
+}
+
+#[test]
+fn fuzzer_shrink_keeps_failure_reason_test() {
+    let failure = fuzzer()
+        .seed(3)
+        .find_failure()
+        .expect("the balance should eventually get too high");
+
+    assert_eq!(failure.reason, "invariant `balance stays low` broken");
+    assert!(
+        !failure.actions.contains(&PANICKING_VALUE),
+        "shrunk into a different failure:\n{failure}"
+    );
+    let total: u64 = failure.actions.iter().sum();
+    assert!(total >= MAX_BALANCE, "not failing:\n{failure}");
+    // none of the remaining actions can be removed
+    for action in &failure.actions {
+        assert!(total - action < MAX_BALANCE, "not shrunk:\n{failure}");
+    }
+}
@@ -143,6 +143,11 @@ fn set_check_username_rs() {
     world().run("tests/scenarios-self/set-check/set-check-username.scen.json");
 }
 
+#[test]
+fn dcdt_system_sc_rs() {
+    world().run("tests/scenarios-self/dcdt-system-sc.scen.json");
+}
+
 #[test]
 fn builtin_func_dcdt_transfer() {
     world().run("tests/scenarios-self/builtin-func-dcdt-transfer.scen.json");
@@ -0,0 +1,120 @@
+use dharitri_sc_scenario::{
+    dharitri_chain_vm::{
+        tx_mock::{
+            AsyncCallTxData, CrossShardCall, CrossShardCallKind, InFlightDcdtPayment,
+            InFlightPayments, Promise, TxFunctionName,
+        },
+        types::{VMAddress, VMTokenType, H256},
+        world_mock::{
+            AccountData, BlockchainState, DcdtInstanceMetadata, DcdtTokenProperties, ShardConfig,
+        },
+    },
+    num_bigint::BigUint,
+    ScenarioWorld,
+};
+
+const NFT_TOKEN_ID: &[u8] = b"NFT-123456";
+
+fn address(name: &[u8; 32]) -> VMAddress {
+    VMAddress::from_slice(name)
+}
+
+fn state() -> BlockchainState {
+    let owner = address(b"owner___________________________");
+    let sc_address = address(b"\0\0\0\0\0\0\0\0\x05\0adder_________________");
+    let mut account = AccountData::new_empty(owner.clone());
+    account.nonce = 3;
+    account.rewa_balance = BigUint::from(1_000u32);
+    account.username = b"owner.x".to_vec();
+    account.storage.insert(b"key".to_vec(), b"value".to_vec());
+    account.dcdt.increase_balance(
+        NFT_TOKEN_ID.to_vec(),
+        1,
+        &BigUint::from(2u32),
+        DcdtInstanceMetadata {
+            name: b"nice nft".to_vec(),
+            creator: Some(owner.clone()),
+            royalties: 1_000,
+            hash: Some(b"hash".to_vec()),
+            uri: vec![b"uri".to_vec()],
+            attributes: b"attributes".to_vec(),
+        },
+    );
+    account.dcdt.set_roles(
+        NFT_TOKEN_ID.to_vec(),
+        vec![b"DCDTRoleNFTCreate".to_vec(), b"DCDTRoleNFTBurn".to_vec()],
+    );
+    let dcdt_data = account.dcdt.get_mut_by_identifier(NFT_TOKEN_ID).unwrap();
+    dcdt_data.frozen = true;
+    dcdt_data.instances.get_mut_by_nonce(1).unwrap().frozen = true;
+
+    let mut token_properties =
+        DcdtTokenProperties::new(b"Nice".to_vec(), VMTokenType::NonFungible, owner.clone(), 0);
+    token_properties.is_paused = true;
+    token_properties.can_pause = true;
+    token_properties.minted_value = BigUint::from(3u32);
+    token_properties.burnt_value = BigUint::from(1u32);
+
+    let call = AsyncCallTxData {
+        from: owner.clone(),
+        to: sc_address.clone(),
+        call_value: BigUint::from(5u32),
+        endpoint_name: TxFunctionName::from("add"),
+        arguments: vec![vec![1, 2]],
+        tx_hash: H256::from([7u8; 32]),
+    };
+
+    let mut state = BlockchainState::default();
+    state.accounts.insert(owner.clone(), account);
+    state.new_addresses.insert((owner.clone(), 3), sc_address);
+    state.new_token_identifiers = vec!["NEW-123456".to_string()];
+    state.current_block_info.block_round = 7;
+    state.current_block_info.block_random_seed = Box::new([9u8; 48]);
+    state
+        .dcdt_tokens
+        .insert(NFT_TOKEN_ID.to_vec(), token_properties);
+    state.shard_config = Some(ShardConfig::new(3));
+    state.cross_shard_calls.push_back(CrossShardCall {
+        delivery_round: 8,
+        payments: InFlightPayments {
+            rewa_value: BigUint::from(5u32),
+            dcdt_values: vec![InFlightDcdtPayment {
+                token_identifier: NFT_TOKEN_ID.to_vec(),
+                nonce: 1,
+                value: BigUint::from(1u32),
+                metadata: DcdtInstanceMetadata {
+                    name: b"nice nft".to_vec(),
+                    ..Default::default()
+                },
+            }],
+        },
+        kind: CrossShardCallKind::AsyncCall {
+            call: call.clone(),
+            promise: Some(Promise {
+                call,
+                success_callback: TxFunctionName::from("success"),
+                error_callback: TxFunctionName::from("error"),
+                callback_closure_data: vec![3, 4],
+            }),
+            gas_limit: 5_000_000,
+        },
+    });
+    state
+}
+
+#[test]
+fn state_snapshot_file_round_trip_test() {
+    let mut world = ScenarioWorld::new();
+    world.restore_state(&state());
+    let snapshot_path = std::env::temp_dir().join("state_snapshot_round_trip.json");
+    world.write_state_snapshot(&snapshot_path);
+
+    let mut loaded_world = ScenarioWorld::new();
+    loaded_world.load_state_snapshot(&snapshot_path);
+
+    let loaded = loaded_world.state();
+    assert!(loaded.dcdt_tokens[NFT_TOKEN_ID].is_paused);
+    assert!(loaded.shard_config.is_some());
+    assert_eq!(loaded.cross_shard_calls.len(), 1);
+    assert_eq!(loaded, world.state());
+}
@@ -0,0 +1,116 @@
+mod abi_value_decoder;
+
+use std::path::Path;
+
+use abi_value_decoder::AbiValueDecoder;
+use anyhow::anyhow;
+use dharitri_sc_scenario::{
+    dharitri_sc::codec::TopDecode,
+    meta::abi_json::{deserialize_abi_from_json, ContractAbiJson, EventAbiJson},
+};
+use dharitri_sdk::data::transaction::Events;
+use serde_json::{Map, Value};
+
+/// Decodes the events emitted by a contract, based on its ABI.
+///
+/// The first topic holds the event identifier, followed by the indexed inputs, one per topic.
+/// The other inputs end up in the data fields, in order.
+pub struct EventDecoder {
+    abi: ContractAbiJson,
+}
+
+impl EventDecoder {
+    pub fn new(abi: ContractAbiJson) -> Self {
+        EventDecoder { abi }
+    }
+
+    /// Loads the ABI from a `.abi.json` file, as generated by the contract build.
+    pub fn from_abi_file(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let abi_json = std::fs::read_to_string(path)?;
+        let abi = deserialize_abi_from_json(&abi_json).map_err(|err| anyhow!(err))?;
+        Ok(Self::new(abi))
+    }
+
+    pub fn event_abi(&self, identifier: &str) -> Option<&EventAbiJson> {
+        self.abi
+            .events
+            .iter()
+            .find(|event_abi| event_abi.identifier == identifier)
+    }
+
+    /// Fails if the event is not declared in the ABI, or if it does not match its declaration.
+    pub fn decode(&self, event: &Events) -> anyhow::Result<DecodedEvent> {
+        let mut topics = event.topics_bytes().into_iter();
+        let identifier = String::from_utf8(topics.next().unwrap_or_default())?;
+        let event_abi = self
+            .event_abi(&identifier)
+            .ok_or_else(|| anyhow!("event not found in the ABI: {identifier}"))?;
+        let mut data = event.data_bytes().into_iter();
+
+        let value_decoder = AbiValueDecoder::new(&self.abi.types);
+        let mut fields = Vec::new();
+        for input in &event_abi.inputs {
+            let indexed = input.indexed.unwrap_or_default();
+            let raw = if indexed {
+                topics
+                    .next()
+                    .ok_or_else(|| anyhow!("missing topic for {}", input.arg_name))?
+            } else {
+                data.next().unwrap_or_default()
+            };
+            let value = value_decoder
+                .decode_top(&input.type_name, &raw)
+                .map_err(|err| anyhow!("cannot decode {}: {err}", input.arg_name))?;
+            fields.push(DecodedEventField {
+                name: input.arg_name.clone(),
+                type_name: input.type_name.clone(),
+                indexed,
+                raw,
+                value,
+            });
+        }
+
+        Ok(DecodedEvent { identifier, fields })
+    }
+}
+
+/// An event, with its inputs decoded according to the ABI.
+#[derive(Clone, Debug)]
+pub struct DecodedEvent {
+    pub identifier: String,
+    pub fields: Vec<DecodedEventField>,
+}
+
+#[derive(Clone, Debug)]
+pub struct DecodedEventField {
+    pub name: String,
+    pub type_name: String,
+    pub indexed: bool,
+    /// The top-encoded value, as emitted by the contract.
+    pub raw: Vec<u8>,
+    pub value: Value,
+}
+
+impl DecodedEvent {
+    pub fn field(&self, name: &str) -> Option<&DecodedEventField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Decodes an input into a Rust type, instead of JSON.
+    pub fn field_value<T: TopDecode>(&self, name: &str) -> anyhow::Result<T> {
+        let field = self
+            .field(name)
+            .ok_or_else(|| anyhow!("event {} has no input {name}", self.identifier))?;
+        T::top_decode(field.raw.as_slice())
+            .map_err(|err| anyhow!("cannot decode {name}: {}", err.message_str()))
+    }
+
+    /// The event inputs, as a JSON object.
+    pub fn to_json(&self) -> Value {
+        let mut fields = Map::new();
+        for field in &self.fields {
+            fields.insert(field.name.clone(), field.value.clone());
+        }
+        Value::Object(fields)
+    }
+}
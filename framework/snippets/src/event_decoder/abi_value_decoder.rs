@@ -0,0 +1,311 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, bail};
+use dharitri_sc_scenario::{
+    bech32,
+    imports::Address,
+    meta::abi_json::{
+        TypeDescriptionJson, TYPE_DESCRIPTION_JSON_TYPE_ENUM,
+        TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM, TYPE_DESCRIPTION_JSON_TYPE_STRUCT,
+    },
+    num_bigint::{BigInt, BigUint},
+};
+use serde_json::{Map, Value};
+
+/// Decodes values to JSON, based on their ABI type names and on the custom types declared in the ABI.
+///
+/// Big numbers become decimal strings, addresses become bech32 strings and raw bytes become hex strings.
+/// Enum variants without fields become their name, the others an object with the variant name as single key.
+pub(crate) struct AbiValueDecoder<'a> {
+    types: &'a BTreeMap<String, TypeDescriptionJson>,
+}
+
+impl<'a> AbiValueDecoder<'a> {
+    pub(crate) fn new(types: &'a BTreeMap<String, TypeDescriptionJson>) -> Self {
+        AbiValueDecoder { types }
+    }
+
+    /// Decodes a value that was top-encoded, such as an event topic.
+    pub(crate) fn decode_top(&self, type_name: &str, bytes: &[u8]) -> anyhow::Result<Value> {
+        let (base, args) = split_type_name(type_name);
+        match (base, args.as_slice()) {
+            ("u8" | "u16" | "u32" | "u64" | "usize", _) => {
+                check_max_len(type_name, bytes, int_width(base))?;
+                Ok(Value::from(unsigned_from_be(bytes)))
+            },
+            ("i8" | "i16" | "i32" | "i64" | "isize", _) => {
+                check_max_len(type_name, bytes, int_width(base))?;
+                Ok(Value::from(signed_from_be(bytes)))
+            },
+            ("bool", _) => match bytes {
+                [] => Ok(Value::Bool(false)),
+                [1] => Ok(Value::Bool(true)),
+                _ => bail!("invalid bool: {}", hex::encode(bytes)),
+            },
+            ("Option", [_]) => {
+                if bytes.is_empty() {
+                    return Ok(Value::Null);
+                }
+                let mut input = bytes;
+                let value = self.decode_nested(type_name, &mut input)?;
+                check_consumed(type_name, input)?;
+                Ok(value)
+            },
+            ("List", [item_type]) => {
+                let mut input = bytes;
+                let mut items = Vec::new();
+                while !input.is_empty() {
+                    items.push(self.decode_nested(item_type, &mut input)?);
+                }
+                Ok(Value::Array(items))
+            },
+            _ => {
+                if let Some(value) = decode_raw_bytes(base, bytes)? {
+                    return Ok(value);
+                }
+                // enums top-encode their first variant as empty bytes
+                let mut input = if bytes.is_empty() && self.is_enum(type_name) {
+                    &[0u8][..]
+                } else {
+                    bytes
+                };
+                let value = self.decode_nested(type_name, &mut input)?;
+                check_consumed(type_name, input)?;
+                Ok(value)
+            },
+        }
+    }
+
+    /// Decodes a value that was nested-encoded, advancing the input.
+    pub(crate) fn decode_nested(
+        &self,
+        type_name: &str,
+        input: &mut &[u8],
+    ) -> anyhow::Result<Value> {
+        let (base, args) = split_type_name(type_name);
+        match (base, args.as_slice()) {
+            ("u8" | "u16" | "u32" | "u64" | "usize", _) => {
+                let bytes = take(input, int_width(base))?;
+                Ok(Value::from(unsigned_from_be(bytes)))
+            },
+            ("i8" | "i16" | "i32" | "i64" | "isize", _) => {
+                let bytes = take(input, int_width(base))?;
+                Ok(Value::from(signed_from_be(bytes)))
+            },
+            ("bool", _) => match take(input, 1)? {
+                [0] => Ok(Value::Bool(false)),
+                [1] => Ok(Value::Bool(true)),
+                other => bail!("invalid bool: {}", hex::encode(other)),
+            },
+            ("Address" | "H256", _) => {
+                let bytes = take(input, 32)?;
+                Ok(decode_raw_bytes(base, bytes)?.unwrap())
+            },
+            ("CodeMetadata", _) => Ok(Value::String(hex::encode(take(input, 2)?))),
+            ("Option", [item_type]) => match take(input, 1)? {
+                [0] => Ok(Value::Null),
+                [1] => self.decode_nested(item_type, input),
+                other => bail!("invalid Option discriminant: {}", hex::encode(other)),
+            },
+            ("List", [item_type]) => {
+                let len = take_len(input)?;
+                let items = (0..len)
+                    .map(|_| self.decode_nested(item_type, input))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                Ok(Value::Array(items))
+            },
+            ("tuple", item_types) => {
+                let items = item_types
+                    .iter()
+                    .map(|item_type| self.decode_nested(item_type, input))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                Ok(Value::Array(items))
+            },
+            (array, [item_type]) if array.starts_with("array") => {
+                let len: usize = array["array".len()..]
+                    .parse()
+                    .map_err(|_| anyhow!("invalid array type: {type_name}"))?;
+                if *item_type == "u8" {
+                    return Ok(Value::String(hex::encode(take(input, len)?)));
+                }
+                let items = (0..len)
+                    .map(|_| self.decode_nested(item_type, input))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                Ok(Value::Array(items))
+            },
+            _ => {
+                if is_dynamic_bytes_type(base) {
+                    let len = take_len(input)?;
+                    let bytes = take(input, len)?;
+                    return Ok(decode_raw_bytes(base, bytes)?.unwrap());
+                }
+                self.decode_custom_type(type_name, input)
+            },
+        }
+    }
+
+    fn decode_custom_type(&self, type_name: &str, input: &mut &[u8]) -> anyhow::Result<Value> {
+        let type_description = self
+            .types
+            .get(type_name)
+            .ok_or_else(|| anyhow!("unknown type: {type_name}"))?;
+        match type_description.content_type.as_str() {
+            TYPE_DESCRIPTION_JSON_TYPE_STRUCT => {
+                let mut fields = Map::new();
+                for field in &type_description.fields {
+                    fields.insert(
+                        field.name.clone(),
+                        self.decode_nested(&field.field_type, input)?,
+                    );
+                }
+                Ok(Value::Object(fields))
+            },
+            TYPE_DESCRIPTION_JSON_TYPE_ENUM => {
+                let discriminant = take(input, 1)?[0] as usize;
+                let (_, variant) = type_description
+                    .variants
+                    .iter()
+                    .enumerate()
+                    .find(|(index, variant)| variant.discriminant.unwrap_or(*index) == discriminant)
+                    .ok_or_else(|| anyhow!("invalid {type_name} discriminant: {discriminant}"))?;
+                if variant.fields.is_empty() {
+                    return Ok(Value::String(variant.name.clone()));
+                }
+
+                let mut fields = Map::new();
+                for field in &variant.fields {
+                    fields.insert(
+                        field.name.clone(),
+                        self.decode_nested(&field.field_type, input)?,
+                    );
+                }
+                let mut value = Map::new();
+                value.insert(variant.name.clone(), Value::Object(fields));
+                Ok(Value::Object(value))
+            },
+            TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM => {
+                let len = take_len(input)?;
+                let name = String::from_utf8(take(input, len)?.to_vec())?;
+                Ok(Value::String(name))
+            },
+            other => bail!("cannot decode {type_name}, of type {other}"),
+        }
+    }
+
+    fn is_enum(&self, type_name: &str) -> bool {
+        self.types.get(type_name).is_some_and(|type_description| {
+            type_description.content_type == TYPE_DESCRIPTION_JSON_TYPE_ENUM
+        })
+    }
+}
+
+/// Splits `Base<Arg1,Arg2>` into its base name and its type arguments.
+fn split_type_name(type_name: &str) -> (&str, Vec<&str>) {
+    let Some(start) = type_name.find('<') else {
+        return (type_name, Vec::new());
+    };
+    if !type_name.ends_with('>') {
+        return (type_name, Vec::new());
+    }
+
+    let inner = &type_name[start + 1..type_name.len() - 1];
+    let mut args = Vec::new();
+    let mut depth = 0;
+    let mut arg_start = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(inner[arg_start..i].trim());
+                arg_start = i + 1;
+            },
+            _ => {},
+        }
+    }
+    args.push(inner[arg_start..].trim());
+    (&type_name[..start], args)
+}
+
+fn int_width(base: &str) -> usize {
+    match base {
+        "u8" | "i8" => 1,
+        "u16" | "i16" => 2,
+        "u32" | "i32" | "usize" | "isize" => 4,
+        _ => 8,
+    }
+}
+
+/// Types encoded as a length followed by their bytes, when nested.
+fn is_dynamic_bytes_type(base: &str) -> bool {
+    matches!(
+        base,
+        "BigUint"
+            | "BigInt"
+            | "bytes"
+            | "utf-8 string"
+            | "TokenIdentifier"
+            | "RewaOrDcdtTokenIdentifier"
+    )
+}
+
+/// The types that top-encode as their raw bytes, as JSON.
+fn decode_raw_bytes(base: &str, bytes: &[u8]) -> anyhow::Result<Option<Value>> {
+    let value = match base {
+        "BigUint" => Value::String(BigUint::from_bytes_be(bytes).to_string()),
+        "BigInt" => Value::String(BigInt::from_signed_bytes_be(bytes).to_string()),
+        "bytes" | "H256" => Value::String(hex::encode(bytes)),
+        "utf-8 string" | "TokenIdentifier" | "RewaOrDcdtTokenIdentifier" => {
+            Value::String(String::from_utf8(bytes.to_vec())?)
+        },
+        "Address" => {
+            if bytes.len() != 32 {
+                bail!("invalid address length: {}", bytes.len());
+            }
+            Value::String(bech32::encode(&Address::from_slice(bytes)))
+        },
+        _ => return Ok(None),
+    };
+    Ok(Some(value))
+}
+
+fn unsigned_from_be(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64)
+}
+
+fn signed_from_be(bytes: &[u8]) -> i64 {
+    if bytes.is_empty() {
+        return 0;
+    }
+    let negative = bytes[0] & 0x80 != 0;
+    let mut padded = [if negative { 0xff } else { 0 }; 8];
+    padded[8 - bytes.len()..].copy_from_slice(bytes);
+    i64::from_be_bytes(padded)
+}
+
+fn take<'b>(input: &mut &'b [u8], len: usize) -> anyhow::Result<&'b [u8]> {
+    if input.len() < len {
+        bail!("input too short");
+    }
+    let (taken, rest) = input.split_at(len);
+    *input = rest;
+    Ok(taken)
+}
+
+fn take_len(input: &mut &[u8]) -> anyhow::Result<usize> {
+    Ok(unsigned_from_be(take(input, 4)?) as usize)
+}
+
+fn check_max_len(type_name: &str, bytes: &[u8], max_len: usize) -> anyhow::Result<()> {
+    if bytes.len() > max_len {
+        bail!("value too large for {type_name}: {}", hex::encode(bytes));
+    }
+    Ok(())
+}
+
+fn check_consumed(type_name: &str, input: &[u8]) -> anyhow::Result<()> {
+    if !input.is_empty() {
+        bail!("input too long for {type_name}");
+    }
+    Ok(())
+}
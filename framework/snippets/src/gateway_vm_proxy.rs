@@ -0,0 +1,155 @@
+mod vm_account;
+mod vm_block;
+mod vm_chain;
+mod vm_chain_simulator;
+mod vm_network;
+mod vm_query;
+mod vm_tx;
+mod vm_tx_cost;
+mod vm_tx_on_network;
+mod vm_tx_simulate;
+
+use std::sync::{Arc, Mutex};
+
+use dharitri_sc_scenario::{
+    api::DebugApi,
+    debug_executor::ContractContainer,
+    dharitri_sc::contract_base::CallableContractBuilder,
+    imports::{GasSchedule, InterpreterContext, RegisterCodeSource},
+    meta::tools::find_current_workspace,
+    ScenarioTxEnvData,
+};
+use dharitri_sdk::gateway::{
+    process_gateway_response, GatewayAsyncService, GatewayError, GatewayRequest,
+};
+
+pub use vm_network::{VM_CHAIN_ID, VM_USER_FUNDS};
+
+use vm_chain::VMChain;
+
+/// Answers gateway requests in-process, by running the transactions on the Rust VM, instead of sending them over HTTP.
+///
+/// Contracts need to be registered upfront, just like in `ScenarioWorld`.
+///
+/// The chain simulator requests are also answered,
+/// so wallets registered in an interactor with `use_chain_simulator(true)` get funded.
+#[derive(Clone, Debug)]
+pub struct GatewayVMProxy {
+    pub(crate) chain: Arc<Mutex<VMChain>>,
+}
+
+impl GatewayVMProxy {
+    /// Starts from an empty blockchain state.
+    pub fn new() -> Self {
+        Self {
+            chain: Arc::new(Mutex::new(VMChain::new())),
+        }
+    }
+
+    /// Tells the VM gateway where the crate lies relative to the workspace,
+    /// so that the contract paths get resolved the same way as in the interactor.
+    pub fn set_current_dir_from_workspace(&mut self, relative_path: &str) -> &mut Self {
+        let mut path = find_current_workspace().unwrap();
+        path.push(relative_path);
+        self.chain.lock().unwrap().current_dir = path;
+        self
+    }
+
+    /// Links a contract path to a contract implementation.
+    ///
+    /// Deploying any other code results in an error.
+    pub fn register_contract<B: CallableContractBuilder>(
+        &mut self,
+        expression: impl RegisterCodeSource,
+        contract_builder: B,
+    ) {
+        let chain = self.chain.lock().unwrap();
+        let contract_bytes = expression.into_code(ScenarioTxEnvData {
+            interpreter_context: InterpreterContext::new()
+                .with_dir(chain.current_dir.clone())
+                .with_allowed_missing_files(),
+            tx_hash: None,
+        });
+        chain.vm_runner.contract_map_ref.lock().register_contract(
+            contract_bytes,
+            ContractContainer::new(contract_builder.new_contract_obj::<DebugApi>(), None, false),
+        );
+    }
+
+    /// Enables gas metering in the VM, using the given gas schedule.
+    ///
+    /// Without it, transaction cost estimates only cover the base cost, not the execution.
+    pub fn gas_schedule(&mut self, gas_schedule: GasSchedule) -> &mut Self {
+        self.chain
+            .lock()
+            .unwrap()
+            .vm_runner
+            .blockchain_mock
+            .vm
+            .set_gas_schedule(Some(gas_schedule));
+        self
+    }
+
+    /// Performs a request to the gateway.
+    ///
+    /// The payload and the response go through the same JSON serialization as over HTTP.
+    /// Requests the gateway would not accept fail with a `400` status.
+    pub fn vm_request<G>(&self, request: G) -> Result<G::Result, GatewayError>
+    where
+        G: GatewayRequest,
+    {
+        let payload = request
+            .get_payload()
+            .map(serde_json::to_value)
+            .transpose()?;
+        let response = self
+            .chain
+            .lock()
+            .unwrap()
+            .handle_request(request.request_type(), &request.get_endpoint(), payload)
+            .map_err(|err| GatewayError::HttpStatus {
+                status: 400,
+                body: err.to_string(),
+            })?;
+
+        process_gateway_response(&request, response)
+    }
+}
+
+impl Default for GatewayVMProxy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GatewayAsyncService for GatewayVMProxy {
+    type Instant = std::time::Instant;
+
+    /// The URI is ignored, each proxy has its own blockchain state.
+    fn from_uri(_uri: &str) -> Self {
+        Self::new()
+    }
+
+    fn request<G>(
+        &self,
+        request: G,
+    ) -> impl std::future::Future<Output = Result<G::Result, GatewayError>>
+    where
+        G: GatewayRequest,
+    {
+        std::future::ready(self.vm_request(request))
+    }
+
+    /// Transactions get processed as soon as they are sent, so there is never any need to wait.
+    fn sleep(&self, _millis: u64) -> impl std::future::Future<Output = ()> {
+        std::future::ready(())
+    }
+
+    fn now(&self) -> Self::Instant {
+        std::time::Instant::now()
+    }
+
+    fn elapsed_seconds(&self, instant: &Self::Instant) -> f32 {
+        instant.elapsed().as_secs_f32()
+    }
+}
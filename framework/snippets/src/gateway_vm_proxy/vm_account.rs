@@ -0,0 +1,87 @@
+use dharitri_sc_scenario::imports::Address;
+use dharitri_sdk::{
+    data::{
+        account::{Account, AccountData},
+        account_storage::AccountStorage,
+        sdk_address::SdkAddress,
+    },
+    utils::base64_encode,
+};
+use serde_json::Value;
+
+use super::vm_chain::{gateway_error, gateway_response, VMChain};
+
+impl VMChain {
+    pub(crate) fn account_response(&self, bech32_address: &str) -> Value {
+        match SdkAddress::from_bech32_string(bech32_address) {
+            Ok(address) => gateway_response(AccountData {
+                account: self.get_account(&address.0),
+            }),
+            Err(err) => gateway_error(err),
+        }
+    }
+
+    pub(crate) fn account_storage_response(&self, bech32_address: &str) -> Value {
+        let address = match SdkAddress::from_bech32_string(bech32_address) {
+            Ok(address) => address.0,
+            Err(err) => return gateway_error(err),
+        };
+
+        let pairs = self
+            .vm_runner
+            .blockchain_mock
+            .state
+            .accounts
+            .get(&address)
+            .map(|account| {
+                account
+                    .storage
+                    .iter()
+                    .map(|(key, value)| (hex::encode(key), hex::encode(value)))
+                    .collect()
+            })
+            .unwrap_or_default();
+        gateway_response(AccountStorage { pairs })
+    }
+
+    /// Accounts that were never created are reported as empty, same as on the network.
+    pub(crate) fn get_account(&self, address: &Address) -> Account {
+        let Some(account) = self.vm_runner.blockchain_mock.state.accounts.get(address) else {
+            return Account {
+                address: address.clone().into(),
+                nonce: 0,
+                balance: "0".to_string(),
+                username: String::new(),
+                code: String::new(),
+                code_hash: None,
+                root_hash: None,
+                code_metadata: None,
+                developer_reward: None,
+                owner_address: None,
+            };
+        };
+
+        Account {
+            address: address.clone().into(),
+            nonce: account.nonce,
+            balance: account.rewa_balance.to_string(),
+            username: String::from_utf8_lossy(&account.username).into_owned(),
+            code: account
+                .contract_path
+                .as_ref()
+                .map(hex::encode)
+                .unwrap_or_default(),
+            code_hash: None,
+            root_hash: None,
+            code_metadata: account
+                .contract_path
+                .as_ref()
+                .map(|_| base64_encode(account.code_metadata.to_byte_array())),
+            developer_reward: Some(account.developer_rewards.to_string()),
+            owner_address: account
+                .contract_owner
+                .as_ref()
+                .map(dharitri_sdk::bech32::encode),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use dharitri_sdk::data::{
+    hyperblock::{HyperBlock, HyperBlockData},
+    network_status::{NetworkStatus, NetworkStatusData},
+};
+use serde_json::Value;
+
+use super::vm_chain::{gateway_error, gateway_response, VMChain};
+
+impl VMChain {
+    /// The block currently being built is not reported, only the ones already closed.
+    fn latest_block_nonce(&self) -> u64 {
+        self.vm_runner
+            .blockchain_mock
+            .state
+            .current_block_info
+            .block_nonce
+            .saturating_sub(1)
+    }
+
+    pub(crate) fn network_status_response(&self) -> Value {
+        let block_info = &self.vm_runner.blockchain_mock.state.current_block_info;
+        gateway_response(NetworkStatusData {
+            status: NetworkStatus {
+                current_round: block_info.block_round,
+                epoch_number: block_info.block_epoch,
+                nonce: self.latest_block_nonce(),
+                ..Default::default()
+            },
+        })
+    }
+
+    /// Every block lives in a single shard, so the hyperblock is just a list of the transactions processed in it.
+    pub(crate) fn hyper_block_by_nonce_response(&self, nonce: u64) -> Value {
+        if nonce > self.latest_block_nonce() {
+            return gateway_error(format!("block with nonce {nonce} not found"));
+        }
+
+        let mut transactions = self
+            .transactions
+            .values()
+            .filter_map(|entry| entry.tx_on_network.clone())
+            .filter(|tx_on_network| tx_on_network.block_nonce == nonce)
+            .collect::<Vec<_>>();
+        transactions.sort_by_key(|tx_on_network| tx_on_network.hash.clone());
+
+        let hyperblock = HyperBlock {
+            nonce,
+            round: nonce,
+            hash: format!("{nonce:064x}"),
+            prev_block_hash: format!("{:064x}", nonce.saturating_sub(1)),
+            epoch: transactions.first().map(|tx| tx.epoch).unwrap_or_default(),
+            num_txs: transactions.len() as u64,
+            shard_blocks: Vec::new(),
+            timestamp: transactions
+                .first()
+                .map(|tx| tx.timestamp)
+                .unwrap_or_default(),
+            accumulated_fees: "0".to_string(),
+            developer_fees: "0".to_string(),
+            accumulated_fees_in_epoch: "0".to_string(),
+            developer_fees_in_epoch: "0".to_string(),
+            transactions,
+        };
+        gateway_response(HyperBlockData { hyperblock })
+    }
+}
@@ -0,0 +1,142 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use anyhow::anyhow;
+use dharitri_sc_scenario::{imports::Address, scenario::run_vm::ScenarioVMRunner};
+use dharitri_sdk::{
+    data::{network_config::NetworkConfig, transaction::Transaction},
+    gateway::GatewayRequestType,
+};
+use serde_json::{json, Value};
+
+use super::vm_tx::VMTxEntry;
+
+const ROUNDS_PER_EPOCH: u64 = 20;
+
+/// The state behind the VM gateway.
+#[derive(Debug)]
+pub(crate) struct VMChain {
+    pub(crate) vm_runner: ScenarioVMRunner,
+    pub(crate) current_dir: PathBuf,
+    pub(crate) network_config: NetworkConfig,
+
+    /// All transactions accepted so far, indexed by hash.
+    pub(crate) transactions: HashMap<String, VMTxEntry>,
+
+    /// Hashes of the transactions waiting for a lower nonce to get processed first.
+    pub(crate) mempool: HashMap<(Address, u64), String>,
+}
+
+impl VMChain {
+    pub(crate) fn new() -> Self {
+        VMChain {
+            vm_runner: ScenarioVMRunner::new(),
+            current_dir: std::env::current_dir().unwrap(),
+            network_config: super::vm_network::default_network_config(),
+            transactions: HashMap::new(),
+            mempool: HashMap::new(),
+        }
+    }
+
+    /// Routes the request based on its endpoint, same as the gateway would.
+    ///
+    /// Returns the JSON response, as the gateway would send it.
+    pub(crate) fn handle_request(
+        &mut self,
+        request_type: GatewayRequestType,
+        endpoint: &str,
+        payload: Option<Value>,
+    ) -> anyhow::Result<Value> {
+        let path = endpoint.split('?').next().unwrap_or_default();
+        let segments = path.split('/').collect::<Vec<_>>();
+        let response = match (request_type, segments.as_slice()) {
+            (GatewayRequestType::Get, ["network", "config"]) => self.network_config_response(),
+            (GatewayRequestType::Get, ["network", "status", _shard]) => {
+                self.network_status_response()
+            },
+            (GatewayRequestType::Get, ["hyperblock", "by-nonce", nonce]) => {
+                self.hyper_block_by_nonce_response(nonce.parse()?)
+            },
+            (GatewayRequestType::Get, ["address", address]) => self.account_response(address),
+            (GatewayRequestType::Get, ["address", address, "keys"]) => {
+                self.account_storage_response(address)
+            },
+            (GatewayRequestType::Post, ["transaction", "send"]) => {
+                self.send_tx_response(parse_payload(payload)?)
+            },
+            (GatewayRequestType::Post, ["transaction", "send-multiple"]) => {
+                self.send_multi_tx_response(parse_payload::<Vec<Transaction>>(payload)?)
+            },
+            (GatewayRequestType::Post, ["transaction", "cost"]) => {
+                self.tx_cost_response(parse_payload(payload)?)
+            },
+            (GatewayRequestType::Post, ["transaction", "simulate"]) => {
+                self.simulate_tx_response(parse_payload(payload)?)
+            },
+            (GatewayRequestType::Post, ["transaction", "send-user-funds"]) => {
+                self.send_user_funds_response(parse_payload(payload)?)
+            },
+            (GatewayRequestType::Get, ["transaction", tx_hash]) => self.tx_info_response(tx_hash),
+            (GatewayRequestType::Get, ["transaction", tx_hash, "process-status"]) => {
+                self.tx_process_status_response(tx_hash)
+            },
+            (GatewayRequestType::Get, ["transaction", tx_hash, "status"]) => {
+                self.tx_status_response(tx_hash)
+            },
+            (GatewayRequestType::Post, ["vm-values", "query"]) => {
+                self.vm_query_response(parse_payload(payload)?)
+            },
+            (GatewayRequestType::Post, ["simulator", "generate-blocks", num_blocks]) => {
+                self.generate_blocks_response(num_blocks.parse()?)
+            },
+            (
+                GatewayRequestType::Post,
+                ["simulator", "generate-blocks-until-epoch-reached", epoch],
+            ) => self.generate_blocks_until_epoch_response(epoch.parse()?),
+            (
+                GatewayRequestType::Post,
+                ["simulator", "generate-blocks-until-transaction-processed", tx_hash],
+            ) => self.generate_blocks_until_tx_processed_response(tx_hash),
+            (GatewayRequestType::Post, ["simulator", "set-state"]) => {
+                self.set_state_response(parse_payload(payload)?)
+            },
+            _ => {
+                return Err(anyhow!(
+                    "endpoint not supported by the VM gateway: {endpoint}"
+                ))
+            },
+        };
+        Ok(response)
+    }
+
+    /// Closes the current block and starts a new one.
+    pub(crate) fn generate_block(&mut self) {
+        let round_duration_seconds = self.network_config.round_duration as u64 / 1000;
+        let state = &mut self.vm_runner.blockchain_mock.state;
+        state.advance_round();
+        state.current_block_info.block_timestamp += round_duration_seconds;
+        state.current_block_info.block_epoch =
+            state.current_block_info.block_round / ROUNDS_PER_EPOCH;
+    }
+}
+
+fn parse_payload<T: serde::de::DeserializeOwned>(payload: Option<Value>) -> anyhow::Result<T> {
+    let payload = payload.ok_or_else(|| anyhow!("missing request payload"))?;
+    Ok(serde_json::from_value(payload)?)
+}
+
+/// Wraps the data the same way the gateway does.
+pub(crate) fn gateway_response<T: serde::Serialize>(data: T) -> Value {
+    json!({
+        "data": data,
+        "error": "",
+        "code": "successful",
+    })
+}
+
+pub(crate) fn gateway_error(error: impl std::fmt::Display) -> Value {
+    json!({
+        "data": null,
+        "error": error.to_string(),
+        "code": "bad_request",
+    })
+}
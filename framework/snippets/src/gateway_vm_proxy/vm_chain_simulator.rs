@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use anyhow::anyhow;
+use base64::{engine::general_purpose, Engine as _};
+use dharitri_sc_scenario::{
+    dharitri_chain_vm::{types::VMCodeMetadata, world_mock::AccountData},
+    num_bigint::BigUint,
+};
+use dharitri_sdk::{data::sdk_address::SdkAddress, gateway::SetStateAccount};
+use serde_json::Value;
+
+use super::{
+    vm_chain::{gateway_error, gateway_response, VMChain},
+    VM_USER_FUNDS,
+};
+
+impl VMChain {
+    pub(crate) fn send_user_funds_response(&mut self, payload: HashMap<String, String>) -> Value {
+        let Some(receiver) = payload.get("receiver") else {
+            return gateway_error("missing receiver");
+        };
+        let address = match SdkAddress::from_bech32_string(receiver) {
+            Ok(address) => address.0,
+            Err(err) => return gateway_error(err),
+        };
+
+        let state = &mut self.vm_runner.blockchain_mock.state;
+        if !state.account_exists(&address) {
+            state.add_account(AccountData::new_empty(address.clone()));
+        }
+        state.accounts.get_mut(&address).unwrap().rewa_balance +=
+            VM_USER_FUNDS.parse::<BigUint>().unwrap();
+
+        self.generate_block();
+        gateway_response(Value::Null)
+    }
+
+    pub(crate) fn generate_blocks_response(&mut self, num_blocks: u64) -> Value {
+        for _ in 0..num_blocks {
+            self.generate_block();
+        }
+        gateway_response(Value::Null)
+    }
+
+    pub(crate) fn generate_blocks_until_epoch_response(&mut self, epoch: u64) -> Value {
+        while self
+            .vm_runner
+            .blockchain_mock
+            .state
+            .current_block_info
+            .block_epoch
+            < epoch
+        {
+            self.generate_block();
+        }
+        gateway_response(Value::Null)
+    }
+
+    /// Transactions are processed as soon as their nonce comes up, so there is no point in generating blocks.
+    pub(crate) fn generate_blocks_until_tx_processed_response(&mut self, tx_hash: &str) -> Value {
+        match self.transactions.get(tx_hash) {
+            Some(entry) if entry.tx_on_network.is_some() => gateway_response(Value::Null),
+            Some(_) => gateway_error(format!(
+                "transaction {tx_hash} is waiting for a lower nonce"
+            )),
+            None => gateway_error(format!("transaction not found: {tx_hash}")),
+        }
+    }
+
+    pub(crate) fn set_state_response(&mut self, accounts: Vec<SetStateAccount>) -> Value {
+        for account in accounts {
+            if let Err(err) = self.set_account_state(account) {
+                return gateway_error(err);
+            }
+        }
+        gateway_response(Value::Null)
+    }
+
+    /// Overwrites the account, same as the chain simulator.
+    fn set_account_state(&mut self, account: SetStateAccount) -> anyhow::Result<()> {
+        let address = SdkAddress::from_bech32_string(&account.address)?.0;
+        let mut account_data = AccountData::new_empty(address.clone());
+        account_data.nonce = account.nonce;
+        account_data.rewa_balance = parse_biguint(&account.balance)?;
+        for (key, value) in &account.keys {
+            account_data
+                .storage
+                .insert(hex::decode(key)?, hex::decode(value)?);
+        }
+        if !account.code.is_empty() {
+            account_data.contract_path = Some(hex::decode(&account.code)?);
+        }
+        if !account.code_metadata.is_empty() {
+            let code_metadata = general_purpose::STANDARD.decode(&account.code_metadata)?;
+            account_data.code_metadata = VMCodeMetadata::from(&code_metadata);
+        }
+        if !account.owner_address.is_empty() {
+            account_data.contract_owner =
+                Some(SdkAddress::from_bech32_string(&account.owner_address)?.0);
+        }
+        account_data.developer_rewards = parse_biguint(&account.developer_reward)?;
+
+        self.vm_runner
+            .blockchain_mock
+            .state
+            .add_account(account_data);
+        Ok(())
+    }
+}
+
+fn parse_biguint(value: &str) -> anyhow::Result<BigUint> {
+    if value.is_empty() {
+        return Ok(BigUint::default());
+    }
+    value
+        .parse()
+        .map_err(|_| anyhow!("invalid number: {value}"))
+}
@@ -0,0 +1,37 @@
+use dharitri_sdk::data::network_config::{NetworkConfig, NetworkConfigData};
+use serde_json::Value;
+
+use super::vm_chain::{gateway_response, VMChain};
+
+/// Same as the chain simulator.
+pub const VM_CHAIN_ID: &str = "chain";
+
+/// Amount received from the `send-user-funds` chain simulator request (10000 REWA), same as the chain simulator.
+pub const VM_USER_FUNDS: &str = "10000000000000000000000";
+
+pub(crate) fn default_network_config() -> NetworkConfig {
+    NetworkConfig {
+        chain_id: VM_CHAIN_ID.to_string(),
+        denomination: 18,
+        gas_per_data_byte: 1_500,
+        latest_tag_software_version: String::new(),
+        meta_consensus_group_size: 1,
+        min_gas_limit: 50_000,
+        min_gas_price: 1_000_000_000,
+        min_transaction_version: 1,
+        num_metachain_nodes: 1,
+        num_nodes_in_shard: 1,
+        num_shards_without_meta: 1,
+        round_duration: 6_000,
+        shard_consensus_group_size: 1,
+        start_time: 0,
+    }
+}
+
+impl VMChain {
+    pub(crate) fn network_config_response(&self) -> Value {
+        gateway_response(NetworkConfigData {
+            config: self.network_config.clone(),
+        })
+    }
+}
@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use dharitri_sc_scenario::dharitri_chain_vm::{
+    tx_execution::execute_current_tx_context_input, tx_mock::TxInput,
+};
+use dharitri_sdk::{
+    data::vm::{VMOutputApi, VMQueryInput, VmValuesResponseData},
+    utils::base64_encode,
+};
+use serde_json::Value;
+
+use super::vm_chain::{gateway_error, gateway_response, VMChain};
+
+impl VMChain {
+    /// Queries are executed on a copy of the state, so they never change it.
+    pub(crate) fn vm_query_response(&mut self, query: VMQueryInput) -> Value {
+        let sc_address = query.sc_address.0.clone();
+        let contract_exists = self
+            .vm_runner
+            .blockchain_mock
+            .state
+            .accounts
+            .get(&sc_address)
+            .is_some_and(|account| account.contract_path.is_some());
+        if !contract_exists {
+            return gateway_error(format!(
+                "executeQuery: contract not found: {}",
+                query.sc_address
+            ));
+        }
+
+        let args = match query.args.iter().map(hex::decode).collect() {
+            Ok(args) => args,
+            Err(err) => return gateway_error(err),
+        };
+        let tx_input = TxInput {
+            from: sc_address.clone(),
+            to: sc_address,
+            func_name: query.func_name.into(),
+            args,
+            gas_limit: u64::MAX,
+            gas_price: 0,
+            ..Default::default()
+        };
+
+        let blockchain_mock = &mut self.vm_runner.blockchain_mock;
+        let tx_result = blockchain_mock.vm.execute_sc_query_lambda(
+            tx_input,
+            &mut blockchain_mock.state,
+            execute_current_tx_context_input,
+        );
+
+        gateway_response(VmValuesResponseData {
+            data: VMOutputApi {
+                return_data: tx_result.result_values.iter().map(base64_encode).collect(),
+                return_code: tx_result.result_status.message().to_string(),
+                return_message: tx_result.result_message,
+                gas_remaining: 0,
+                gas_refund: 0,
+                output_accounts: HashMap::new(),
+                deleted_accounts: None,
+                touched_accounts: None,
+                logs: None,
+            },
+        })
+    }
+}
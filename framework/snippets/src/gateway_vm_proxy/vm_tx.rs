@@ -0,0 +1,474 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail};
+use base64::{engine::general_purpose, Engine as _};
+use dharitri_sc_scenario::{
+    dharitri_chain_vm::{
+        chain_core::builtin_func_names::UPGRADE_CONTRACT_FUNC_NAME,
+        crypto_functions::verify_ed25519,
+        tx_execution::{execute_current_tx_context_input, is_system_sc_address},
+        tx_mock::{TxFunctionName, TxInput, TxResult},
+        types::{VMCodeMetadata, H256},
+        vm_err_msg,
+        world_mock::AccountData,
+    },
+    imports::{Address, ReturnCode},
+    num_bigint::BigUint,
+};
+use dharitri_sdk::data::{
+    sdk_address::SdkAddress,
+    transaction::{
+        SendTransactionData, SendTransactionsResponseData, Transaction, TransactionOnNetwork,
+        TransactionProcessStatusData, TransactionStatusData,
+    },
+};
+use serde_json::Value;
+
+use crate::network_response::compute_new_deployed_address;
+
+use super::{
+    vm_chain::{gateway_error, gateway_response, VMChain},
+    vm_tx_on_network::{processed_tx_on_network, ProcessingType},
+};
+
+/// Transactions with nonces this far ahead of the account nonce are rejected, instead of waiting in the mempool.
+const MAX_NONCE_GAP: u64 = 100;
+
+const STATUS_PENDING: &str = "pending";
+
+/// A transaction accepted by the VM gateway.
+#[derive(Debug)]
+pub(crate) struct VMTxEntry {
+    pub(crate) tx: Transaction,
+
+    /// Only set once the transaction got processed.
+    pub(crate) tx_on_network: Option<TransactionOnNetwork>,
+
+    /// Explains why a transaction failed, in the same format as the process status API.
+    pub(crate) reason: String,
+}
+
+/// The decoded data field: the function name (or the code, for deploys), followed by the arguments.
+struct VMTxData {
+    function: String,
+    args: Vec<Vec<u8>>,
+}
+
+impl VMChain {
+    pub(crate) fn send_tx_response(&mut self, tx: Transaction) -> Value {
+        match self.send_tx(tx) {
+            Ok(tx_hash) => gateway_response(SendTransactionData { tx_hash }),
+            Err(err) => gateway_error(err),
+        }
+    }
+
+    /// Invalid transactions are skipped, the same as on the network.
+    pub(crate) fn send_multi_tx_response(&mut self, txs: Vec<Transaction>) -> Value {
+        let mut txs_hashes = HashMap::new();
+        for (index, tx) in txs.into_iter().enumerate() {
+            if let Ok(tx_hash) = self.send_tx(tx) {
+                txs_hashes.insert(index as i32, tx_hash);
+            }
+        }
+
+        gateway_response(SendTransactionsResponseData {
+            num_of_sent_txs: txs_hashes.len() as i32,
+            txs_hashes,
+        })
+    }
+
+    pub(crate) fn tx_info_response(&self, tx_hash: &str) -> Value {
+        match self.transactions.get(tx_hash) {
+            Some(entry) => gateway_response(serde_json::json!({
+                "transaction": entry.tx_on_network.clone().unwrap_or_else(|| pending_tx_on_network(&entry.tx, tx_hash)),
+            })),
+            None => gateway_error(format!("transaction not found: {tx_hash}")),
+        }
+    }
+
+    pub(crate) fn tx_process_status_response(&self, tx_hash: &str) -> Value {
+        match self.transactions.get(tx_hash) {
+            Some(entry) => gateway_response(TransactionProcessStatusData {
+                status: entry_status(entry),
+                reason: entry.reason.clone(),
+            }),
+            None => gateway_error(format!("transaction not found: {tx_hash}")),
+        }
+    }
+
+    pub(crate) fn tx_status_response(&self, tx_hash: &str) -> Value {
+        match self.transactions.get(tx_hash) {
+            Some(entry) => gateway_response(TransactionStatusData {
+                status: entry_status(entry),
+            }),
+            None => gateway_error(format!("transaction not found: {tx_hash}")),
+        }
+    }
+
+    /// Validates the transaction and puts it in the mempool.
+    ///
+    /// Transactions get processed right away, unless they are waiting for a lower nonce.
+    pub(crate) fn send_tx(&mut self, tx: Transaction) -> anyhow::Result<String> {
+        self.validate_tx(&tx)?;
+
+        let sender = tx.sender.0.clone();
+        let account_nonce = self.get_account(&sender).nonce;
+        if tx.nonce < account_nonce {
+            bail!("transaction generation failed: invalid transaction lowerNonceInTx: true, veryHighNonceInTx: false");
+        }
+        if tx.nonce > account_nonce + MAX_NONCE_GAP {
+            bail!("transaction generation failed: invalid transaction lowerNonceInTx: false, veryHighNonceInTx: true");
+        }
+
+        let tx_hash = tx.compute_hash()?;
+        if self.transactions.contains_key(&tx_hash) {
+            return Ok(tx_hash);
+        }
+
+        self.mempool
+            .insert((sender.clone(), tx.nonce), tx_hash.clone());
+        self.transactions.insert(
+            tx_hash.clone(),
+            VMTxEntry {
+                tx,
+                tx_on_network: None,
+                reason: String::new(),
+            },
+        );
+        self.process_mempool(&sender);
+
+        Ok(tx_hash)
+    }
+
+    /// Performs the same checks as the network, before accepting a transaction.
+    pub(super) fn validate_tx(&self, tx: &Transaction) -> anyhow::Result<()> {
+        if tx.chain_id != self.network_config.chain_id {
+            bail!("transaction generation failed: invalid chain ID");
+        }
+        if tx.version < self.network_config.min_transaction_version {
+            bail!("transaction generation failed: invalid transaction version");
+        }
+        if tx.gas_price < self.network_config.min_gas_price {
+            bail!("transaction generation failed: insufficient gas price in tx");
+        }
+
+        let data = decode_data_field(tx)?;
+        if tx.gas_limit < self.min_gas_limit(tx, &data) {
+            bail!("transaction generation failed: insufficient gas limit in tx");
+        }
+
+        let signing_bytes = tx.signing_bytes();
+        if !is_signed_by(&signing_bytes, Some(&tx.sender), &tx.signature) {
+            bail!("transaction generation failed: invalid signature");
+        }
+        if tx.is_guarded()
+            && !is_signed_by(&signing_bytes, tx.guardian.as_ref(), &tx.guardian_signature)
+        {
+            bail!("transaction generation failed: invalid guardian signature");
+        }
+        if tx.is_relayed()
+            && !is_signed_by(&signing_bytes, tx.relayer.as_ref(), &tx.relayer_signature)
+        {
+            bail!("transaction generation failed: invalid relayer signature");
+        }
+
+        self.validate_tx_data(tx, &data)?;
+
+        // in a relayed transaction the relayer pays the fee, but the value still comes from the sender
+        let fee = tx_fee(tx);
+        let value = parse_value(tx)?;
+        match &tx.relayer {
+            Some(relayer) => {
+                self.check_funds(relayer, fee)?;
+                self.check_funds(&tx.sender, value)
+            },
+            None => self.check_funds(&tx.sender, fee + value),
+        }
+    }
+
+    fn check_funds(&self, address: &SdkAddress, required_balance: BigUint) -> anyhow::Result<()> {
+        if self.balance_of(&address.0) < required_balance {
+            bail!("transaction generation failed: insufficient funds for address {address}");
+        }
+        Ok(())
+    }
+
+    /// The gas needed just to move the transaction, before any execution. Each co-signer adds to it.
+    pub(super) fn min_gas_limit(&self, tx: &Transaction, data: &[u8]) -> u64 {
+        let num_co_signers = tx.is_guarded() as u64 + tx.is_relayed() as u64;
+        self.network_config.min_gas_limit * (1 + num_co_signers)
+            + self.network_config.gas_per_data_byte * data.len() as u64
+    }
+
+    /// Checks that the data field can be executed: the arguments are well formed, and deployed code is known.
+    pub(super) fn validate_tx_data(&self, tx: &Transaction, data: &[u8]) -> anyhow::Result<()> {
+        if tx.receiver.0.is_zero() {
+            let deploy_data = parse_tx_data(data)?;
+            let code = hex::decode(&deploy_data.function)?;
+            if deploy_data.args.len() < 2 {
+                bail!("transaction generation failed: invalid deploy arguments");
+            }
+            if !self
+                .vm_runner
+                .contract_map_ref
+                .lock()
+                .contains_contract(&code)
+            {
+                bail!("contract code not registered in the VM gateway");
+            }
+        } else {
+            parse_tx_data(data)?;
+        }
+        Ok(())
+    }
+
+    fn process_mempool(&mut self, sender: &Address) {
+        loop {
+            let account_nonce = self.get_account(sender).nonce;
+            let Some(tx_hash) = self.mempool.remove(&(sender.clone(), account_nonce)) else {
+                return;
+            };
+            self.process_tx(&tx_hash);
+        }
+    }
+
+    /// Executes the transaction in the current block, then moves on to the next block.
+    fn process_tx(&mut self, tx_hash: &str) {
+        let tx = self.transactions[tx_hash].tx.clone();
+        let (processing_type, result_sender, tx_result) = self.execute_tx(&tx, tx_hash);
+
+        let block_info = &self.vm_runner.blockchain_mock.state.current_block_info;
+        let tx_on_network = processed_tx_on_network(
+            &tx,
+            tx_hash,
+            processing_type,
+            &result_sender,
+            &tx_result,
+            block_info,
+        );
+
+        let entry = self.transactions.get_mut(tx_hash).unwrap();
+        if !tx_result.result_status.is_success() {
+            entry.reason = fail_reason(&tx_result);
+        }
+        entry.tx_on_network = Some(tx_on_network);
+
+        self.generate_block();
+    }
+
+    /// Executes the transaction on the current state, without recording it.
+    ///
+    /// Returns the address that sends back the results, along with the result itself.
+    pub(super) fn execute_tx(
+        &mut self,
+        tx: &Transaction,
+        tx_hash: &str,
+    ) -> (ProcessingType, Address, TxResult) {
+        let sender = tx.sender.0.clone();
+        let receiver = tx.receiver.0.clone();
+        let tx_data = decode_data_field(tx)
+            .and_then(|data| parse_tx_data(&data))
+            .expect("transaction data already validated");
+
+        let state = &mut self.vm_runner.blockchain_mock.state;
+        if !state.account_exists(&sender) {
+            state.add_account(AccountData::new_empty(sender.clone()));
+        }
+
+        if !self.can_pay_fee(tx) {
+            let state = &mut self.vm_runner.blockchain_mock.state;
+            state.increase_account_nonce(&sender);
+            (
+                ProcessingType::of(tx, &tx_data.function),
+                receiver,
+                TxResult {
+                    result_status: ReturnCode::OutOfFunds,
+                    result_message: "insufficient funds".to_string(),
+                    ..Default::default()
+                },
+            )
+        } else if receiver.is_zero() {
+            let (new_address, tx_result) = self.deploy(tx, tx_hash, tx_data);
+            (ProcessingType::SCDeployment, new_address, tx_result)
+        } else {
+            let processing_type = ProcessingType::of(tx, &tx_data.function);
+            let tx_result = self.call(tx, tx_hash, tx_data);
+            (processing_type, receiver, tx_result)
+        }
+    }
+
+    /// With relayed transactions, the relayer pays for the gas instead of the sender.
+    fn can_pay_fee(&self, tx: &Transaction) -> bool {
+        let payer = tx.relayer.as_ref().unwrap_or(&tx.sender);
+        self.balance_of(&payer.0) >= tx_fee(tx)
+    }
+
+    fn deploy(
+        &mut self,
+        tx: &Transaction,
+        tx_hash: &str,
+        tx_data: VMTxData,
+    ) -> (Address, TxResult) {
+        let sender = tx.sender.0.clone();
+        let code = hex::decode(&tx_data.function).expect("code already validated");
+        let mut args = tx_data.args.into_iter();
+        let _vm_type = args.next();
+        let code_metadata = VMCodeMetadata::from(&args.next().unwrap_or_default());
+
+        let blockchain_mock = &mut self.vm_runner.blockchain_mock;
+        blockchain_mock.state.put_new_address(
+            sender.clone(),
+            tx.nonce,
+            compute_new_deployed_address(&sender, tx.nonce),
+        );
+        let tx_input = tx_input(tx, tx_hash, TxFunctionName::INIT, args.collect());
+        blockchain_mock.vm.sc_create(
+            tx_input,
+            &code,
+            code_metadata,
+            &mut blockchain_mock.state,
+            execute_current_tx_context_input,
+        )
+    }
+
+    fn call(&mut self, tx: &Transaction, tx_hash: &str, tx_data: VMTxData) -> TxResult {
+        let receiver = &tx.receiver.0;
+        let blockchain_mock = &mut self.vm_runner.blockchain_mock;
+
+        // nonce gets increased irrespective of whether the tx fails or not
+        blockchain_mock.state.increase_account_nonce(&tx.sender.0);
+
+        let contract = blockchain_mock
+            .state
+            .accounts
+            .get(receiver)
+            .filter(|account| account.contract_path.is_some());
+        if receiver.is_smart_contract_address()
+            && !is_system_sc_address(receiver)
+            && contract.is_none()
+        {
+            return TxResult {
+                result_status: ReturnCode::ContractNotFound,
+                result_message: ReturnCode::ContractNotFound.message().to_string(),
+                ..Default::default()
+            };
+        }
+
+        // the VM only checks the upgradeable flag, the chain also requires the owner
+        let is_owner =
+            contract.is_some_and(|account| account.contract_owner.as_ref() == Some(&tx.sender.0));
+        if tx_data.function == UPGRADE_CONTRACT_FUNC_NAME && !is_owner {
+            return TxResult::from_vm_error(vm_err_msg::UPGRADE_NOT_ALLOWED);
+        }
+
+        let tx_input = tx_input(tx, tx_hash, tx_data.function.into(), tx_data.args);
+        blockchain_mock.vm.sc_call_with_async_and_callback(
+            tx_input,
+            &mut blockchain_mock.state,
+            execute_current_tx_context_input,
+        )
+    }
+
+    pub(crate) fn balance_of(&self, address: &Address) -> BigUint {
+        self.vm_runner
+            .blockchain_mock
+            .state
+            .accounts
+            .get(address)
+            .map(|account| account.rewa_balance.clone())
+            .unwrap_or_default()
+    }
+}
+
+fn entry_status(entry: &VMTxEntry) -> String {
+    entry
+        .tx_on_network
+        .as_ref()
+        .map(|tx_on_network| tx_on_network.status.clone())
+        .unwrap_or_else(|| STATUS_PENDING.to_string())
+}
+
+fn pending_tx_on_network(tx: &Transaction, tx_hash: &str) -> TransactionOnNetwork {
+    TransactionOnNetwork {
+        kind: "normal".to_string(),
+        hash: Some(tx_hash.to_string()),
+        nonce: tx.nonce,
+        value: tx.value.clone(),
+        receiver: tx.receiver.clone(),
+        sender: tx.sender.clone(),
+        gas_price: tx.gas_price,
+        gas_limit: tx.gas_limit,
+        signature: tx.signature.clone().unwrap_or_default(),
+        data: tx.data.clone(),
+        status: STATUS_PENDING.to_string(),
+        ..Default::default()
+    }
+}
+
+fn tx_input(
+    tx: &Transaction,
+    tx_hash: &str,
+    func_name: TxFunctionName,
+    args: Vec<Vec<u8>>,
+) -> TxInput {
+    TxInput {
+        from: tx.sender.0.clone(),
+        to: tx.receiver.0.clone(),
+        rewa_value: parse_value(tx).expect("value already validated"),
+        func_name,
+        args,
+        gas_limit: tx.gas_limit,
+        gas_price: tx.gas_price,
+        tx_hash: H256::from_slice(&hex::decode(tx_hash).unwrap()),
+        relayer_address: tx.relayer.as_ref().map(|relayer| relayer.0.clone()),
+        ..Default::default()
+    }
+}
+
+/// Explains why a transaction failed, in the same format as the process status API.
+pub(super) fn fail_reason(tx_result: &TxResult) -> String {
+    format!(
+        "@{:02x}@{}",
+        tx_result.result_status.as_u64(),
+        hex::encode(&tx_result.result_message)
+    )
+}
+
+fn tx_fee(tx: &Transaction) -> BigUint {
+    BigUint::from(tx.gas_limit) * tx.gas_price
+}
+
+fn parse_value(tx: &Transaction) -> anyhow::Result<BigUint> {
+    tx.value
+        .parse()
+        .map_err(|_| anyhow!("transaction generation failed: invalid transaction value"))
+}
+
+pub(super) fn decode_data_field(tx: &Transaction) -> anyhow::Result<Vec<u8>> {
+    match &tx.data {
+        Some(data) => Ok(general_purpose::STANDARD.decode(data)?),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn parse_tx_data(data: &[u8]) -> anyhow::Result<VMTxData> {
+    let data = String::from_utf8(data.to_vec())?;
+    let mut split = data.split('@');
+    let function = split.next().unwrap_or_default().to_string();
+    let args = split.map(hex::decode).collect::<Result<_, _>>()?;
+    Ok(VMTxData { function, args })
+}
+
+fn is_signed_by(
+    signing_bytes: &[u8],
+    signer: Option<&SdkAddress>,
+    signature: &Option<String>,
+) -> bool {
+    let (Some(signer), Some(signature)) = (signer, signature) else {
+        return false;
+    };
+    let Ok(signature) = hex::decode(signature) else {
+        return false;
+    };
+    verify_ed25519(signer.0.as_bytes(), signing_bytes, &signature)
+}
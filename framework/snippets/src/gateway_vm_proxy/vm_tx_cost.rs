@@ -0,0 +1,45 @@
+use dharitri_sc_scenario::dharitri_chain_vm::with_shared::Shareable;
+use dharitri_sdk::data::transaction::{Transaction, TxCostResponseData};
+use serde_json::Value;
+
+use super::{
+    vm_chain::{gateway_error, gateway_response, VMChain},
+    vm_tx::decode_data_field,
+};
+
+impl VMChain {
+    pub(crate) fn tx_cost_response(&mut self, tx: Transaction) -> Value {
+        match self.tx_cost(tx) {
+            Ok(tx_cost) => gateway_response(tx_cost),
+            Err(err) => gateway_error(err),
+        }
+    }
+
+    /// Executes the transaction on a copy of the state, so it never changes it.
+    ///
+    /// Just like on the network, the nonce and signatures are ignored.
+    /// The execution gas only gets counted if the VM has a gas schedule.
+    fn tx_cost(&mut self, mut tx: Transaction) -> anyhow::Result<TxCostResponseData> {
+        let data = decode_data_field(&tx)?;
+        self.validate_tx_data(&tx, &data)?;
+        tx.nonce = self.get_account(&tx.sender.0).nonce;
+        let tx_hash = tx.compute_hash()?;
+
+        let state_copy = Shareable::new((*self.vm_runner.blockchain_mock.state).clone());
+        let state = std::mem::replace(&mut self.vm_runner.blockchain_mock.state, state_copy);
+        let (_, _, tx_result) = self.execute_tx(&tx, &tx_hash);
+        self.vm_runner.blockchain_mock.state = state;
+
+        if !tx_result.result_status.is_success() {
+            return Ok(TxCostResponseData {
+                tx_gas_units: 0,
+                return_message: tx_result.result_message,
+            });
+        }
+
+        Ok(TxCostResponseData {
+            tx_gas_units: self.min_gas_limit(&tx, &data) + tx_result.gas_used,
+            return_message: String::new(),
+        })
+    }
+}
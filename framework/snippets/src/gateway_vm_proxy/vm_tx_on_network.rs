@@ -0,0 +1,173 @@
+use dharitri_sc_scenario::{
+    dharitri_chain_vm::{tx_mock::TxResult, world_mock::BlockInfo},
+    imports::Address,
+};
+use dharitri_sdk::{
+    data::{
+        sdk_address::SdkAddress,
+        transaction::{
+            ApiLogs, ApiSmartContractResult, Events, LogData, Transaction, TransactionOnNetwork,
+        },
+        vm::CallType,
+    },
+    utils::base64_encode,
+};
+
+const STATUS_SUCCESS: &str = "success";
+const STATUS_FAIL: &str = "fail";
+const LOG_IDENTIFIER_SIGNAL_ERROR: &str = "signalError";
+
+/// The `processingTypeOnDestination` field, as reported by the network.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum ProcessingType {
+    MoveBalance,
+    BuiltInFunctionCall,
+    SCInvoking,
+    SCDeployment,
+}
+
+impl ProcessingType {
+    pub(crate) fn of(tx: &Transaction, function: &str) -> Self {
+        if tx.receiver.0.is_zero() {
+            ProcessingType::SCDeployment
+        } else if tx.receiver.0.is_smart_contract_address() {
+            ProcessingType::SCInvoking
+        } else if function.is_empty() {
+            ProcessingType::MoveBalance
+        } else {
+            ProcessingType::BuiltInFunctionCall
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            ProcessingType::MoveBalance => "MoveBalance",
+            ProcessingType::BuiltInFunctionCall => "BuiltInFunctionCall",
+            ProcessingType::SCInvoking => "SCInvoking",
+            ProcessingType::SCDeployment => "SCDeployment",
+        }
+    }
+}
+
+/// Describes the processed transaction the same way the gateway does, so that the interactor can parse it as usual.
+///
+/// The results get returned in a smart contract result, sent back from `result_sender` to the transaction sender.
+pub(crate) fn processed_tx_on_network(
+    tx: &Transaction,
+    tx_hash: &str,
+    processing_type: ProcessingType,
+    result_sender: &Address,
+    tx_result: &TxResult,
+    block_info: &BlockInfo,
+) -> TransactionOnNetwork {
+    let is_success = tx_result.result_status.is_success();
+    let mut tx_on_network = TransactionOnNetwork {
+        kind: "normal".to_string(),
+        hash: Some(tx_hash.to_string()),
+        nonce: tx.nonce,
+        round: block_info.block_round,
+        epoch: block_info.block_epoch,
+        value: tx.value.clone(),
+        receiver: tx.receiver.clone(),
+        sender: tx.sender.clone(),
+        gas_price: tx.gas_price,
+        gas_limit: tx.gas_limit,
+        gas_used: tx.gas_limit,
+        signature: tx.signature.clone().unwrap_or_default(),
+        block_nonce: block_info.block_nonce,
+        processing_type_on_destination: processing_type.as_str().to_string(),
+        timestamp: block_info.block_timestamp,
+        data: tx.data.clone(),
+        status: if is_success {
+            STATUS_SUCCESS
+        } else {
+            STATUS_FAIL
+        }
+        .to_string(),
+        ..Default::default()
+    };
+
+    if !is_success {
+        tx_on_network.logs = Some(ApiLogs {
+            address: tx.sender.clone(),
+            events: vec![signal_error_event(tx, &tx_result.result_message)],
+        });
+        return tx_on_network;
+    }
+
+    if tx_result.gas_used > 0 {
+        tx_on_network.gas_used = tx_result.gas_used;
+    }
+
+    if !matches!(processing_type, ProcessingType::MoveBalance) {
+        tx_on_network.smart_contract_results.push(out_scr(
+            tx,
+            tx_hash,
+            result_sender,
+            &tx_result.result_values,
+        ));
+    }
+
+    if !tx_result.result_logs.is_empty() {
+        tx_on_network.logs = Some(ApiLogs {
+            address: SdkAddress(result_sender.clone()),
+            events: tx_result
+                .result_logs
+                .iter()
+                .map(|log| Events {
+                    address: SdkAddress(log.address.clone()),
+                    identifier: log.endpoint.clone().into_string(),
+                    topics: Some(log.topics.iter().map(base64_encode).collect()),
+                    data: LogData::Vec(log.data.iter().map(base64_encode).collect()),
+                })
+                .collect(),
+        });
+    }
+
+    tx_on_network
+}
+
+fn out_scr(
+    tx: &Transaction,
+    tx_hash: &str,
+    result_sender: &Address,
+    result_values: &[Vec<u8>],
+) -> ApiSmartContractResult {
+    let mut data = "@6f6b".to_string();
+    for value in result_values {
+        data.push('@');
+        data.push_str(&hex::encode(value));
+    }
+
+    ApiSmartContractResult {
+        hash: String::new(),
+        nonce: tx.nonce + 1,
+        value: 0,
+        receiver: tx.sender.clone(),
+        sender: SdkAddress(result_sender.clone()),
+        data,
+        prev_tx_hash: tx_hash.to_string(),
+        original_tx_hash: tx_hash.to_string(),
+        gas_limit: 0,
+        gas_price: tx.gas_price,
+        call_type: CallType::DirectCall,
+        relayer_address: None,
+        relayed_value: None,
+        code: None,
+        code_metadata: None,
+        return_message: None,
+        original_sender: None,
+    }
+}
+
+fn signal_error_event(tx: &Transaction, message: &str) -> Events {
+    Events {
+        address: tx.sender.clone(),
+        identifier: LOG_IDENTIFIER_SIGNAL_ERROR.to_string(),
+        topics: Some(vec![
+            base64_encode(tx.sender.0.as_bytes()),
+            base64_encode(message),
+        ]),
+        data: LogData::Empty,
+    }
+}
@@ -0,0 +1,72 @@
+use anyhow::bail;
+use dharitri_sc_scenario::dharitri_chain_vm::with_shared::Shareable;
+use dharitri_sdk::data::transaction::{SimulateTxResponseData, SimulationResults, Transaction};
+use serde_json::Value;
+
+use super::{
+    vm_chain::{gateway_error, gateway_response, VMChain},
+    vm_tx::fail_reason,
+    vm_tx_on_network::processed_tx_on_network,
+};
+
+impl VMChain {
+    pub(crate) fn simulate_tx_response(&mut self, tx: Transaction) -> Value {
+        match self.simulate_tx(tx) {
+            Ok(result) => gateway_response(SimulateTxResponseData { result }),
+            Err(err) => gateway_error(err),
+        }
+    }
+
+    /// Validates the transaction like when sending it, then executes it on a copy of the state, so it never changes it.
+    ///
+    /// Unlike when sending, the nonce has to be the current nonce of the account.
+    fn simulate_tx(&mut self, tx: Transaction) -> anyhow::Result<SimulationResults> {
+        self.validate_tx(&tx)?;
+        let account_nonce = self.get_account(&tx.sender.0).nonce;
+        if tx.nonce != account_nonce {
+            bail!(
+                "transaction generation failed: invalid transaction lowerNonceInTx: {}, veryHighNonceInTx: {}",
+                tx.nonce < account_nonce,
+                tx.nonce > account_nonce
+            );
+        }
+        let tx_hash = tx.compute_hash()?;
+
+        let state_copy = Shareable::new((*self.vm_runner.blockchain_mock.state).clone());
+        let state = std::mem::replace(&mut self.vm_runner.blockchain_mock.state, state_copy);
+        let (processing_type, result_sender, tx_result) = self.execute_tx(&tx, &tx_hash);
+        self.vm_runner.blockchain_mock.state = state;
+
+        let block_info = &self.vm_runner.blockchain_mock.state.current_block_info;
+        let tx_on_network = processed_tx_on_network(
+            &tx,
+            &tx_hash,
+            processing_type,
+            &result_sender,
+            &tx_result,
+            block_info,
+        );
+
+        if !tx_result.result_status.is_success() {
+            return Ok(SimulationResults {
+                status: tx_on_network.status,
+                fail_reason: fail_reason(&tx_result),
+                hash: tx_hash,
+                ..Default::default()
+            });
+        }
+
+        Ok(SimulationResults {
+            status: tx_on_network.status,
+            fail_reason: String::new(),
+            sc_results: tx_on_network
+                .smart_contract_results
+                .into_iter()
+                .enumerate()
+                .map(|(index, scr)| (format!("{tx_hash}-{index}"), scr))
+                .collect(),
+            hash: tx_hash,
+            logs: tx_on_network.logs,
+        })
+    }
+}
@@ -1,10 +1,17 @@
 pub use crate::dharitri_sc_scenario::imports::*;
 
 pub use crate::{
-    dns_address_for_name, InteractorBase, InteractorPrepareAsync, InteractorRunAsync, StepBuffer,
+    dns_address_for_name, EventDecoder, GasMode, GatewayVMProxy, InteractorBase,
+    InteractorOfflineAsync, InteractorPrepareAsync, InteractorRunAsync, InteractorSimulateAsync,
+    NonceMode, StepBuffer, VMInteractor,
 };
 
-pub use crate::sdk::{data::keystore::InsertPassword, test_wallets, wallet::Wallet};
+pub use crate::sdk::{
+    data::keystore::InsertPassword,
+    test_wallets,
+    wallet::{MnemonicWallet, Wallet},
+    BlockStream, EventFilter,
+};
 
 pub use env_logger;
 
@@ -1,11 +1,17 @@
 mod interactor_base;
 mod interactor_chain_simulator;
 mod interactor_dns;
+mod interactor_gas;
+mod interactor_nonce;
 mod interactor_scenario;
+mod interactor_scenario_address;
 mod interactor_sender;
 mod interactor_tx;
 
 pub use interactor_base::*;
 pub use interactor_dns::*;
+pub use interactor_gas::*;
+pub use interactor_nonce::*;
+pub use interactor_scenario_address::*;
 pub use interactor_sender::*;
 pub use interactor_tx::*;
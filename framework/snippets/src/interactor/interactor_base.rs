@@ -1,20 +1,29 @@
-use crate::sdk::{data::network_config::NetworkConfig, wallet::Wallet};
+use crate::sdk::{
+    data::network_config::NetworkConfig,
+    wallet::{MnemonicWallet, Wallet},
+};
 use dharitri_sc_scenario::{
     imports::{Bech32Address, ScenarioRunner},
     denali_system::{run_list::ScenarioRunnerList, run_trace::ScenarioTraceFile},
     meta::tools::find_current_workspace,
     dharitri_sc::types::Address,
 };
-use dharitri_sdk::gateway::{GatewayAsyncService, NetworkConfigRequest, SetStateAccount};
+use dharitri_sdk::gateway::{
+    GatewayAsyncService, GatewayError, NetworkConfigRequest, SetStateAccount,
+};
 use std::{
     collections::HashMap,
     fs::File,
     io::BufReader,
+    ops::Range,
     path::{Path, PathBuf},
     time::Duration,
 };
 
-use crate::{account_tool::retrieve_account_as_scenario_set_state, Sender};
+use crate::{
+    account_tool::retrieve_account_as_scenario_set_state, GasMode, NonceMode, ScenarioAddressMap,
+    Sender,
+};
 
 pub const INTERACTOR_SCENARIO_TRACE_PATH: &str = "interactor_trace.scen.json";
 pub const INTERACTOR_SET_STATE_PATH: &str = "set_state.json";
@@ -25,6 +34,8 @@ where
 {
     pub proxy: GatewayProxy,
     pub use_chain_simulator: bool,
+    pub nonce_mode: NonceMode,
+    pub gas_mode: GasMode,
     pub network_config: NetworkConfig,
     pub sender_map: HashMap<Address, Sender>,
 
@@ -33,6 +44,7 @@ where
     pub post_runners: ScenarioRunnerList,
 
     pub current_dir: PathBuf,
+    pub scenario_addresses: ScenarioAddressMap,
 }
 
 impl<GatewayProxy> InteractorBase<GatewayProxy>
@@ -41,18 +53,28 @@ where
 {
     /// Not yet changed for backwards compatibility.
     pub async fn new(gateway_uri: &str) -> Self {
+        Self::try_new(gateway_uri)
+            .await
+            .expect("failed to retrieve network config")
+    }
+
+    /// Same as [`Self::new`], but fails instead of panicking if the gateway cannot be reached.
+    pub async fn try_new(gateway_uri: &str) -> Result<Self, GatewayError> {
         let proxy = GatewayProxy::from_uri(gateway_uri);
-        let network_config = proxy.request(NetworkConfigRequest).await.unwrap();
-        Self {
+        let network_config = proxy.request(NetworkConfigRequest).await?;
+        Ok(Self {
             proxy,
             use_chain_simulator: false,
+            nonce_mode: NonceMode::default(),
+            gas_mode: GasMode::default(),
             network_config,
             sender_map: HashMap::new(),
             waiting_time_ms: 0,
             pre_runners: ScenarioRunnerList::empty(),
             post_runners: ScenarioRunnerList::empty(),
             current_dir: PathBuf::default(),
-        }
+            scenario_addresses: ScenarioAddressMap::default(),
+        })
     }
 
     pub fn use_chain_simulator(mut self, use_chain_simulator: bool) -> Self {
@@ -60,20 +82,85 @@ where
         self
     }
 
+    /// Changes how the nonces of the transactions get set. See [`NonceMode`].
+    pub fn with_nonce_mode(mut self, nonce_mode: NonceMode) -> Self {
+        self.nonce_mode = nonce_mode;
+        self
+    }
+
+    /// Changes how the gas limits of the transactions get set. See [`GasMode`].
+    pub fn with_gas_mode(mut self, gas_mode: GasMode) -> Self {
+        self.gas_mode = gas_mode;
+        self
+    }
+
     pub async fn register_wallet(&mut self, wallet: Wallet) -> Address {
+        self.try_register_wallet(wallet)
+            .await
+            .expect("failed to register wallet")
+    }
+
+    pub async fn try_register_wallet(&mut self, wallet: Wallet) -> Result<Address, GatewayError> {
+        let address = self.add_sender(wallet).await?;
+        self.generate_blocks(1).await?;
+        Ok(address)
+    }
+
+    /// Registers a pool of senders at once, e.g. the accounts derived from a [`MnemonicWallet`].
+    pub async fn register_wallets(&mut self, wallets: Vec<Wallet>) -> Vec<Address> {
+        self.try_register_wallets(wallets)
+            .await
+            .expect("failed to register wallets")
+    }
+
+    pub async fn try_register_wallets(
+        &mut self,
+        wallets: Vec<Wallet>,
+    ) -> Result<Vec<Address>, GatewayError> {
+        let mut addresses = Vec::with_capacity(wallets.len());
+        for wallet in wallets {
+            addresses.push(self.add_sender(wallet).await?);
+        }
+        self.generate_blocks(1).await?;
+        Ok(addresses)
+    }
+
+    /// Registers the accounts derived from the mnemonic at the given address indices, as a pool of senders.
+    pub async fn register_mnemonic_wallet(
+        &mut self,
+        mnemonic_wallet: &MnemonicWallet,
+        address_indices: Range<u32>,
+    ) -> Vec<Address> {
+        self.try_register_mnemonic_wallet(mnemonic_wallet, address_indices)
+            .await
+            .expect("failed to register mnemonic wallet")
+    }
+
+    pub async fn try_register_mnemonic_wallet(
+        &mut self,
+        mnemonic_wallet: &MnemonicWallet,
+        address_indices: Range<u32>,
+    ) -> Result<Vec<Address>, GatewayError> {
+        self.try_register_wallets(mnemonic_wallet.wallets(address_indices))
+            .await
+    }
+
+    async fn add_sender(&mut self, wallet: Wallet) -> Result<Address, GatewayError> {
         let address = wallet.to_address();
 
-        self.send_user_funds(&address).await.unwrap();
-        self.generate_blocks(1).await.unwrap();
+        self.send_user_funds(&address).await?;
         self.sender_map.insert(
             address.clone(),
             Sender {
                 address: address.clone(),
                 wallet,
                 current_nonce: None,
+                num_txs_since_recall: 0,
+                relayer: None,
+                guardian: None,
             },
         );
-        address
+        Ok(address)
     }
 
     pub async fn sleep(&mut self, duration: Duration) {
@@ -1,8 +1,7 @@
-use anyhow::Error;
 use dharitri_sc_scenario::imports::Address;
 use dharitri_sdk::gateway::{
     ChainSimulatorGenerateBlocksRequest, ChainSimulatorSendFundsRequest,
-    ChainSimulatorSetStateRequest, GatewayAsyncService, SetStateAccount,
+    ChainSimulatorSetStateRequest, GatewayAsyncService, GatewayError, SetStateAccount,
 };
 
 use crate::InteractorBase;
@@ -11,7 +10,7 @@ impl<GatewayProxy> InteractorBase<GatewayProxy>
 where
     GatewayProxy: GatewayAsyncService,
 {
-    pub async fn send_user_funds(&self, receiver: &Address) -> Result<String, Error> {
+    pub async fn send_user_funds(&self, receiver: &Address) -> Result<String, GatewayError> {
         if !self.use_chain_simulator {
             return Ok(String::from("no-simulator"));
         }
@@ -21,7 +20,7 @@ where
             .await
     }
 
-    pub async fn generate_blocks(&self, num_blocks: u64) -> Result<String, Error> {
+    pub async fn generate_blocks(&self, num_blocks: u64) -> Result<String, GatewayError> {
         if !self.use_chain_simulator {
             return Ok(String::from("no-simulator"));
         }
@@ -31,7 +30,10 @@ where
             .await
     }
 
-    pub async fn generate_blocks_until_epoch(&self, epoch_number: u64) -> Result<String, Error> {
+    pub async fn generate_blocks_until_epoch(
+        &self,
+        epoch_number: u64,
+    ) -> Result<String, GatewayError> {
         if !self.use_chain_simulator {
             return Ok(String::from("no-simulator"));
         }
@@ -43,7 +45,10 @@ where
             .await
     }
 
-    pub async fn generate_blocks_until_tx_processed(&self, tx_hash: &str) -> Result<String, Error> {
+    pub async fn generate_blocks_until_tx_processed(
+        &self,
+        tx_hash: &str,
+    ) -> Result<String, GatewayError> {
         if !self.use_chain_simulator {
             return Ok(String::from("no-simulator"));
         }
@@ -55,7 +60,7 @@ where
             .await
     }
 
-    pub async fn set_state(&self, accounts: Vec<SetStateAccount>) -> Result<String, Error> {
+    pub async fn set_state(&self, accounts: Vec<SetStateAccount>) -> Result<String, GatewayError> {
         if !self.use_chain_simulator {
             return Ok(String::from("no-simulator"));
         }
@@ -65,7 +70,7 @@ where
             .await
     }
 
-    pub async fn set_state_for_saved_accounts(&self) -> Result<String, Error> {
+    pub async fn set_state_for_saved_accounts(&self) -> Result<String, GatewayError> {
         if !self.use_chain_simulator {
             return Ok(String::from("no-simulator"));
         }
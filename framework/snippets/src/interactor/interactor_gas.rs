@@ -0,0 +1,85 @@
+use crate::sdk::data::transaction::Transaction;
+use dharitri_sc_scenario::{
+    scenario_format::serde_raw::ValueSubTree,
+    scenario_model::{U64Value, DEFAULT_GAS_EXPR},
+};
+use dharitri_sdk::gateway::{GatewayAsyncService, GetTxCost};
+
+use crate::InteractorBase;
+
+/// Decides how the interactor picks the gas limits of the transactions it sends.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum GasMode {
+    /// Uses the gas limit set on each transaction.
+    #[default]
+    Explicit,
+
+    /// Asks the gateway for the cost of each transaction without an explicit gas limit before signing it,
+    /// and uses the cost, multiplied by `safety_multiplier`, as gas limit.
+    ///
+    /// Transactions with an explicit gas limit are sent as they are.
+    ///
+    /// The gas limit never goes above `max_gas_limit`, which is also the gas limit used for the estimation itself.
+    /// If the estimation fails, the gas limit set on the transaction is kept.
+    Estimate {
+        safety_multiplier: f64,
+        max_gas_limit: u64,
+    },
+}
+
+impl<GatewayProxy> InteractorBase<GatewayProxy>
+where
+    GatewayProxy: GatewayAsyncService,
+{
+    /// Estimates the gas limit of the transaction, if the gas mode requires it.
+    pub async fn estimate_gas(&self, transaction: &Transaction) -> Option<u64> {
+        let GasMode::Estimate {
+            safety_multiplier,
+            max_gas_limit,
+        } = self.gas_mode
+        else {
+            return None;
+        };
+
+        let mut transaction = transaction.clone();
+        transaction.gas_limit = max_gas_limit;
+        match self.proxy.request(GetTxCost(&transaction)).await {
+            Ok(tx_cost) if tx_cost.return_message.is_empty() => {
+                let gas_estimate = (tx_cost.tx_gas_units as f64 * safety_multiplier).ceil() as u64;
+                let gas_estimate = gas_estimate.min(max_gas_limit);
+                println!(
+                    "estimated gas: {gas_estimate} (cost: {})",
+                    tx_cost.tx_gas_units
+                );
+                Some(gas_estimate)
+            },
+            Ok(tx_cost) => {
+                println!("gas estimation failed: {}", tx_cost.return_message);
+                None
+            },
+            Err(err) => {
+                println!("gas estimation failed: {err}");
+                None
+            },
+        }
+    }
+
+    /// Replaces the gas limit of the step with the estimated one, if any.
+    ///
+    /// Only steps left with the default gas limit get estimated, explicit gas limits are always kept.
+    ///
+    /// `transaction` is the step, converted to a blockchain transaction.
+    pub(crate) async fn estimate_step_gas(
+        &self,
+        transaction: &Transaction,
+        gas_limit: &mut U64Value,
+    ) -> Option<u64> {
+        if gas_limit.original != ValueSubTree::Str(DEFAULT_GAS_EXPR.to_string()) {
+            return None;
+        }
+
+        let gas_estimate = self.estimate_gas(transaction).await?;
+        *gas_limit = U64Value::from(gas_estimate);
+        Some(gas_estimate)
+    }
+}
@@ -0,0 +1,142 @@
+use crate::sdk::data::transaction::Transaction;
+use dharitri_sc_scenario::dharitri_sc::types::Address;
+use dharitri_sdk::gateway::{GatewayAsyncService, GatewayError, SendTxRequest};
+
+use crate::InteractorBase;
+
+const LOWER_NONCE_ERROR: &str = "lowerNonceInTx: true";
+const HIGHER_NONCE_ERROR: &str = "veryHighNonceInTx: true";
+
+/// Decides how the interactor picks the nonces of the transactions it sends.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NonceMode {
+    /// Retrieves the nonce from the gateway before each transaction.
+    ///
+    /// Transactions from the same account cannot be pipelined, and the gateway might lag behind the mempool.
+    #[default]
+    Recall,
+
+    /// Retrieves the nonce from the gateway once, then increments it locally with each transaction.
+    ///
+    /// Only works if no one else sends transactions from the same account.
+    LocalIncrement,
+
+    /// Increments the nonce locally, but retrieves it again from the gateway every `num_txs` transactions.
+    PeriodicResync { num_txs: u64 },
+
+    /// Increments the nonce locally, but retrieves it again whenever the gateway rejects a transaction
+    /// because of its nonce. The transaction is then signed and sent again, with the new nonce.
+    DetectAndRepair,
+}
+
+impl<GatewayProxy> InteractorBase<GatewayProxy>
+where
+    GatewayProxy: GatewayAsyncService,
+{
+    /// Retrieves the nonce of the account from the gateway, and uses it for the next transaction.
+    pub async fn resync_nonce(&mut self, sender_address: &Address) -> u64 {
+        self.try_resync_nonce(sender_address)
+            .await
+            .expect("failed to retrieve account nonce")
+    }
+
+    pub async fn try_resync_nonce(
+        &mut self,
+        sender_address: &Address,
+    ) -> Result<u64, GatewayError> {
+        let nonce = self.try_recall_nonce(sender_address).await?;
+        println!("sender's recalled nonce: {nonce}");
+
+        let sender = self.get_sender_mut(sender_address);
+        sender.current_nonce = Some(nonce);
+        sender.num_txs_since_recall = 0;
+        Ok(nonce)
+    }
+
+    /// Resyncs the nonce, if the nonce mode requires it.
+    pub(crate) async fn resync_nonce_if_due(
+        &mut self,
+        sender_address: &Address,
+    ) -> Result<(), GatewayError> {
+        if self.is_nonce_recall_due(sender_address) {
+            self.try_resync_nonce(sender_address).await?;
+        }
+        Ok(())
+    }
+
+    fn is_nonce_recall_due(&self, sender_address: &Address) -> bool {
+        let sender = self
+            .sender_map
+            .get(sender_address)
+            .expect("sender not registered");
+        match self.nonce_mode {
+            NonceMode::Recall => true,
+            NonceMode::LocalIncrement | NonceMode::DetectAndRepair => {
+                sender.current_nonce.is_none()
+            },
+            NonceMode::PeriodicResync { num_txs } => {
+                sender.current_nonce.is_none() || sender.num_txs_since_recall >= num_txs
+            },
+        }
+    }
+
+    /// Takes the next nonce of the account, without checking with the gateway.
+    pub(crate) fn take_local_nonce(&mut self, sender_address: &Address) -> u64 {
+        let sender = self.get_sender_mut(sender_address);
+        let nonce = sender
+            .current_nonce
+            .expect("sender nonce not yet retrieved");
+        sender.current_nonce = Some(nonce + 1);
+        sender.num_txs_since_recall += 1;
+        nonce
+    }
+
+    /// Sets the nonce, signs and sends the transaction.
+    ///
+    /// In [`NonceMode::DetectAndRepair`], a transaction rejected because of its nonce is sent once more, with a fresh nonce.
+    /// Any other rejection causes the nonce to be retrieved again before the next transaction.
+    pub(crate) async fn sign_and_send_tx(
+        &mut self,
+        sender_address: &Address,
+        unsigned_tx: Transaction,
+    ) -> Result<String, GatewayError> {
+        let result = self
+            .sign_and_send_tx_with_repair(sender_address, unsigned_tx)
+            .await;
+
+        if result.is_err() {
+            self.get_sender_mut(sender_address).current_nonce = None;
+        }
+        result
+    }
+
+    async fn sign_and_send_tx_with_repair(
+        &mut self,
+        sender_address: &Address,
+        unsigned_tx: Transaction,
+    ) -> Result<String, GatewayError> {
+        let mut transaction = unsigned_tx.clone();
+        self.set_nonce_and_sign_tx(sender_address, &mut transaction)
+            .await?;
+        let result = self.proxy.request(SendTxRequest(&transaction)).await;
+
+        match result {
+            Err(err) if self.nonce_mode == NonceMode::DetectAndRepair && is_nonce_error(&err) => {
+                println!("nonce {} rejected: {err}", transaction.nonce);
+                self.try_resync_nonce(sender_address).await?;
+                let mut transaction = unsigned_tx;
+                self.set_nonce_and_sign_tx(sender_address, &mut transaction)
+                    .await?;
+                self.proxy.request(SendTxRequest(&transaction)).await
+            },
+            result => result,
+        }
+    }
+}
+
+/// Checks whether the gateway rejected the transaction because its nonce was too low or too high.
+pub fn is_nonce_error(err: &GatewayError) -> bool {
+    err.gateway_message().is_some_and(|message| {
+        message.contains(LOWER_NONCE_ERROR) || message.contains(HIGHER_NONCE_ERROR)
+    })
+}
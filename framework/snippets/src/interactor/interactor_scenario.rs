@@ -1,5 +1,9 @@
+mod interactor_offline;
 mod interactor_sc_call;
 mod interactor_sc_deploy;
 mod interactor_sc_extra;
+mod interactor_scenario_dcdt;
+mod interactor_scenario_file;
+mod interactor_simulate;
 mod interactor_transfer;
 mod interactor_vm_query;
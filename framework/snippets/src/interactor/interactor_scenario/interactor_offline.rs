@@ -0,0 +1,148 @@
+use crate::InteractorBase;
+use dharitri_sc_scenario::{
+    imports::Address,
+    scenario::ScenarioRunner,
+    scenario_model::{ScCallStep, ScDeployStep},
+};
+use dharitri_sdk::{
+    data::transaction::Transaction,
+    gateway::{GatewayAsyncService, GatewayError, SendTxRequest},
+};
+
+impl<GatewayProxy> InteractorBase<GatewayProxy>
+where
+    GatewayProxy: GatewayAsyncService,
+{
+    /// Converts the call to a transaction that can be signed elsewhere, e.g. on an air-gapped machine.
+    ///
+    /// The nonce gets used up, same as when sending the transaction.
+    /// The relayer and guardian of the sender get set, but none of the signatures.
+    pub async fn export_unsigned_sc_call<S>(
+        &mut self,
+        mut sc_call_step: S,
+    ) -> Result<Transaction, GatewayError>
+    where
+        S: AsMut<ScCallStep>,
+    {
+        let sc_call_step = sc_call_step.as_mut();
+        let transaction = self.tx_call_to_blockchain_tx(&sc_call_step.tx);
+        self.estimate_step_gas(&transaction, &mut sc_call_step.tx.gas_limit)
+            .await;
+
+        let sender_address = sc_call_step.tx.from.to_address();
+        let transaction = self.tx_call_to_blockchain_tx(&sc_call_step.tx);
+        self.prepare_unsigned_tx(&sender_address, transaction).await
+    }
+
+    /// Converts the deploy to a transaction that can be signed elsewhere, see [`Self::export_unsigned_sc_call`].
+    pub async fn export_unsigned_sc_deploy<S>(
+        &mut self,
+        mut sc_deploy_step: S,
+    ) -> Result<Transaction, GatewayError>
+    where
+        S: AsMut<ScDeployStep>,
+    {
+        let sc_deploy_step = sc_deploy_step.as_mut();
+        let transaction = self.sc_deploy_to_blockchain_tx(sc_deploy_step);
+        self.estimate_step_gas(&transaction, &mut sc_deploy_step.tx.gas_limit)
+            .await;
+
+        let sender_address = sc_deploy_step.tx.from.to_address();
+        let transaction = self.sc_deploy_to_blockchain_tx(sc_deploy_step);
+        self.prepare_unsigned_tx(&sender_address, transaction).await
+    }
+
+    async fn prepare_unsigned_tx(
+        &mut self,
+        sender_address: &Address,
+        mut transaction: Transaction,
+    ) -> Result<Transaction, GatewayError> {
+        self.resync_nonce_if_due(sender_address).await?;
+        transaction.nonce = self.take_local_nonce(sender_address);
+        println!("-- tx nonce: {}", transaction.nonce);
+        self.set_co_signers(sender_address, &mut transaction);
+        Ok(transaction)
+    }
+
+    /// Sends a call that was signed elsewhere, then processes its results the same as [`Self::sc_call`].
+    ///
+    /// Panics if the signed transaction does not match the step.
+    pub async fn broadcast_signed_sc_call<S>(
+        &mut self,
+        mut sc_call_step: S,
+        signed_tx: &Transaction,
+    ) -> Result<(), GatewayError>
+    where
+        S: AsMut<ScCallStep>,
+    {
+        let sc_call_step = sc_call_step.as_mut();
+        check_signed_tx(&self.tx_call_to_blockchain_tx(&sc_call_step.tx), signed_tx);
+        self.pre_runners.run_sc_call_step(sc_call_step);
+
+        let tx_hash = self.proxy.request(SendTxRequest(signed_tx)).await?;
+        println!("sc call tx hash: {tx_hash}");
+        self.process_sc_call_tx(sc_call_step, tx_hash, None).await
+    }
+
+    /// Sends a deploy that was signed elsewhere, then processes its results the same as [`Self::sc_deploy`].
+    ///
+    /// Panics if the signed transaction does not match the step.
+    pub async fn broadcast_signed_sc_deploy<S>(
+        &mut self,
+        mut sc_deploy_step: S,
+        signed_tx: &Transaction,
+    ) -> Result<(), GatewayError>
+    where
+        S: AsMut<ScDeployStep>,
+    {
+        let sc_deploy_step = sc_deploy_step.as_mut();
+        check_signed_tx(&self.sc_deploy_to_blockchain_tx(sc_deploy_step), signed_tx);
+        self.pre_runners.run_sc_deploy_step(sc_deploy_step);
+
+        let tx_hash = self.proxy.request(SendTxRequest(signed_tx)).await?;
+        println!("sc deploy tx hash: {tx_hash}");
+        self.process_sc_deploy_tx(sc_deploy_step, tx_hash, None)
+            .await
+    }
+
+    /// Waits for a call that was sent elsewhere, then processes its results the same as [`Self::sc_call`].
+    pub async fn track_sc_call<S>(
+        &mut self,
+        mut sc_call_step: S,
+        tx_hash: &str,
+    ) -> Result<(), GatewayError>
+    where
+        S: AsMut<ScCallStep>,
+    {
+        self.process_sc_call_tx(sc_call_step.as_mut(), tx_hash.to_string(), None)
+            .await
+    }
+
+    /// Waits for a deploy that was sent elsewhere, then processes its results the same as [`Self::sc_deploy`].
+    pub async fn track_sc_deploy<S>(
+        &mut self,
+        mut sc_deploy_step: S,
+        tx_hash: &str,
+    ) -> Result<(), GatewayError>
+    where
+        S: AsMut<ScDeployStep>,
+    {
+        self.process_sc_deploy_tx(sc_deploy_step.as_mut(), tx_hash.to_string(), None)
+            .await
+    }
+}
+
+/// The gas limit is not checked, since it might have been estimated when exporting.
+fn check_signed_tx(expected: &Transaction, signed_tx: &Transaction) {
+    assert!(
+        signed_tx.sender.0 == expected.sender.0
+            && signed_tx.receiver.0 == expected.receiver.0
+            && signed_tx.value == expected.value
+            && signed_tx.data == expected.data,
+        "the signed transaction does not match the step"
+    );
+    assert!(
+        signed_tx.signature.is_some(),
+        "the signed transaction is missing the sender signature"
+    );
+}
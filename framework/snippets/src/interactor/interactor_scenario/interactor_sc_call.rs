@@ -6,26 +6,53 @@ use dharitri_sc_scenario::{
 };
 use dharitri_sdk::{data::transaction::Transaction, utils::base64_encode};
 use dharitri_sdk::{
-    gateway::{GatewayAsyncService, SendTxRequest},
-    retrieve_tx_on_network,
+    gateway::{GatewayAsyncService, GatewayError},
+    try_retrieve_tx_on_network,
 };
 
 impl<GatewayProxy> InteractorBase<GatewayProxy>
 where
     GatewayProxy: GatewayAsyncService,
 {
-    pub async fn sc_call<S>(&mut self, mut sc_call_step: S)
+    pub async fn sc_call<S>(&mut self, sc_call_step: S)
+    where
+        S: AsMut<ScCallStep>,
+    {
+        self.try_sc_call(sc_call_step)
+            .await
+            .expect("sc call failed (possible API failure)");
+    }
+
+    /// Same as [`Self::sc_call`], but gateway failures are returned instead of causing a panic.
+    ///
+    /// A transaction that gets processed, but fails, is not an error here: the failure ends up in the step response.
+    pub async fn try_sc_call<S>(&mut self, mut sc_call_step: S) -> Result<(), GatewayError>
     where
         S: AsMut<ScCallStep>,
     {
         let sc_call_step = sc_call_step.as_mut();
-        let tx_hash = self.launch_sc_call(sc_call_step).await;
-        self.generate_blocks_until_tx_processed(&tx_hash)
+        let transaction = self.tx_call_to_blockchain_tx(&sc_call_step.tx);
+        let gas_estimate = self
+            .estimate_step_gas(&transaction, &mut sc_call_step.tx.gas_limit)
+            .await;
+        let tx_hash = self.launch_sc_call(sc_call_step).await?;
+        self.process_sc_call_tx(sc_call_step, tx_hash, gas_estimate)
             .await
-            .unwrap();
-        let (tx, return_code) = retrieve_tx_on_network(&self.proxy, tx_hash.clone()).await;
+    }
+
+    /// Waits for the transaction of the step to be processed, then saves its response.
+    pub(crate) async fn process_sc_call_tx(
+        &mut self,
+        sc_call_step: &mut ScCallStep,
+        tx_hash: String,
+        gas_estimate: Option<u64>,
+    ) -> Result<(), GatewayError> {
+        self.generate_blocks_until_tx_processed(&tx_hash).await?;
+        let (tx, return_code) = try_retrieve_tx_on_network(&self.proxy, tx_hash).await?;
 
-        sc_call_step.save_response(network_response::parse_tx_response(tx, return_code));
+        let mut response = network_response::parse_tx_response(tx, return_code);
+        response.gas_estimate = gas_estimate;
+        sc_call_step.save_response(response);
 
         if let Some(token_identifier) = sc_call_step.response().new_issued_token_identifier.clone()
         {
@@ -37,24 +64,22 @@ where
         }
 
         self.post_runners.run_sc_call_step(sc_call_step);
+        Ok(())
     }
 
-    async fn launch_sc_call(&mut self, sc_call_step: &mut ScCallStep) -> String {
+    async fn launch_sc_call(
+        &mut self,
+        sc_call_step: &mut ScCallStep,
+    ) -> Result<String, GatewayError> {
         self.pre_runners.run_sc_call_step(sc_call_step);
 
         let sender_address = &sc_call_step.tx.from.value;
-        let mut transaction = self.tx_call_to_blockchain_tx(&sc_call_step.tx);
-        self.set_nonce_and_sign_tx(sender_address, &mut transaction)
-            .await;
-        let tx_hash = self
-            .proxy
-            .request(SendTxRequest(&transaction))
-            .await
-            .expect("Launch sc call failed");
+        let transaction = self.tx_call_to_blockchain_tx(&sc_call_step.tx);
+        let tx_hash = self.sign_and_send_tx(sender_address, transaction).await?;
         println!("sc call tx hash: {tx_hash}");
         info!("sc call tx hash: {}", tx_hash);
 
-        tx_hash
+        Ok(tx_hash)
     }
 
     pub(crate) fn tx_call_to_blockchain_tx(&self, tx_call: &TxCall) -> Transaction {
@@ -78,6 +103,10 @@ where
             chain_id: self.network_config.chain_id.clone(),
             version: self.network_config.min_transaction_version,
             options: 0,
+            guardian: None,
+            guardian_signature: None,
+            relayer: None,
+            relayer_signature: None,
         }
     }
 }
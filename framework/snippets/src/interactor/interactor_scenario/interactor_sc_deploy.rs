@@ -7,8 +7,8 @@ use dharitri_sc_scenario::{
 };
 use dharitri_sdk::{data::transaction::Transaction, utils::base64_encode};
 use dharitri_sdk::{
-    gateway::{GatewayAsyncService, SendTxRequest},
-    retrieve_tx_on_network,
+    gateway::{GatewayAsyncService, GatewayError},
+    try_retrieve_tx_on_network,
 };
 
 impl<GatewayProxy> InteractorBase<GatewayProxy>
@@ -28,41 +28,67 @@ where
             chain_id: self.network_config.chain_id.clone(),
             version: self.network_config.min_transaction_version,
             options: 0,
+            guardian: None,
+            guardian_signature: None,
+            relayer: None,
+            relayer_signature: None,
         }
     }
 
-    pub async fn launch_sc_deploy(&mut self, sc_deploy_step: &mut ScDeployStep) -> String {
+    pub async fn launch_sc_deploy(
+        &mut self,
+        sc_deploy_step: &mut ScDeployStep,
+    ) -> Result<String, GatewayError> {
         self.pre_runners.run_sc_deploy_step(sc_deploy_step);
 
         let sender_address = &sc_deploy_step.tx.from.value;
-        let mut transaction = self.sc_deploy_to_blockchain_tx(sc_deploy_step);
-        self.set_nonce_and_sign_tx(sender_address, &mut transaction)
-            .await;
-        let tx_hash = self
-            .proxy
-            .request(SendTxRequest(&transaction))
-            .await
-            .expect("error sending tx (possible API failure)");
+        let transaction = self.sc_deploy_to_blockchain_tx(sc_deploy_step);
+        let tx_hash = self.sign_and_send_tx(sender_address, transaction).await?;
         println!("sc deploy tx hash: {tx_hash}");
         info!("sc deploy tx hash: {}", tx_hash);
 
-        tx_hash
+        Ok(tx_hash)
+    }
+
+    pub async fn sc_deploy<S>(&mut self, sc_deploy_step: S)
+    where
+        S: AsMut<ScDeployStep>,
+    {
+        self.try_sc_deploy(sc_deploy_step)
+            .await
+            .expect("error sending tx (possible API failure)");
     }
 
-    pub async fn sc_deploy<S>(&mut self, mut sc_deploy_step: S)
+    /// Same as [`Self::sc_deploy`], but gateway failures are returned instead of causing a panic.
+    pub async fn try_sc_deploy<S>(&mut self, mut sc_deploy_step: S) -> Result<(), GatewayError>
     where
         S: AsMut<ScDeployStep>,
     {
         let sc_deploy_step = sc_deploy_step.as_mut();
-        let tx_hash = self.launch_sc_deploy(sc_deploy_step).await;
-        self.generate_blocks_until_tx_processed(&tx_hash)
+        let transaction = self.sc_deploy_to_blockchain_tx(sc_deploy_step);
+        let gas_estimate = self
+            .estimate_step_gas(&transaction, &mut sc_deploy_step.tx.gas_limit)
+            .await;
+        let tx_hash = self.launch_sc_deploy(sc_deploy_step).await?;
+        self.process_sc_deploy_tx(sc_deploy_step, tx_hash, gas_estimate)
             .await
-            .unwrap();
-        let (tx, return_code) = retrieve_tx_on_network(&self.proxy, tx_hash.clone()).await;
+    }
+
+    /// Waits for the transaction of the step to be processed, then saves its response.
+    pub(crate) async fn process_sc_deploy_tx(
+        &mut self,
+        sc_deploy_step: &mut ScDeployStep,
+        tx_hash: String,
+        gas_estimate: Option<u64>,
+    ) -> Result<(), GatewayError> {
+        self.generate_blocks_until_tx_processed(&tx_hash).await?;
+        let (tx, return_code) = try_retrieve_tx_on_network(&self.proxy, tx_hash).await?;
 
         let addr = sc_deploy_step.tx.from.clone();
         let nonce = tx.nonce;
-        sc_deploy_step.save_response(network_response::parse_tx_response(tx, return_code));
+        let mut response = network_response::parse_tx_response(tx, return_code);
+        response.gas_estimate = gas_estimate;
+        sc_deploy_step.save_response(response);
 
         let deploy_address = sc_deploy_step
             .response()
@@ -78,5 +104,6 @@ where
         self.post_runners.run_set_state_step(&set_state_step);
 
         self.post_runners.run_sc_deploy_step(sc_deploy_step);
+        Ok(())
     }
 }
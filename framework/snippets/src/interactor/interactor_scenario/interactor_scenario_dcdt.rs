@@ -0,0 +1,178 @@
+use dharitri_sc_scenario::{
+    num_bigint::BigUint,
+    scenario::model::{Dcdt, DcdtInstance},
+};
+
+const DCDT_KEY_PREFIX: &[u8] = b"NUMBATdcdt";
+const DCDT_ROLES_KEY_PREFIX: &[u8] = b"NUMBATroledcdt";
+const DCDT_NONCE_KEY_PREFIX: &[u8] = b"NUMBATnonce";
+
+const DCDT_TYPE_FUNGIBLE: u64 = 0;
+const DCDT_TYPE_NON_FUNGIBLE: u64 = 1;
+const DCDT_PROPERTY_FROZEN: u8 = 1;
+
+/// The account storage entries that hold a DCDT balance on the protocol side,
+/// so that it can be set like any other storage on the chain simulator.
+///
+/// The values are protobuf messages, same as the ones written by the protocol.
+pub(super) fn dcdt_storage_keys(token_id: &[u8], dcdt: &Dcdt) -> Vec<(Vec<u8>, Vec<u8>)> {
+    let mut dcdt = dcdt.clone();
+    let dcdt_obj = dcdt.get_mut_dcdt_object();
+    let token_frozen = value_is_set(dcdt_obj.frozen.as_ref().map(|frozen| frozen.value));
+
+    let mut keys = Vec::new();
+    for instance in &dcdt_obj.instances {
+        let nonce = instance
+            .nonce
+            .as_ref()
+            .map(|nonce| nonce.value)
+            .unwrap_or_default();
+        let key = [DCDT_KEY_PREFIX, token_id, &nonce_bytes(nonce)].concat();
+        keys.push((key, encode_dcdt_token(nonce, instance, token_frozen)));
+    }
+
+    if !dcdt_obj.roles.is_empty() {
+        let mut value = Vec::new();
+        for role in &dcdt_obj.roles {
+            encode_bytes_field(&mut value, 1, role.as_bytes());
+        }
+        keys.push(([DCDT_ROLES_KEY_PREFIX, token_id].concat(), value));
+    }
+
+    if let Some(last_nonce) = &dcdt_obj.last_nonce {
+        keys.push((
+            [DCDT_NONCE_KEY_PREFIX, token_id].concat(),
+            nonce_bytes(last_nonce.value),
+        ));
+    }
+
+    keys
+}
+
+fn encode_dcdt_token(nonce: u64, instance: &DcdtInstance, token_frozen: bool) -> Vec<u8> {
+    let balance = instance
+        .balance
+        .as_ref()
+        .map(|balance| balance.value.clone())
+        .unwrap_or_default();
+    let frozen = token_frozen || value_is_set(instance.frozen.as_ref().map(|frozen| frozen.value));
+
+    let mut message = Vec::new();
+    if nonce == 0 {
+        encode_varint_field(&mut message, 1, DCDT_TYPE_FUNGIBLE);
+    } else {
+        encode_varint_field(&mut message, 1, DCDT_TYPE_NON_FUNGIBLE);
+    }
+    encode_bytes_field(&mut message, 2, &encode_big_uint(&balance));
+    if frozen {
+        encode_bytes_field(&mut message, 3, &[DCDT_PROPERTY_FROZEN, 0]);
+    }
+    if nonce > 0 {
+        encode_bytes_field(&mut message, 4, &encode_token_metadata(nonce, instance));
+    }
+    message
+}
+
+fn encode_token_metadata(nonce: u64, instance: &DcdtInstance) -> Vec<u8> {
+    let mut message = Vec::new();
+    encode_varint_field(&mut message, 1, nonce);
+    if let Some(creator) = &instance.creator {
+        encode_bytes_field(&mut message, 3, creator.value.as_bytes());
+    }
+    if let Some(royalties) = &instance.royalties {
+        encode_varint_field(&mut message, 4, royalties.value);
+    }
+    if let Some(hash) = &instance.hash {
+        encode_bytes_field(&mut message, 5, &hash.value);
+    }
+    for uri in &instance.uri {
+        encode_bytes_field(&mut message, 6, &uri.value);
+    }
+    if let Some(attributes) = &instance.attributes {
+        encode_bytes_field(&mut message, 7, &attributes.value);
+    }
+    message
+}
+
+fn value_is_set(value: Option<u64>) -> bool {
+    value.unwrap_or_default() != 0
+}
+
+/// Minimal big endian representation, empty for 0.
+fn nonce_bytes(nonce: u64) -> Vec<u8> {
+    let bytes = nonce.to_be_bytes();
+    let leading_zeros = bytes.iter().take_while(|b| **b == 0).count();
+    bytes[leading_zeros..].to_vec()
+}
+
+/// The protocol prefixes big integers with a sign byte.
+fn encode_big_uint(value: &BigUint) -> Vec<u8> {
+    let mut encoded = vec![0u8];
+    if *value != BigUint::default() {
+        encoded.extend_from_slice(&value.to_bytes_be());
+    }
+    encoded
+}
+
+fn encode_varint(message: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        message.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    message.push(value as u8);
+}
+
+/// Default values are left out, same as in proto3.
+fn encode_varint_field(message: &mut Vec<u8>, field: u64, value: u64) {
+    if value != 0 {
+        encode_varint(message, field << 3);
+        encode_varint(message, value);
+    }
+}
+
+fn encode_bytes_field(message: &mut Vec<u8>, field: u64, value: &[u8]) {
+    encode_varint(message, (field << 3) | 2);
+    encode_varint(message, value.len() as u64);
+    message.extend_from_slice(value);
+}
+
+#[test]
+fn test_dcdt_storage_keys_fungible() {
+    use dharitri_sc_scenario::scenario::model::BigUintValue;
+
+    let dcdt = Dcdt::Short(BigUintValue::from(1000u64));
+    let keys = dcdt_storage_keys(b"TOKEN-123456", &dcdt);
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0].0, b"NUMBATdcdtTOKEN-123456".to_vec());
+    // type omitted, balance 1000 with the sign byte
+    assert_eq!(hex::encode(&keys[0].1), "12030003e8");
+}
+
+#[test]
+fn test_dcdt_storage_keys_nft() {
+    use dharitri_sc_scenario::scenario::model::DcdtObject;
+
+    let mut dcdt = Dcdt::Full(DcdtObject::default());
+    let dcdt_obj = dcdt.get_mut_dcdt_object();
+    dcdt_obj.set_balance(300u64, 1u64);
+    dcdt_obj.set_token_attributes(300u64, "str:ab");
+    dcdt_obj.set_roles(vec!["DCDTRoleNFTCreate".to_string()]);
+    dcdt_obj.last_nonce = Some(300u64.into());
+    dcdt_obj.frozen = Some(1u64.into());
+
+    let keys = dcdt_storage_keys(b"NFT-123456", &dcdt);
+    assert_eq!(keys.len(), 3);
+    assert_eq!(
+        keys[0].0,
+        [b"NUMBATdcdtNFT-123456".as_slice(), &[1, 44]].concat()
+    );
+    // type 1, balance 1, frozen, metadata with nonce 300 and attributes
+    assert_eq!(
+        hex::encode(&keys[0].1),
+        "0801120200011a020100220708ac023a026162"
+    );
+    assert_eq!(keys[1].0, b"NUMBATroledcdtNFT-123456".to_vec());
+    assert_eq!(keys[1].1, [&[10u8, 17][..], b"DCDTRoleNFTCreate"].concat());
+    assert_eq!(keys[2].0, b"NUMBATnonceNFT-123456".to_vec());
+    assert_eq!(keys[2].1, vec![1, 44]);
+}
@@ -0,0 +1,283 @@
+use std::path::Path;
+
+use super::interactor_scenario_dcdt::dcdt_storage_keys;
+use crate::{account_tool::retrieve_account_as_scenario_set_state, InteractorBase};
+use dharitri_sc_scenario::{
+    dharitri_sc::types::Address,
+    imports::Bech32Address,
+    scenario::{
+        model::{
+            AddressKey, CheckStateStep, ScCallStep, ScDeployStep, ScQueryStep, SetStateStep, Step,
+            TransferStep, TxExpect, TxResponse,
+        },
+        parse_scenario,
+        run_vm::{check_tx_response, ScenarioVMRunner},
+    },
+};
+use dharitri_sdk::{
+    gateway::{GatewayAsyncService, GatewayError, SetStateAccount},
+    utils::base64_encode,
+};
+
+impl<GatewayProxy> InteractorBase<GatewayProxy>
+where
+    GatewayProxy: GatewayAsyncService,
+{
+    /// Binds an account of the scenario files, e.g. `address:owner`, to a registered wallet.
+    pub fn bind_scenario_address<A>(&mut self, scenario_address: A, network_address: &Address)
+    where
+        A: Into<AddressKey>,
+    {
+        self.scenario_addresses
+            .bind(scenario_address.into().value, network_address.clone());
+    }
+
+    /// Runs a scenario file (`.scen.json`) against the network, checking the same expectations as the VM would.
+    ///
+    /// The path is relative to the current directory of the interactor.
+    ///
+    /// Transactions are signed by the wallets bound via [`Self::bind_scenario_address`],
+    /// and `checkState` steps are checked against the accounts retrieved from the network.
+    /// Accounts can only be set on the chain simulator, on a real network they are expected to already exist.
+    ///
+    /// DCDT balances are set as the account storage that holds them on the protocol side.
+    ///
+    /// Some things cannot be reproduced on a network: setting block info,
+    /// validator rewards, and the gas checks. Addresses are only translated where they stand alone,
+    /// as an argument, result, log topic or account, not inside storage or encoded structures.
+    pub async fn run_scenario_file<P: AsRef<Path>>(&mut self, relative_path: P) {
+        self.try_run_scenario_file(relative_path)
+            .await
+            .expect("scenario step failed (possible API failure)");
+    }
+
+    /// Same as [`Self::run_scenario_file`], but gateway failures are returned instead of causing a panic.
+    ///
+    /// Expectations that are not met still cause a panic, same as in the VM.
+    pub async fn try_run_scenario_file<P: AsRef<Path>>(
+        &mut self,
+        relative_path: P,
+    ) -> Result<(), GatewayError> {
+        let path = self.current_dir.join(relative_path);
+        self.run_scenario_path(&path).await
+    }
+
+    async fn run_scenario_path(&mut self, path: &Path) -> Result<(), GatewayError> {
+        let scenario = parse_scenario(path);
+        for step in scenario.steps {
+            match step {
+                Step::ExternalSteps(external_steps_step) => {
+                    let new_path = path.parent().unwrap().join(&external_steps_step.path);
+                    Box::pin(self.run_scenario_path(&new_path)).await?;
+                },
+                Step::SetState(set_state_step) => {
+                    self.run_scenario_set_state(&set_state_step).await?;
+                },
+                Step::ScCall(sc_call_step) => self.run_scenario_sc_call(sc_call_step).await?,
+                Step::ScQuery(sc_query_step) => self.run_scenario_sc_query(sc_query_step).await?,
+                Step::ScDeploy(sc_deploy_step) => {
+                    self.run_scenario_sc_deploy(sc_deploy_step).await?;
+                },
+                Step::Transfer(transfer_step) => self.run_scenario_transfer(transfer_step).await?,
+                Step::ValidatorReward(_) => {
+                    panic!("validatorReward steps cannot be run against a network")
+                },
+                Step::CheckState(check_state_step) => {
+                    self.run_scenario_check_state(&check_state_step).await?;
+                },
+                Step::DumpState(_) => {},
+            }
+        }
+        Ok(())
+    }
+
+    async fn run_scenario_set_state(
+        &mut self,
+        set_state_step: &SetStateStep,
+    ) -> Result<(), GatewayError> {
+        assert!(
+            set_state_step.current_block_info.is_none()
+                && set_state_step.previous_block_info.is_none()
+                && set_state_step.block_hashes.is_empty(),
+            "block info cannot be set on a network"
+        );
+        for new_address in &set_state_step.new_addresses {
+            self.scenario_addresses.push_new_address(
+                new_address.creator_address.to_address(),
+                new_address.new_address.to_address(),
+            );
+        }
+        if set_state_step.accounts.is_empty() {
+            return Ok(());
+        }
+        if !self.use_chain_simulator {
+            println!("setState accounts skipped, they can only be set on the chain simulator");
+            return Ok(());
+        }
+
+        let mut accounts = Vec::new();
+        for (address_key, account) in &set_state_step.accounts {
+            let address = self.scenario_addresses.to_network(&address_key.value);
+            accounts.push(SetStateAccount {
+                address: Bech32Address::from(&address).to_bech32_string(),
+                nonce: account
+                    .nonce
+                    .as_ref()
+                    .map(|nonce| nonce.value)
+                    .unwrap_or_default(),
+                balance: account
+                    .balance
+                    .as_ref()
+                    .map(|balance| balance.value.to_string())
+                    .unwrap_or_default(),
+                keys: account
+                    .storage
+                    .iter()
+                    .map(|(key, value)| (key.value.clone(), value.value.clone()))
+                    .chain(
+                        account
+                            .dcdt
+                            .iter()
+                            .flat_map(|(token_id, dcdt)| dcdt_storage_keys(&token_id.value, dcdt)),
+                    )
+                    .map(|(key, value)| (hex::encode(key), hex::encode(value)))
+                    .collect(),
+                code: account
+                    .code
+                    .as_ref()
+                    .map(|code| hex::encode(&code.value))
+                    .unwrap_or_default(),
+                code_metadata: account
+                    .code_metadata
+                    .as_ref()
+                    .map(|code_metadata| base64_encode(&code_metadata.value))
+                    .unwrap_or_default(),
+                owner_address: account
+                    .owner
+                    .as_ref()
+                    .map(|owner| {
+                        let owner = self.scenario_addresses.to_network(&owner.value);
+                        Bech32Address::from(&owner).to_bech32_string()
+                    })
+                    .unwrap_or_default(),
+                developer_reward: account
+                    .developer_rewards
+                    .as_ref()
+                    .map(|rewards| rewards.value.to_string())
+                    .unwrap_or_default(),
+                ..Default::default()
+            });
+
+            // the nonce was overwritten, so it needs to be retrieved again
+            if let Some(sender) = self.sender_map.get_mut(&address) {
+                sender.current_nonce = None;
+            }
+        }
+        self.set_state(accounts).await?;
+        Ok(())
+    }
+
+    async fn run_scenario_sc_call(&mut self, mut step: ScCallStep) -> Result<(), GatewayError> {
+        let map = &self.scenario_addresses;
+        step.tx.from.value = map.to_network(&step.tx.from.value);
+        step.tx.to.value = map.to_network(&step.tx.to.value);
+        for argument in &mut step.tx.arguments {
+            map.bytes_to_network(&mut argument.value);
+        }
+
+        self.try_sc_call(&mut step).await?;
+        self.check_scenario_response(&step.id, step.expect.as_ref(), step.response.as_ref());
+        Ok(())
+    }
+
+    async fn run_scenario_sc_query(&mut self, mut step: ScQueryStep) -> Result<(), GatewayError> {
+        let map = &self.scenario_addresses;
+        step.tx.to.value = map.to_network(&step.tx.to.value);
+        for argument in &mut step.tx.arguments {
+            map.bytes_to_network(&mut argument.value);
+        }
+
+        self.try_perform_sc_query(&mut step).await?;
+        self.check_scenario_response(&step.id, step.expect.as_ref(), step.response.as_ref());
+        Ok(())
+    }
+
+    async fn run_scenario_sc_deploy(&mut self, mut step: ScDeployStep) -> Result<(), GatewayError> {
+        let creator = step.tx.from.value.clone();
+        let map = &self.scenario_addresses;
+        step.tx.from.value = map.to_network(&creator);
+        for argument in &mut step.tx.arguments {
+            map.bytes_to_network(&mut argument.value);
+        }
+
+        self.try_sc_deploy(&mut step).await?;
+        let new_deployed_address = step
+            .response
+            .as_ref()
+            .and_then(|response| response.new_deployed_address.clone());
+        if let (Some(network_address), Some(scenario_address)) = (
+            new_deployed_address,
+            self.scenario_addresses.pop_new_address(&creator),
+        ) {
+            self.scenario_addresses
+                .bind(scenario_address, network_address);
+        }
+        self.check_scenario_response(&step.id, step.expect.as_ref(), step.response.as_ref());
+        Ok(())
+    }
+
+    async fn run_scenario_transfer(&mut self, mut step: TransferStep) -> Result<(), GatewayError> {
+        let map = &self.scenario_addresses;
+        step.tx.from.value = map.to_network(&step.tx.from.value);
+        step.tx.to.value = map.to_network(&step.tx.to.value);
+
+        self.try_transfer(step).await?;
+        Ok(())
+    }
+
+    /// The results and logs are translated back to scenario addresses before checking.
+    fn check_scenario_response(
+        &self,
+        step_id: &str,
+        expect: Option<&TxExpect>,
+        response: Option<&TxResponse>,
+    ) {
+        let (Some(expect), Some(response)) = (expect, response) else {
+            return;
+        };
+        let map = &self.scenario_addresses;
+        let mut response = response.clone();
+        for result in &mut response.out {
+            map.bytes_from_network(result);
+        }
+        for log in &mut response.logs {
+            log.address = map.from_network(&log.address);
+            for topic in &mut log.topics {
+                map.bytes_from_network(topic);
+            }
+        }
+        check_tx_response(step_id, expect, &response);
+    }
+
+    /// The accounts are retrieved from the network and loaded into a VM state, to be checked the same way.
+    async fn run_scenario_check_state(
+        &mut self,
+        check_state_step: &CheckStateStep,
+    ) -> Result<(), GatewayError> {
+        let mut set_state_step = SetStateStep::new();
+        for address_key in check_state_step.accounts.accounts.keys() {
+            let address = self.scenario_addresses.to_network(&address_key.value);
+            let (_, account_set_state) =
+                retrieve_account_as_scenario_set_state(&self.proxy, &Bech32Address::from(&address))
+                    .await;
+            if let Some(account) = account_set_state.accounts.into_values().next() {
+                set_state_step.accounts.insert(address_key.clone(), account);
+            }
+        }
+
+        let mut vm_runner = ScenarioVMRunner::new();
+        vm_runner.perform_set_state(&set_state_step);
+        vm_runner.perform_check_state(check_state_step);
+        Ok(())
+    }
+}
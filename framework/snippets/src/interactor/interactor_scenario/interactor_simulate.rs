@@ -0,0 +1,106 @@
+use crate::{network_response, InteractorBase};
+use dharitri_sc_scenario::{
+    imports::Address,
+    scenario_model::{ScCallStep, ScDeployStep, TxResponse},
+};
+use dharitri_sdk::{
+    data::transaction::Transaction,
+    gateway::{GatewayAsyncService, GatewayError, GetTxCost, SimulateTxRequest},
+    simulated_tx_on_network,
+};
+
+impl<GatewayProxy> InteractorBase<GatewayProxy>
+where
+    GatewayProxy: GatewayAsyncService,
+{
+    /// Same as [`Self::sc_call`], but the transaction only gets simulated: nothing changes on the chain.
+    pub async fn sc_call_simulate<S>(&mut self, sc_call_step: S)
+    where
+        S: AsMut<ScCallStep>,
+    {
+        self.try_sc_call_simulate(sc_call_step)
+            .await
+            .expect("sc call simulation failed (possible API failure)");
+    }
+
+    /// Same as [`Self::sc_call_simulate`], but gateway failures are returned instead of causing a panic.
+    pub async fn try_sc_call_simulate<S>(&mut self, mut sc_call_step: S) -> Result<(), GatewayError>
+    where
+        S: AsMut<ScCallStep>,
+    {
+        let sc_call_step = sc_call_step.as_mut();
+        let transaction = self.tx_call_to_blockchain_tx(&sc_call_step.tx);
+        let gas_estimate = self
+            .estimate_step_gas(&transaction, &mut sc_call_step.tx.gas_limit)
+            .await;
+
+        let sender_address = sc_call_step.tx.from.to_address();
+        let transaction = self.tx_call_to_blockchain_tx(&sc_call_step.tx);
+        let mut response = self.simulate_tx(&sender_address, transaction).await?;
+        response.gas_estimate = gas_estimate;
+        sc_call_step.save_response(response);
+        Ok(())
+    }
+
+    /// Same as [`Self::sc_deploy`], but the transaction only gets simulated: nothing changes on the chain.
+    ///
+    /// The response still contains the address the contract would be deployed at.
+    pub async fn sc_deploy_simulate<S>(&mut self, sc_deploy_step: S)
+    where
+        S: AsMut<ScDeployStep>,
+    {
+        self.try_sc_deploy_simulate(sc_deploy_step)
+            .await
+            .expect("sc deploy simulation failed (possible API failure)");
+    }
+
+    /// Same as [`Self::sc_deploy_simulate`], but gateway failures are returned instead of causing a panic.
+    pub async fn try_sc_deploy_simulate<S>(
+        &mut self,
+        mut sc_deploy_step: S,
+    ) -> Result<(), GatewayError>
+    where
+        S: AsMut<ScDeployStep>,
+    {
+        let sc_deploy_step = sc_deploy_step.as_mut();
+        let transaction = self.sc_deploy_to_blockchain_tx(sc_deploy_step);
+        let gas_estimate = self
+            .estimate_step_gas(&transaction, &mut sc_deploy_step.tx.gas_limit)
+            .await;
+
+        let sender_address = sc_deploy_step.tx.from.to_address();
+        let transaction = self.sc_deploy_to_blockchain_tx(sc_deploy_step);
+        let mut response = self.simulate_tx(&sender_address, transaction).await?;
+        response.gas_estimate = gas_estimate;
+        sc_deploy_step.save_response(response);
+        Ok(())
+    }
+
+    /// Signs the transaction with the next nonce of the sender, without using the nonce up, and simulates it.
+    ///
+    /// The gateway does not report the gas used in simulations, so it gets retrieved from the transaction cost.
+    pub async fn simulate_tx(
+        &mut self,
+        sender_address: &Address,
+        unsigned_tx: Transaction,
+    ) -> Result<TxResponse, GatewayError> {
+        self.resync_nonce_if_due(sender_address).await?;
+        let mut transaction = unsigned_tx;
+        transaction.nonce = self
+            .get_sender_mut(sender_address)
+            .current_nonce
+            .expect("sender nonce not yet retrieved");
+        self.sign_tx(sender_address, &mut transaction);
+
+        let simulation = self.proxy.request(SimulateTxRequest(&transaction)).await?;
+        println!("simulation status: {}", simulation.status);
+        let (tx, return_code) = simulated_tx_on_network(&transaction, simulation);
+
+        let mut response = network_response::parse_tx_response(tx, return_code);
+        if response.tx_error.is_success() {
+            let tx_cost = self.proxy.request(GetTxCost(&transaction)).await?;
+            response.gas_used = tx_cost.tx_gas_units;
+        }
+        Ok(response)
+    }
+}
@@ -2,8 +2,8 @@ use crate::InteractorBase;
 use log::info;
 use dharitri_sc_scenario::{scenario::ScenarioRunner, scenario_model::TransferStep};
 use dharitri_sdk::{
-    gateway::{GatewayAsyncService, SendTxRequest},
-    retrieve_tx_on_network,
+    gateway::{GatewayAsyncService, GatewayError},
+    try_retrieve_tx_on_network,
 };
 
 impl<GatewayProxy> InteractorBase<GatewayProxy>
@@ -11,28 +11,34 @@ where
     GatewayProxy: GatewayAsyncService,
 {
     pub async fn transfer(&mut self, transfer_step: TransferStep) -> String {
+        self.try_transfer(transfer_step)
+            .await
+            .expect("transfer failed (possible API failure)")
+    }
+
+    /// Same as [`Self::transfer`], but gateway failures are returned instead of causing a panic.
+    pub async fn try_transfer(
+        &mut self,
+        mut transfer_step: TransferStep,
+    ) -> Result<String, GatewayError> {
+        let transaction = self.tx_call_to_blockchain_tx(&transfer_step.tx.to_tx_call());
+        self.estimate_step_gas(&transaction, &mut transfer_step.tx.gas_limit)
+            .await;
+
         self.pre_runners.run_transfer_step(&transfer_step);
 
         let sender_address = &transfer_step.tx.from.value;
-        let mut transaction = self.tx_call_to_blockchain_tx(&transfer_step.tx.to_tx_call());
-        self.set_nonce_and_sign_tx(sender_address, &mut transaction)
-            .await;
-        let tx_hash = self
-            .proxy
-            .request(SendTxRequest(&transaction))
-            .await
-            .unwrap();
-        self.generate_blocks_until_tx_processed(&tx_hash)
-            .await
-            .unwrap();
+        let transaction = self.tx_call_to_blockchain_tx(&transfer_step.tx.to_tx_call());
+        let tx_hash = self.sign_and_send_tx(sender_address, transaction).await?;
+        self.generate_blocks_until_tx_processed(&tx_hash).await?;
 
         println!("transfer tx hash: {tx_hash}");
         info!("transfer tx hash: {}", tx_hash);
 
-        retrieve_tx_on_network(&self.proxy, tx_hash.clone()).await;
+        try_retrieve_tx_on_network(&self.proxy, tx_hash.clone()).await?;
 
         self.post_runners.run_transfer_step(&transfer_step);
 
-        tx_hash
+        Ok(tx_hash)
     }
 }
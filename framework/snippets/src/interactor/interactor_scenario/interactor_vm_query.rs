@@ -8,7 +8,7 @@ use dharitri_sc_scenario::{
     dharitri_sc::{abi::TypeAbiFrom, codec::TopDecodeMulti, types::ContractCall},
     scenario_model::{ScQueryStep, TxResponse},
 };
-use dharitri_sdk::gateway::{GatewayAsyncService, VMQueryRequest};
+use dharitri_sdk::gateway::{GatewayAsyncService, GatewayError, VMQueryRequest};
 use dharitri_sdk::{data::vm::VMQueryInput, utils::base64_decode};
 
 impl<GatewayProxy> InteractorBase<GatewayProxy>
@@ -23,7 +23,24 @@ where
         self
     }
 
+    /// Same as [`Self::sc_query`], but gateway failures are returned instead of causing a panic.
+    pub async fn try_sc_query<S>(&mut self, mut step: S) -> Result<(), GatewayError>
+    where
+        S: AsMut<ScQueryStep>,
+    {
+        self.try_perform_sc_query(step.as_mut()).await
+    }
+
     pub async fn perform_sc_query(&mut self, step: &mut ScQueryStep) {
+        self.try_perform_sc_query(step)
+            .await
+            .expect("error executing VM query");
+    }
+
+    pub async fn try_perform_sc_query(
+        &mut self,
+        step: &mut ScQueryStep,
+    ) -> Result<(), GatewayError> {
         let sc_address = step.tx.to.to_address();
         let req = VMQueryInput {
             sc_address: sc_address.clone().into(),
@@ -35,11 +52,7 @@ where
                 .map(|arg| hex::encode(&arg.value))
                 .collect(),
         };
-        let result = self
-            .proxy
-            .request(VMQueryRequest(&req))
-            .await
-            .expect("error executing VM query");
+        let result = self.proxy.request(VMQueryRequest(&req)).await?;
 
         info!("{:#?}", result);
 
@@ -49,6 +62,7 @@ where
 
         self.pre_runners.run_sc_query_step(step);
         self.post_runners.run_sc_query_step(step);
+        Ok(())
     }
 
     #[deprecated(since = "0.42.0", note = "Was renamed to `quick_query`.")]
@@ -0,0 +1,69 @@
+use std::collections::{HashMap, VecDeque};
+
+use dharitri_sc_scenario::imports::Address;
+
+/// Translates the addresses used in scenario files to the ones of the accounts on the network.
+///
+/// Scenario accounts have made-up addresses, like `address:owner`, so they need to be bound to registered wallets.
+/// Contracts get bound as they are deployed, to the addresses declared in the `newAddresses` of the `setState` steps.
+/// Addresses that are not bound are used as they are.
+#[derive(Clone, Debug, Default)]
+pub struct ScenarioAddressMap {
+    to_network: HashMap<Address, Address>,
+    from_network: HashMap<Address, Address>,
+    pending_new_addresses: HashMap<Address, VecDeque<Address>>,
+}
+
+impl ScenarioAddressMap {
+    pub fn bind(&mut self, scenario_address: Address, network_address: Address) {
+        self.from_network
+            .insert(network_address.clone(), scenario_address.clone());
+        self.to_network.insert(scenario_address, network_address);
+    }
+
+    pub fn to_network(&self, scenario_address: &Address) -> Address {
+        self.to_network
+            .get(scenario_address)
+            .unwrap_or(scenario_address)
+            .clone()
+    }
+
+    pub fn from_network(&self, network_address: &Address) -> Address {
+        self.from_network
+            .get(network_address)
+            .unwrap_or(network_address)
+            .clone()
+    }
+
+    /// Replaces arguments and results that consist of a bound address.
+    pub fn bytes_to_network(&self, bytes: &mut Vec<u8>) {
+        translate_bytes(&self.to_network, bytes);
+    }
+
+    pub fn bytes_from_network(&self, bytes: &mut Vec<u8>) {
+        translate_bytes(&self.from_network, bytes);
+    }
+
+    /// The contracts deployed by the creator get bound in order to these addresses.
+    pub(crate) fn push_new_address(&mut self, creator: Address, new_address: Address) {
+        self.pending_new_addresses
+            .entry(creator)
+            .or_default()
+            .push_back(new_address);
+    }
+
+    pub(crate) fn pop_new_address(&mut self, creator: &Address) -> Option<Address> {
+        self.pending_new_addresses
+            .get_mut(creator)
+            .and_then(VecDeque::pop_front)
+    }
+}
+
+fn translate_bytes(address_map: &HashMap<Address, Address>, bytes: &mut Vec<u8>) {
+    if bytes.len() != Address::len_bytes() {
+        return;
+    }
+    if let Some(translated) = address_map.get(&Address::from_slice(bytes)) {
+        *bytes = translated.to_vec();
+    }
+}
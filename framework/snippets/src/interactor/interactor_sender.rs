@@ -4,7 +4,9 @@ use crate::sdk::{data::transaction::Transaction, wallet::Wallet};
 use log::debug;
 use dharitri_sc_scenario::dharitri_sc::types::Address;
 use dharitri_sdk::data::account::Account;
-use dharitri_sdk::gateway::{GatewayAsyncService, GetAccountRequest, GetAccountStorageRequest};
+use dharitri_sdk::gateway::{
+    GatewayAsyncService, GatewayError, GetAccountRequest, GetAccountStorageRequest,
+};
 
 use crate::InteractorBase;
 
@@ -12,7 +14,14 @@ use crate::InteractorBase;
 pub struct Sender {
     pub address: Address,
     pub wallet: Wallet,
+    /// The nonce of the next transaction, if known.
     pub current_nonce: Option<u64>,
+    /// Transactions sent since the nonce was last retrieved from the gateway.
+    pub num_txs_since_recall: u64,
+    /// If set, the transactions of this account are relayed (v3) by the given registered wallet.
+    pub relayer: Option<Address>,
+    /// If set, the account is guarded and its transactions are co-signed by the given registered wallet.
+    pub guardian: Option<Address>,
 }
 
 impl<GatewayProxy> InteractorBase<GatewayProxy>
@@ -20,57 +29,131 @@ where
     GatewayProxy: GatewayAsyncService,
 {
     pub async fn recall_nonce(&self, address: &Address) -> u64 {
-        let account = self
-            .proxy
-            .request(GetAccountRequest::new(address))
+        self.try_recall_nonce(address)
             .await
-            .expect("failed to retrieve account nonce");
-        account.nonce
+            .expect("failed to retrieve account nonce")
+    }
+
+    pub async fn try_recall_nonce(&self, address: &Address) -> Result<u64, GatewayError> {
+        let account = self.try_get_account(address).await?;
+        Ok(account.nonce)
     }
 
     pub async fn get_account(&self, address: &Address) -> Account {
-        self.proxy
-            .request(GetAccountRequest::new(address))
+        self.try_get_account(address)
             .await
             .expect("failed to retrieve account")
     }
 
+    pub async fn try_get_account(&self, address: &Address) -> Result<Account, GatewayError> {
+        self.proxy.request(GetAccountRequest::new(address)).await
+    }
+
     pub async fn get_account_storage(&self, address: &Address) -> HashMap<String, String> {
+        self.try_get_account_storage(address)
+            .await
+            .expect("failed to retrieve account")
+    }
+
+    pub async fn try_get_account_storage(
+        &self,
+        address: &Address,
+    ) -> Result<HashMap<String, String>, GatewayError> {
         self.proxy
             .request(GetAccountStorageRequest::new(address))
             .await
-            .expect("failed to retrieve account")
     }
 
     pub(crate) async fn set_nonce_and_sign_tx(
         &mut self,
         sender_address: &Address,
         transaction: &mut Transaction,
-    ) {
-        // read
+    ) -> Result<(), GatewayError> {
+        // recall, if needed
+        self.resync_nonce_if_due(sender_address).await?;
+
+        // set tx nonce
+        transaction.nonce = self.take_local_nonce(sender_address);
+        println!("-- tx nonce: {}", transaction.nonce);
+
+        // sign
+        self.sign_tx(sender_address, transaction);
+        Ok(())
+    }
+
+    /// All transactions sent from the account will be relayed by the relayer.
+    ///
+    /// Both wallets need to be registered. Relayed transactions cost extra gas, paid by the relayer,
+    /// which is added to the gas limit of each transaction.
+    pub fn set_sender_relayer(&mut self, sender_address: &Address, relayer_address: &Address) {
+        self.get_sender_mut(sender_address).relayer = Some(relayer_address.clone());
+    }
+
+    /// All transactions sent from the account will be co-signed by the guardian.
+    ///
+    /// Both wallets need to be registered. The guardian must already be set and active on the account.
+    /// Guarded transactions cost extra gas, which is added to the gas limit of each transaction.
+    pub fn set_sender_guardian(&mut self, sender_address: &Address, guardian_address: &Address) {
+        self.get_sender_mut(sender_address).guardian = Some(guardian_address.clone());
+    }
+
+    pub(crate) fn get_sender_mut(&mut self, sender_address: &Address) -> &mut Sender {
+        self.sender_map
+            .get_mut(sender_address)
+            .expect("sender not registered")
+    }
+
+    /// Sets the relayer and the guardian of the sender, if it has any, along with the extra gas they cost.
+    pub(crate) fn set_co_signers(&self, sender_address: &Address, transaction: &mut Transaction) {
         let sender = self
             .sender_map
             .get(sender_address)
-            .expect("the wallet that was supposed to sign is not registered");
+            .expect("sender not registered");
 
-        // recall
-        let nonce = self.recall_nonce(&sender.address).await;
-        println!("sender's recalled nonce: {nonce}");
+        if let Some(guardian) = &sender.guardian {
+            transaction.set_guardian(guardian.clone());
+            transaction.gas_limit += self.network_config.min_gas_limit;
+        }
+        if let Some(relayer) = &sender.relayer {
+            transaction.set_relayer(relayer.clone());
+            transaction.gas_limit += self.network_config.min_gas_limit;
+        }
+    }
 
-        // set tx nonce
-        transaction.nonce = nonce;
-        println!("-- tx nonce: {}", transaction.nonce);
+    /// Signs the transaction on behalf of the sender, as well as of its relayer and guardian, if it has any.
+    pub(crate) fn sign_tx(&self, sender_address: &Address, transaction: &mut Transaction) {
+        // the co-signers are part of the signed content, so they need to be set first
+        self.set_co_signers(sender_address, transaction);
 
-        // update
         let sender = self
             .sender_map
-            .get_mut(sender_address)
+            .get(sender_address)
             .expect("the wallet that was supposed to sign is not registered");
-        sender.current_nonce = Some(nonce + 1);
-
-        // sign
         let signature = sender.wallet.sign_tx(transaction);
         transaction.signature = Some(hex::encode(signature));
+
+        if let Some(guardian) = &sender.guardian {
+            let signature = self
+                .co_signer_wallet(guardian)
+                .sign_tx_as_guardian(transaction)
+                .expect("failed to co-sign the transaction");
+            transaction.guardian_signature = Some(hex::encode(signature));
+        }
+        if let Some(relayer) = &sender.relayer {
+            let signature = self
+                .co_signer_wallet(relayer)
+                .sign_tx_as_relayer(transaction)
+                .expect("failed to co-sign the transaction");
+            transaction.relayer_signature = Some(hex::encode(signature));
+        }
         debug!("transaction {:#?}", transaction);
     }
+
+    fn co_signer_wallet(&self, address: &Address) -> &Wallet {
+        &self
+            .sender_map
+            .get(address)
+            .expect("the wallet that was supposed to co-sign is not registered")
+            .wallet
+    }
 }
@@ -11,11 +11,17 @@ use dharitri_sc_scenario::{
     scenario_model::{ScCallStep, TxResponse},
     ScenarioTxEnvData,
 };
-use dharitri_sdk::gateway::GatewayAsyncService;
+use dharitri_sdk::{
+    data::transaction::Transaction,
+    gateway::{GatewayAsyncService, GatewayError},
+};
 
 use crate::InteractorBase;
 
-use super::{InteractorEnvExec, InteractorExecStep, InteractorPrepareAsync, InteractorRunAsync};
+use super::{
+    InteractorEnvExec, InteractorExecStep, InteractorOfflineAsync, InteractorPrepareAsync,
+    InteractorRunAsync, InteractorSimulateAsync,
+};
 
 async fn run_async_call<'w, GatewayProxy, From, To, Payment, Gas, RH>(
     tx: Tx<
@@ -42,6 +48,181 @@ where
     step_wrapper.process_result()
 }
 
+async fn try_run_async_call<'w, GatewayProxy, From, To, Payment, Gas, RH>(
+    tx: Tx<
+        InteractorEnvExec<'w, GatewayProxy>,
+        From,
+        To,
+        Payment,
+        Gas,
+        FunctionCall<StaticApi>,
+        RH,
+    >,
+) -> Result<<RH::ListReturns as NestedTupleFlatten>::Unpacked, GatewayError>
+where
+    GatewayProxy: GatewayAsyncService,
+    From: TxFromSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    To: TxToSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    Payment: TxPayment<InteractorEnvExec<'w, GatewayProxy>>,
+    Gas: TxGas<InteractorEnvExec<'w, GatewayProxy>>,
+    RH: RHListExec<TxResponse, InteractorEnvExec<'w, GatewayProxy>>,
+    RH::ListReturns: NestedTupleFlatten,
+{
+    let mut step_wrapper = tx.tx_to_step();
+    step_wrapper
+        .env
+        .world
+        .try_sc_call(&mut step_wrapper.step)
+        .await?;
+    Ok(step_wrapper.process_result())
+}
+
+async fn simulate_async_call<'w, GatewayProxy, From, To, Payment, Gas, RH>(
+    tx: Tx<
+        InteractorEnvExec<'w, GatewayProxy>,
+        From,
+        To,
+        Payment,
+        Gas,
+        FunctionCall<StaticApi>,
+        RH,
+    >,
+) -> <RH::ListReturns as NestedTupleFlatten>::Unpacked
+where
+    GatewayProxy: GatewayAsyncService,
+    From: TxFromSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    To: TxToSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    Payment: TxPayment<InteractorEnvExec<'w, GatewayProxy>>,
+    Gas: TxGas<InteractorEnvExec<'w, GatewayProxy>>,
+    RH: RHListExec<TxResponse, InteractorEnvExec<'w, GatewayProxy>>,
+    RH::ListReturns: NestedTupleFlatten,
+{
+    let mut step_wrapper = tx.tx_to_step();
+    step_wrapper
+        .env
+        .world
+        .sc_call_simulate(&mut step_wrapper.step)
+        .await;
+    step_wrapper.process_result()
+}
+
+async fn try_simulate_async_call<'w, GatewayProxy, From, To, Payment, Gas, RH>(
+    tx: Tx<
+        InteractorEnvExec<'w, GatewayProxy>,
+        From,
+        To,
+        Payment,
+        Gas,
+        FunctionCall<StaticApi>,
+        RH,
+    >,
+) -> Result<<RH::ListReturns as NestedTupleFlatten>::Unpacked, GatewayError>
+where
+    GatewayProxy: GatewayAsyncService,
+    From: TxFromSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    To: TxToSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    Payment: TxPayment<InteractorEnvExec<'w, GatewayProxy>>,
+    Gas: TxGas<InteractorEnvExec<'w, GatewayProxy>>,
+    RH: RHListExec<TxResponse, InteractorEnvExec<'w, GatewayProxy>>,
+    RH::ListReturns: NestedTupleFlatten,
+{
+    let mut step_wrapper = tx.tx_to_step();
+    step_wrapper
+        .env
+        .world
+        .try_sc_call_simulate(&mut step_wrapper.step)
+        .await?;
+    Ok(step_wrapper.process_result())
+}
+
+async fn export_unsigned_async_call<'w, GatewayProxy, From, To, Payment, Gas, RH>(
+    tx: Tx<
+        InteractorEnvExec<'w, GatewayProxy>,
+        From,
+        To,
+        Payment,
+        Gas,
+        FunctionCall<StaticApi>,
+        RH,
+    >,
+) -> Result<Transaction, GatewayError>
+where
+    GatewayProxy: GatewayAsyncService,
+    From: TxFromSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    To: TxToSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    Payment: TxPayment<InteractorEnvExec<'w, GatewayProxy>>,
+    Gas: TxGas<InteractorEnvExec<'w, GatewayProxy>>,
+    RH: RHListExec<TxResponse, InteractorEnvExec<'w, GatewayProxy>>,
+    RH::ListReturns: NestedTupleFlatten,
+{
+    let mut step_wrapper = tx.tx_to_step();
+    step_wrapper
+        .env
+        .world
+        .export_unsigned_sc_call(&mut step_wrapper.step)
+        .await
+}
+
+async fn broadcast_signed_async_call<'w, GatewayProxy, From, To, Payment, Gas, RH>(
+    tx: Tx<
+        InteractorEnvExec<'w, GatewayProxy>,
+        From,
+        To,
+        Payment,
+        Gas,
+        FunctionCall<StaticApi>,
+        RH,
+    >,
+    signed_tx: Transaction,
+) -> Result<<RH::ListReturns as NestedTupleFlatten>::Unpacked, GatewayError>
+where
+    GatewayProxy: GatewayAsyncService,
+    From: TxFromSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    To: TxToSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    Payment: TxPayment<InteractorEnvExec<'w, GatewayProxy>>,
+    Gas: TxGas<InteractorEnvExec<'w, GatewayProxy>>,
+    RH: RHListExec<TxResponse, InteractorEnvExec<'w, GatewayProxy>>,
+    RH::ListReturns: NestedTupleFlatten,
+{
+    let mut step_wrapper = tx.tx_to_step();
+    step_wrapper
+        .env
+        .world
+        .broadcast_signed_sc_call(&mut step_wrapper.step, &signed_tx)
+        .await?;
+    Ok(step_wrapper.process_result())
+}
+
+async fn track_async_call<'w, GatewayProxy, From, To, Payment, Gas, RH>(
+    tx: Tx<
+        InteractorEnvExec<'w, GatewayProxy>,
+        From,
+        To,
+        Payment,
+        Gas,
+        FunctionCall<StaticApi>,
+        RH,
+    >,
+    tx_hash: String,
+) -> Result<<RH::ListReturns as NestedTupleFlatten>::Unpacked, GatewayError>
+where
+    GatewayProxy: GatewayAsyncService,
+    From: TxFromSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    To: TxToSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    Payment: TxPayment<InteractorEnvExec<'w, GatewayProxy>>,
+    Gas: TxGas<InteractorEnvExec<'w, GatewayProxy>>,
+    RH: RHListExec<TxResponse, InteractorEnvExec<'w, GatewayProxy>>,
+    RH::ListReturns: NestedTupleFlatten,
+{
+    let mut step_wrapper = tx.tx_to_step();
+    step_wrapper
+        .env
+        .world
+        .track_sc_call(&mut step_wrapper.step, &tx_hash)
+        .await?;
+    Ok(step_wrapper.process_result())
+}
+
 impl<'w, GatewayProxy, From, To, Payment, Gas, RH> InteractorRunAsync
     for Tx<InteractorEnvExec<'w, GatewayProxy>, From, To, Payment, Gas, FunctionCall<StaticApi>, RH>
 where
@@ -58,6 +239,66 @@ where
     fn run(self) -> impl std::future::Future<Output = Self::Result> {
         run_async_call(self)
     }
+
+    fn try_run(self) -> impl std::future::Future<Output = Result<Self::Result, GatewayError>> {
+        try_run_async_call(self)
+    }
+}
+
+impl<'w, GatewayProxy, From, To, Payment, Gas, RH> InteractorSimulateAsync
+    for Tx<InteractorEnvExec<'w, GatewayProxy>, From, To, Payment, Gas, FunctionCall<StaticApi>, RH>
+where
+    GatewayProxy: GatewayAsyncService,
+    From: TxFromSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    To: TxToSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    Payment: TxPayment<InteractorEnvExec<'w, GatewayProxy>>,
+    Gas: TxGas<InteractorEnvExec<'w, GatewayProxy>>,
+    RH: RHListExec<TxResponse, InteractorEnvExec<'w, GatewayProxy>>,
+    RH::ListReturns: NestedTupleFlatten,
+{
+    type Result = <RH::ListReturns as NestedTupleFlatten>::Unpacked;
+
+    fn simulate(self) -> impl std::future::Future<Output = Self::Result> {
+        simulate_async_call(self)
+    }
+
+    fn try_simulate(self) -> impl std::future::Future<Output = Result<Self::Result, GatewayError>> {
+        try_simulate_async_call(self)
+    }
+}
+
+impl<'w, GatewayProxy, From, To, Payment, Gas, RH> InteractorOfflineAsync
+    for Tx<InteractorEnvExec<'w, GatewayProxy>, From, To, Payment, Gas, FunctionCall<StaticApi>, RH>
+where
+    GatewayProxy: GatewayAsyncService,
+    From: TxFromSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    To: TxToSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    Payment: TxPayment<InteractorEnvExec<'w, GatewayProxy>>,
+    Gas: TxGas<InteractorEnvExec<'w, GatewayProxy>>,
+    RH: RHListExec<TxResponse, InteractorEnvExec<'w, GatewayProxy>>,
+    RH::ListReturns: NestedTupleFlatten,
+{
+    type Result = <RH::ListReturns as NestedTupleFlatten>::Unpacked;
+
+    fn export_unsigned(
+        self,
+    ) -> impl std::future::Future<Output = Result<Transaction, GatewayError>> {
+        export_unsigned_async_call(self)
+    }
+
+    fn broadcast_signed(
+        self,
+        signed_tx: Transaction,
+    ) -> impl std::future::Future<Output = Result<Self::Result, GatewayError>> {
+        broadcast_signed_async_call(self, signed_tx)
+    }
+
+    fn track(
+        self,
+        tx_hash: String,
+    ) -> impl std::future::Future<Output = Result<Self::Result, GatewayError>> {
+        track_async_call(self, tx_hash)
+    }
 }
 
 impl<'w, GatewayProxy, From, To, Payment, Gas, RH> InteractorPrepareAsync
@@ -10,13 +10,16 @@ use dharitri_sc_scenario::{
     scenario_model::{ScDeployStep, TxResponse},
     ScenarioTxEnvData,
 };
-use dharitri_sdk::gateway::GatewayAsyncService;
+use dharitri_sdk::{
+    data::transaction::Transaction,
+    gateway::{GatewayAsyncService, GatewayError},
+};
 
 use crate::InteractorBase;
 
 use super::{
     interactor_prepare_async::InteractorRunAsync, InteractorEnvExec, InteractorExecStep,
-    InteractorPrepareAsync,
+    InteractorOfflineAsync, InteractorPrepareAsync, InteractorSimulateAsync,
 };
 
 #[allow(clippy::type_complexity)]
@@ -49,6 +52,187 @@ where
     step_wrapper.process_result()
 }
 
+#[allow(clippy::type_complexity)]
+async fn try_run_async_deploy<'w, GatewayProxy, From, Payment, Gas, CodeValue, RH>(
+    tx: Tx<
+        InteractorEnvExec<'w, GatewayProxy>,
+        From,
+        (),
+        Payment,
+        Gas,
+        DeployCall<InteractorEnvExec<'w, GatewayProxy>, Code<CodeValue>>,
+        RH,
+    >,
+) -> Result<<RH::ListReturns as NestedTupleFlatten>::Unpacked, GatewayError>
+where
+    GatewayProxy: GatewayAsyncService,
+    From: TxFromSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    Payment: TxPayment<InteractorEnvExec<'w, GatewayProxy>>,
+    Gas: TxGas<InteractorEnvExec<'w, GatewayProxy>>,
+    CodeValue: TxCodeValue<InteractorEnvExec<'w, GatewayProxy>>,
+    RH: RHListExec<TxResponse, InteractorEnvExec<'w, GatewayProxy>>,
+    RH::ListReturns: NestedTupleFlatten,
+{
+    let mut step_wrapper = tx.tx_to_step();
+    step_wrapper
+        .env
+        .world
+        .try_sc_deploy(&mut step_wrapper.step)
+        .await?;
+    Ok(step_wrapper.process_result())
+}
+
+#[allow(clippy::type_complexity)]
+async fn simulate_async_deploy<'w, GatewayProxy, From, Payment, Gas, CodeValue, RH>(
+    tx: Tx<
+        InteractorEnvExec<'w, GatewayProxy>,
+        From,
+        (),
+        Payment,
+        Gas,
+        DeployCall<InteractorEnvExec<'w, GatewayProxy>, Code<CodeValue>>,
+        RH,
+    >,
+) -> <RH::ListReturns as NestedTupleFlatten>::Unpacked
+where
+    GatewayProxy: GatewayAsyncService,
+    From: TxFromSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    Payment: TxPayment<InteractorEnvExec<'w, GatewayProxy>>,
+    Gas: TxGas<InteractorEnvExec<'w, GatewayProxy>>,
+    CodeValue: TxCodeValue<InteractorEnvExec<'w, GatewayProxy>>,
+    RH: RHListExec<TxResponse, InteractorEnvExec<'w, GatewayProxy>>,
+    RH::ListReturns: NestedTupleFlatten,
+{
+    let mut step_wrapper = tx.tx_to_step();
+    step_wrapper
+        .env
+        .world
+        .sc_deploy_simulate(&mut step_wrapper.step)
+        .await;
+    step_wrapper.process_result()
+}
+
+#[allow(clippy::type_complexity)]
+async fn try_simulate_async_deploy<'w, GatewayProxy, From, Payment, Gas, CodeValue, RH>(
+    tx: Tx<
+        InteractorEnvExec<'w, GatewayProxy>,
+        From,
+        (),
+        Payment,
+        Gas,
+        DeployCall<InteractorEnvExec<'w, GatewayProxy>, Code<CodeValue>>,
+        RH,
+    >,
+) -> Result<<RH::ListReturns as NestedTupleFlatten>::Unpacked, GatewayError>
+where
+    GatewayProxy: GatewayAsyncService,
+    From: TxFromSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    Payment: TxPayment<InteractorEnvExec<'w, GatewayProxy>>,
+    Gas: TxGas<InteractorEnvExec<'w, GatewayProxy>>,
+    CodeValue: TxCodeValue<InteractorEnvExec<'w, GatewayProxy>>,
+    RH: RHListExec<TxResponse, InteractorEnvExec<'w, GatewayProxy>>,
+    RH::ListReturns: NestedTupleFlatten,
+{
+    let mut step_wrapper = tx.tx_to_step();
+    step_wrapper
+        .env
+        .world
+        .try_sc_deploy_simulate(&mut step_wrapper.step)
+        .await?;
+    Ok(step_wrapper.process_result())
+}
+
+#[allow(clippy::type_complexity)]
+async fn export_unsigned_async_deploy<'w, GatewayProxy, From, Payment, Gas, CodeValue, RH>(
+    tx: Tx<
+        InteractorEnvExec<'w, GatewayProxy>,
+        From,
+        (),
+        Payment,
+        Gas,
+        DeployCall<InteractorEnvExec<'w, GatewayProxy>, Code<CodeValue>>,
+        RH,
+    >,
+) -> Result<Transaction, GatewayError>
+where
+    GatewayProxy: GatewayAsyncService,
+    From: TxFromSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    Payment: TxPayment<InteractorEnvExec<'w, GatewayProxy>>,
+    Gas: TxGas<InteractorEnvExec<'w, GatewayProxy>>,
+    CodeValue: TxCodeValue<InteractorEnvExec<'w, GatewayProxy>>,
+    RH: RHListExec<TxResponse, InteractorEnvExec<'w, GatewayProxy>>,
+    RH::ListReturns: NestedTupleFlatten,
+{
+    let mut step_wrapper = tx.tx_to_step();
+    step_wrapper
+        .env
+        .world
+        .export_unsigned_sc_deploy(&mut step_wrapper.step)
+        .await
+}
+
+#[allow(clippy::type_complexity)]
+async fn broadcast_signed_async_deploy<'w, GatewayProxy, From, Payment, Gas, CodeValue, RH>(
+    tx: Tx<
+        InteractorEnvExec<'w, GatewayProxy>,
+        From,
+        (),
+        Payment,
+        Gas,
+        DeployCall<InteractorEnvExec<'w, GatewayProxy>, Code<CodeValue>>,
+        RH,
+    >,
+    signed_tx: Transaction,
+) -> Result<<RH::ListReturns as NestedTupleFlatten>::Unpacked, GatewayError>
+where
+    GatewayProxy: GatewayAsyncService,
+    From: TxFromSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    Payment: TxPayment<InteractorEnvExec<'w, GatewayProxy>>,
+    Gas: TxGas<InteractorEnvExec<'w, GatewayProxy>>,
+    CodeValue: TxCodeValue<InteractorEnvExec<'w, GatewayProxy>>,
+    RH: RHListExec<TxResponse, InteractorEnvExec<'w, GatewayProxy>>,
+    RH::ListReturns: NestedTupleFlatten,
+{
+    let mut step_wrapper = tx.tx_to_step();
+    step_wrapper
+        .env
+        .world
+        .broadcast_signed_sc_deploy(&mut step_wrapper.step, &signed_tx)
+        .await?;
+    Ok(step_wrapper.process_result())
+}
+
+#[allow(clippy::type_complexity)]
+async fn track_async_deploy<'w, GatewayProxy, From, Payment, Gas, CodeValue, RH>(
+    tx: Tx<
+        InteractorEnvExec<'w, GatewayProxy>,
+        From,
+        (),
+        Payment,
+        Gas,
+        DeployCall<InteractorEnvExec<'w, GatewayProxy>, Code<CodeValue>>,
+        RH,
+    >,
+    tx_hash: String,
+) -> Result<<RH::ListReturns as NestedTupleFlatten>::Unpacked, GatewayError>
+where
+    GatewayProxy: GatewayAsyncService,
+    From: TxFromSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    Payment: TxPayment<InteractorEnvExec<'w, GatewayProxy>>,
+    Gas: TxGas<InteractorEnvExec<'w, GatewayProxy>>,
+    CodeValue: TxCodeValue<InteractorEnvExec<'w, GatewayProxy>>,
+    RH: RHListExec<TxResponse, InteractorEnvExec<'w, GatewayProxy>>,
+    RH::ListReturns: NestedTupleFlatten,
+{
+    let mut step_wrapper = tx.tx_to_step();
+    step_wrapper
+        .env
+        .world
+        .track_sc_deploy(&mut step_wrapper.step, &tx_hash)
+        .await?;
+    Ok(step_wrapper.process_result())
+}
+
 impl<'w, GatewayProxy, From, Payment, Gas, CodeValue, RH> InteractorRunAsync
     for Tx<
         InteractorEnvExec<'w, GatewayProxy>,
@@ -73,6 +257,82 @@ where
     fn run(self) -> impl std::future::Future<Output = Self::Result> {
         run_async_deploy(self)
     }
+
+    fn try_run(self) -> impl std::future::Future<Output = Result<Self::Result, GatewayError>> {
+        try_run_async_deploy(self)
+    }
+}
+
+impl<'w, GatewayProxy, From, Payment, Gas, CodeValue, RH> InteractorSimulateAsync
+    for Tx<
+        InteractorEnvExec<'w, GatewayProxy>,
+        From,
+        (),
+        Payment,
+        Gas,
+        DeployCall<InteractorEnvExec<'w, GatewayProxy>, Code<CodeValue>>,
+        RH,
+    >
+where
+    GatewayProxy: GatewayAsyncService,
+    From: TxFromSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    Payment: TxPayment<InteractorEnvExec<'w, GatewayProxy>>,
+    Gas: TxGas<InteractorEnvExec<'w, GatewayProxy>>,
+    CodeValue: TxCodeValue<InteractorEnvExec<'w, GatewayProxy>>,
+    RH: RHListExec<TxResponse, InteractorEnvExec<'w, GatewayProxy>>,
+    RH::ListReturns: NestedTupleFlatten,
+{
+    type Result = <RH::ListReturns as NestedTupleFlatten>::Unpacked;
+
+    fn simulate(self) -> impl std::future::Future<Output = Self::Result> {
+        simulate_async_deploy(self)
+    }
+
+    fn try_simulate(self) -> impl std::future::Future<Output = Result<Self::Result, GatewayError>> {
+        try_simulate_async_deploy(self)
+    }
+}
+
+impl<'w, GatewayProxy, From, Payment, Gas, CodeValue, RH> InteractorOfflineAsync
+    for Tx<
+        InteractorEnvExec<'w, GatewayProxy>,
+        From,
+        (),
+        Payment,
+        Gas,
+        DeployCall<InteractorEnvExec<'w, GatewayProxy>, Code<CodeValue>>,
+        RH,
+    >
+where
+    GatewayProxy: GatewayAsyncService,
+    From: TxFromSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    Payment: TxPayment<InteractorEnvExec<'w, GatewayProxy>>,
+    Gas: TxGas<InteractorEnvExec<'w, GatewayProxy>>,
+    CodeValue: TxCodeValue<InteractorEnvExec<'w, GatewayProxy>>,
+    RH: RHListExec<TxResponse, InteractorEnvExec<'w, GatewayProxy>>,
+    RH::ListReturns: NestedTupleFlatten,
+{
+    type Result = <RH::ListReturns as NestedTupleFlatten>::Unpacked;
+
+    fn export_unsigned(
+        self,
+    ) -> impl std::future::Future<Output = Result<Transaction, GatewayError>> {
+        export_unsigned_async_deploy(self)
+    }
+
+    fn broadcast_signed(
+        self,
+        signed_tx: Transaction,
+    ) -> impl std::future::Future<Output = Result<Self::Result, GatewayError>> {
+        broadcast_signed_async_deploy(self, signed_tx)
+    }
+
+    fn track(
+        self,
+        tx_hash: String,
+    ) -> impl std::future::Future<Output = Result<Self::Result, GatewayError>> {
+        track_async_deploy(self, tx_hash)
+    }
 }
 
 impl<'w, GatewayProxy, From, Payment, Gas, CodeValue, RH> InteractorPrepareAsync
@@ -3,7 +3,7 @@ use dharitri_sc_scenario::{
     scenario::tx_to_step::TxToStep,
     scenario_model::TransferStep,
 };
-use dharitri_sdk::gateway::GatewayAsyncService;
+use dharitri_sdk::gateway::{GatewayAsyncService, GatewayError};
 
 use super::{InteractorEnvExec, InteractorExecStep, InteractorPrepareAsync, InteractorRunAsync};
 
@@ -20,6 +20,25 @@ async fn run_async_transfer<'w, GatewayProxy, From, To, Payment, Gas>(
     step_wrapper.env.world.transfer(step_wrapper.step).await;
 }
 
+async fn try_run_async_transfer<'w, GatewayProxy, From, To, Payment, Gas>(
+    tx: Tx<InteractorEnvExec<'w, GatewayProxy>, From, To, Payment, Gas, (), ()>,
+) -> Result<(), GatewayError>
+where
+    GatewayProxy: GatewayAsyncService,
+    From: TxFromSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    To: TxToSpecified<InteractorEnvExec<'w, GatewayProxy>>,
+    Payment: TxPayment<InteractorEnvExec<'w, GatewayProxy>>,
+    Gas: TxGas<InteractorEnvExec<'w, GatewayProxy>>,
+{
+    let step_wrapper = tx.tx_to_step();
+    step_wrapper
+        .env
+        .world
+        .try_transfer(step_wrapper.step)
+        .await?;
+    Ok(())
+}
+
 impl<'w, GatewayProxy, From, To, Payment, Gas> InteractorRunAsync
     for Tx<InteractorEnvExec<'w, GatewayProxy>, From, To, Payment, Gas, (), ()>
 where
@@ -34,6 +53,10 @@ where
     fn run(self) -> impl std::future::Future<Output = Self::Result> {
         run_async_transfer(self)
     }
+
+    fn try_run(self) -> impl std::future::Future<Output = Result<Self::Result, GatewayError>> {
+        try_run_async_transfer(self)
+    }
 }
 
 impl<'w, GatewayProxy, From, To, Payment, Gas> InteractorPrepareAsync
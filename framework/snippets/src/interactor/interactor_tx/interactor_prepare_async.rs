@@ -1,5 +1,8 @@
 use dharitri_sc_scenario::{imports::InterpreterContext, ScenarioTxEnvData};
-use dharitri_sdk::gateway::GatewayAsyncService;
+use dharitri_sdk::{
+    data::transaction::Transaction,
+    gateway::{GatewayAsyncService, GatewayError},
+};
 
 use crate::InteractorBase;
 
@@ -29,4 +32,47 @@ pub trait InteractorRunAsync {
     type Result;
 
     fn run(self) -> impl std::future::Future<Output = Self::Result>;
+
+    /// Same as `run`, but gateway failures are returned instead of causing a panic.
+    ///
+    /// Transactions that get processed, but fail, are still handled by the result handlers.
+    fn try_run(self) -> impl std::future::Future<Output = Result<Self::Result, GatewayError>>;
+}
+
+pub trait InteractorSimulateAsync {
+    type Result;
+
+    /// Simulates the transaction instead of sending it, so nothing changes on the chain.
+    ///
+    /// The simulated results, logs and gas go through the result handlers, same as with `run`.
+    fn simulate(self) -> impl std::future::Future<Output = Self::Result>;
+
+    /// Same as `simulate`, but gateway failures are returned instead of causing a panic.
+    fn try_simulate(self) -> impl std::future::Future<Output = Result<Self::Result, GatewayError>>;
+}
+
+/// Runs the transaction in stages, so that it can be signed elsewhere, e.g. on an air-gapped machine.
+///
+/// Each stage consumes the transaction, so it needs to be built again for the next one.
+pub trait InteractorOfflineAsync {
+    type Result;
+
+    /// Builds the blockchain transaction, with its nonce and gas limit, but without any signature.
+    fn export_unsigned(
+        self,
+    ) -> impl std::future::Future<Output = Result<Transaction, GatewayError>>;
+
+    /// Sends the transaction, signed elsewhere, then processes its results through the result handlers, same as `run`.
+    ///
+    /// Panics if the signed transaction is not the one built here.
+    fn broadcast_signed(
+        self,
+        signed_tx: Transaction,
+    ) -> impl std::future::Future<Output = Result<Self::Result, GatewayError>>;
+
+    /// Waits for the transaction, sent elsewhere, then processes its results through the result handlers, same as `run`.
+    fn track(
+        self,
+        tx_hash: String,
+    ) -> impl std::future::Future<Output = Result<Self::Result, GatewayError>>;
 }
@@ -8,7 +8,7 @@ use dharitri_sc_scenario::{
     scenario_model::TxResponse,
     ScenarioTxEnvData,
 };
-use dharitri_sdk::gateway::GatewayAsyncService;
+use dharitri_sdk::gateway::{GatewayAsyncService, GatewayError};
 
 use crate::InteractorBase;
 
@@ -33,6 +33,25 @@ where
     step_wrapper.process_result()
 }
 
+async fn try_run_async_query<'w, GatewayProxy, To, Payment, RH>(
+    tx: Tx<InteractorEnvQuery<'w, GatewayProxy>, (), To, Payment, (), FunctionCall<StaticApi>, RH>,
+) -> Result<<RH::ListReturns as NestedTupleFlatten>::Unpacked, GatewayError>
+where
+    GatewayProxy: GatewayAsyncService,
+    To: TxToSpecified<InteractorEnvQuery<'w, GatewayProxy>>,
+    Payment: TxNoPayment<InteractorEnvQuery<'w, GatewayProxy>>,
+    RH: RHListExec<TxResponse, InteractorEnvQuery<'w, GatewayProxy>>,
+    RH::ListReturns: NestedTupleFlatten,
+{
+    let mut step_wrapper = tx.tx_to_query_step();
+    step_wrapper
+        .env
+        .world
+        .try_sc_query(&mut step_wrapper.step)
+        .await?;
+    Ok(step_wrapper.process_result())
+}
+
 impl<'w, GatewayProxy, To, Payment, RH> InteractorRunAsync
     for Tx<InteractorEnvQuery<'w, GatewayProxy>, (), To, Payment, (), FunctionCall<StaticApi>, RH>
 where
@@ -47,6 +66,10 @@ where
     fn run(self) -> impl std::future::Future<Output = Self::Result> {
         run_async_query(self)
     }
+
+    fn try_run(self) -> impl std::future::Future<Output = Result<Self::Result, GatewayError>> {
+        try_run_async_query(self)
+    }
 }
 
 impl<'w, GatewayProxy, To, Payment, RH> InteractorPrepareAsync
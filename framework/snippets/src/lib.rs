@@ -1,9 +1,13 @@
 pub mod account_tool;
+mod event_decoder;
+mod gateway_vm_proxy;
 mod interactor;
 mod multi;
 pub mod network_response;
 
 pub use env_logger;
+pub use event_decoder::{DecodedEvent, DecodedEventField, EventDecoder};
+pub use gateway_vm_proxy::{GatewayVMProxy, VM_CHAIN_ID, VM_USER_FUNDS};
 pub use hex;
 pub use interactor::*;
 pub use log;
@@ -27,3 +31,6 @@ pub type Interactor = HttpInteractor;
 
 #[cfg(feature = "dapp")]
 pub type DappInteractor = crate::InteractorBase<dharitri_sdk_dapp::GatewayDappProxy>;
+
+/// Runs the interactions in-process, on the Rust VM, instead of sending them to a real network.
+pub type VMInteractor = crate::InteractorBase<GatewayVMProxy>;
@@ -7,7 +7,7 @@ use dharitri_sc_scenario::{
     scenario_model::TxResponse,
     ScenarioTxEnvData,
 };
-use dharitri_sdk::gateway::GatewayAsyncService;
+use dharitri_sdk::gateway::{GatewayAsyncService, GatewayError};
 
 use crate::{InteractorBase, InteractorEnvExec, InteractorStep, StepBuffer};
 
@@ -60,16 +60,26 @@ where
         self
     }
 
-    pub async fn run(mut self) -> Vec<<RH::ListReturns as NestedTupleFlatten>::Unpacked> {
+    pub async fn run(self) -> Vec<<RH::ListReturns as NestedTupleFlatten>::Unpacked> {
+        self.try_run()
+            .await
+            .expect("multi sc exec failed (possible API failure)")
+    }
+
+    /// Same as [`Self::run`], but gateway failures are returned instead of causing a panic.
+    pub async fn try_run(
+        mut self,
+    ) -> Result<Vec<<RH::ListReturns as NestedTupleFlatten>::Unpacked>, GatewayError> {
         let mut step_buffer = StepBuffer::default();
         for step in &mut self.steps {
             step_buffer.refs.push(step.step.as_interactor_step());
         }
-        self.env.world.multi_sc_exec(step_buffer).await;
+        self.env.world.try_multi_sc_exec(step_buffer).await?;
 
-        self.steps
+        Ok(self
+            .steps
             .into_iter()
             .map(|step| step.process_result())
-            .collect()
+            .collect())
     }
 }
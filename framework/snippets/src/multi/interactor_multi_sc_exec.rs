@@ -1,47 +1,64 @@
-use super::interactor_multi_sc_process::{update_nonces_and_sign_tx, SenderSet, Txs};
+use super::interactor_multi_sc_process::{SenderSet, Txs};
 use super::InteractorStepRef;
 use crate::sdk::data::transaction::Transaction;
-use crate::sdk::gateway::GatewayAsyncService;
+use crate::sdk::gateway::{GatewayAsyncService, GatewayError};
 use crate::{network_response, InteractorBase, StepBuffer};
 
 impl<GatewayProxy> InteractorBase<GatewayProxy>
 where
     GatewayProxy: GatewayAsyncService,
 {
-    pub async fn multi_sc_exec(&mut self, mut buffer: StepBuffer<'_>) {
+    pub async fn multi_sc_exec(&mut self, buffer: StepBuffer<'_>) {
+        self.try_multi_sc_exec(buffer)
+            .await
+            .expect("multi sc exec failed (possible API failure)");
+    }
+
+    /// Same as [`Self::multi_sc_exec`], but gateway failures are returned instead of causing a panic.
+    ///
+    /// On failure, none of the step responses are set.
+    pub async fn try_multi_sc_exec(
+        &mut self,
+        mut buffer: StepBuffer<'_>,
+    ) -> Result<(), GatewayError> {
+        let mut gas_estimates = Vec::new();
+        for step in buffer.refs.iter_mut() {
+            let transaction = step.to_transaction(self);
+            gas_estimates.push(
+                self.estimate_step_gas(&transaction, step.gas_limit_mut())
+                    .await,
+            );
+        }
+
         for step in buffer.refs.iter_mut() {
             step.run_step(&mut self.pre_runners);
         }
 
         let senders = retrieve_senders(buffer.refs.as_slice());
-        self.recall_senders_nonce(senders).await;
+        self.recall_senders_nonce(senders).await?;
 
         let txs = self.retrieve_txs(&mut buffer);
-        let results = self.process_txs(txs).await;
+        let results = self.process_txs(txs).await?;
 
         for (i, sc_call_step) in buffer.refs.iter_mut().enumerate() {
             let (tx, return_code) = results.get(i).unwrap().clone();
-            sc_call_step.set_response(network_response::parse_tx_response(tx, return_code));
+            let mut response = network_response::parse_tx_response(tx, return_code);
+            response.gas_estimate = gas_estimates[i];
+            sc_call_step.set_response(response);
         }
 
         for step in buffer.refs.iter_mut() {
             step.run_step(&mut self.post_runners);
         }
+        Ok(())
     }
 
+    /// The transactions are not yet signed, since the nonces are not yet set.
     fn retrieve_txs(&mut self, buffer: &mut StepBuffer<'_>) -> Vec<Transaction> {
         let mut txs = Txs::new();
 
         for sc_call_step in &mut buffer.refs {
-            let mut transaction = sc_call_step.to_transaction(self);
-            let sender_address = &sc_call_step.sender_address().value;
-            let sender = self
-                .sender_map
-                .get_mut(sender_address)
-                .expect("sender not registered");
-
-            update_nonces_and_sign_tx(&mut transaction, sender);
-            txs.push(transaction);
+            txs.push(sc_call_step.to_transaction(self));
         }
         txs
     }
@@ -1,10 +1,10 @@
 use crate::sdk::data::transaction::{Transaction, TransactionOnNetwork};
-use crate::{dharitri_sc::types::Address, InteractorBase, Sender};
+use crate::{dharitri_sc::types::Address, is_nonce_error, InteractorBase, NonceMode};
 use futures::future::join_all;
 use dharitri_sc_scenario::imports::ReturnCode;
-use dharitri_sdk::gateway::{GatewayAsyncService, SendTxRequest};
-use dharitri_sdk::retrieve_tx_on_network;
-use std::collections::HashSet;
+use dharitri_sdk::gateway::{GatewayAsyncService, GatewayError, SendTxRequest};
+use dharitri_sdk::try_retrieve_tx_on_network;
+use std::collections::{HashMap, HashSet};
 
 pub(crate) type Txs = Vec<Transaction>;
 pub(crate) type SenderSet = HashSet<Address>;
@@ -13,45 +13,115 @@ impl<GatewayProxy> InteractorBase<GatewayProxy>
 where
     GatewayProxy: GatewayAsyncService,
 {
-    pub(crate) async fn recall_senders_nonce(&mut self, senders: HashSet<Address>) {
+    /// Retrieves the nonces once for the entire batch, so they can be incremented locally for each transaction.
+    pub(crate) async fn recall_senders_nonce(
+        &mut self,
+        senders: HashSet<Address>,
+    ) -> Result<(), GatewayError> {
         for sender_address in &senders {
-            let nonce = self.recall_nonce(sender_address).await;
-            let sender = self
-                .sender_map
-                .get_mut(sender_address)
-                .expect("sender not registered");
-
-            sender.current_nonce = Some(nonce);
-            println!("sender's recalled nonce: {nonce}");
+            self.resync_nonce_if_due(sender_address).await?;
         }
+        Ok(())
+    }
+
+    /// Sets the nonces and signs the transactions, in order.
+    pub(crate) fn set_nonces_and_sign_txs(&mut self, unsigned_txs: &[Transaction]) -> Txs {
+        unsigned_txs
+            .iter()
+            .map(|unsigned_tx| {
+                let sender_address = &unsigned_tx.sender.0;
+                let mut transaction = unsigned_tx.clone();
+                transaction.nonce = self.take_local_nonce(sender_address);
+                self.sign_tx(sender_address, &mut transaction);
+                transaction
+            })
+            .collect()
     }
 
+    /// Sends all transactions, then waits for all of them to be processed.
+    ///
+    /// Transactions from the same sender are sent one after the other, without waiting for them to get processed.
+    /// Different senders send concurrently.
+    ///
+    /// Fails with the first gateway error, once all the sent transactions have been waited for.
     pub(crate) async fn process_txs(
         &mut self,
-        txs: Vec<Transaction>,
-    ) -> Vec<(TransactionOnNetwork, ReturnCode)> {
-        let mut futures = Vec::new();
+        unsigned_txs: Txs,
+    ) -> Result<Vec<(TransactionOnNetwork, ReturnCode)>, GatewayError> {
+        let txs = self.set_nonces_and_sign_txs(&unsigned_txs);
+        let mut send_results = self.send_txs_by_sender(&txs).await;
 
-        for tx in &txs {
-            let tx_hash = self
-                .proxy
-                .request(SendTxRequest(tx))
-                .await
-                .expect("failed to send transaction");
+        // each sender only resyncs once, the later transactions then follow the fresh nonce
+        let mut resynced_senders = SenderSet::new();
+        for (index, send_result) in send_results.iter_mut().enumerate() {
+            if let Err(err) = send_result {
+                if self.nonce_mode == NonceMode::DetectAndRepair && is_nonce_error(err) {
+                    println!("nonce {} rejected: {err}", txs[index].nonce);
+                    let sender_address = &unsigned_txs[index].sender.0;
+                    if resynced_senders.insert(sender_address.clone()) {
+                        self.try_resync_nonce(sender_address).await?;
+                    }
+                    *send_result = self.resend(&unsigned_txs[index]).await;
+                }
+            }
+        }
 
-            println!("process tx hash: {tx_hash} with nonce: {}", tx.nonce);
-            futures.push(retrieve_tx_on_network(&self.proxy, tx_hash.clone()));
+        let mut futures = Vec::new();
+        let mut send_error = None;
+        for (tx, send_result) in txs.iter().zip(send_results) {
+            match send_result {
+                Ok(tx_hash) => {
+                    println!("process tx hash: {tx_hash} with nonce: {}", tx.nonce);
+                    futures.push(try_retrieve_tx_on_network(&self.proxy, tx_hash));
+                },
+                Err(err) => {
+                    send_error.get_or_insert(err);
+                },
+            }
         }
 
-        self.generate_blocks(4).await.unwrap();
-        join_all(futures).await
+        self.generate_blocks(4).await?;
+        let results = join_all(futures).await;
+
+        if let Some(err) = send_error {
+            return Err(err);
+        }
+        results.into_iter().collect()
     }
-}
 
-pub(crate) fn update_nonces_and_sign_tx(transaction: &mut Transaction, sender: &mut Sender) {
-    transaction.nonce = sender.current_nonce.unwrap();
-    sender.current_nonce = Some(sender.current_nonce.unwrap() + 1);
+    /// The results are in the same order as the transactions.
+    async fn send_txs_by_sender(&self, txs: &[Transaction]) -> Vec<Result<String, GatewayError>> {
+        let mut indices_by_sender: HashMap<&Address, Vec<usize>> = HashMap::new();
+        for (index, tx) in txs.iter().enumerate() {
+            indices_by_sender
+                .entry(&tx.sender.0)
+                .or_default()
+                .push(index);
+        }
+
+        let sender_futures = indices_by_sender.into_values().map(|indices| async move {
+            let mut sender_results = Vec::new();
+            for index in indices {
+                let result = self.proxy.request(SendTxRequest(&txs[index])).await;
+                sender_results.push((index, result));
+            }
+            sender_results
+        });
 
-    let signature = sender.wallet.sign_tx(&*transaction);
-    transaction.signature = Some(hex::encode(signature));
+        let mut results = join_all(sender_futures)
+            .await
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        results.sort_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+
+    async fn resend(&mut self, unsigned_tx: &Transaction) -> Result<String, GatewayError> {
+        let sender_address = &unsigned_tx.sender.0;
+        let mut transaction = unsigned_tx.clone();
+        transaction.nonce = self.take_local_nonce(sender_address);
+        self.sign_tx(sender_address, &mut transaction);
+        self.proxy.request(SendTxRequest(&transaction)).await
+    }
 }
@@ -2,7 +2,7 @@ use crate::sdk::data::transaction::Transaction;
 use dharitri_sc_scenario::{
     denali_system::ScenarioRunner,
     scenario::tx_to_step::StepWithResponse,
-    scenario_model::{AddressValue, ScCallStep, ScDeployStep, TxResponse},
+    scenario_model::{AddressValue, ScCallStep, ScDeployStep, TxResponse, U64Value},
 };
 use dharitri_sdk::gateway::GatewayAsyncService;
 
@@ -33,6 +33,13 @@ impl InteractorStepRef<'_> {
         }
     }
 
+    pub fn gas_limit_mut(&mut self) -> &mut U64Value {
+        match self {
+            InteractorStepRef::ScCall(sc_call) => &mut sc_call.tx.gas_limit,
+            InteractorStepRef::ScDeploy(sc_deploy) => &mut sc_deploy.tx.gas_limit,
+        }
+    }
+
     pub fn run_step(&mut self, step_runner: &mut dyn ScenarioRunner) {
         match self {
             InteractorStepRef::ScCall(sc_call) => step_runner.run_sc_call_step(sc_call),
@@ -129,10 +129,15 @@ fn process_new_deployed_address(tx: &TransactionOnNetwork) -> Option<Address> {
         return None;
     }
 
-    let sender_address_bytes = tx.sender.to_bytes();
-    let sender_nonce_bytes = tx.nonce.to_le_bytes();
+    Some(compute_new_deployed_address(&tx.sender.0, tx.nonce))
+}
+
+/// The address of a contract deployed on the network, derived from the deployer address and nonce.
+pub fn compute_new_deployed_address(sender: &Address, nonce: u64) -> Address {
+    let sender_address_bytes = sender.as_bytes();
+    let sender_nonce_bytes = nonce.to_le_bytes();
     let mut bytes_to_hash: Vec<u8> = Vec::new();
-    bytes_to_hash.extend_from_slice(&sender_address_bytes);
+    bytes_to_hash.extend_from_slice(sender_address_bytes);
     bytes_to_hash.extend_from_slice(&sender_nonce_bytes);
 
     let address_keccak = keccak256(&bytes_to_hash);
@@ -144,7 +149,7 @@ fn process_new_deployed_address(tx: &TransactionOnNetwork) -> Option<Address> {
     address[10..30].copy_from_slice(&address_keccak[10..30]);
     address[30..32].copy_from_slice(&sender_address_bytes[30..32]);
 
-    Some(Address::from(address))
+    Address::from(address)
 }
 
 fn process_new_issued_token_identifier(tx: &TransactionOnNetwork) -> Option<String> {
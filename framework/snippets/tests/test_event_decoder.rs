@@ -0,0 +1,132 @@
+use dharitri_sc_scenario::{bech32, imports::Address, meta::abi_json::deserialize_abi_from_json};
+use dharitri_sc_snippets::EventDecoder;
+use dharitri_sdk::{
+    data::{
+        sdk_address::SdkAddress,
+        transaction::{Events, LogData},
+    },
+    utils::base64_encode,
+    EventFilter,
+};
+use serde_json::json;
+
+const ABI_JSON: &str = r#"{
+    "name": "Transfers",
+    "endpoints": [],
+    "events": [
+        {
+            "identifier": "transferEvent",
+            "inputs": [
+                { "name": "caller", "type": "Address", "indexed": true },
+                { "name": "epoch", "type": "u64", "indexed": true },
+                { "name": "status", "type": "Status", "indexed": true },
+                { "name": "tags", "type": "List<u32>", "indexed": true },
+                { "name": "memo", "type": "Option<utf-8 string>", "indexed": true },
+                { "name": "info", "type": "TransferInfo" }
+            ]
+        }
+    ],
+    "types": {
+        "Status": {
+            "type": "enum",
+            "variants": [
+                { "name": "Active", "discriminant": 0 },
+                { "name": "Paused", "discriminant": 1 },
+                {
+                    "name": "Custom",
+                    "discriminant": 2,
+                    "fields": [{ "name": "code", "type": "u8" }]
+                }
+            ]
+        },
+        "TransferInfo": {
+            "type": "struct",
+            "fields": [
+                { "name": "amount", "type": "BigUint" },
+                { "name": "token", "type": "TokenIdentifier" },
+                { "name": "nonce", "type": "u64" }
+            ]
+        }
+    }
+}"#;
+
+const CALLER: &str = "drt1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssey5egf";
+const CONTRACT: &str = "drt1qqqqqqqqqqqqqpgqqnw862rla67qnm7qwcxnkaw42kpg2t7ld8ssdjc0tz";
+
+fn transfer_event(status: &[u8]) -> Events {
+    let caller = bech32::decode(CALLER);
+    let info = [
+        &[0, 0, 0, 2, 0x03, 0xe8][..],
+        &[0, 0, 0, 11],
+        b"WREWA-abcde",
+        &[0, 0, 0, 0, 0, 0, 0, 3],
+    ]
+    .concat();
+    Events {
+        address: SdkAddress(bech32::decode(CONTRACT)),
+        identifier: "transfer".to_string(),
+        topics: Some(vec![
+            base64_encode("transferEvent"),
+            base64_encode(caller.as_bytes()),
+            base64_encode([5]),
+            base64_encode(status),
+            base64_encode([0, 0, 0, 1, 0, 0, 0, 2]),
+            base64_encode(""),
+        ]),
+        data: LogData::String(base64_encode(info)),
+    }
+}
+
+#[test]
+fn test_decode_event() {
+    let decoder = EventDecoder::new(deserialize_abi_from_json(ABI_JSON).unwrap());
+
+    let decoded = decoder.decode(&transfer_event(&[2, 7])).unwrap();
+    assert_eq!(decoded.identifier, "transferEvent");
+    assert_eq!(
+        decoded.to_json(),
+        json!({
+            "caller": CALLER,
+            "epoch": 5,
+            "status": { "Custom": { "code": 7 } },
+            "tags": [1, 2],
+            "memo": null,
+            "info": {
+                "amount": "1000",
+                "token": "WREWA-abcde",
+                "nonce": 3,
+            },
+        })
+    );
+    assert_eq!(decoded.field_value::<u64>("epoch").unwrap(), 5);
+    assert_eq!(
+        decoded.field_value::<Address>("caller").unwrap(),
+        bech32::decode(CALLER)
+    );
+
+    // the first variant is top-encoded as empty bytes
+    let decoded = decoder.decode(&transfer_event(&[])).unwrap();
+    assert_eq!(decoded.to_json()["status"], json!("Active"));
+
+    let err = decoder.decode(&transfer_event(&[3])).unwrap_err();
+    assert!(err.to_string().contains("status"));
+}
+
+#[test]
+fn test_event_filter() {
+    let event = transfer_event(&[1]);
+
+    assert!(EventFilter::new().matches(&event));
+    assert!(EventFilter::new().identifier("transfer").matches(&event));
+    assert!(EventFilter::new()
+        .identifier("transferEvent")
+        .matches(&event));
+    assert!(!EventFilter::new().identifier("otherEvent").matches(&event));
+    assert!(EventFilter::new()
+        .address(&bech32::decode(CONTRACT))
+        .identifier("transferEvent")
+        .matches(&event));
+    assert!(!EventFilter::new()
+        .address(&bech32::decode(CALLER))
+        .matches(&event));
+}
@@ -84,6 +84,8 @@ extern "C" {
         resultHandle: i32,
     );
 
+    fn managedDeleteContract(dstHandle: i32, gas: i64, argumentsHandle: i32);
+
     fn managedAsyncCall(
         dstHandle: i32,
         valueHandle: i32,
@@ -312,6 +314,17 @@ impl SendApiImpl for VmApiImpl {
         }
     }
 
+    fn delete_contract(
+        &self,
+        sc_address_handle: RawHandle,
+        gas: u64,
+        arg_buffer_handle: RawHandle,
+    ) {
+        unsafe {
+            managedDeleteContract(sc_address_handle, gas as i64, arg_buffer_handle);
+        }
+    }
+
     fn execute_on_dest_context_raw(
         &self,
         gas: u64,
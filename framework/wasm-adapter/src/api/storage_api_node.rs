@@ -1,6 +1,7 @@
 use super::VmApiImpl;
-use dharitri_sc::api::{
-    StorageReadApi, StorageReadApiImpl, StorageWriteApi, StorageWriteApiImpl,
+use dharitri_sc::{
+    api::{StorageReadApi, StorageReadApiImpl, StorageWriteApi, StorageWriteApiImpl},
+    types::{ManagedBuffer, ManagedType},
 };
 
 extern "C" {
@@ -10,6 +11,12 @@ extern "C" {
 
     // from another account
     fn mBufferStorageLoadFromAddress(addressHandle: i32, keyHandle: i32, mBufferHandle: i32);
+
+    // storage locks
+    fn setStorageLock(keyOffset: *const u8, keyLength: i32, lockTimestamp: i64) -> i32;
+    fn getStorageLock(keyOffset: *const u8, keyLength: i32) -> i64;
+    fn isStorageLocked(keyOffset: *const u8, keyLength: i32) -> i32;
+    fn clearStorageLock(keyOffset: *const u8, keyLength: i32) -> i32;
 }
 
 impl StorageReadApi for VmApiImpl {
@@ -44,6 +51,18 @@ impl StorageReadApiImpl for VmApiImpl {
             mBufferStorageLoadFromAddress(address_handle, key_handle, dest);
         }
     }
+
+    fn storage_get_lock(&self, key_handle: Self::ManagedBufferHandle) -> i64 {
+        with_key_bytes(key_handle, |key| unsafe {
+            getStorageLock(key.as_ptr(), key.len() as i32)
+        })
+    }
+
+    fn storage_is_locked(&self, key_handle: Self::ManagedBufferHandle) -> i32 {
+        with_key_bytes(key_handle, |key| unsafe {
+            isStorageLocked(key.as_ptr(), key.len() as i32)
+        })
+    }
 }
 
 impl StorageWriteApi for VmApiImpl {
@@ -65,4 +84,25 @@ impl StorageWriteApiImpl for VmApiImpl {
             mBufferStorageStore(key_handle, value_handle);
         }
     }
+
+    fn storage_set_lock(&self, key_handle: Self::ManagedBufferHandle, lock_timestamp: i64) -> i32 {
+        with_key_bytes(key_handle, |key| unsafe {
+            setStorageLock(key.as_ptr(), key.len() as i32, lock_timestamp)
+        })
+    }
+
+    fn storage_clear_lock(&self, key_handle: Self::ManagedBufferHandle) -> i32 {
+        with_key_bytes(key_handle, |key| unsafe {
+            clearStorageLock(key.as_ptr(), key.len() as i32)
+        })
+    }
+}
+
+/// The storage lock hooks take the key from contract memory, instead of from a managed buffer.
+fn with_key_bytes<R, F>(key_handle: i32, f: F) -> R
+where
+    F: FnOnce(&[u8]) -> R,
+{
+    let key = unsafe { ManagedBuffer::<VmApiImpl>::from_handle(key_handle) };
+    key.with_buffer_contents(f)
 }
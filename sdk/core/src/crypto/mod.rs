@@ -1,3 +1,4 @@
 mod edwards25519;
 pub mod private_key;
 pub mod public_key;
+pub mod signable_message;
@@ -1,6 +1,9 @@
 use std::fmt::Display;
 
-use super::edwards25519::{sc_mul_add, sc_reduce};
+use super::{
+    edwards25519::{sc_mul_add, sc_reduce},
+    signable_message::message_signing_bytes,
+};
 use crate::crypto::edwards25519::extended_group_element::ExtendedGroupElement;
 use anyhow::{anyhow, Result};
 use rand::{CryptoRng, RngCore};
@@ -126,6 +129,11 @@ impl PrivateKey {
 
         signature
     }
+
+    /// Signs an off-chain message, with the standard message prefix.
+    pub fn sign_message(&self, message: &[u8]) -> [u8; 64] {
+        self.sign(message_signing_bytes(message))
+    }
 }
 
 impl Display for PrivateKey {
@@ -1,8 +1,10 @@
 use std::fmt::Display;
 
-use super::private_key::PrivateKey;
+use super::{private_key::PrivateKey, signable_message::message_signing_bytes};
+use crate::data::transaction::Transaction;
 use anyhow::Result;
 use dharitri_chain_core::types::Address;
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
 use serde::{
     de::{Deserialize, Deserializer},
     ser::{Serialize, Serializer},
@@ -32,6 +34,33 @@ impl PublicKey {
         bits.copy_from_slice(&bytes[32..]);
         Ok(Self(bits))
     }
+
+    /// Checks an ed25519 signature of the raw message bytes.
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
+        let Ok(verifying_key) = VerifyingKey::from_bytes(&self.0) else {
+            return false;
+        };
+        let Ok(signature) = Signature::from_slice(signature) else {
+            return false;
+        };
+        verifying_key.verify(message, &signature).is_ok()
+    }
+
+    /// Checks the signature of an off-chain message, signed with the standard message prefix.
+    pub fn verify_message(&self, message: &[u8], signature: &[u8]) -> bool {
+        self.verify(&message_signing_bytes(message), signature)
+    }
+
+    /// Checks a signature of the transaction, as produced by the sender, the guardian or the relayer.
+    pub fn verify_tx(&self, tx: &Transaction, signature: &[u8]) -> bool {
+        self.verify(&tx.signing_bytes(), signature)
+    }
+}
+
+impl From<&Address> for PublicKey {
+    fn from(address: &Address) -> PublicKey {
+        PublicKey(*address.as_array())
+    }
 }
 
 impl From<&PrivateKey> for PublicKey {
@@ -0,0 +1,16 @@
+use sha3::{Digest, Keccak256};
+
+/// Prepended to all messages signed off-chain, so that a signed message can never pass for a signed transaction.
+///
+/// Starts with its own length, 25 bytes.
+pub const MESSAGE_PREFIX: &str = "\x19Dharitri Signed Message:\n";
+
+/// The content actually signed for an off-chain message:
+/// the keccak256 hash of the prefix, followed by the message length in decimal and the message itself.
+pub fn message_signing_bytes(message: &[u8]) -> Vec<u8> {
+    let mut hasher = Keccak256::new();
+    hasher.update(MESSAGE_PREFIX.as_bytes());
+    hasher.update(message.len().to_string().as_bytes());
+    hasher.update(message);
+    hasher.finalize().to_vec()
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::transaction::TransactionOnNetwork;
+
 // HyperBlock holds a hyper block's details
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -16,6 +18,8 @@ pub struct HyperBlock {
     pub developer_fees: String,
     pub accumulated_fees_in_epoch: String,
     pub developer_fees_in_epoch: String,
+    #[serde(default)]
+    pub transactions: Vec<TransactionOnNetwork>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
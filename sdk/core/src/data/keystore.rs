@@ -5,6 +5,8 @@ pub const KDF_R: u32 = 8;
 pub const KDF_P: u32 = 1;
 pub const KDF_DKLEN: usize = 32;
 pub const KEYSTORE_VERSION: u32 = 4;
+pub const KEYSTORE_KIND_SECRET_KEY: &str = "secretKey";
+pub const KEYSTORE_KIND_MNEMONIC: &str = "mnemonic";
 
 #[derive(Debug)]
 pub enum WalletError {
@@ -48,7 +50,10 @@ pub struct Keystore {
     pub version: u32,
     pub kind: String,
     pub id: String,
+    /// Empty for mnemonic keystores, which hold many addresses.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub address: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub bech32: String,
     pub crypto: Crypto,
 }
@@ -1,7 +1,15 @@
 use std::collections::HashMap;
 
 use super::{sdk_address::SdkAddress, vm::CallType};
+use anyhow::anyhow;
+use base64::{engine::general_purpose, Engine as _};
+use blake2::{digest::consts::U32, Blake2b};
+use dharitri_chain_core::types::Address;
+use indexmap::IndexMap;
+use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sha3::{Digest, Keccak256};
 
 // Transaction holds the fields of a transaction to be broadcasted to the network
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,8 +28,155 @@ pub struct Transaction {
     #[serde(rename = "chainID")]
     pub chain_id: String,
     pub version: u32,
-    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default, skip_serializing_if = "is_zero")]
     pub options: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guardian: Option<SdkAddress>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guardian_signature: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relayer: Option<SdkAddress>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relayer_signature: Option<String>,
+}
+
+/// Option bit: the parties sign the keccak256 hash of the serialized transaction, instead of the transaction itself.
+pub const TX_OPTION_HASH_SIGN: u32 = 0b01;
+
+/// Option bit: the sender account is guarded, so the transaction also carries the guardian's signature.
+pub const TX_OPTION_GUARDED: u32 = 0b10;
+
+/// The options field is only taken into account starting with this transaction version.
+pub const MIN_TX_VERSION_WITH_OPTIONS: u32 = 2;
+
+impl Transaction {
+    pub fn is_guarded(&self) -> bool {
+        self.version >= MIN_TX_VERSION_WITH_OPTIONS && self.options & TX_OPTION_GUARDED != 0
+    }
+
+    /// Relayed v3: the relayer pays for the gas and co-signs the transaction.
+    pub fn is_relayed(&self) -> bool {
+        self.relayer.is_some()
+    }
+
+    /// Sets the guardian and the guarded option.
+    ///
+    /// Must be called before any of the parties signs, since the guardian is part of the signed content.
+    pub fn set_guardian(&mut self, guardian: Address) {
+        self.guardian = Some(guardian.into());
+        self.options |= TX_OPTION_GUARDED;
+        self.version = self.version.max(MIN_TX_VERSION_WITH_OPTIONS);
+    }
+
+    /// Sets the relayer, turning the transaction into a relayed v3 transaction.
+    ///
+    /// Must be called before any of the parties signs, since the relayer is part of the signed content.
+    pub fn set_relayer(&mut self, relayer: Address) {
+        self.relayer = Some(relayer.into());
+    }
+
+    /// The content signed by the sender, the guardian and the relayer alike:
+    /// the transaction serialized without any of the signatures, or its hash, if the hash sign option is set.
+    pub fn signing_bytes(&self) -> Vec<u8> {
+        let mut unsigned_tx = self.clone();
+        unsigned_tx.signature = None;
+        unsigned_tx.guardian_signature = None;
+        unsigned_tx.relayer_signature = None;
+
+        let tx_bytes = json!(unsigned_tx).to_string().into_bytes();
+
+        let should_sign_on_tx_hash =
+            self.version >= MIN_TX_VERSION_WITH_OPTIONS && self.options & TX_OPTION_HASH_SIGN != 0;
+        if should_sign_on_tx_hash {
+            Keccak256::digest(tx_bytes).to_vec()
+        } else {
+            tx_bytes
+        }
+    }
+
+    /// The hash under which the chain registers the transaction, hex encoded.
+    ///
+    /// It is the blake2b-256 hash of the transaction in its protobuf encoding, signatures included.
+    pub fn compute_hash(&self) -> anyhow::Result<String> {
+        let hash = Blake2b::<U32>::digest(self.proto_encode()?);
+        Ok(hex::encode(hash))
+    }
+
+    /// Mirrors the protobuf `Transaction` message of the protocol. Fields with default values are omitted.
+    fn proto_encode(&self) -> anyhow::Result<Vec<u8>> {
+        let value: BigUint = self
+            .value
+            .parse()
+            .map_err(|_| anyhow!("invalid transaction value: {}", self.value))?;
+        // the value is encoded as a sign byte, followed by the magnitude
+        let mut value_bytes = vec![0u8];
+        value_bytes.extend(value.to_bytes_be());
+
+        let data = match &self.data {
+            Some(data) => general_purpose::STANDARD.decode(data)?,
+            None => Vec::new(),
+        };
+
+        let mut encoded = Vec::new();
+        proto_varint_field(&mut encoded, 1, self.nonce);
+        proto_bytes_field(&mut encoded, 2, &value_bytes);
+        proto_bytes_field(&mut encoded, 3, self.receiver.0.as_bytes());
+        proto_bytes_field(&mut encoded, 5, self.sender.0.as_bytes());
+        proto_varint_field(&mut encoded, 7, self.gas_price);
+        proto_varint_field(&mut encoded, 8, self.gas_limit);
+        proto_bytes_field(&mut encoded, 9, &data);
+        proto_bytes_field(&mut encoded, 10, self.chain_id.as_bytes());
+        proto_varint_field(&mut encoded, 11, self.version as u64);
+        proto_bytes_field(&mut encoded, 12, &decode_signature(&self.signature)?);
+        proto_varint_field(&mut encoded, 13, self.options as u64);
+        if let Some(guardian) = &self.guardian {
+            proto_bytes_field(&mut encoded, 14, guardian.0.as_bytes());
+        }
+        proto_bytes_field(
+            &mut encoded,
+            15,
+            &decode_signature(&self.guardian_signature)?,
+        );
+        if let Some(relayer) = &self.relayer {
+            proto_bytes_field(&mut encoded, 16, relayer.0.as_bytes());
+        }
+        proto_bytes_field(
+            &mut encoded,
+            17,
+            &decode_signature(&self.relayer_signature)?,
+        );
+        Ok(encoded)
+    }
+}
+
+fn decode_signature(signature: &Option<String>) -> anyhow::Result<Vec<u8>> {
+    match signature {
+        Some(signature) => Ok(hex::decode(signature)?),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn proto_varint(encoded: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        encoded.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    encoded.push(value as u8);
+}
+
+fn proto_varint_field(encoded: &mut Vec<u8>, field_number: u64, value: u64) {
+    if value != 0 {
+        proto_varint(encoded, field_number << 3);
+        proto_varint(encoded, value);
+    }
+}
+
+fn proto_bytes_field(encoded: &mut Vec<u8>, field_number: u64, value: &[u8]) {
+    if !value.is_empty() {
+        proto_varint(encoded, (field_number << 3) | 2);
+        proto_varint(encoded, value.len() as u64);
+        encoded.extend_from_slice(value);
+    }
 }
 
 /// This is only used for serialize
@@ -118,6 +273,25 @@ impl LogData {
     }
 }
 
+impl Events {
+    /// The topics, decoded from base64.
+    pub fn topics_bytes(&self) -> Vec<Vec<u8>> {
+        self.topics
+            .iter()
+            .flatten()
+            .map(crate::utils::base64_decode)
+            .collect()
+    }
+
+    /// The data fields, decoded from base64.
+    pub fn data_bytes(&self) -> Vec<Vec<u8>> {
+        let mut out = Vec::new();
+        self.data
+            .for_each(|data_field| out.push(crate::utils::base64_decode(data_field)));
+        out
+    }
+}
+
 // ApiLogs represents logs with changed fields' types in order to make it friendly for API's json
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -236,6 +410,41 @@ pub struct SendTransactionsResponse {
     pub data: Option<SendTransactionsResponseData>,
 }
 
+// SimulationResults holds the outcome of a transaction that was executed without being committed to the chain
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulationResults {
+    pub status: String,
+    #[serde(default)]
+    pub fail_reason: String,
+    /// In the order the gateway sent them, which is the order they were produced in.
+    #[serde(default)]
+    pub sc_results: IndexMap<String, ApiSmartContractResult>,
+    #[serde(default)]
+    pub hash: String,
+    pub logs: Option<ApiLogs>,
+}
+
+impl SimulationResults {
+    pub fn is_success(&self) -> bool {
+        self.status == "success"
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulateTxResponseData {
+    pub result: SimulationResults,
+}
+
+// ResponseSimulateTx holds the response received from the network when simulating a transaction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResponseSimulateTx {
+    pub data: Option<SimulateTxResponseData>,
+    #[serde(default)]
+    pub error: String,
+    pub code: String,
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -294,6 +503,67 @@ mod test {
         );
     }
 
+    #[test]
+    fn parse_simulate_tx_fail() {
+        let data = r#"
+{
+    "data": {
+        "result": {
+            "status": "fail",
+            "failReason": "@04@75706772616465206e6f7420616c6c6f776564",
+            "hash": "a1a5cc2c6cb7f9d6f7d0a2f5a6e8c2b1a0f4b2c3d4e5f60718293a4b5c6d7e8f"
+        }
+    },
+    "error": "",
+    "code": "successful"
+}
+        "#;
+
+        let response = serde_json::from_str::<ResponseSimulateTx>(data).unwrap();
+        let result = response.data.unwrap().result;
+        assert!(!result.is_success());
+        assert_eq!(
+            result.fail_reason,
+            "@04@75706772616465206e6f7420616c6c6f776564"
+        );
+        assert!(result.sc_results.is_empty());
+        assert!(result.logs.is_none());
+    }
+
+    #[test]
+    fn parse_simulate_tx_sc_results_order() {
+        let scr = |hash: &str, data: &str| {
+            json!({
+                "hash": hash,
+                "nonce": 0,
+                "value": 0,
+                "receiver": "drt1qqqqqqqqqqqqqpgq0mhy244pyr9pzdhahvvyze4rw3xl29q4kklslc4ra5",
+                "sender": "drt1qqqqqqqqqqqqqpgq0mhy244pyr9pzdhahvvyze4rw3xl29q4kklslc4ra5",
+                "data": data,
+                "prevTxHash": "",
+                "originalTxHash": "",
+                "gasLimit": 0,
+                "gasPrice": 0,
+                "callType": 0,
+            })
+        };
+        let data = json!({
+            "status": "success",
+            "scResults": {
+                "ff01": scr("ff01", "first"),
+                "0a02": scr("0a02", "second"),
+            },
+        });
+
+        let result = serde_json::from_value::<SimulationResults>(data).unwrap();
+        let scr_data: Vec<&str> = result
+            .sc_results
+            .values()
+            .map(|scr| scr.data.as_str())
+            .collect();
+        assert_eq!(scr_data, vec!["first", "second"]);
+    }
+
     #[test]
     fn parse_transaction_info_no_signature() {
         let data = r#"
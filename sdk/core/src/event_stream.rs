@@ -0,0 +1,175 @@
+use crate::{
+    data::{
+        hyperblock::HyperBlock,
+        transaction::{Events, TransactionOnNetwork},
+    },
+    gateway::{GatewayAsyncService, GatewayError, GetHyperBlockRequest, NetworkStatusRequest},
+};
+use dharitri_chain_core::types::Address;
+
+const DEFAULT_POLL_INTERVAL_MS: u64 = 1000;
+
+/// Selects the events, and the transactions that produced them, to be yielded by a [`BlockStream`].
+///
+/// An empty list of addresses or identifiers means no filtering on that criterion.
+#[derive(Clone, Debug, Default)]
+pub struct EventFilter {
+    pub addresses: Vec<Address>,
+    pub identifiers: Vec<String>,
+}
+
+impl EventFilter {
+    /// Matches all events.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only events emitted by this address, can be called multiple times.
+    pub fn address(mut self, address: &Address) -> Self {
+        self.addresses.push(address.clone());
+        self
+    }
+
+    /// Only events with this identifier, can be called multiple times.
+    pub fn identifier(mut self, identifier: &str) -> Self {
+        self.identifiers.push(identifier.to_string());
+        self
+    }
+
+    /// Contracts emit their events under the name of the endpoint that was called,
+    /// with the actual event identifier as first topic, so both get checked.
+    pub fn matches(&self, event: &Events) -> bool {
+        if !self.addresses.is_empty() && !self.addresses.contains(&event.address.0) {
+            return false;
+        }
+
+        if self.identifiers.is_empty() {
+            return true;
+        }
+
+        let first_topic = event.topics_bytes().into_iter().next().unwrap_or_default();
+        self.identifiers.iter().any(|identifier| {
+            event.identifier == *identifier || first_topic == identifier.as_bytes()
+        })
+    }
+
+    /// A transaction matches if any of its events does.
+    ///
+    /// Without an identifier filter, transactions sent to one of the addresses also match, even without events.
+    pub fn matches_tx(&self, tx: &TransactionOnNetwork) -> bool {
+        if self.identifiers.is_empty()
+            && (self.addresses.is_empty() || self.addresses.contains(&tx.receiver.0))
+        {
+            return true;
+        }
+
+        tx_events(tx).any(|event| self.matches(event))
+    }
+}
+
+/// An event yielded by a [`BlockStream`], along with where it comes from.
+#[derive(Clone, Debug)]
+pub struct StreamedEvent {
+    pub block_nonce: u64,
+    pub tx_hash: String,
+    pub event: Events,
+}
+
+/// Follows the chain, one hyperblock at a time, starting from a given nonce.
+///
+/// Once it reaches the latest block, it polls the network status until a new block comes out.
+pub struct BlockStream<'a, GatewayProxy: GatewayAsyncService> {
+    proxy: &'a GatewayProxy,
+    next_nonce: u64,
+    filter: EventFilter,
+    poll_interval_ms: u64,
+}
+
+impl<'a, GatewayProxy: GatewayAsyncService> BlockStream<'a, GatewayProxy> {
+    pub fn new(proxy: &'a GatewayProxy, from_nonce: u64) -> Self {
+        BlockStream {
+            proxy,
+            next_nonce: from_nonce,
+            filter: EventFilter::default(),
+            poll_interval_ms: DEFAULT_POLL_INTERVAL_MS,
+        }
+    }
+
+    pub fn with_filter(mut self, filter: EventFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    pub fn with_poll_interval_ms(mut self, poll_interval_ms: u64) -> Self {
+        self.poll_interval_ms = poll_interval_ms;
+        self
+    }
+
+    /// The nonce of the block to be yielded next.
+    ///
+    /// Saving it allows resuming the stream later, without skipping or repeating blocks.
+    pub fn next_nonce(&self) -> u64 {
+        self.next_nonce
+    }
+
+    /// Waits for the next block, then retrieves it, along with its transaction logs.
+    ///
+    /// A failed request does not advance the stream, so calling it again retries the same block.
+    pub async fn next_block(&mut self) -> Result<HyperBlock, GatewayError> {
+        loop {
+            let latest_nonce = self
+                .proxy
+                .request(NetworkStatusRequest::default())
+                .await?
+                .nonce;
+            if self.next_nonce <= latest_nonce {
+                break;
+            }
+
+            self.proxy.sleep(self.poll_interval_ms).await;
+        }
+
+        let block = self
+            .proxy
+            .request(GetHyperBlockRequest::by_nonce(self.next_nonce).with_logs())
+            .await?;
+        self.next_nonce += 1;
+        Ok(block)
+    }
+
+    /// The transactions of the next block that match the filter.
+    ///
+    /// Blocks without any match yield an empty list.
+    pub async fn next_transactions(&mut self) -> Result<Vec<TransactionOnNetwork>, GatewayError> {
+        let block = self.next_block().await?;
+        Ok(block
+            .transactions
+            .into_iter()
+            .filter(|tx| self.filter.matches_tx(tx))
+            .collect())
+    }
+
+    /// The events of the next block that match the filter, in the order in which they were emitted.
+    ///
+    /// Blocks without any match yield an empty list.
+    pub async fn next_events(&mut self) -> Result<Vec<StreamedEvent>, GatewayError> {
+        let block = self.next_block().await?;
+        let mut events = Vec::new();
+        for tx in &block.transactions {
+            for event in tx_events(tx) {
+                if self.filter.matches(event) {
+                    events.push(StreamedEvent {
+                        block_nonce: block.nonce,
+                        tx_hash: tx.hash.clone().unwrap_or_default(),
+                        event: event.clone(),
+                    });
+                }
+            }
+        }
+        Ok(events)
+    }
+}
+
+fn tx_events(tx: &TransactionOnNetwork) -> impl Iterator<Item = &Events> {
+    tx.logs.iter().flat_map(|logs| logs.events.iter())
+}
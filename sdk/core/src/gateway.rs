@@ -6,14 +6,17 @@ mod gateway_block;
 mod gateway_chain_simulator_blocks;
 mod gateway_chain_simulator_send_funds;
 mod gateway_chain_simulator_set_state;
+mod gateway_error;
 mod gateway_network_config;
 mod gateway_network_economics;
 mod gateway_network_status;
+mod gateway_retry_policy;
 mod gateway_tx_cost;
 mod gateway_tx_info;
 mod gateway_tx_process_status;
 mod gateway_tx_send;
 mod gateway_tx_send_multi;
+mod gateway_tx_simulate;
 mod gateway_tx_status;
 mod gateway_tx_vmquery;
 
@@ -25,14 +28,17 @@ pub use gateway_block::GetHyperBlockRequest;
 pub use gateway_chain_simulator_blocks::ChainSimulatorGenerateBlocksRequest;
 pub use gateway_chain_simulator_send_funds::ChainSimulatorSendFundsRequest;
 pub use gateway_chain_simulator_set_state::{ChainSimulatorSetStateRequest, SetStateAccount};
+pub use gateway_error::{process_gateway_http_response, process_gateway_response, GatewayError};
 pub use gateway_network_config::NetworkConfigRequest;
 pub use gateway_network_economics::NetworkEconimicsRequest;
 pub use gateway_network_status::NetworkStatusRequest;
+pub use gateway_retry_policy::RetryPolicy;
 pub use gateway_tx_cost::GetTxCost;
 pub use gateway_tx_info::GetTxInfo;
 pub use gateway_tx_process_status::GetTxProcessStatus;
 pub use gateway_tx_send::SendTxRequest;
 pub use gateway_tx_send_multi::SendMultiTxRequest;
+pub use gateway_tx_simulate::SimulateTxRequest;
 pub use gateway_tx_status::GetTxStatus;
 pub use gateway_tx_vmquery::VMQueryRequest;
 
@@ -53,8 +59,10 @@ const GET_HYPER_BLOCK_BY_HASH_ENDPOINT: &str = "hyperblock/by-hash";
 const COST_TRANSACTION_ENDPOINT: &str = "transaction/cost";
 const SEND_TRANSACTION_ENDPOINT: &str = "transaction/send";
 const SEND_MULTIPLE_TRANSACTIONS_ENDPOINT: &str = "transaction/send-multiple";
+const SIMULATE_TRANSACTION_ENDPOINT: &str = "transaction/simulate";
 const GET_TRANSACTION_INFO_ENDPOINT: &str = "transaction";
 const WITH_RESULTS_QUERY_PARAM: &str = "?withResults=true";
+const WITH_LOGS_QUERY_PARAM: &str = "?withLogs=true";
 const VM_VALUES_ENDPOINT: &str = "vm-values/query";
 
 const SEND_USER_FUNDS_ENDPOINT: &str = "transaction/send-user-funds";
@@ -86,6 +94,13 @@ pub trait GatewayRequest {
         None
     }
 
+    /// Whether the request can safely be sent again, if it fails.
+    ///
+    /// Requests that send transactions or funds are not, a retry could execute them twice.
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+
     fn process_json(&self, decoded: Self::DecodedJson) -> anyhow::Result<Self::Result>;
 }
 
@@ -98,7 +113,7 @@ pub trait GatewayAsyncService {
     fn request<G>(
         &self,
         request: G,
-    ) -> impl std::future::Future<Output = anyhow::Result<G::Result>>
+    ) -> impl std::future::Future<Output = Result<G::Result, GatewayError>>
     where
         G: GatewayRequest;
 
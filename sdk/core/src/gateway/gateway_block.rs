@@ -3,7 +3,7 @@ use anyhow::anyhow;
 
 use super::{
     GatewayRequest, GatewayRequestType, GET_HYPER_BLOCK_BY_HASH_ENDPOINT,
-    GET_HYPER_BLOCK_BY_NONCE_ENDPOINT,
+    GET_HYPER_BLOCK_BY_NONCE_ENDPOINT, WITH_LOGS_QUERY_PARAM,
 };
 
 /// Retrieves the data of a hyper block.
@@ -23,6 +23,12 @@ impl GetHyperBlockRequest {
             query: format!("{GET_HYPER_BLOCK_BY_HASH_ENDPOINT}/{hash}"),
         }
     }
+
+    /// Also retrieves the logs of the transactions in the block.
+    pub fn with_logs(mut self) -> Self {
+        self.query.push_str(WITH_LOGS_QUERY_PARAM);
+        self
+    }
 }
 
 impl GatewayRequest for GetHyperBlockRequest {
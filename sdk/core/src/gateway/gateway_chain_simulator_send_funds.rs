@@ -30,6 +30,10 @@ impl GatewayRequest for ChainSimulatorSendFundsRequest {
         GatewayRequestType::Post
     }
 
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+
     fn get_endpoint(&self) -> String {
         SEND_USER_FUNDS_ENDPOINT.to_owned()
     }
@@ -0,0 +1,125 @@
+use std::fmt;
+
+use serde_json::Value;
+
+use super::GatewayRequest;
+
+const GATEWAY_CODE_SYSTEM_BUSY: &str = "system_busy";
+
+/// All the ways in which a gateway request can fail.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GatewayError {
+    /// The request could not be sent, or the response could not be received.
+    Transport(String),
+
+    /// No response arrived within the configured timeout.
+    Timeout,
+
+    /// The gateway answered with a non-success HTTP status, and without a gateway error in the body.
+    HttpStatus { status: u16, body: String },
+
+    /// The gateway answered with an `error` field, and the `code` that came with it.
+    Gateway { code: String, message: String },
+
+    /// The response does not have the expected format.
+    Decode(String),
+}
+
+impl GatewayError {
+    /// Errors that might go away if the request is sent again.
+    ///
+    /// Gateway errors are only retryable if the gateway reports being busy. Internal issues are not,
+    /// since the gateway also reports missing transactions and failed queries this way.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            GatewayError::Transport(_) | GatewayError::Timeout => true,
+            GatewayError::HttpStatus { status, .. } => *status == 429 || *status >= 500,
+            GatewayError::Gateway { code, .. } => code == GATEWAY_CODE_SYSTEM_BUSY,
+            GatewayError::Decode(_) => false,
+        }
+    }
+
+    /// The message sent by the gateway, if it sent one.
+    pub fn gateway_message(&self) -> Option<&str> {
+        match self {
+            GatewayError::Gateway { message, .. } => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for GatewayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GatewayError::Transport(message) => write!(f, "gateway transport error: {message}"),
+            GatewayError::Timeout => write!(f, "gateway request timed out"),
+            GatewayError::HttpStatus { status, body } => {
+                write!(f, "gateway responded with HTTP status {status}: {body}")
+            },
+            GatewayError::Gateway { code, message } => write!(f, "{message} (code: {code})"),
+            GatewayError::Decode(message) => write!(f, "invalid gateway response: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for GatewayError {}
+
+impl From<serde_json::Error> for GatewayError {
+    fn from(err: serde_json::Error) -> Self {
+        GatewayError::Decode(err.to_string())
+    }
+}
+
+/// Decodes a raw gateway response into the result of the request.
+///
+/// A non-empty `error` field is reported as a [`GatewayError::Gateway`], regardless of the rest of the response.
+pub fn process_gateway_response<G>(request: &G, response: Value) -> Result<G::Result, GatewayError>
+where
+    G: GatewayRequest,
+{
+    let code = response
+        .get("code")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_owned();
+    if let Some(message) = response.get("error").and_then(Value::as_str) {
+        if !message.is_empty() {
+            return Err(GatewayError::Gateway {
+                code,
+                message: message.to_owned(),
+            });
+        }
+    }
+
+    let decoded = serde_json::from_value::<G::DecodedJson>(response)?;
+    request
+        .process_json(decoded)
+        .map_err(|err| GatewayError::Gateway {
+            code,
+            message: err.to_string(),
+        })
+}
+
+/// Decodes the body of an HTTP gateway response, taking its status into account.
+///
+/// Failed responses only become [`GatewayError::HttpStatus`] if their body holds no gateway error.
+pub fn process_gateway_http_response<G>(
+    request: &G,
+    status: u16,
+    body: String,
+) -> Result<G::Result, GatewayError>
+where
+    G: GatewayRequest,
+{
+    if !(200..300).contains(&status) {
+        if let Ok(json) = serde_json::from_str(&body) {
+            if let Err(err @ GatewayError::Gateway { .. }) = process_gateway_response(request, json)
+            {
+                return Err(err);
+            }
+        }
+        return Err(GatewayError::HttpStatus { status, body });
+    }
+
+    process_gateway_response(request, serde_json::from_str(&body)?)
+}
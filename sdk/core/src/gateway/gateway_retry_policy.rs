@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use super::GatewayError;
+
+/// Decides whether, and after how long, a failed gateway request gets sent again.
+///
+/// The delay between attempts grows exponentially, from `initial_backoff_ms` up to `max_backoff_ms`.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// How many times a request can be sent again, after the first attempt.
+    pub max_retries: usize,
+    pub initial_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+    pub backoff_multiplier: u64,
+    /// Limits how long a single attempt can take. No limit if `None`.
+    pub timeout: Option<Duration>,
+    /// Picks the errors worth retrying. By default, [`GatewayError::is_retryable`].
+    pub retry_if: fn(&GatewayError) -> bool,
+}
+
+impl RetryPolicy {
+    /// Every request gets sent exactly once.
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    /// The policy used to wait for a transaction to be processed.
+    pub fn tx_retrieval() -> Self {
+        RetryPolicy {
+            max_retries: 7,
+            initial_backoff_ms: 1400,
+            max_backoff_ms: 6000,
+            retry_if: |_| true,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn with_backoff(mut self, initial_backoff_ms: u64, max_backoff_ms: u64) -> Self {
+        self.initial_backoff_ms = initial_backoff_ms;
+        self.max_backoff_ms = max_backoff_ms;
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_retry_if(mut self, retry_if: fn(&GatewayError) -> bool) -> Self {
+        self.retry_if = retry_if;
+        self
+    }
+
+    /// The delay before the next attempt, or `None` if the request should not be sent again.
+    ///
+    /// `retry` counts the retries already performed, so it is 0 after the first failed attempt.
+    pub fn next_backoff_ms(&self, retry: usize, err: &GatewayError) -> Option<u64> {
+        if retry >= self.max_retries || !(self.retry_if)(err) {
+            return None;
+        }
+
+        let factor = self.backoff_multiplier.saturating_pow(retry as u32);
+        Some(
+            self.initial_backoff_ms
+                .saturating_mul(factor)
+                .min(self.max_backoff_ms),
+        )
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            initial_backoff_ms: 500,
+            max_backoff_ms: 5000,
+            backoff_multiplier: 2,
+            timeout: None,
+            retry_if: GatewayError::is_retryable,
+        }
+    }
+}
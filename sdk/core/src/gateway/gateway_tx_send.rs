@@ -15,6 +15,10 @@ impl GatewayRequest for SendTxRequest<'_> {
         GatewayRequestType::Post
     }
 
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+
     fn get_payload(&self) -> Option<&Self::Payload> {
         Some(self.0)
     }
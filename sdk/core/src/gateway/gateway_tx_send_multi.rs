@@ -16,6 +16,10 @@ impl GatewayRequest for SendMultiTxRequest<'_> {
         GatewayRequestType::Post
     }
 
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+
     fn get_payload(&self) -> Option<&Self::Payload> {
         Some(self.0)
     }
@@ -0,0 +1,34 @@
+use crate::data::transaction::{ResponseSimulateTx, SimulationResults, Transaction};
+use anyhow::anyhow;
+
+use super::{GatewayRequest, GatewayRequestType, SIMULATE_TRANSACTION_ENDPOINT};
+
+/// Executes a signed transaction without committing its effects to the chain.
+///
+/// Note: it is a POST request.
+pub struct SimulateTxRequest<'a>(pub &'a Transaction);
+
+impl GatewayRequest for SimulateTxRequest<'_> {
+    type Payload = Transaction;
+    type DecodedJson = ResponseSimulateTx;
+    type Result = SimulationResults;
+
+    fn request_type(&self) -> GatewayRequestType {
+        GatewayRequestType::Post
+    }
+
+    fn get_payload(&self) -> Option<&Self::Payload> {
+        Some(self.0)
+    }
+
+    fn get_endpoint(&self) -> String {
+        SIMULATE_TRANSACTION_ENDPOINT.to_owned()
+    }
+
+    fn process_json(&self, decoded: Self::DecodedJson) -> anyhow::Result<Self::Result> {
+        match decoded.data {
+            None => Err(anyhow!("{}", decoded.error)),
+            Some(b) => Ok(b.result),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 pub mod bech32;
 pub mod crypto;
 pub mod data;
+pub mod event_stream;
 pub mod gateway;
 pub mod retrieve_tx_on_network;
 pub mod test_wallets;
@@ -8,4 +9,8 @@ pub mod utils;
 pub mod wallet;
 
 pub use dharitri_chain_core as chain_core;
-pub use retrieve_tx_on_network::retrieve_tx_on_network;
+pub use event_stream::{BlockStream, EventFilter};
+pub use retrieve_tx_on_network::{
+    retrieve_tx_on_network, retrieve_tx_on_network_with_policy, simulated_tx_on_network,
+    try_retrieve_tx_on_network,
+};
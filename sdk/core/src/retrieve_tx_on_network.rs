@@ -1,88 +1,155 @@
 use crate::{
     data::{
         sdk_address::SdkAddress,
-        transaction::{ApiLogs, Events, LogData, TransactionOnNetwork},
+        transaction::{
+            ApiLogs, Events, LogData, SimulationResults, Transaction, TransactionOnNetwork,
+        },
     },
-    gateway::{GetTxInfo, GetTxProcessStatus},
+    gateway::{GatewayError, GetTxInfo, GetTxProcessStatus, RetryPolicy},
 };
-use log::info;
 use dharitri_chain_core::types::{Address, ReturnCode};
+use log::info;
 
 use crate::gateway::GatewayAsyncService;
 
-const INITIAL_BACKOFF_DELAY: u64 = 1400;
-const MAX_RETRIES: usize = 8;
-const MAX_BACKOFF_DELAY: u64 = 6000;
 const LOG_IDENTIFIER_SIGNAL_ERROR: &str = "signalError";
+const SC_DEPLOY_PROCESSING_TYPE: &str = "SCDeployment";
 
 /// Retrieves a transaction from the network.
+///
+/// If the transaction cannot be retrieved, a failed transaction with [`ReturnCode::NetworkTimeout`] is returned instead.
+/// Use [`try_retrieve_tx_on_network`] to get the gateway error.
 pub async fn retrieve_tx_on_network<GatewayProxy: GatewayAsyncService>(
     proxy: &GatewayProxy,
     tx_hash: String,
 ) -> (TransactionOnNetwork, ReturnCode) {
-    let mut retries = 0;
-    let mut backoff_delay = INITIAL_BACKOFF_DELAY;
     let start_time = proxy.now();
+    match try_retrieve_tx_on_network(proxy, tx_hash).await {
+        Ok(processed_tx) => processed_tx,
+        Err(_) => {
+            println!(
+                "Fetching transaction failed and retries exhausted, returning default transaction. Total elapsed time: {:?}s",
+                proxy.elapsed_seconds(&start_time)
+            );
+
+            let error_message = ReturnCode::message(ReturnCode::NetworkTimeout);
+            (create_tx_failed(error_message), ReturnCode::NetworkTimeout)
+        },
+    }
+}
+
+/// Retrieves a transaction from the network, or the gateway error that prevented it.
+///
+/// A transaction that was processed, but failed, is not an error here: it comes with its return code.
+pub async fn try_retrieve_tx_on_network<GatewayProxy: GatewayAsyncService>(
+    proxy: &GatewayProxy,
+    tx_hash: String,
+) -> Result<(TransactionOnNetwork, ReturnCode), GatewayError> {
+    retrieve_tx_on_network_with_policy(proxy, tx_hash, &RetryPolicy::tx_retrieval()).await
+}
+
+/// Retrieves a transaction from the network, retrying failed requests according to the given policy.
+///
+/// Once the retries are exhausted, the last gateway error is returned.
+pub async fn retrieve_tx_on_network_with_policy<GatewayProxy: GatewayAsyncService>(
+    proxy: &GatewayProxy,
+    tx_hash: String,
+    retry_policy: &RetryPolicy,
+) -> Result<(TransactionOnNetwork, ReturnCode), GatewayError> {
+    let mut retries = 0;
 
     loop {
-        match proxy.request(GetTxProcessStatus::new(&tx_hash)).await {
-            Ok((status, reason)) => {
-                // checks if transaction status is final
-                match status.as_str() {
-                    "success" => {
-                        // retrieve transaction info with results
-                        let transaction_info_with_results = proxy
-                            .request(GetTxInfo::new(&tx_hash).with_results())
-                            .await
-                            .unwrap();
-
-                        info!(
-                            "Transaction retrieved successfully, with status {}: {:#?}",
-                            status, transaction_info_with_results
-                        );
-                        return (transaction_info_with_results, ReturnCode::Success);
-                    },
-                    "fail" => {
-                        let (error_code, error_message) = parse_reason(&reason);
-                        let failed_transaction_info: TransactionOnNetwork =
-                            create_tx_failed(&error_message);
-
-                        info!(
-                            "Transaction failed with error code: {} and message: {error_message}",
-                            error_code.as_u64()
-                        );
-                        return (failed_transaction_info, error_code);
-                    },
-                    _ => {
-                        continue;
-                    },
-                }
+        match fetch_processed_tx(proxy, &tx_hash).await {
+            Ok(Some(processed_tx)) => return Ok(processed_tx),
+            Ok(None) => {
+                continue;
             },
-            Err(err) => {
-                retries += 1;
-                if retries >= MAX_RETRIES {
+            Err(err) => match retry_policy.next_backoff_ms(retries, &err) {
+                Some(backoff_time) => {
+                    retries += 1;
+                    proxy.sleep(backoff_time).await;
+                },
+                None => {
                     info!("Transaction failed, max retries exceeded: {}", err);
                     println!("Transaction failed, max retries exceeded: {}", err);
-                    break;
-                }
-
-                let backoff_time = backoff_delay.min(MAX_BACKOFF_DELAY);
-                proxy.sleep(backoff_time).await;
-                backoff_delay *= 2; // exponential backoff
+                    return Err(err);
+                },
             },
         }
     }
+}
 
-    // retries have been exhausted
-    println!(
-            "Fetching transaction failed and retries exhausted, returning default transaction. Total elapsed time: {:?}s",
-            proxy.elapsed_seconds(&start_time)
-        );
+/// Retrieves the transaction, if its status is final.
+async fn fetch_processed_tx<GatewayProxy: GatewayAsyncService>(
+    proxy: &GatewayProxy,
+    tx_hash: &str,
+) -> Result<Option<(TransactionOnNetwork, ReturnCode)>, GatewayError> {
+    let (status, reason) = proxy.request(GetTxProcessStatus::new(tx_hash)).await?;
+
+    // checks if transaction status is final
+    match status.as_str() {
+        "success" => {
+            // retrieve transaction info with results
+            let transaction_info_with_results = proxy
+                .request(GetTxInfo::new(tx_hash).with_results())
+                .await?;
+
+            info!(
+                "Transaction retrieved successfully, with status {}: {:#?}",
+                status, transaction_info_with_results
+            );
+            Ok(Some((transaction_info_with_results, ReturnCode::Success)))
+        },
+        "fail" => {
+            let (error_code, error_message) = parse_reason(&reason);
+            let failed_transaction_info: TransactionOnNetwork = create_tx_failed(&error_message);
+
+            info!(
+                "Transaction failed with error code: {} and message: {error_message}",
+                error_code.as_u64()
+            );
+            Ok(Some((failed_transaction_info, error_code)))
+        },
+        _ => Ok(None),
+    }
+}
+
+/// Describes a simulated transaction the same way as a processed one, so that its results can be parsed as usual.
+///
+/// The simulation does not report the gas used, it has to be filled in separately.
+pub fn simulated_tx_on_network(
+    tx: &Transaction,
+    simulation: SimulationResults,
+) -> (TransactionOnNetwork, ReturnCode) {
+    if !simulation.is_success() {
+        let (error_code, error_message) = parse_reason(&simulation.fail_reason);
+        return (create_tx_failed(&error_message), error_code);
+    }
 
-    let error_message = ReturnCode::message(ReturnCode::NetworkTimeout);
-    let failed_transaction: TransactionOnNetwork = create_tx_failed(error_message);
+    let processing_type = if tx.receiver.0.is_zero() {
+        SC_DEPLOY_PROCESSING_TYPE
+    } else {
+        ""
+    };
 
-    (failed_transaction, ReturnCode::NetworkTimeout)
+    let tx_on_network = TransactionOnNetwork {
+        kind: "normal".to_string(),
+        hash: Some(simulation.hash).filter(|hash| !hash.is_empty()),
+        nonce: tx.nonce,
+        value: tx.value.clone(),
+        receiver: tx.receiver.clone(),
+        sender: tx.sender.clone(),
+        gas_price: tx.gas_price,
+        gas_limit: tx.gas_limit,
+        signature: tx.signature.clone().unwrap_or_default(),
+        processing_type_on_destination: processing_type.to_string(),
+        data: tx.data.clone(),
+        status: simulation.status,
+        smart_contract_results: simulation.sc_results.into_values().collect(),
+        logs: simulation.logs,
+        ..Default::default()
+    };
+    (tx_on_network, ReturnCode::Success)
 }
 
 pub fn parse_reason(reason: &str) -> (ReturnCode, String) {
@@ -1,5 +1,11 @@
 extern crate rand;
 
+mod mnemonic_wallet;
+mod native_auth;
+
+pub use mnemonic_wallet::MnemonicWallet;
+pub use native_auth::{NativeAuthToken, NativeAuthValidator};
+
 use core::str;
 use std::{
     fs::{self},
@@ -7,7 +13,7 @@ use std::{
 };
 
 use aes::{cipher::KeyIvInit, Aes128};
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use bip39::{Language, Mnemonic};
 use ctr::{cipher::StreamCipher, Ctr128BE};
 use hmac::{Hmac, Mac};
@@ -15,9 +21,7 @@ use dharitri_chain_core::types::Address;
 use pbkdf2::pbkdf2;
 use rand::RngCore;
 use scrypt::{scrypt, Params};
-use serde_json::json;
-use sha2::{Digest, Sha256, Sha512};
-use sha3::Keccak256;
+use sha2::{Sha256, Sha512};
 use zeroize::Zeroize;
 
 use crate::{
@@ -141,6 +145,23 @@ impl Wallet {
         Ok(Self { priv_key: pri_key })
     }
 
+    /// Loads all the keys of a PEM file holding several of them, one after the other.
+    pub fn from_multi_pem_file(file_path: &str) -> Result<Vec<Self>> {
+        let contents = std::fs::read_to_string(file_path)?;
+        Self::from_multi_pem_file_contents(contents)
+    }
+
+    pub fn from_multi_pem_file_contents(contents: String) -> Result<Vec<Self>> {
+        pem::parse_many(contents)?
+            .iter()
+            .map(|pem| {
+                let priv_key_str = std::str::from_utf8(&pem.contents()[..PRIVATE_KEY_LENGTH])?;
+                let priv_key = PrivateKey::from_hex_str(priv_key_str)?;
+                Ok(Self { priv_key })
+            })
+            .collect()
+    }
+
     pub fn get_pem_decoded_content(file: &str) -> Vec<u8> {
         let pem_content = fs::read_to_string(file).unwrap();
         let lines: Vec<&str> = pem_content.split('\n').collect();
@@ -202,23 +223,53 @@ impl Wallet {
     }
 
     pub fn to_address(&self) -> Address {
-        PublicKey::from(&self.priv_key).to_address()
+        self.public_key().to_address()
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey::from(&self.priv_key)
+    }
+
+    /// Signs an off-chain message, e.g. for login-with-wallet. The standard message prefix is added.
+    pub fn sign_message(&self, message: &[u8]) -> [u8; 64] {
+        self.priv_key.sign_message(message)
     }
 
     pub fn sign_tx(&self, unsign_tx: &Transaction) -> [u8; 64] {
-        let mut unsign_tx = unsign_tx.clone();
-        unsign_tx.signature = None;
+        self.priv_key.sign(unsign_tx.signing_bytes())
+    }
 
-        let mut tx_bytes = json!(unsign_tx).to_string().as_bytes().to_vec();
+    /// Co-signs a guarded transaction. The wallet must be the guardian set in the transaction.
+    pub fn sign_tx_as_guardian(&self, tx: &Transaction) -> Result<[u8; 64]> {
+        if !tx.is_guarded() {
+            return Err(anyhow!("transaction is not guarded"));
+        }
+        if tx.guardian.as_ref().map(|guardian| &guardian.0) != Some(&self.to_address()) {
+            return Err(anyhow!("wallet is not the transaction guardian"));
+        }
+        Ok(self.sign_tx(tx))
+    }
 
-        let should_sign_on_tx_hash = unsign_tx.version >= 2 && unsign_tx.options & 1 > 0;
-        if should_sign_on_tx_hash {
-            let mut h = Keccak256::new();
-            h.update(tx_bytes);
-            tx_bytes = h.finalize().as_slice().to_vec();
+    /// Co-signs a relayed v3 transaction. The wallet must be the relayer set in the transaction.
+    pub fn sign_tx_as_relayer(&self, tx: &Transaction) -> Result<[u8; 64]> {
+        if tx.relayer.as_ref().map(|relayer| &relayer.0) != Some(&self.to_address()) {
+            return Err(anyhow!("wallet is not the transaction relayer"));
         }
+        Ok(self.sign_tx(tx))
+    }
 
-        self.priv_key.sign(tx_bytes)
+    /// Checks the signatures of the sender, and also of the guardian and the relayer, if any.
+    pub fn verify_tx_signatures(tx: &Transaction) -> Result<()> {
+        verify_tx_signature(tx, "sender", Some(&tx.sender.0), &tx.signature)?;
+        if tx.is_guarded() {
+            let guardian = tx.guardian.as_ref().map(|guardian| &guardian.0);
+            verify_tx_signature(tx, "guardian", guardian, &tx.guardian_signature)?;
+        }
+        if tx.is_relayed() {
+            let relayer = tx.relayer.as_ref().map(|relayer| &relayer.0);
+            verify_tx_signature(tx, "relayer", relayer, &tx.relayer_signature)?;
+        }
+        Ok(())
     }
 
     pub fn get_keystore_password() -> String {
@@ -299,6 +350,21 @@ impl Wallet {
         public_key: &str,
         password: &str,
     ) -> String {
+        let keystore = Keystore {
+            crypto: Self::encrypt_keystore_crypto(data, password),
+            id: Uuid::new_v4().to_string(),
+            version: KEYSTORE_VERSION,
+            kind: KEYSTORE_KIND_SECRET_KEY.to_string(),
+            address: public_key.to_string(),
+            bech32: crate::bech32::encode(address),
+        };
+
+        let mut keystore_json: String = serde_json::to_string_pretty(&keystore).unwrap();
+        keystore_json.push('\n');
+        keystore_json
+    }
+
+    pub(crate) fn encrypt_keystore_crypto(data: &[u8], password: &str) -> Crypto {
         let params = Params::new((KDF_N as f64).log2() as u8, KDF_R, KDF_P, KDF_DKLEN).unwrap();
         let mut rand_salt: [u8; 32] = [0u8; 32];
         rand::thread_rng().fill_bytes(&mut rand_salt);
@@ -325,31 +391,20 @@ impl Wallet {
         let mut h = HmacSha256::new_from_slice(&derived_key_second_half).unwrap();
         h.update(&ciphertext);
         let mac = h.finalize().into_bytes();
-        let keystore = Keystore {
-            crypto: Crypto {
-                cipher: CIPHER_ALGORITHM_AES_128_CTR.to_string(),
-                cipherparams: CryptoParams { iv: iv_hex },
-                ciphertext: hex::encode(&ciphertext),
-                kdf: KDF_SCRYPT.to_string(),
-                kdfparams: KdfParams {
-                    salt: salt_hex,
-                    n: KDF_N,
-                    r: KDF_R,
-                    p: KDF_P,
-                    dklen: KDF_DKLEN as u32,
-                },
-                mac: hex::encode(mac),
+        Crypto {
+            cipher: CIPHER_ALGORITHM_AES_128_CTR.to_string(),
+            cipherparams: CryptoParams { iv: iv_hex },
+            ciphertext: hex::encode(&ciphertext),
+            kdf: KDF_SCRYPT.to_string(),
+            kdfparams: KdfParams {
+                salt: salt_hex,
+                n: KDF_N,
+                r: KDF_R,
+                p: KDF_P,
+                dklen: KDF_DKLEN as u32,
             },
-            id: Uuid::new_v4().to_string(),
-            version: KEYSTORE_VERSION,
-            kind: "secretKey".to_string(),
-            address: public_key.to_string(),
-            bech32: crate::bech32::encode(address),
-        };
-
-        let mut keystore_json: String = serde_json::to_string_pretty(&keystore).unwrap();
-        keystore_json.push('\n');
-        keystore_json
+            mac: hex::encode(mac),
+        }
     }
 
     pub fn generate_pem_content(address: &Address, private_key: &str, public_key: &str) -> String {
@@ -372,3 +427,19 @@ impl Wallet {
         pem_content
     }
 }
+
+fn verify_tx_signature(
+    tx: &Transaction,
+    signer_role: &str,
+    signer: Option<&Address>,
+    signature: &Option<String>,
+) -> Result<()> {
+    let signer = signer.ok_or_else(|| anyhow!("missing {signer_role}"))?;
+    let signature = signature
+        .as_ref()
+        .ok_or_else(|| anyhow!("missing {signer_role} signature"))?;
+    if !PublicKey::from(signer).verify_tx(tx, &hex::decode(signature)?) {
+        return Err(anyhow!("invalid {signer_role} signature"));
+    }
+    Ok(())
+}
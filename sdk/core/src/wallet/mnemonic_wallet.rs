@@ -0,0 +1,130 @@
+use std::{fs, ops::Range};
+
+use anyhow::{anyhow, Result};
+use bip39::Mnemonic;
+use dharitri_chain_core::types::Address;
+use uuid::Uuid;
+
+use super::Wallet;
+use crate::{
+    crypto::public_key::PublicKey,
+    data::keystore::{Keystore, KEYSTORE_KIND_MNEMONIC, KEYSTORE_VERSION},
+};
+
+/// Hierarchical deterministic wallet: derives any number of accounts from a single mnemonic.
+///
+/// The accounts are derived on the standard path, `m/44'/508'/account'/0'/address_index'`.
+/// Most tools only use the account 0 and iterate over the address index, which is also what this wallet does,
+/// unless configured otherwise.
+#[derive(Clone, Debug)]
+pub struct MnemonicWallet {
+    mnemonic: Mnemonic,
+    account: u32,
+}
+
+impl MnemonicWallet {
+    pub fn new(mnemonic: Mnemonic) -> Self {
+        MnemonicWallet {
+            mnemonic,
+            account: 0,
+        }
+    }
+
+    /// Generates a new random 24-word mnemonic.
+    pub fn generate() -> Self {
+        Self::new(Wallet::generate_mnemonic())
+    }
+
+    pub fn from_mnemonic_str(mnemonic: &str) -> Result<Self> {
+        let mnemonic = Mnemonic::parse_normalized(mnemonic.trim())?;
+        Ok(Self::new(mnemonic))
+    }
+
+    /// Loads the mnemonic from a keystore of kind `mnemonic`.
+    pub fn from_keystore(file_path: &str, password: &str) -> Result<Self> {
+        let keystore: Keystore = serde_json::from_str(&fs::read_to_string(file_path)?)?;
+        if keystore.kind != KEYSTORE_KIND_MNEMONIC {
+            return Err(anyhow!(
+                "expected a keystore of kind {KEYSTORE_KIND_MNEMONIC}, got {}",
+                keystore.kind
+            ));
+        }
+        let decryption_params = Wallet::validate_keystore_password(file_path, password.to_string())
+            .map_err(|err| anyhow!("cannot decrypt keystore: {err:?}"))?;
+        let mnemonic = String::from_utf8(Wallet::decrypt_secret_key(decryption_params))?;
+        Self::from_mnemonic_str(&mnemonic)
+    }
+
+    /// Derives on another account, instead of the default account 0.
+    pub fn with_account(mut self, account: u32) -> Self {
+        self.account = account;
+        self
+    }
+
+    pub fn mnemonic(&self) -> &Mnemonic {
+        &self.mnemonic
+    }
+
+    pub fn account(&self) -> u32 {
+        self.account
+    }
+
+    pub fn wallet(&self, address_index: u32) -> Wallet {
+        Wallet {
+            priv_key: Wallet::get_private_key_from_mnemonic(
+                self.mnemonic.clone(),
+                self.account,
+                address_index,
+            ),
+        }
+    }
+
+    pub fn wallets(&self, address_indices: Range<u32>) -> Vec<Wallet> {
+        address_indices.map(|index| self.wallet(index)).collect()
+    }
+
+    pub fn address(&self, address_index: u32) -> Address {
+        self.wallet(address_index).to_address()
+    }
+
+    pub fn addresses(&self, address_indices: Range<u32>) -> Vec<Address> {
+        address_indices.map(|index| self.address(index)).collect()
+    }
+
+    /// Exports the keys of the given addresses in a single PEM file, one after the other.
+    ///
+    /// Can be read back with [`Wallet::from_multi_pem_file`].
+    pub fn to_multi_pem(&self, address_indices: Range<u32>) -> String {
+        address_indices
+            .map(|index| {
+                let private_key = Wallet::get_private_key_from_mnemonic(
+                    self.mnemonic.clone(),
+                    self.account,
+                    index,
+                );
+                let public_key = PublicKey::from(&private_key);
+                Wallet::generate_pem_content(
+                    &public_key.to_address(),
+                    &private_key.to_string(),
+                    &public_key.to_string(),
+                )
+            })
+            .collect()
+    }
+
+    /// Encrypts the mnemonic in a keystore of kind `mnemonic`, which holds no address of its own.
+    pub fn encrypt_keystore(&self, password: &str) -> String {
+        let keystore = Keystore {
+            crypto: Wallet::encrypt_keystore_crypto(self.mnemonic.to_string().as_bytes(), password),
+            id: Uuid::new_v4().to_string(),
+            version: KEYSTORE_VERSION,
+            kind: KEYSTORE_KIND_MNEMONIC.to_string(),
+            address: String::new(),
+            bech32: String::new(),
+        };
+
+        let mut keystore_json: String = serde_json::to_string_pretty(&keystore).unwrap();
+        keystore_json.push('\n');
+        keystore_json
+    }
+}
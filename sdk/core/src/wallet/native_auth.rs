@@ -0,0 +1,179 @@
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use dharitri_chain_core::types::Address;
+use serde_json::Value;
+
+use super::Wallet;
+use crate::crypto::public_key::PublicKey;
+
+/// Native-auth token, proving that the holder of an address logged in to an origin, without any transaction.
+///
+/// The wallet signs the address followed by the token body, as an off-chain message.
+/// The body references a recent block, which sets the start of the validity period.
+///
+/// Encoded as `base64url(address).base64url(body).hex(signature)`,
+/// where the body is `base64url(origin).blockHash.ttl.base64url(extraInfo)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NativeAuthToken {
+    pub address: Address,
+    pub origin: String,
+    pub block_hash: String,
+    /// Validity, in seconds, counted from the block timestamp.
+    pub ttl: u64,
+    pub extra_info: Value,
+    pub signature: Vec<u8>,
+}
+
+impl NativeAuthToken {
+    pub fn body(&self) -> String {
+        format!(
+            "{}.{}.{}.{}",
+            URL_SAFE_NO_PAD.encode(&self.origin),
+            self.block_hash,
+            self.ttl,
+            URL_SAFE_NO_PAD.encode(self.extra_info.to_string())
+        )
+    }
+
+    /// The message signed by the wallet.
+    pub fn signable_message(&self) -> Vec<u8> {
+        format!("{}{}", crate::bech32::encode(&self.address), self.body()).into_bytes()
+    }
+
+    pub fn encode(&self) -> String {
+        format!(
+            "{}.{}.{}",
+            URL_SAFE_NO_PAD.encode(crate::bech32::encode(&self.address)),
+            URL_SAFE_NO_PAD.encode(self.body()),
+            hex::encode(&self.signature)
+        )
+    }
+
+    /// Parses an encoded token. Does not check the signature, see [`NativeAuthValidator`].
+    pub fn decode(token: &str) -> Result<Self> {
+        let [address, body, signature] = split_parts(token)?;
+        let address = decode_address(&base64url_decode_str(address)?)?;
+        let body = base64url_decode_str(body)?;
+        let [origin, block_hash, ttl, extra_info] = split_parts(&body)?;
+
+        Ok(NativeAuthToken {
+            address,
+            origin: base64url_decode_str(origin)?,
+            block_hash: block_hash.to_string(),
+            ttl: ttl.parse()?,
+            extra_info: serde_json::from_str(&base64url_decode_str(extra_info)?)?,
+            signature: hex::decode(signature)?,
+        })
+    }
+
+    pub fn verify_signature(&self) -> bool {
+        PublicKey::from(&self.address).verify_message(&self.signable_message(), &self.signature)
+    }
+}
+
+impl Wallet {
+    /// Generates a signed native-auth token for the given origin, e.g. `https://example.com`.
+    ///
+    /// The block hash should be the hash of a recent block, usually the latest one.
+    pub fn generate_native_auth_token(
+        &self,
+        origin: &str,
+        block_hash: &str,
+        ttl: u64,
+        extra_info: Value,
+    ) -> NativeAuthToken {
+        let mut token = NativeAuthToken {
+            address: self.to_address(),
+            origin: origin.to_string(),
+            block_hash: block_hash.to_string(),
+            ttl,
+            extra_info,
+            signature: Vec::new(),
+        };
+        token.signature = self.sign_message(&token.signable_message()).to_vec();
+        token
+    }
+}
+
+/// Server-side validation of native-auth tokens.
+///
+/// The timestamp of the block referenced in the token has to be retrieved by the caller, e.g. from the gateway.
+#[derive(Clone, Debug, Default)]
+pub struct NativeAuthValidator {
+    accepted_origins: Vec<String>,
+    max_ttl: Option<u64>,
+}
+
+impl NativeAuthValidator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// If no origin is added, all origins are accepted.
+    pub fn accept_origin(mut self, origin: &str) -> Self {
+        self.accepted_origins.push(origin.to_string());
+        self
+    }
+
+    pub fn max_ttl(mut self, max_ttl: u64) -> Self {
+        self.max_ttl = Some(max_ttl);
+        self
+    }
+
+    /// Checks the signature, the origin and the expiration of the token.
+    ///
+    /// Both timestamps are in seconds.
+    pub fn validate(&self, token: &NativeAuthToken, block_timestamp: u64, now: u64) -> Result<()> {
+        if !token.verify_signature() {
+            return Err(anyhow!("invalid native-auth token signature"));
+        }
+        if !self.accepted_origins.is_empty() && !self.accepted_origins.contains(&token.origin) {
+            return Err(anyhow!("origin not accepted: {}", token.origin));
+        }
+        if let Some(max_ttl) = self.max_ttl {
+            if token.ttl > max_ttl {
+                return Err(anyhow!(
+                    "ttl {} exceeds the maximum of {max_ttl}",
+                    token.ttl
+                ));
+            }
+        }
+        if now > block_timestamp.saturating_add(token.ttl) {
+            return Err(anyhow!("native-auth token expired"));
+        }
+        Ok(())
+    }
+
+    /// Decodes and validates an encoded token, returning it if valid.
+    pub fn validate_encoded(
+        &self,
+        token: &str,
+        block_timestamp: u64,
+        now: u64,
+    ) -> Result<NativeAuthToken> {
+        let token = NativeAuthToken::decode(token)?;
+        self.validate(&token, block_timestamp, now)?;
+        Ok(token)
+    }
+}
+
+fn split_parts<const N: usize>(s: &str) -> Result<[&str; N]> {
+    let parts: Vec<&str> = s.split('.').collect();
+    parts
+        .try_into()
+        .map_err(|_| anyhow!("invalid native-auth token: expected {N} parts"))
+}
+
+/// Some clients keep the base64 padding, so it is ignored.
+fn base64url_decode_str(s: &str) -> Result<String> {
+    let bytes = URL_SAFE_NO_PAD.decode(s.trim_end_matches('='))?;
+    Ok(String::from_utf8(bytes)?)
+}
+
+fn decode_address(bech32_address: &str) -> Result<Address> {
+    let (_hrp, bytes) = bech32::decode(bech32_address)?;
+    if bytes.len() != 32 {
+        return Err(anyhow!("invalid address length: {bech32_address}"));
+    }
+    Ok(Address::from_slice(&bytes))
+}
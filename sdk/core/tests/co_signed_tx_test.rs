@@ -0,0 +1,102 @@
+use dharitri_sdk::{
+    data::transaction::{Transaction, TX_OPTION_GUARDED, TX_OPTION_HASH_SIGN},
+    test_wallets,
+};
+
+fn transfer_tx() -> Transaction {
+    Transaction {
+        nonce: 7,
+        value: "1000".to_string(),
+        receiver: test_wallets::bob().to_address().into(),
+        sender: test_wallets::alice().to_address().into(),
+        gas_price: 1_000_000_000,
+        gas_limit: 50_000,
+        data: None,
+        signature: None,
+        chain_id: "D".to_string(),
+        version: 1,
+        options: 0,
+        guardian: None,
+        guardian_signature: None,
+        relayer: None,
+        relayer_signature: None,
+    }
+}
+
+#[test]
+fn test_set_guardian_and_relayer() {
+    let mut tx = transfer_tx();
+    assert!(!tx.is_guarded());
+    assert!(!tx.is_relayed());
+
+    tx.set_guardian(test_wallets::carol().to_address());
+    tx.set_relayer(test_wallets::dan().to_address());
+    assert!(tx.is_guarded());
+    assert!(tx.is_relayed());
+    assert_eq!(tx.options, TX_OPTION_GUARDED);
+    assert_eq!(tx.version, 2);
+
+    let signing_json = String::from_utf8(tx.signing_bytes()).unwrap();
+    let guardian_pos = signing_json.find("\"guardian\":").unwrap();
+    let relayer_pos = signing_json.find("\"relayer\":").unwrap();
+    assert!(signing_json.find("\"options\":2").unwrap() < guardian_pos);
+    assert!(guardian_pos < relayer_pos);
+}
+
+#[test]
+fn test_signatures_not_signed() {
+    let mut tx = transfer_tx();
+    tx.set_guardian(test_wallets::carol().to_address());
+    tx.set_relayer(test_wallets::dan().to_address());
+    let signing_bytes = tx.signing_bytes();
+
+    let sender_signature = test_wallets::alice().sign_tx(&tx);
+    tx.signature = Some(hex::encode(sender_signature));
+    let guardian_signature = test_wallets::carol().sign_tx_as_guardian(&tx).unwrap();
+    tx.guardian_signature = Some(hex::encode(guardian_signature));
+    let relayer_signature = test_wallets::dan().sign_tx_as_relayer(&tx).unwrap();
+    tx.relayer_signature = Some(hex::encode(relayer_signature));
+
+    // all parties sign the same content, regardless of the signatures already present
+    assert_eq!(tx.signing_bytes(), signing_bytes);
+    assert_eq!(test_wallets::alice().sign_tx(&tx), sender_signature);
+
+    let tx_json = serde_json::to_value(&tx).unwrap();
+    assert_eq!(
+        tx_json["guardianSignature"],
+        hex::encode(guardian_signature)
+    );
+    assert_eq!(tx_json["relayerSignature"], hex::encode(relayer_signature));
+}
+
+#[test]
+fn test_hash_sign_option() {
+    let mut tx = transfer_tx();
+    tx.set_guardian(test_wallets::carol().to_address());
+    tx.options |= TX_OPTION_HASH_SIGN;
+    assert_eq!(tx.signing_bytes().len(), 32);
+}
+
+#[test]
+fn test_sign_as_wrong_guardian() {
+    let mut tx = transfer_tx();
+    tx.set_guardian(test_wallets::carol().to_address());
+    let err = test_wallets::dan().sign_tx_as_guardian(&tx).unwrap_err();
+    assert_eq!(err.to_string(), "wallet is not the transaction guardian");
+}
+
+#[test]
+fn test_sign_as_guardian_unguarded() {
+    let err = test_wallets::carol()
+        .sign_tx_as_guardian(&transfer_tx())
+        .unwrap_err();
+    assert_eq!(err.to_string(), "transaction is not guarded");
+}
+
+#[test]
+fn test_sign_as_missing_relayer() {
+    let err = test_wallets::dan()
+        .sign_tx_as_relayer(&transfer_tx())
+        .unwrap_err();
+    assert_eq!(err.to_string(), "wallet is not the transaction relayer");
+}
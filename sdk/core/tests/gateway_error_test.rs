@@ -0,0 +1,98 @@
+use dharitri_sdk::gateway::{
+    process_gateway_http_response, process_gateway_response, GatewayError, GetTxStatus, RetryPolicy,
+};
+use serde_json::json;
+
+const TX_HASH: &str = "f3c3b3e5ad0b3f7ee8b1a4c6b0f2b6f2d1c1c8e1d7c6f4b1a6d2f6b4e5a3c2d1";
+
+#[test]
+fn process_gateway_response_success_test() {
+    let response = json!({
+        "data": { "status": "success" },
+        "error": "",
+        "code": "successful",
+    });
+    let status = process_gateway_response(&GetTxStatus::new(TX_HASH), response).unwrap();
+    assert_eq!(status, "success");
+}
+
+#[test]
+fn process_gateway_response_error_test() {
+    let response = json!({
+        "data": null,
+        "error": "transaction not found",
+        "code": "internal_issue",
+    });
+    let err = process_gateway_response(&GetTxStatus::new(TX_HASH), response).unwrap_err();
+    assert_eq!(
+        err,
+        GatewayError::Gateway {
+            code: "internal_issue".to_string(),
+            message: "transaction not found".to_string(),
+        }
+    );
+    assert_eq!(err.gateway_message(), Some("transaction not found"));
+    assert!(!err.is_retryable());
+
+    let response = json!({
+        "data": null,
+        "error": "system busy",
+        "code": "system_busy",
+    });
+    let err = process_gateway_response(&GetTxStatus::new(TX_HASH), response).unwrap_err();
+    assert!(err.is_retryable());
+}
+
+#[test]
+fn process_gateway_response_decode_test() {
+    let response = json!({ "data": 5 });
+    let err = process_gateway_response(&GetTxStatus::new(TX_HASH), response).unwrap_err();
+    assert!(matches!(err, GatewayError::Decode(_)));
+    assert!(!err.is_retryable());
+}
+
+#[test]
+fn process_gateway_http_response_status_test() {
+    // the gateway error takes precedence over the status
+    let body = r#"{"data":null,"error":"invalid tx","code":"bad_request"}"#;
+    let err = process_gateway_http_response(&GetTxStatus::new(TX_HASH), 400, body.to_string())
+        .unwrap_err();
+    assert_eq!(err.gateway_message(), Some("invalid tx"));
+    assert!(!err.is_retryable());
+
+    let body = "<html>Bad Gateway</html>";
+    let err = process_gateway_http_response(&GetTxStatus::new(TX_HASH), 502, body.to_string())
+        .unwrap_err();
+    assert_eq!(
+        err,
+        GatewayError::HttpStatus {
+            status: 502,
+            body: body.to_string(),
+        }
+    );
+    assert!(err.is_retryable());
+}
+
+#[test]
+fn retry_policy_backoff_test() {
+    let policy = RetryPolicy::default()
+        .with_max_retries(4)
+        .with_backoff(100, 500);
+    let err = GatewayError::Timeout;
+    assert_eq!(policy.next_backoff_ms(0, &err), Some(100));
+    assert_eq!(policy.next_backoff_ms(1, &err), Some(200));
+    assert_eq!(policy.next_backoff_ms(2, &err), Some(400));
+    assert_eq!(policy.next_backoff_ms(3, &err), Some(500));
+    assert_eq!(policy.next_backoff_ms(4, &err), None);
+
+    let err = GatewayError::Decode("unexpected".to_string());
+    assert_eq!(policy.next_backoff_ms(0, &err), None);
+
+    let policy = policy.with_retry_if(|_| true);
+    assert_eq!(policy.next_backoff_ms(0, &err), Some(100));
+
+    assert_eq!(
+        RetryPolicy::none().next_backoff_ms(0, &GatewayError::Timeout),
+        None
+    );
+}
@@ -0,0 +1,155 @@
+use dharitri_sdk::{
+    crypto::signable_message::message_signing_bytes,
+    data::transaction::Transaction,
+    test_wallets,
+    wallet::{NativeAuthToken, NativeAuthValidator, Wallet},
+};
+use serde_json::json;
+
+const ORIGIN: &str = "https://example.com";
+const BLOCK_HASH: &str = "b3d07565293fd5684c97d2b96eb862d124fd698678f3f95b2515ed07178a27b4";
+const BLOCK_TIMESTAMP: u64 = 1_700_000_000;
+
+#[test]
+fn test_message_signing_bytes() {
+    // keccak256 of "\x19Dharitri Signed Message:\n5hello"
+    assert_eq!(
+        hex::encode(message_signing_bytes(b"hello")),
+        "58ac8720e6ff33c5f4309af9251582f132e9b5c9b0b160b2d1e1fc4bce1c50b7"
+    );
+}
+
+#[test]
+fn test_sign_and_verify_message() {
+    let alice = test_wallets::alice();
+    let signature = alice.sign_message(b"hello");
+
+    assert!(alice.public_key().verify_message(b"hello", &signature));
+    assert!(!alice.public_key().verify_message(b"hello!", &signature));
+    assert!(!test_wallets::bob()
+        .public_key()
+        .verify_message(b"hello", &signature));
+    // the prefix keeps message signatures apart from raw signatures
+    assert!(!alice.public_key().verify(b"hello", &signature));
+    assert!(!alice
+        .public_key()
+        .verify_message(b"hello", &signature[..63]));
+}
+
+fn transfer_tx() -> Transaction {
+    Transaction {
+        nonce: 1,
+        value: "1000".to_string(),
+        receiver: test_wallets::bob().to_address().into(),
+        sender: test_wallets::alice().to_address().into(),
+        gas_price: 1_000_000_000,
+        gas_limit: 50_000,
+        data: None,
+        signature: None,
+        chain_id: "D".to_string(),
+        version: 1,
+        options: 0,
+        guardian: None,
+        guardian_signature: None,
+        relayer: None,
+        relayer_signature: None,
+    }
+}
+
+#[test]
+fn test_verify_tx_signatures() {
+    let mut tx = transfer_tx();
+    assert!(Wallet::verify_tx_signatures(&tx).is_err());
+
+    tx.signature = Some(hex::encode(test_wallets::alice().sign_tx(&tx)));
+    Wallet::verify_tx_signatures(&tx).unwrap();
+    assert!(test_wallets::alice()
+        .public_key()
+        .verify_tx(&tx, &hex::decode(tx.signature.as_ref().unwrap()).unwrap()));
+
+    tx.nonce = 2;
+    let err = Wallet::verify_tx_signatures(&tx).unwrap_err();
+    assert_eq!(err.to_string(), "invalid sender signature");
+}
+
+#[test]
+fn test_verify_co_signed_tx_signatures() {
+    let mut tx = transfer_tx();
+    tx.set_guardian(test_wallets::carol().to_address());
+    tx.set_relayer(test_wallets::dan().to_address());
+    tx.signature = Some(hex::encode(test_wallets::alice().sign_tx(&tx)));
+    tx.guardian_signature = Some(hex::encode(
+        test_wallets::carol().sign_tx_as_guardian(&tx).unwrap(),
+    ));
+
+    let err = Wallet::verify_tx_signatures(&tx).unwrap_err();
+    assert_eq!(err.to_string(), "missing relayer signature");
+
+    tx.relayer_signature = Some(hex::encode(test_wallets::eve().sign_tx(&tx)));
+    let err = Wallet::verify_tx_signatures(&tx).unwrap_err();
+    assert_eq!(err.to_string(), "invalid relayer signature");
+
+    tx.relayer_signature = Some(hex::encode(
+        test_wallets::dan().sign_tx_as_relayer(&tx).unwrap(),
+    ));
+    Wallet::verify_tx_signatures(&tx).unwrap();
+}
+
+#[test]
+fn test_native_auth_token_encode_decode() {
+    let token = test_wallets::alice().generate_native_auth_token(
+        ORIGIN,
+        BLOCK_HASH,
+        86400,
+        json!({ "timestamp": 1_700_000_000 }),
+    );
+    assert!(token.verify_signature());
+    assert!(token.body().starts_with("aHR0cHM6Ly9leGFtcGxlLmNvbQ."));
+
+    let encoded = token.encode();
+    assert_eq!(encoded.split('.').count(), 3);
+    assert_eq!(NativeAuthToken::decode(&encoded).unwrap(), token);
+    assert!(NativeAuthToken::decode("not.a-token").is_err());
+}
+
+#[test]
+fn test_native_auth_validator() {
+    let token = test_wallets::alice()
+        .generate_native_auth_token(ORIGIN, BLOCK_HASH, 600, json!({}))
+        .encode();
+    let validator = NativeAuthValidator::new()
+        .accept_origin(ORIGIN)
+        .max_ttl(3600);
+
+    let validated = validator
+        .validate_encoded(&token, BLOCK_TIMESTAMP, BLOCK_TIMESTAMP + 10)
+        .unwrap();
+    assert_eq!(validated.address, test_wallets::alice().to_address());
+
+    let err = validator
+        .validate_encoded(&token, BLOCK_TIMESTAMP, BLOCK_TIMESTAMP + 601)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "native-auth token expired");
+
+    let err = NativeAuthValidator::new()
+        .accept_origin("https://other.com")
+        .validate_encoded(&token, BLOCK_TIMESTAMP, BLOCK_TIMESTAMP)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "origin not accepted: https://example.com");
+
+    let err = NativeAuthValidator::new()
+        .max_ttl(60)
+        .validate_encoded(&token, BLOCK_TIMESTAMP, BLOCK_TIMESTAMP)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "ttl 600 exceeds the maximum of 60");
+
+    // a token signed by another wallet does not pass for alice
+    let mut forged = NativeAuthToken::decode(&token).unwrap();
+    forged.signature = test_wallets::bob()
+        .sign_message(&forged.signable_message())
+        .to_vec();
+    let err = validator
+        .validate(&forged, BLOCK_TIMESTAMP, BLOCK_TIMESTAMP)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "invalid native-auth token signature");
+}
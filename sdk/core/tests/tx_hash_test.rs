@@ -0,0 +1,55 @@
+use dharitri_sdk::data::transaction::Transaction;
+use serde_json::json;
+
+// transactions from the devnet, along with the hashes the chain registered them under
+
+#[test]
+fn test_compute_hash_v1() {
+    let tx: Transaction = serde_json::from_value(json!({
+        "nonce": 30,
+        "value": "0",
+        "receiver": "drt1qqqqqqqqqqqqqpgq5400a82at6ttplyrdhyn8kk9lhxaed5d0n4scvfa4u",
+        "sender": "drt14r7m6drneg69jyxvxxnrsss6x5gg2cqqwreyhdwanj0fcza0ynnqfwv8kk",
+        "gasPrice": 1000000000,
+        "gasLimit": 25500000,
+        "data": "RVNEVFRyYW5zZmVyQDQ4NTQ0ZDJkNjY2NTMxNjYzNjM5QDBkZTBiNmIzYTc2NDAwMDBANzM3NzYxNzA1NDZmNmI2NTZlNzM0NjY5Nzg2NTY0NDk2ZTcwNzU3NEA1NzQ1NDc0YzQ0MmQ2NDM3NjMzNjYyNjJAMDM3Yzc3OGZjY2U5YzU1Yg==",
+        "signature": "e912fae4b7a9e51ddf316a5e82a0f457d453a62e3c17477f5d6175e1b33c5e92ddb187d65f54cf3131a0603321290279a0456c20778039f2ab09b54e33c60f0d",
+        "chainID": "D",
+        "version": 1
+    }))
+    .unwrap();
+
+    assert_eq!(
+        tx.compute_hash().unwrap(),
+        "d4058bd3c13db0b14832c7c58fe163db2f579ff6544380586b8a27d88b4a5070"
+    );
+}
+
+#[test]
+fn test_compute_hash_v2() {
+    let mut tx: Transaction = serde_json::from_value(json!({
+        "nonce": 49,
+        "value": "0",
+        "receiver": "drt1qqqqqqqqqqqqqpgqshqmekudxlxwp0d9j368etjamr5dw7k45u7qmfcdef",
+        "sender": "drt1uh67c2lkhyj4vh73akv7jky9sfgvus8awwcj64uju69mmfne5u7qhejgak",
+        "gasPrice": 1000000000,
+        "gasLimit": 600000000,
+        "data": "Y2FsbEFub3RoZXJDb250cmFjdFJldHVyblR3b1U2NEAwMDAwMDAwMDAwMDAwMDAwMDUwMEFDRkY2QjdBNEVCODEwMUE4REU3RkY3RjVEMkMwQkYzRTRENjNGNDdBNzND",
+        "signature": "53cc6496647287d735bd7950f4ec79d7b51f884defda1d6d840d722b7d0d869900ccecc01602da7a7c717955e8d4ed0711b92acd980d64ed6eebd6eaed0c4608",
+        "chainID": "D",
+        "version": 2
+    }))
+    .unwrap();
+
+    assert_eq!(
+        tx.compute_hash().unwrap(),
+        "e914857f1bfd003ba411bae372266703e5f706fa412c378feb37faa5e18c3d73"
+    );
+
+    // the signature is part of the hash
+    tx.signature = None;
+    assert_ne!(
+        tx.compute_hash().unwrap(),
+        "e914857f1bfd003ba411bae372266703e5f706fa412c378feb37faa5e18c3d73"
+    );
+}
@@ -1,5 +1,8 @@
 use gloo_net::http::Request;
-use dharitri_sdk::gateway::{GatewayAsyncService, GatewayRequest, GatewayRequestType};
+use dharitri_sdk::gateway::{
+    process_gateway_http_response, GatewayAsyncService, GatewayError, GatewayRequest,
+    GatewayRequestType,
+};
 
 /// Allows communication with the Dharitri gateway API.
 #[derive(Clone, Debug)]
@@ -14,7 +17,7 @@ impl GatewayDappProxy {
 
     /// Performs a request to the gateway.
     /// Can be either GET or POST, depending on the argument.
-    pub async fn http_request<G>(&self, request: G) -> anyhow::Result<G::Result>
+    pub async fn http_request<G>(&self, request: G) -> Result<G::Result, GatewayError>
     where
         G: GatewayRequest,
     {
@@ -25,14 +28,22 @@ impl GatewayDappProxy {
         };
 
         let response = if let Some(payload) = request.get_payload() {
-            request_builder.json(&payload)?.send().await?
+            request_builder
+                .json(&payload)
+                .map_err(|err| GatewayError::Transport(err.to_string()))?
+                .send()
+                .await
         } else {
-            request_builder.send().await?
-        };
-
-        let decoded = response.json::<G::DecodedJson>().await?;
+            request_builder.send().await
+        }
+        .map_err(|err| GatewayError::Transport(err.to_string()))?;
 
-        request.process_json(decoded)
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|err| GatewayError::Transport(err.to_string()))?;
+        process_gateway_http_response(&request, status, body)
     }
 }
 
@@ -43,9 +54,12 @@ impl GatewayAsyncService for GatewayDappProxy {
         Self::new(uri.to_owned())
     }
 
-    fn request<G>(&self, request: G) -> impl std::future::Future<Output = anyhow::Result<G::Result>>
+    fn request<G>(
+        &self,
+        request: G,
+    ) -> impl std::future::Future<Output = Result<G::Result, GatewayError>>
     where
-        G: dharitri_sdk::gateway::GatewayRequest,
+        G: GatewayRequest,
     {
         self.http_request(request)
     }
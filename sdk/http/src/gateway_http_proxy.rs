@@ -6,51 +6,95 @@ mod http_tx;
 
 use std::time::Duration;
 
-use dharitri_sdk::gateway::{GatewayAsyncService, GatewayRequest};
+use dharitri_sdk::gateway::{
+    process_gateway_http_response, GatewayAsyncService, GatewayError, GatewayRequest,
+    GatewayRequestType, RetryPolicy,
+};
 
 /// Allows communication with the Dharitri gateway API.
 #[derive(Clone, Debug)]
 pub struct GatewayHttpProxy {
     pub(crate) proxy_uri: String,
     pub(crate) client: reqwest::Client,
+    pub(crate) retry_policy: RetryPolicy,
 }
 
 impl GatewayHttpProxy {
+    /// Failed requests are not retried, unless a retry policy is set via `with_retry_policy`.
     pub fn new(proxy_uri: String) -> Self {
         Self {
             proxy_uri,
             client: reqwest::Client::new(),
+            retry_policy: RetryPolicy::none(),
         }
     }
 
+    /// Changes how failed requests get retried, and how long each attempt may take.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
     /// Performs a request to the gateway.
     /// Can be either GET or POST, depending on the argument.
-    pub async fn http_request<G>(&self, request: G) -> anyhow::Result<G::Result>
+    ///
+    /// Failed attempts are retried according to the retry policy,
+    /// except for requests that are not idempotent, such as transaction sends.
+    pub async fn http_request<G>(&self, request: G) -> Result<G::Result, GatewayError>
     where
         G: GatewayRequest,
     {
-        let url = format!("{}/{}", self.proxy_uri, request.get_endpoint());
-        let mut request_builder;
-        match request.request_type() {
-            dharitri_sdk::gateway::GatewayRequestType::Get => {
-                request_builder = self.client.get(url);
-            },
-            dharitri_sdk::gateway::GatewayRequestType::Post => {
-                request_builder = self.client.post(url);
-            },
+        let mut retries = 0;
+        loop {
+            let err = match self.http_request_attempt(&request).await {
+                Ok(result) => return Ok(result),
+                Err(err) => err,
+            };
+
+            if !request.is_idempotent() {
+                return Err(err);
+            }
+
+            let Some(backoff_ms) = self.retry_policy.next_backoff_ms(retries, &err) else {
+                return Err(err);
+            };
+            log::info!("gateway request failed, retrying in {backoff_ms} ms: {err}");
+            retries += 1;
+            tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
         }
+    }
+
+    async fn http_request_attempt<G>(&self, request: &G) -> Result<G::Result, GatewayError>
+    where
+        G: GatewayRequest,
+    {
+        let url = format!("{}/{}", self.proxy_uri, request.get_endpoint());
+        let mut request_builder = match request.request_type() {
+            GatewayRequestType::Get => self.client.get(url),
+            GatewayRequestType::Post => self.client.post(url),
+        };
 
         if let Some(payload) = request.get_payload() {
             request_builder = request_builder.json(&payload);
         }
 
-        let decoded = request_builder
-            .send()
-            .await?
-            .json::<G::DecodedJson>()
-            .await?;
+        if let Some(timeout) = self.retry_policy.timeout {
+            request_builder = request_builder.timeout(timeout);
+        }
+
+        let response = request_builder.send().await.map_err(transport_error)?;
+        let status = response.status();
+        let body = response.text().await.map_err(transport_error)?;
 
-        request.process_json(decoded)
+        process_gateway_http_response(request, status.as_u16(), body)
+    }
+}
+
+fn transport_error(err: reqwest::Error) -> GatewayError {
+    if err.is_timeout() {
+        GatewayError::Timeout
+    } else {
+        GatewayError::Transport(err.to_string())
     }
 }
 
@@ -61,9 +105,12 @@ impl GatewayAsyncService for GatewayHttpProxy {
         Self::new(uri.to_owned())
     }
 
-    fn request<G>(&self, request: G) -> impl std::future::Future<Output = anyhow::Result<G::Result>>
+    fn request<G>(
+        &self,
+        request: G,
+    ) -> impl std::future::Future<Output = Result<G::Result, GatewayError>>
     where
-        G: dharitri_sdk::gateway::GatewayRequest,
+        G: GatewayRequest,
     {
         self.http_request(request)
     }
@@ -1,9 +1,8 @@
-use anyhow::Result;
 use dharitri_sdk::{
     chain_core::types::Address,
     data::{account::Account, dcdt::DcdtBalance},
     gateway::{
-        GetAccountDcdtRolesRequest, GetAccountDcdtTokensRequest, GetAccountRequest,
+        GatewayError, GetAccountDcdtRolesRequest, GetAccountDcdtTokensRequest, GetAccountRequest,
         GetAccountStorageRequest,
     },
 };
@@ -13,7 +12,7 @@ use super::GatewayHttpProxy;
 
 impl GatewayHttpProxy {
     // get_account retrieves an account info from the network (nonce, balance)
-    pub async fn get_account(&self, address: &Address) -> Result<Account> {
+    pub async fn get_account(&self, address: &Address) -> Result<Account, GatewayError> {
         self.http_request(GetAccountRequest::new(address)).await
     }
 
@@ -21,7 +20,7 @@ impl GatewayHttpProxy {
     pub async fn get_account_dcdt_roles(
         &self,
         address: &Address,
-    ) -> Result<HashMap<String, Vec<String>>> {
+    ) -> Result<HashMap<String, Vec<String>>, GatewayError> {
         self.http_request(GetAccountDcdtRolesRequest::new(address))
             .await
     }
@@ -30,7 +29,7 @@ impl GatewayHttpProxy {
     pub async fn get_account_dcdt_tokens(
         &self,
         address: &Address,
-    ) -> Result<HashMap<String, DcdtBalance>> {
+    ) -> Result<HashMap<String, DcdtBalance>, GatewayError> {
         self.http_request(GetAccountDcdtTokensRequest::new(address))
             .await
     }
@@ -39,7 +38,7 @@ impl GatewayHttpProxy {
     pub async fn get_account_storage_keys(
         &self,
         address: &Address,
-    ) -> Result<HashMap<String, String>> {
+    ) -> Result<HashMap<String, String>, GatewayError> {
         self.http_request(GetAccountStorageRequest::new(address))
             .await
     }
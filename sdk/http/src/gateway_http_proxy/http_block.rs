@@ -1,25 +1,24 @@
-use anyhow::Result;
 use dharitri_sdk::{
     data::hyperblock::HyperBlock,
-    gateway::{GetHyperBlockRequest, NetworkStatusRequest},
+    gateway::{GatewayError, GetHyperBlockRequest, NetworkStatusRequest},
 };
 
 use super::GatewayHttpProxy;
 
 impl GatewayHttpProxy {
     // get_hyper_block_by_hash retrieves a hyper block's info by hash from the network
-    pub async fn get_hyper_block_by_hash(&self, hash: &str) -> Result<HyperBlock> {
+    pub async fn get_hyper_block_by_hash(&self, hash: &str) -> Result<HyperBlock, GatewayError> {
         self.http_request(GetHyperBlockRequest::by_hash(hash)).await
     }
 
     // get_hyper_block_by_nonce retrieves a hyper block's info by nonce from the network
-    pub async fn get_hyper_block_by_nonce(&self, nonce: u64) -> Result<HyperBlock> {
+    pub async fn get_hyper_block_by_nonce(&self, nonce: u64) -> Result<HyperBlock, GatewayError> {
         self.http_request(GetHyperBlockRequest::by_nonce(nonce))
             .await
     }
 
     // get_latest_hyper_block_nonce retrieves the latest hyper block (metachain) nonce from the network
-    pub async fn get_latest_hyper_block_nonce(&self) -> Result<u64> {
+    pub async fn get_latest_hyper_block_nonce(&self) -> Result<u64, GatewayError> {
         let network_status = self.http_request(NetworkStatusRequest::default()).await?;
         Ok(network_status.nonce)
     }
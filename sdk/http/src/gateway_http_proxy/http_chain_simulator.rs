@@ -1,31 +1,37 @@
 use super::GatewayHttpProxy;
-use anyhow::Error;
 use dharitri_sdk::{
     chain_core::types::Address,
     gateway::{
         ChainSimulatorGenerateBlocksRequest, ChainSimulatorSendFundsRequest, GatewayAsyncService,
+        GatewayError,
     },
 };
 
 impl GatewayHttpProxy {
-    pub async fn send_user_funds(&self, receiver: &Address) -> Result<String, Error> {
+    pub async fn send_user_funds(&self, receiver: &Address) -> Result<String, GatewayError> {
         self.request(ChainSimulatorSendFundsRequest::to_address(receiver))
             .await
     }
 
-    pub async fn generate_blocks(&self, num_blocks: u64) -> Result<String, Error> {
+    pub async fn generate_blocks(&self, num_blocks: u64) -> Result<String, GatewayError> {
         self.request(ChainSimulatorGenerateBlocksRequest::num_blocks(num_blocks))
             .await
     }
 
-    pub async fn generate_blocks_until_epoch(&self, epoch_number: u64) -> Result<String, Error> {
+    pub async fn generate_blocks_until_epoch(
+        &self,
+        epoch_number: u64,
+    ) -> Result<String, GatewayError> {
         self.request(ChainSimulatorGenerateBlocksRequest::until_epoch(
             epoch_number,
         ))
         .await
     }
 
-    pub async fn generate_blocks_until_tx_processed(&self, tx_hash: &str) -> Result<String, Error> {
+    pub async fn generate_blocks_until_tx_processed(
+        &self,
+        tx_hash: &str,
+    ) -> Result<String, GatewayError> {
         self.request(ChainSimulatorGenerateBlocksRequest::until_tx_processed(
             tx_hash,
         ))
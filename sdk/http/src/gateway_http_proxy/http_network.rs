@@ -1,19 +1,18 @@
-use anyhow::Result;
 use dharitri_sdk::{
     data::{network_config::NetworkConfig, network_economics::NetworkEconomics},
-    gateway::{NetworkConfigRequest, NetworkEconimicsRequest},
+    gateway::{GatewayError, NetworkConfigRequest, NetworkEconimicsRequest},
 };
 
 use super::GatewayHttpProxy;
 
 impl GatewayHttpProxy {
     // get_network_config retrieves the network configuration from the proxy
-    pub async fn get_network_config(&self) -> Result<NetworkConfig> {
+    pub async fn get_network_config(&self) -> Result<NetworkConfig, GatewayError> {
         self.http_request(NetworkConfigRequest).await
     }
 
     // get_network_economics retrieves the network economics from the proxy
-    pub async fn get_network_economics(&self) -> Result<NetworkEconomics> {
+    pub async fn get_network_economics(&self) -> Result<NetworkEconomics, GatewayError> {
         self.http_request(NetworkEconimicsRequest).await
     }
 }
@@ -1,4 +1,3 @@
-use anyhow::Result;
 use dharitri_sdk::{
     chain_core::types::Address,
     data::{
@@ -10,8 +9,8 @@ use dharitri_sdk::{
         vm::{VMQueryInput, VmValuesResponseData},
     },
     gateway::{
-        GetTxCost, GetTxInfo, GetTxProcessStatus, GetTxStatus, SendMultiTxRequest, SendTxRequest,
-        VMQueryRequest,
+        GatewayError, GetTxCost, GetTxInfo, GetTxProcessStatus, GetTxStatus, SendMultiTxRequest,
+        SendTxRequest, VMQueryRequest,
     },
 };
 
@@ -19,12 +18,18 @@ use super::GatewayHttpProxy;
 
 impl GatewayHttpProxy {
     // request_transaction_cost retrieves how many gas a transaction will consume
-    pub async fn request_transaction_cost(&self, tx: &Transaction) -> Result<TxCostResponseData> {
+    pub async fn request_transaction_cost(
+        &self,
+        tx: &Transaction,
+    ) -> Result<TxCostResponseData, GatewayError> {
         self.http_request(GetTxCost(tx)).await
     }
 
     // get_transaction_info retrieves a transaction's details from the network
-    pub async fn get_transaction_info(&self, hash: &str) -> Result<TransactionOnNetwork> {
+    pub async fn get_transaction_info(
+        &self,
+        hash: &str,
+    ) -> Result<TransactionOnNetwork, GatewayError> {
         self.http_request(GetTxInfo::new(hash)).await
     }
 
@@ -32,17 +37,20 @@ impl GatewayHttpProxy {
     pub async fn get_transaction_info_with_results(
         &self,
         hash: &str,
-    ) -> Result<TransactionOnNetwork> {
+    ) -> Result<TransactionOnNetwork, GatewayError> {
         self.http_request(GetTxInfo::new(hash).with_results()).await
     }
 
     // get_transaction_status retrieves a transaction's status from the network
-    pub async fn get_transaction_status(&self, hash: &str) -> Result<String> {
+    pub async fn get_transaction_status(&self, hash: &str) -> Result<String, GatewayError> {
         self.http_request(GetTxStatus::new(hash)).await
     }
 
     // get_transaction_process_status retrieves a transaction's status from the network using process-status API
-    pub async fn get_transaction_process_status(&self, hash: &str) -> Result<(String, String)> {
+    pub async fn get_transaction_process_status(
+        &self,
+        hash: &str,
+    ) -> Result<(String, String), GatewayError> {
         self.http_request(GetTxProcessStatus::new(hash)).await
     }
 
@@ -51,7 +59,7 @@ impl GatewayHttpProxy {
         &self,
         address: &Address,
         network_configs: &NetworkConfig,
-    ) -> Result<ArgCreateTransaction> {
+    ) -> Result<ArgCreateTransaction, GatewayError> {
         let account = self.get_account(address).await?;
 
         Ok(ArgCreateTransaction {
@@ -70,17 +78,23 @@ impl GatewayHttpProxy {
         })
     }
 
-    pub async fn send_transaction(&self, tx: &Transaction) -> Result<String> {
+    pub async fn send_transaction(&self, tx: &Transaction) -> Result<String, GatewayError> {
         self.http_request(SendTxRequest(tx)).await
     }
 
     #[allow(clippy::ptr_arg)]
-    pub async fn send_transactions(&self, txs: &Vec<Transaction>) -> Result<Vec<String>> {
+    pub async fn send_transactions(
+        &self,
+        txs: &Vec<Transaction>,
+    ) -> Result<Vec<String>, GatewayError> {
         self.http_request(SendMultiTxRequest(txs)).await
     }
 
     // execute_vmquery retrieves data from existing SC trie through the use of a VM
-    pub async fn execute_vmquery(&self, vm_request: &VMQueryInput) -> Result<VmValuesResponseData> {
+    pub async fn execute_vmquery(
+        &self,
+        vm_request: &VMQueryInput,
+    ) -> Result<VmValuesResponseData, GatewayError> {
         self.http_request(VMQueryRequest(vm_request)).await
     }
 }
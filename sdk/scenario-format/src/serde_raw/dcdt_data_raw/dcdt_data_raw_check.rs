@@ -13,7 +13,6 @@ pub struct CheckDcdtDataRaw {
     #[serde(skip_serializing_if = "CheckBytesValueRaw::is_unspecified")]
     pub last_nonce: CheckBytesValueRaw,
 
-    /// Currently not actually checked anywhere.
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub roles: Vec<String>,
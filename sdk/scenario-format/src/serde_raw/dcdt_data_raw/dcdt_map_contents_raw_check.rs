@@ -15,7 +15,9 @@ impl Serialize for CheckDcdtMapContentsRaw {
     where
         S: Serializer,
     {
-        let mut map = serializer.serialize_map(Some(self.contents.len()))?;
+        let mut map = serializer.serialize_map(Some(
+            self.contents.len() + self.other_dcdts_allowed as usize,
+        ))?;
         for (k, v) in self.contents.iter() {
             map.serialize_entry(k, v)?;
         }
@@ -32,4 +32,8 @@ pub struct DcdtInstanceRaw {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attributes: Option<ValueSubTree>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frozen: Option<ValueSubTree>,
 }